@@ -1,14 +1,18 @@
+mod ceremony;
 mod debug;
 mod keys;
 mod query;
+mod relay;
 mod tx;
 mod utils;
 mod validator;
 mod view;
 
+pub use ceremony::CeremonyCmd;
 pub use debug::DebugCmd;
 pub use keys::KeysCmd;
 pub use query::QueryCmd;
+pub use relay::RelayCmd;
 pub use tx::TxCmd;
 pub use validator::ValidatorCmd;
 pub use view::transaction_hashes::TransactionHashesCmd;
@@ -44,6 +48,12 @@ pub enum Command {
     /// Manage your wallet's keys.
     #[clap(subcommand, display_order = 500)]
     Keys(KeysCmd),
+    /// Contribute to the summoning ceremony.
+    #[clap(subcommand, display_order = 990)]
+    Ceremony(CeremonyCmd),
+    /// Relay IBC messages between two Penumbra chains, for testing without a separate relayer.
+    #[clap(subcommand, display_order = 995)]
+    Relay(RelayCmd),
     /// Manage a validator.
     #[clap(subcommand, display_order = 998)]
     Validator(ValidatorCmd),
@@ -62,6 +72,8 @@ impl Command {
             Command::Validator(cmd) => cmd.offline(),
             Command::Query(cmd) => cmd.offline(),
             Command::Debug(cmd) => cmd.offline(),
+            Command::Ceremony(cmd) => cmd.offline(),
+            Command::Relay(cmd) => cmd.offline(),
         }
     }
 }
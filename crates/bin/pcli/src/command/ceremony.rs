@@ -0,0 +1,124 @@
+use anyhow::{anyhow, Context, Result};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_proof_setup::all::{Phase2CeremonyContribution, Phase2RawCeremonyCRS};
+use penumbra_proto::tools::summoning::v1alpha1::{
+    ceremony_coordinator_service_client::CeremonyCoordinatorServiceClient, participate_request,
+    participate_response, ParticipateRequest,
+};
+use rand_core::OsRng;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::{Channel, ClientTlsConfig};
+use url::Url;
+
+use crate::App;
+
+/// The largest message we accept, which needs to fit the CRS elements of every circuit.
+const MAX_MESSAGE_SIZE: usize = 1 << 30;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum CeremonyCmd {
+    /// Contribute to the ceremony, waiting in the coordinator's queue for our turn.
+    Contribute {
+        /// The URL of the ceremony coordinator.
+        #[clap(long)]
+        coordinator_url: Url,
+        /// The address index to identify ourselves to the coordinator with.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+}
+
+impl CeremonyCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            CeremonyCmd::Contribute { .. } => true,
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            CeremonyCmd::Contribute {
+                coordinator_url,
+                source,
+            } => {
+                let address = app
+                    .fvk
+                    .incoming()
+                    .payment_address(AddressIndex::new(*source))
+                    .0;
+
+                let channel = match coordinator_url.scheme() {
+                    "http" => Channel::from_shared(coordinator_url.to_string())?,
+                    "https" => Channel::from_shared(coordinator_url.to_string())?
+                        .tls_config(ClientTlsConfig::new())?,
+                    other => return Err(anyhow!("unknown url scheme {other}")),
+                }
+                .connect()
+                .await
+                .with_context(|| format!("could not connect to {coordinator_url}"))?;
+                let mut client = CeremonyCoordinatorServiceClient::new(channel)
+                    .max_decoding_message_size(MAX_MESSAGE_SIZE)
+                    .max_encoding_message_size(MAX_MESSAGE_SIZE);
+
+                let (tx, rx) = mpsc::channel(8);
+                tx.send(ParticipateRequest {
+                    msg: Some(participate_request::Msg::Identify(
+                        participate_request::Identify {
+                            address: Some(address.into()),
+                        },
+                    )),
+                })
+                .await?;
+                let mut responses = client
+                    .participate(ReceiverStream::new(rx))
+                    .await?
+                    .into_inner();
+
+                println!("connected to the coordinator as {address}");
+                while let Some(response) = responses.message().await? {
+                    match response.msg {
+                        Some(participate_response::Msg::Position(position)) => {
+                            println!(
+                                "waiting in queue: position {} of {}",
+                                position.position, position.connected_participants
+                            );
+                        }
+                        Some(participate_response::Msg::ContributeNow(contribute_now)) => {
+                            println!("it's our turn, making a contribution...");
+                            let parent = Phase2RawCeremonyCRS::try_from(
+                                contribute_now
+                                    .parent
+                                    .ok_or_else(|| anyhow!("coordinator sent no parent"))?,
+                            )?
+                            .assume_valid();
+                            let contribution = tokio::task::spawn_blocking(move || {
+                                Phase2CeremonyContribution::make(&mut OsRng, &parent)
+                            })
+                            .await?;
+                            tx.send(ParticipateRequest {
+                                msg: Some(participate_request::Msg::Contribution(
+                                    contribution.try_into()?,
+                                )),
+                            })
+                            .await?;
+                            println!("contribution sent, waiting for confirmation...");
+                        }
+                        Some(participate_response::Msg::Confirm(confirm)) => {
+                            println!(
+                                "contribution accepted in slot {}, thank you for participating!",
+                                confirm.slot
+                            );
+                            return Ok(());
+                        }
+                        None => return Err(anyhow!("coordinator sent an empty message")),
+                    }
+                }
+
+                Err(anyhow!(
+                    "coordinator closed the connection before confirming a contribution"
+                ))
+            }
+        }
+    }
+}
@@ -1,13 +1,19 @@
 use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 //use penumbra_app::dao;
-use penumbra_dao::component::state_key;
+use penumbra_dao::{component::state_key, DaoDisbursement};
 
-use penumbra_asset::{asset, Value};
+use penumbra_asset::{asset, asset::DenomMetadata, Value};
+use penumbra_dex::lp::{position::Position, LpNft};
 use penumbra_num::Amount;
+use penumbra_proto::client::v1alpha1::{DenomMetadataByIdRequest, LiquidityPositionByIdRequest};
 use penumbra_view::ViewClient;
 
-use crate::{command::query::dao, App};
+use crate::{
+    command::{query::dao, utils::render_positions},
+    App,
+};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum DaoCmd {
@@ -16,12 +22,21 @@ pub enum DaoCmd {
         /// Get only the balance of the specified asset.
         asset: Option<String>,
     },
+    /// List the recurring disbursements scheduled by passed DAO disbursement proposals.
+    Disbursements {
+        /// Show only the disbursement scheduled by the specified proposal.
+        proposal: Option<u64>,
+    },
+    /// List the liquidity positions whose NFTs are held by the DAO.
+    Positions,
 }
 
 impl DaoCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
             DaoCmd::Balance { asset } => self.print_balance(app, asset).await,
+            DaoCmd::Disbursements { proposal } => self.print_disbursements(app, *proposal).await,
+            DaoCmd::Positions => self.print_positions(app).await,
         }
     }
 
@@ -59,9 +74,8 @@ impl DaoCmd {
                 let asset_id: asset::Id = key
                     .rsplit('/')
                     .next()
-                    .expect("valid key")
-                    .parse()
-                    .expect("valid asset ID");
+                    .with_context(|| format!("invalid DAO balance key {key}"))?
+                    .parse()?;
                 let value = Value { asset_id, amount };
                 let value_str = value.format(&asset_cache);
                 println!("{value_str}");
@@ -70,4 +84,109 @@ impl DaoCmd {
 
         Ok(())
     }
+
+    pub async fn print_disbursements(&self, app: &mut App, proposal: Option<u64>) -> Result<()> {
+        let mut client = app.specific_client().await?;
+        let asset_cache = app.view().assets().await?;
+
+        let disbursements: Vec<DaoDisbursement> = if let Some(proposal) = proposal {
+            let key = state_key::disbursement(proposal);
+            vec![client
+                .key_domain(&key)
+                .await?
+                .context(format!("No disbursement scheduled by proposal {proposal}"))?]
+        } else {
+            let prefix = state_key::all_disbursements();
+            client
+                .prefix_domain(prefix)
+                .await?
+                .map_ok(|(_key, disbursement)| disbursement)
+                .try_collect()
+                .await?
+        };
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Proposal",
+            "Value",
+            "Every",
+            "Next Epoch",
+            "Remaining Payouts",
+            "Remaining Value",
+            "Recipient",
+        ]);
+        for disbursement in disbursements {
+            table.add_row(vec![
+                format!("#{}", disbursement.proposal),
+                disbursement.value.format(&asset_cache),
+                format!("{} epochs", disbursement.epoch_interval),
+                disbursement.next_epoch.to_string(),
+                disbursement.payouts_remaining.to_string(),
+                disbursement.value_remaining().format(&asset_cache),
+                disbursement.address.to_string(),
+            ]);
+        }
+        println!("{table}");
+
+        Ok(())
+    }
+
+    pub async fn print_positions(&self, app: &mut App) -> Result<()> {
+        let mut client = app.specific_client().await?;
+        let asset_cache = app.view().assets().await?;
+        let chain_id = app.view().chain_params().await?.chain_id;
+
+        let balances: Vec<(String, Amount)> = client
+            .prefix_domain(state_key::all_assets_balance())
+            .await?
+            .try_collect()
+            .await?;
+
+        let mut positions: Vec<Position> = Vec::new();
+        for (key, _amount) in balances {
+            let asset_id: asset::Id = key
+                .rsplit('/')
+                .next()
+                .with_context(|| format!("invalid DAO balance key {key}"))?
+                .parse()?;
+
+            // Only LP NFTs have position IDs in their denominations.
+            let Some(denom) = client
+                .denom_metadata_by_id(DenomMetadataByIdRequest {
+                    asset_id: Some(asset_id.into()),
+                    chain_id: chain_id.clone(),
+                })
+                .await?
+                .into_inner()
+                .denom_metadata
+            else {
+                continue;
+            };
+            let Ok(nft) = LpNft::try_from(DenomMetadata::try_from(denom)?) else {
+                continue;
+            };
+
+            // The DAO may hold the NFTs of a position in several states, but only the
+            // position's current state matters.
+            if positions.iter().any(|p| p.id() == nft.position_id()) {
+                continue;
+            }
+            let position: Position = client
+                .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                    chain_id: chain_id.clone(),
+                    position_id: Some(nft.position_id().into()),
+                })
+                .await?
+                .into_inner()
+                .data
+                .with_context(|| format!("position {} not found", nft.position_id()))?
+                .try_into()?;
+            positions.push(position);
+        }
+
+        println!("{}", render_positions(&asset_cache, &positions));
+
+        Ok(())
+    }
 }
@@ -8,11 +8,12 @@ use penumbra_dex::{
     lp::position::{self, Position},
     BatchSwapOutputData, DirectedTradingPair, SwapExecution, TradingPair,
 };
+use penumbra_num::Amount;
 use penumbra_proto::client::v1alpha1::{
     specific_query_service_client::SpecificQueryServiceClient, ArbExecutionRequest,
     BatchSwapOutputDataRequest, DenomMetadataByIdRequest, LiquidityPositionByIdRequest,
     LiquidityPositionsByPriceRequest, LiquidityPositionsRequest, SimulateTradeRequest,
-    SwapExecutionRequest,
+    SimulateTradeResponse, SwapExecutionRequest,
 };
 use penumbra_view::ViewClient;
 use tonic::transport::Channel;
@@ -83,6 +84,14 @@ pub enum DexCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
+        /// The minimum output to accept, written as a typed value of the `into` denomination,
+        /// e.g. `10gm`.
+        #[clap(long, display_order = 150)]
+        min_output: Option<String>,
+        /// Other input amounts to simulate, to show how the price impact grows with the
+        /// size of the trade, e.g. `1penumbra,10penumbra,100penumbra`.
+        #[clap(long, value_delimiter = ',', display_order = 200)]
+        ladder: Vec<String>,
     },
 }
 
@@ -143,28 +152,31 @@ impl DexCmd {
             .context("cannot parse batch swap output data")
     }
 
+    /// Simulate a trade, also simulating it with each input of the `input_ladder`.
     pub async fn get_simulated_execution(
         &self,
         app: &mut App,
         input: Value,
         output: asset::Id,
-    ) -> Result<SwapExecution> {
+        min_output: Option<Amount>,
+        input_ladder: Vec<Amount>,
+    ) -> Result<SimulateTradeResponse> {
         use penumbra_proto::client::v1alpha1::simulate_trade_request::{routing::Setting, Routing};
         let mut client = app.specific_client().await?;
-        client
+        let response = client
             .simulate_trade(SimulateTradeRequest {
                 input: Some(input.into()),
                 output: Some(output.into()),
                 routing: Some(Routing {
                     setting: Some(Setting::Default(Default::default())),
                 }),
+                min_output: min_output.map(Into::into),
+                hypothetical_positions: Vec::new(),
+                input_ladder: input_ladder.into_iter().map(Into::into).collect(),
             })
             .await?
-            .into_inner()
-            .output
-            .ok_or_else(|| anyhow::anyhow!("proto response missing swap execution"))?
-            .try_into()
-            .context("cannot parse simulation response")
+            .into_inner();
+        Ok(response)
     }
 
     pub async fn get_all_liquidity_positions(
@@ -252,6 +264,7 @@ impl DexCmd {
                     offered: output,
                     desired: input,
                     fee: 0,
+                    trigger: None,
                 }
                 .price_str(&cache)
                 .expect("assets are known"),
@@ -292,6 +305,99 @@ impl DexCmd {
 
         println!("{}", table);
 
+        // If the trade was split across several routes, summarize each of them.
+        let paths = swap_execution.paths();
+        let path_count = paths.len();
+        if path_count > 1 {
+            let mut table = Table::new();
+            table.load_preset(presets::NOTHING);
+            table.set_header(vec!["", "Route", "Input", "Output", "Price"]);
+            for path in paths {
+                let route = path
+                    .route
+                    .iter()
+                    .map(|id| {
+                        cache
+                            .get(id)
+                            .map(|denom| denom.default_unit().to_string())
+                            .unwrap_or_else(|| id.to_string())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" => ");
+                table.add_row(vec![
+                    String::new(),
+                    route,
+                    path.input.format(&cache),
+                    path.output.format(&cache),
+                    price_string(path.input, path.output),
+                ]);
+            }
+            println!("Split across {} routes:\n{}", path_count, table);
+        }
+
+        Ok(())
+    }
+
+    /// Prints the price impact, unfilled input and price impact curve of a simulated trade.
+    pub async fn print_price_impact(
+        &self,
+        app: &mut App,
+        response: &SimulateTradeResponse,
+    ) -> Result<()> {
+        let cache = app.view().assets().await?;
+
+        println!(
+            "Price impact: {:.2}% across {} positions",
+            response.price_impact * 100.0,
+            response.positions_consumed.len()
+        );
+
+        if let Some(output) = &response.output {
+            let input: Value = output
+                .input
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("proto response missing swap input"))?
+                .try_into()?;
+            let unfilled: Amount = response
+                .unfilled
+                .clone()
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default();
+            if unfilled > Amount::zero() {
+                let unfilled = Value {
+                    amount: unfilled,
+                    asset_id: input.asset_id,
+                };
+                println!("Unfilled: {}", unfilled.format(&cache));
+            }
+        }
+
+        if !response.ladder.is_empty() {
+            let mut table = Table::new();
+            table.load_preset(presets::NOTHING);
+            table.set_header(vec!["", "Input", "Output", "Price impact"]);
+            for step in &response.ladder {
+                let input: Value = step
+                    .input
+                    .clone()
+                    .ok_or_else(|| anyhow::anyhow!("ladder step missing input"))?
+                    .try_into()?;
+                let output: Value = step
+                    .output
+                    .clone()
+                    .ok_or_else(|| anyhow::anyhow!("ladder step missing output"))?
+                    .try_into()?;
+                table.add_row(vec![
+                    String::new(),
+                    input.format(&cache),
+                    output.format(&cache),
+                    format!("{:.2}%", step.price_impact * 100.0),
+                ]);
+            }
+            println!("{}", table);
+        }
+
         Ok(())
     }
 
@@ -390,12 +496,54 @@ impl DexCmd {
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
-            DexCmd::Simulate { input, into } => {
+            DexCmd::Simulate {
+                input,
+                into,
+                min_output,
+                ladder,
+            } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
+                let min_output = min_output
+                    .as_ref()
+                    .map(|min_output| {
+                        let min_output = min_output.parse::<Value>()?;
+                        if min_output.asset_id != into.id() {
+                            anyhow::bail!(
+                                "minimum output must be denominated in the asset being swapped into"
+                            );
+                        }
+                        Ok(min_output.amount)
+                    })
+                    .transpose()?;
+
+                let ladder = ladder
+                    .iter()
+                    .map(|amount| {
+                        let amount = amount.parse::<Value>()?;
+                        if amount.asset_id != input.asset_id {
+                            anyhow::bail!(
+                                "ladder amounts must be denominated in the asset being swapped"
+                            );
+                        }
+                        Ok(amount.amount)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
 
-                let swap_execution = self.get_simulated_execution(app, input, into.id()).await?;
+                let response = self
+                    .get_simulated_execution(app, input, into.id(), min_output, ladder)
+                    .await?;
+                let swap_execution: SwapExecution = response
+                    .output
+                    .clone()
+                    .ok_or_else(|| anyhow::anyhow!("proto response missing swap execution"))?
+                    .try_into()
+                    .context("cannot parse simulation response")?;
                 self.print_swap_execution(app, &swap_execution).await?;
+                self.print_price_impact(app, &response).await?;
+                if response.below_min_output {
+                    println!("The output is below the minimum output, so a swap with it would be refunded.");
+                }
             }
             DexCmd::AllPositions { include_closed } => {
                 let client = app.specific_client().await?;
@@ -0,0 +1,742 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use ibc_proto::ibc::core::{
+    channel::v1::{
+        Channel as ChannelEnd, Counterparty as ChannelCounterparty,
+        MsgAcknowledgement as RawMsgAcknowledgement, MsgChannelOpenAck as RawMsgChannelOpenAck,
+        MsgChannelOpenConfirm as RawMsgChannelOpenConfirm,
+        MsgChannelOpenInit as RawMsgChannelOpenInit, MsgChannelOpenTry as RawMsgChannelOpenTry,
+        MsgRecvPacket as RawMsgRecvPacket, MsgTimeout as RawMsgTimeout, Order, Packet,
+        State as ChannelState,
+    },
+    client::v1::{MsgCreateClient as RawMsgCreateClient, MsgUpdateClient as RawMsgUpdateClient},
+    commitment::v1::MerklePrefix,
+    connection::v1::{
+        Counterparty as ConnectionCounterparty, MsgConnectionOpenAck as RawMsgConnectionOpenAck,
+        MsgConnectionOpenConfirm as RawMsgConnectionOpenConfirm,
+        MsgConnectionOpenInit as RawMsgConnectionOpenInit,
+        MsgConnectionOpenTry as RawMsgConnectionOpenTry,
+    },
+};
+use penumbra_chain::component::PENUMBRA_COMMITMENT_PREFIX;
+use penumbra_ibc::IbcAction;
+use penumbra_proto::client::v1alpha1::Event;
+use url::Url;
+
+use crate::App;
+
+mod chain;
+
+use chain::{attribute, Chain, Proof};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum RelayCmd {
+    /// Create light clients of two Penumbra chains on each other, then open a connection and a
+    /// channel between them.
+    CreatePath {
+        /// The gRPC URL of the first chain's pd.
+        #[clap(long)]
+        chain_a: Url,
+        /// The gRPC URL of the second chain's pd.
+        #[clap(long)]
+        chain_b: Url,
+        /// The port to open the channel on, on both chains.
+        #[clap(long, default_value = "transfer")]
+        port: String,
+        /// The channel version to propose.
+        #[clap(long, default_value = "ics20-1")]
+        version: String,
+        /// Open an ordered channel, rather than an unordered one.
+        #[clap(long)]
+        ordered: bool,
+    },
+    /// Relay packets, acknowledgements and timeouts over a channel between two Penumbra chains,
+    /// until interrupted.
+    Start {
+        /// The gRPC URL of the first chain's pd.
+        #[clap(long)]
+        chain_a: Url,
+        /// The gRPC URL of the second chain's pd.
+        #[clap(long)]
+        chain_b: Url,
+        /// The channel to relay over, as identified on the first chain.
+        #[clap(long)]
+        channel: String,
+        /// The port of the channel on the first chain.
+        #[clap(long, default_value = "transfer")]
+        port: String,
+        /// How long to wait between checks for new packets, in seconds.
+        #[clap(long, default_value = "1")]
+        poll_interval: u64,
+        /// The height to start scanning both chains for packets and acknowledgements at, rather
+        /// than their first block. Packets sent and acknowledgements written below it are not
+        /// relayed.
+        #[clap(long)]
+        start_height: Option<u64>,
+    },
+}
+
+impl RelayCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            RelayCmd::CreatePath { .. } => true,
+            RelayCmd::Start { .. } => true,
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            RelayCmd::CreatePath {
+                chain_a,
+                chain_b,
+                port,
+                version,
+                ordered,
+            } => {
+                let mut a = Chain::connect(chain_a, app.fvk.clone()).await?;
+                let mut b = Chain::connect(chain_b, app.fvk.clone()).await?;
+
+                let client_a = create_client(&mut a, &mut b).await?;
+                println!(
+                    "created client {client_a} of {} on {}",
+                    b.chain_id, a.chain_id
+                );
+                let client_b = create_client(&mut b, &mut a).await?;
+                println!(
+                    "created client {client_b} of {} on {}",
+                    a.chain_id, b.chain_id
+                );
+
+                let (connection_a, connection_b) =
+                    open_connection(&mut a, &mut b, &client_a, &client_b).await?;
+                println!(
+                    "opened connection {connection_a} on {} to {connection_b} on {}",
+                    a.chain_id, b.chain_id
+                );
+
+                let ordering = if *ordered {
+                    Order::Ordered
+                } else {
+                    Order::Unordered
+                };
+                let (channel_a, channel_b) = open_channel(
+                    &mut a,
+                    &mut b,
+                    Path {
+                        client_a: &client_a,
+                        client_b: &client_b,
+                        port_a: port,
+                        port_b: port,
+                    },
+                    &connection_a,
+                    version,
+                    ordering,
+                )
+                .await?;
+                println!(
+                    "opened channel {channel_a} on {} to {channel_b} on {}",
+                    a.chain_id, b.chain_id
+                );
+            }
+            RelayCmd::Start {
+                chain_a,
+                chain_b,
+                channel,
+                port,
+                poll_interval,
+                start_height,
+            } => {
+                let mut a = Chain::connect(chain_a, app.fvk.clone()).await?;
+                let mut b = Chain::connect(chain_b, app.fvk.clone()).await?;
+                if let Some(height) = start_height {
+                    a.start_scan_at(*height);
+                    b.start_scan_at(*height);
+                }
+
+                let (channel_end, _) = a.channel_with_proof(port, channel).await?;
+                let counterparty = channel_end
+                    .counterparty
+                    .context("channel has no counterparty")?;
+                let connection_id = channel_end
+                    .connection_hops
+                    .first()
+                    .context("channel has no connection")?;
+                let (connection_end, _) = a.connection_with_proof(connection_id).await?;
+                let client_b = connection_end
+                    .counterparty
+                    .context("connection has no counterparty")?
+                    .client_id;
+                let ordered = channel_end.ordering == Order::Ordered as i32;
+
+                let client_a = connection_end.client_id;
+                let path = Path {
+                    client_a: &client_a,
+                    client_b: &client_b,
+                    port_a: port,
+                    port_b: &counterparty.port_id,
+                };
+                let (channel_a, channel_b) = (channel.as_str(), counterparty.channel_id.as_str());
+
+                println!(
+                    "relaying between {channel_a} on {} and {channel_b} on {}",
+                    a.chain_id, b.chain_id
+                );
+                loop {
+                    if let Err(e) =
+                        relay_packets(&mut a, &mut b, &path, channel_a, channel_b, ordered).await
+                    {
+                        tracing::warn!(?e, "error relaying from {} to {}", a.chain_id, b.chain_id);
+                    }
+                    if let Err(e) = relay_packets(
+                        &mut b,
+                        &mut a,
+                        &path.reverse(),
+                        channel_b,
+                        channel_a,
+                        ordered,
+                    )
+                    .await
+                    {
+                        tracing::warn!(?e, "error relaying from {} to {}", b.chain_id, a.chain_id);
+                    }
+                    tokio::time::sleep(Duration::from_secs(*poll_interval)).await;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The clients and ports joining a chain "a" to a chain "b", where each client lives on the
+/// chain it's named after and tracks the other.
+struct Path<'a> {
+    client_a: &'a str,
+    client_b: &'a str,
+    port_a: &'a str,
+    port_b: &'a str,
+}
+
+impl<'a> Path<'a> {
+    fn reverse(&self) -> Path<'a> {
+        Path {
+            client_a: self.client_b,
+            client_b: self.client_a,
+            port_a: self.port_b,
+            port_b: self.port_a,
+        }
+    }
+}
+
+/// Creates a client of `counterparty` on `host`, returning its identifier.
+async fn create_client(host: &mut Chain, counterparty: &mut Chain) -> Result<String> {
+    let (height, _) = counterparty.latest_block().await?;
+    let msg = RawMsgCreateClient {
+        client_state: Some(counterparty.client_state(height)?),
+        consensus_state: Some(counterparty.consensus_state(height).await?),
+        signer: host.signer(),
+    };
+    let events = host
+        .submit(vec![IbcAction::CreateClient(msg.try_into()?)])
+        .await?;
+    event_attribute(&events, "create_client", "client_id")
+}
+
+/// Returns a message updating `host`'s client of `counterparty` so that it can verify proofs
+/// made against `proof`, or `None` if it already can.
+async fn update_client(
+    host: &mut Chain,
+    counterparty: &mut Chain,
+    client_id: &str,
+    proof: &Proof,
+) -> Result<Option<IbcAction>> {
+    let target = counterparty.proof_height(proof);
+    if host.has_consensus_state(client_id, &target).await? {
+        return Ok(None);
+    }
+
+    let trusted = host
+        .tendermint_client_state(client_id)
+        .await?
+        .latest_height
+        .context("client state has no latest height")?
+        .revision_height;
+    if trusted >= target.revision_height {
+        anyhow::bail!(
+            "client {client_id} on {} is already past height {}, try again with a newer proof",
+            host.chain_id,
+            target.revision_height
+        );
+    }
+
+    let msg = RawMsgUpdateClient {
+        client_id: client_id.to_string(),
+        client_message: Some(
+            counterparty
+                .client_header(trusted, target.revision_height)
+                .await?,
+        ),
+        signer: host.signer(),
+    };
+    Ok(Some(IbcAction::UpdateClient(msg.try_into()?)))
+}
+
+/// Submits `actions` to `host`, preceded by an update of its client of `counterparty` if one is
+/// needed to verify `proof`.
+async fn submit_with_update(
+    host: &mut Chain,
+    counterparty: &mut Chain,
+    client_id: &str,
+    proof: &Proof,
+    actions: Vec<IbcAction>,
+) -> Result<Vec<Event>> {
+    let update = update_client(host, counterparty, client_id, proof).await?;
+    host.submit(update.into_iter().chain(actions).collect())
+        .await
+}
+
+/// Runs the connection handshake between `a` and `b`, returning the identifiers of the
+/// connection on each.
+async fn open_connection(
+    a: &mut Chain,
+    b: &mut Chain,
+    client_a: &str,
+    client_b: &str,
+) -> Result<(String, String)> {
+    let prefix = Some(MerklePrefix {
+        key_prefix: PENUMBRA_COMMITMENT_PREFIX.key_prefix.clone(),
+    });
+
+    let init = RawMsgConnectionOpenInit {
+        client_id: client_a.to_string(),
+        counterparty: Some(ConnectionCounterparty {
+            client_id: client_b.to_string(),
+            connection_id: String::new(),
+            prefix: prefix.clone(),
+        }),
+        version: None,
+        delay_period: 0,
+        signer: a.signer(),
+    };
+    let events = a
+        .submit(vec![IbcAction::ConnectionOpenInit(init.try_into()?)])
+        .await?;
+    let connection_a = event_attribute(&events, "connection_open_init", "connection_id")?;
+
+    // The counterparty checks the connection end, client state and consensus state all against
+    // a single proof height, so retry until we get a consistent set of proofs.
+    let (connection_end_a, client_state, consensus_height, proofs) = loop {
+        let (connection_end, proof_init) = a.connection_with_proof(&connection_a).await?;
+        let (client_state, proof_client) = a.client_state_with_proof(client_a).await?;
+        let consensus_height = a
+            .tendermint_client_state(client_a)
+            .await?
+            .latest_height
+            .context("client state has no latest height")?;
+        let (_, proof_consensus) = a
+            .consensus_state_with_proof(client_a, &consensus_height)
+            .await?;
+        if proof_init.height == proof_client.height && proof_init.height == proof_consensus.height {
+            break (
+                connection_end,
+                client_state,
+                consensus_height,
+                [proof_init, proof_client, proof_consensus],
+            );
+        }
+    };
+    let [proof_init, proof_client, proof_consensus] = proofs;
+
+    let try_ = RawMsgConnectionOpenTry {
+        client_id: client_b.to_string(),
+        client_state: Some(client_state),
+        counterparty: Some(ConnectionCounterparty {
+            client_id: client_a.to_string(),
+            connection_id: connection_a.clone(),
+            prefix: prefix.clone(),
+        }),
+        delay_period: 0,
+        counterparty_versions: connection_end_a.versions,
+        proof_height: Some(a.proof_height(&proof_init)),
+        proof_init: proof_init.bytes.clone(),
+        proof_client: proof_client.bytes,
+        proof_consensus: proof_consensus.bytes,
+        consensus_height: Some(consensus_height),
+        signer: b.signer(),
+        ..Default::default()
+    };
+    let events = submit_with_update(
+        b,
+        a,
+        client_b,
+        &proof_init,
+        vec![IbcAction::ConnectionOpenTry(try_.try_into()?)],
+    )
+    .await?;
+    let connection_b = event_attribute(&events, "connection_open_try", "connection_id")?;
+
+    let (connection_end_b, client_state, consensus_height, proofs) = loop {
+        let (connection_end, proof_try) = b.connection_with_proof(&connection_b).await?;
+        let (client_state, proof_client) = b.client_state_with_proof(client_b).await?;
+        let consensus_height = b
+            .tendermint_client_state(client_b)
+            .await?
+            .latest_height
+            .context("client state has no latest height")?;
+        let (_, proof_consensus) = b
+            .consensus_state_with_proof(client_b, &consensus_height)
+            .await?;
+        if proof_try.height == proof_client.height && proof_try.height == proof_consensus.height {
+            break (
+                connection_end,
+                client_state,
+                consensus_height,
+                [proof_try, proof_client, proof_consensus],
+            );
+        }
+    };
+    let [proof_try, proof_client, proof_consensus] = proofs;
+
+    let ack = RawMsgConnectionOpenAck {
+        connection_id: connection_a.clone(),
+        counterparty_connection_id: connection_b.clone(),
+        version: connection_end_b.versions.into_iter().next(),
+        client_state: Some(client_state),
+        proof_height: Some(b.proof_height(&proof_try)),
+        proof_try: proof_try.bytes.clone(),
+        proof_client: proof_client.bytes,
+        proof_consensus: proof_consensus.bytes,
+        consensus_height: Some(consensus_height),
+        signer: a.signer(),
+        ..Default::default()
+    };
+    submit_with_update(
+        a,
+        b,
+        client_a,
+        &proof_try,
+        vec![IbcAction::ConnectionOpenAck(ack.try_into()?)],
+    )
+    .await?;
+
+    let (_, proof_ack) = a.connection_with_proof(&connection_a).await?;
+    let confirm = RawMsgConnectionOpenConfirm {
+        connection_id: connection_b.clone(),
+        proof_height: Some(a.proof_height(&proof_ack)),
+        proof_ack: proof_ack.bytes.clone(),
+        signer: b.signer(),
+    };
+    submit_with_update(
+        b,
+        a,
+        client_b,
+        &proof_ack,
+        vec![IbcAction::ConnectionOpenConfirm(confirm.try_into()?)],
+    )
+    .await?;
+
+    Ok((connection_a, connection_b))
+}
+
+/// Runs the channel handshake between `a` and `b` over the connection `connection_a` (as
+/// identified on `a`), returning the identifiers of the channel on each.
+async fn open_channel(
+    a: &mut Chain,
+    b: &mut Chain,
+    path: Path<'_>,
+    connection_a: &str,
+    version: &str,
+    ordering: Order,
+) -> Result<(String, String)> {
+    let (connection_end_a, _) = a.connection_with_proof(connection_a).await?;
+    let connection_b = connection_end_a
+        .counterparty
+        .context("connection has no counterparty")?
+        .connection_id;
+
+    let init = RawMsgChannelOpenInit {
+        port_id: path.port_a.to_string(),
+        channel: Some(ChannelEnd {
+            state: ChannelState::Init as i32,
+            ordering: ordering as i32,
+            counterparty: Some(ChannelCounterparty {
+                port_id: path.port_b.to_string(),
+                channel_id: String::new(),
+            }),
+            connection_hops: vec![connection_a.to_string()],
+            version: version.to_string(),
+        }),
+        signer: a.signer(),
+    };
+    let events = a
+        .submit(vec![IbcAction::ChannelOpenInit(init.try_into()?)])
+        .await?;
+    let channel_a = event_attribute(&events, "channel_open_init", "channel_id")?;
+
+    let (channel_end_a, proof_init) = a.channel_with_proof(path.port_a, &channel_a).await?;
+    let try_ = RawMsgChannelOpenTry {
+        port_id: path.port_b.to_string(),
+        channel: Some(ChannelEnd {
+            state: ChannelState::Tryopen as i32,
+            ordering: ordering as i32,
+            counterparty: Some(ChannelCounterparty {
+                port_id: path.port_a.to_string(),
+                channel_id: channel_a.clone(),
+            }),
+            connection_hops: vec![connection_b],
+            version: channel_end_a.version.clone(),
+        }),
+        counterparty_version: channel_end_a.version,
+        proof_height: Some(a.proof_height(&proof_init)),
+        proof_init: proof_init.bytes.clone(),
+        signer: b.signer(),
+        ..Default::default()
+    };
+    let events = submit_with_update(
+        b,
+        a,
+        path.client_b,
+        &proof_init,
+        vec![IbcAction::ChannelOpenTry(try_.try_into()?)],
+    )
+    .await?;
+    let channel_b = event_attribute(&events, "channel_open_try", "channel_id")?;
+
+    let (channel_end_b, proof_try) = b.channel_with_proof(path.port_b, &channel_b).await?;
+    let ack = RawMsgChannelOpenAck {
+        port_id: path.port_a.to_string(),
+        channel_id: channel_a.clone(),
+        counterparty_channel_id: channel_b.clone(),
+        counterparty_version: channel_end_b.version,
+        proof_height: Some(b.proof_height(&proof_try)),
+        proof_try: proof_try.bytes.clone(),
+        signer: a.signer(),
+    };
+    submit_with_update(
+        a,
+        b,
+        path.client_a,
+        &proof_try,
+        vec![IbcAction::ChannelOpenAck(ack.try_into()?)],
+    )
+    .await?;
+
+    let (_, proof_ack) = a.channel_with_proof(path.port_a, &channel_a).await?;
+    let confirm = RawMsgChannelOpenConfirm {
+        port_id: path.port_b.to_string(),
+        channel_id: channel_b.clone(),
+        proof_height: Some(a.proof_height(&proof_ack)),
+        proof_ack: proof_ack.bytes.clone(),
+        signer: b.signer(),
+    };
+    submit_with_update(
+        b,
+        a,
+        path.client_b,
+        &proof_ack,
+        vec![IbcAction::ChannelOpenConfirm(confirm.try_into()?)],
+    )
+    .await?;
+
+    Ok((channel_a, channel_b))
+}
+
+/// Relays the packets sent from `src` over `src_channel` to `dst`, or times them out on `src`
+/// if they can no longer be received, then relays the acknowledgements `src` has written for
+/// packets it received from `dst` over it.
+async fn relay_packets(
+    src: &mut Chain,
+    dst: &mut Chain,
+    path: &Path<'_>,
+    src_channel: &str,
+    dst_channel: &str,
+    ordered: bool,
+) -> Result<()> {
+    src.scan().await?;
+    dst.scan().await?;
+
+    let pending = src.packet_commitments(path.port_a, src_channel).await?;
+    let sent = src.sent_packets(path.port_a, src_channel, &pending);
+    let unreceived = dst
+        .unreceived_packets(path.port_b, dst_channel, pending)
+        .await?;
+    let (dst_height, dst_time) = dst.latest_block().await?;
+
+    let mut receives = Vec::new();
+    let mut timeouts = Vec::new();
+    for sequence in unreceived {
+        let Some(packet) = sent.get(&sequence) else {
+            tracing::debug!(sequence, "no send_packet event found for pending packet");
+            continue;
+        };
+        if timed_out(packet, dst_height, dst_time) {
+            timeouts.push(packet.clone());
+        } else {
+            receives.push(packet.clone());
+        }
+    }
+
+    // Each proof is checked at the height it claims, so all the receives in one transaction
+    // need proofs from the same height; any others are picked up on the next pass.
+    let mut proven = Vec::new();
+    for packet in receives {
+        let proof = src
+            .packet_commitment_proof(path.port_a, src_channel, packet.sequence)
+            .await?;
+        proven.push((packet, proof));
+    }
+    if let Some(height) = proven.first().map(|(_, proof)| proof.height) {
+        proven.retain(|(_, proof)| proof.height == height);
+        let count = proven.len();
+        let mut actions = Vec::new();
+        for (packet, proof) in &proven {
+            let msg = RawMsgRecvPacket {
+                packet: Some(packet.clone()),
+                proof_commitment: proof.bytes.clone(),
+                proof_height: Some(src.proof_height(proof)),
+                signer: dst.signer(),
+            };
+            actions.push(IbcAction::RecvPacket(msg.try_into()?));
+        }
+        submit_with_update(dst, src, path.client_b, &proven[0].1, actions).await?;
+        println!(
+            "relayed {count} packet(s) from {} to {}",
+            src.chain_id, dst.chain_id
+        );
+    }
+
+    for packet in timeouts {
+        let (next_sequence_recv, proof) = if ordered {
+            dst.next_sequence_receive_with_proof(path.port_b, dst_channel)
+                .await?
+        } else {
+            let proof = dst
+                .packet_receipt_proof(path.port_b, dst_channel, packet.sequence)
+                .await?;
+            (packet.sequence, proof)
+        };
+        let sequence = packet.sequence;
+        let msg = RawMsgTimeout {
+            proof_height: Some(dst.proof_height(&proof)),
+            proof_unreceived: proof.bytes.clone(),
+            next_sequence_recv,
+            packet: Some(packet),
+            signer: src.signer(),
+        };
+        submit_with_update(
+            src,
+            dst,
+            path.client_a,
+            &proof,
+            vec![IbcAction::Timeout(msg.try_into()?)],
+        )
+        .await?;
+        println!(
+            "timed out packet {sequence} from {} to {}",
+            src.chain_id, dst.chain_id
+        );
+    }
+
+    // Acknowledgements flow the other way: `src` wrote them for packets it received from
+    // `dst`, and they are relayed back to `dst`.
+    let written = src.written_acks(path.port_a, src_channel);
+    let awaiting = dst
+        .unreceived_acks(path.port_b, dst_channel, written.keys().copied().collect())
+        .await?;
+    for sequence in written.keys() {
+        if !awaiting.contains(sequence) {
+            src.forget_ack(path.port_a, src_channel, *sequence);
+        }
+    }
+    for sequence in awaiting {
+        let Some((packet, acknowledgement)) = written.get(&sequence).cloned() else {
+            continue;
+        };
+        let proof = src
+            .packet_acknowledgement_proof(path.port_a, src_channel, sequence)
+            .await?;
+        let msg = RawMsgAcknowledgement {
+            proof_height: Some(src.proof_height(&proof)),
+            proof_acked: proof.bytes.clone(),
+            acknowledgement,
+            packet: Some(packet),
+            signer: dst.signer(),
+        };
+        submit_with_update(
+            dst,
+            src,
+            path.client_b,
+            &proof,
+            vec![IbcAction::Acknowledgement(msg.try_into()?)],
+        )
+        .await?;
+        println!(
+            "relayed acknowledgement of packet {sequence} from {} to {}",
+            src.chain_id, dst.chain_id
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns whether `packet` can no longer be received by a chain at `height` and `time`.
+fn timed_out(packet: &Packet, height: u64, time: u64) -> bool {
+    let height_passed = packet
+        .timeout_height
+        .as_ref()
+        .map(|timeout| timeout.revision_height != 0 && height >= timeout.revision_height)
+        .unwrap_or(false);
+    let time_passed = packet.timeout_timestamp != 0 && time >= packet.timeout_timestamp;
+    height_passed || time_passed
+}
+
+/// Returns the value of the attribute `key` of the first event of kind `kind` in `events`.
+fn event_attribute(events: &[Event], kind: &str, key: &str) -> Result<String> {
+    let event = events
+        .iter()
+        .find(|event| event.kind == kind)
+        .with_context(|| format!("transaction emitted no {kind} event"))?;
+    attribute(event, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use super::*;
+
+    fn packet(timeout_height: Option<u64>, timeout_timestamp: u64) -> Packet {
+        Packet {
+            sequence: 1,
+            timeout_height: timeout_height.map(|revision_height| Height {
+                revision_number: 0,
+                revision_height,
+            }),
+            timeout_timestamp,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn packets_time_out_at_their_timeout_height_or_timestamp() {
+        let by_height = packet(Some(100), 0);
+        assert!(!timed_out(&by_height, 99, u64::MAX));
+        assert!(timed_out(&by_height, 100, 0));
+        assert!(timed_out(&by_height, 101, 0));
+
+        let by_time = packet(None, 1_000);
+        assert!(!timed_out(&by_time, u64::MAX, 999));
+        assert!(timed_out(&by_time, 0, 1_000));
+
+        let by_either = packet(Some(100), 1_000);
+        assert!(!timed_out(&by_either, 99, 999));
+        assert!(timed_out(&by_either, 100, 999));
+        assert!(timed_out(&by_either, 99, 1_000));
+    }
+
+    #[test]
+    fn packets_without_timeouts_never_time_out() {
+        assert!(!timed_out(&packet(None, 0), u64::MAX, u64::MAX));
+        assert!(!timed_out(&packet(Some(0), 0), u64::MAX, u64::MAX));
+    }
+}
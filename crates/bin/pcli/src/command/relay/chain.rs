@@ -0,0 +1,899 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use ibc_proto::{
+    google::protobuf::{Any, Duration as ProtoDuration},
+    ibc::{
+        core::{
+            channel::v1::{
+                query_client::QueryClient as ChannelQueryClient, Channel as ChannelEnd, Packet,
+                QueryChannelRequest, QueryNextSequenceReceiveRequest,
+                QueryPacketAcknowledgementRequest, QueryPacketCommitmentRequest,
+                QueryPacketCommitmentsRequest, QueryPacketReceiptRequest,
+                QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
+            },
+            client::v1::{
+                query_client::QueryClient as ClientQueryClient, Height, QueryClientStateRequest,
+                QueryConsensusStateRequest,
+            },
+            commitment::v1::MerkleRoot,
+            connection::v1::{
+                query_client::QueryClient as ConnectionQueryClient, ConnectionEnd,
+                QueryConnectionRequest,
+            },
+        },
+        lightclients::tendermint::v1::{
+            ClientState as TendermintClientState, ConsensusState as TendermintConsensusState,
+            Fraction, Header as TendermintHeader,
+        },
+    },
+};
+use ibc_types::core::connection::ChainId;
+use ibc_types::lightclients::tendermint::{
+    client_state::TENDERMINT_CLIENT_STATE_TYPE_URL,
+    consensus_state::TENDERMINT_CONSENSUS_STATE_TYPE_URL, header::TENDERMINT_HEADER_TYPE_URL,
+};
+use penumbra_chain::component::PENUMBRA_PROOF_SPECS;
+use penumbra_ibc::IbcAction;
+use penumbra_keys::{keys::AddressIndex, FullViewingKey};
+use penumbra_proto::{
+    client::v1alpha1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
+        Event, GetBlockByHeightRequest, GetStatusRequest, GetTxRequest, GetValidatorSetRequest,
+    },
+    tendermint::types as tm,
+    DomainType, Message,
+};
+use penumbra_transaction::{
+    plan::{ActionPlan, TransactionPlan},
+    AuthorizationData, WitnessData,
+};
+use rand::Rng;
+use rand_core::OsRng;
+use sha2::{Digest, Sha256};
+use tonic::transport::{Channel, ClientTlsConfig};
+use url::Url;
+
+/// How often to poll a chain while waiting for it to reach a height or include a transaction.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How many polls to wait for a broadcast transaction to be included in a block before giving up.
+const MAX_INCLUSION_POLLS: usize = 60;
+
+/// How many heights of headers, commits and validator sets to keep cached.
+const HEADER_CACHE_SIZE: usize = 1000;
+
+/// The trusting period of the light clients we create, which must be shorter than the unbonding
+/// period: a client that goes this long without an update expires.
+const TRUSTING_PERIOD: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// The unbonding period we declare for the chains we create light clients of.
+const UNBONDING_PERIOD: Duration = Duration::from_secs(21 * 24 * 60 * 60);
+
+/// How far ahead of the verifier's clock a header's timestamp may be.
+const MAX_CLOCK_DRIFT: Duration = Duration::from_secs(60);
+
+/// A proof made against a chain's state.
+pub struct Proof {
+    /// The encoded Merkle proof.
+    pub bytes: Vec<u8>,
+    /// The height of the state the proof was made against.
+    pub height: u64,
+}
+
+impl Proof {
+    fn new(bytes: Vec<u8>, height: Option<Height>) -> Result<Self> {
+        let height = height
+            .context("query response is missing a proof height")?
+            .revision_height;
+        Ok(Self { bytes, height })
+    }
+}
+
+/// One of the two chains being relayed between: clients for its pd endpoint, and a cache of the
+/// headers, commits and validator sets it has served, which are needed to build light client
+/// updates for its counterparty.
+pub struct Chain {
+    pub chain_id: String,
+    revision_number: u64,
+    fvk: FullViewingKey,
+    signer: String,
+    tendermint: TendermintProxyServiceClient<Channel>,
+    clients: ClientQueryClient<Channel>,
+    connections: ConnectionQueryClient<Channel>,
+    channels: ChannelQueryClient<Channel>,
+    /// Headers, by height.
+    headers: BTreeMap<u64, tm::Header>,
+    /// Commits, by the height of the block they sign (which is one less than the height of the
+    /// block they were taken from).
+    commits: BTreeMap<u64, tm::Commit>,
+    /// Validator sets, by height.
+    validator_sets: BTreeMap<u64, tm::ValidatorSet>,
+    /// The height of the last block scanned for packet events, or of the block before the one
+    /// scanning starts at.
+    scanned_height: u64,
+    /// Packets sent from this chain, by source port, source channel and sequence.
+    sent_packets: BTreeMap<(String, String, u64), Packet>,
+    /// Acknowledgements written on this chain, by destination port, destination channel and
+    /// sequence of the packet they acknowledge.
+    written_acks: BTreeMap<(String, String, u64), (Packet, Vec<u8>)>,
+}
+
+impl Chain {
+    /// Connects to the pd endpoint at `url`, signing relayed messages as the first address of
+    /// `fvk`.
+    pub async fn connect(url: &Url, fvk: FullViewingKey) -> Result<Self> {
+        let channel = match url.scheme() {
+            "http" => Channel::from_shared(url.to_string())?,
+            "https" => Channel::from_shared(url.to_string())?.tls_config(ClientTlsConfig::new())?,
+            other => anyhow::bail!("unknown url scheme {other}"),
+        }
+        .connect()
+        .await
+        .with_context(|| format!("could not connect to {url}"))?;
+
+        let mut tendermint = TendermintProxyServiceClient::new(channel.clone());
+        let chain_id = tendermint
+            .get_status(GetStatusRequest::default())
+            .await?
+            .into_inner()
+            .node_info
+            .context("status response is missing node info")?
+            .network;
+
+        let signer = fvk.payment_address(AddressIndex::new(0)).0.to_string();
+
+        Ok(Self {
+            revision_number: ChainId::from_string(&chain_id).version(),
+            chain_id,
+            fvk,
+            signer,
+            tendermint,
+            clients: ClientQueryClient::new(channel.clone()),
+            connections: ConnectionQueryClient::new(channel.clone()),
+            channels: ChannelQueryClient::new(channel),
+            headers: BTreeMap::new(),
+            commits: BTreeMap::new(),
+            validator_sets: BTreeMap::new(),
+            scanned_height: 0,
+            sent_packets: BTreeMap::new(),
+            written_acks: BTreeMap::new(),
+        })
+    }
+
+    /// The signer to name in messages submitted to this chain.
+    pub fn signer(&self) -> String {
+        self.signer.clone()
+    }
+
+    /// This chain's `height`, as an IBC height.
+    pub fn ibc_height(&self, height: u64) -> Height {
+        Height {
+            revision_number: self.revision_number,
+            revision_height: height,
+        }
+    }
+
+    /// The height at which a counterparty can verify `proof`.
+    ///
+    /// Proofs are made against the state committed at a height, but the app hash of that state
+    /// is only included in the header of the next block, so that is the consensus state the
+    /// counterparty's client needs in order to verify it.
+    pub fn proof_height(&self, proof: &Proof) -> Height {
+        self.ibc_height(proof.height + 1)
+    }
+
+    /// Returns the height and timestamp (in nanoseconds) of the latest block.
+    pub async fn latest_block(&mut self) -> Result<(u64, u64)> {
+        let sync_info = self
+            .tendermint
+            .get_status(GetStatusRequest::default())
+            .await?
+            .into_inner()
+            .sync_info
+            .context("status response is missing sync info")?;
+
+        let time = sync_info
+            .latest_block_time
+            .context("status response is missing the latest block time")?;
+        let time = u64::try_from(time.seconds)? * 1_000_000_000 + u64::try_from(time.nanos)?;
+
+        Ok((sync_info.latest_block_height, time))
+    }
+
+    /// Waits until this chain has committed a block at `height`.
+    pub async fn wait_for_height(&mut self, height: u64) -> Result<()> {
+        while self.latest_block().await?.0 < height {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        Ok(())
+    }
+
+    /// Fetches the block at `height`, caching its header and the commit it carries for the
+    /// previous block.
+    async fn fetch_block(&mut self, height: u64) -> Result<tm::Block> {
+        let block = self
+            .tendermint
+            .get_block_by_height(GetBlockByHeightRequest {
+                height: height.try_into()?,
+            })
+            .await?
+            .into_inner()
+            .block
+            .with_context(|| format!("no block at height {height}"))?;
+
+        if let Some(header) = block.header.clone() {
+            self.headers.insert(height, header);
+        }
+        if let Some(commit) = block.last_commit.clone() {
+            if height > 1 {
+                self.commits.insert(height - 1, commit);
+            }
+        }
+        prune(&mut self.headers);
+        prune(&mut self.commits);
+
+        Ok(block)
+    }
+
+    async fn header(&mut self, height: u64) -> Result<tm::Header> {
+        if let Some(header) = self.headers.get(&height) {
+            return Ok(header.clone());
+        }
+        self.fetch_block(height)
+            .await?
+            .header
+            .with_context(|| format!("block at height {height} is missing its header"))
+    }
+
+    async fn commit(&mut self, height: u64) -> Result<tm::Commit> {
+        if let Some(commit) = self.commits.get(&height) {
+            return Ok(commit.clone());
+        }
+        // The commit for a block is only available once the next block has been committed.
+        self.wait_for_height(height + 1).await?;
+        self.fetch_block(height + 1)
+            .await?
+            .last_commit
+            .with_context(|| format!("block at height {} is missing its last commit", height + 1))
+    }
+
+    async fn validator_set(&mut self, height: u64) -> Result<tm::ValidatorSet> {
+        if let Some(validator_set) = self.validator_sets.get(&height) {
+            return Ok(validator_set.clone());
+        }
+        let validator_set = self
+            .tendermint
+            .get_validator_set(GetValidatorSetRequest {
+                height: height.try_into()?,
+            })
+            .await?
+            .into_inner()
+            .validator_set
+            .with_context(|| format!("no validator set at height {height}"))?;
+
+        self.validator_sets.insert(height, validator_set.clone());
+        prune(&mut self.validator_sets);
+
+        Ok(validator_set)
+    }
+
+    /// A Tendermint light client state for this chain, trusting it as of `height`.
+    pub fn client_state(&self, height: u64) -> Result<Any> {
+        let client_state = TendermintClientState {
+            chain_id: self.chain_id.clone(),
+            trust_level: Some(Fraction {
+                numerator: 1,
+                denominator: 3,
+            }),
+            trusting_period: Some(proto_duration(TRUSTING_PERIOD)),
+            unbonding_period: Some(proto_duration(UNBONDING_PERIOD)),
+            max_clock_drift: Some(proto_duration(MAX_CLOCK_DRIFT)),
+            frozen_height: None,
+            latest_height: Some(self.ibc_height(height)),
+            proof_specs: PENUMBRA_PROOF_SPECS
+                .iter()
+                .map(transcode)
+                .collect::<Result<_>>()?,
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            ..Default::default()
+        };
+
+        Ok(Any {
+            type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            value: client_state.encode_to_vec(),
+        })
+    }
+
+    /// The Tendermint light client consensus state of this chain at `height`.
+    pub async fn consensus_state(&mut self, height: u64) -> Result<Any> {
+        let header = self.header(height).await?;
+        let consensus_state = TendermintConsensusState {
+            timestamp: Some(transcode(
+                header.time.as_ref().context("header is missing its time")?,
+            )?),
+            root: Some(MerkleRoot {
+                hash: header.app_hash,
+            }),
+            next_validators_hash: header.next_validators_hash,
+        };
+
+        Ok(Any {
+            type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: consensus_state.encode_to_vec(),
+        })
+    }
+
+    /// A Tendermint light client header taking a client of this chain from `trusted_height` to
+    /// `height`.
+    pub async fn client_header(&mut self, trusted_height: u64, height: u64) -> Result<Any> {
+        let signed_header = tm::SignedHeader {
+            header: Some(self.header(height).await?),
+            commit: Some(self.commit(height).await?),
+        };
+        let validator_set = self.validator_set(height).await?;
+        // The trusted consensus state commits to the hash of the *next* validator set.
+        let trusted_validators = self.validator_set(trusted_height + 1).await?;
+
+        let header = TendermintHeader {
+            signed_header: Some(transcode(&signed_header)?),
+            validator_set: Some(transcode(&validator_set)?),
+            trusted_height: Some(self.ibc_height(trusted_height)),
+            trusted_validators: Some(transcode(&trusted_validators)?),
+        };
+
+        Ok(Any {
+            type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
+            value: header.encode_to_vec(),
+        })
+    }
+
+    /// Returns the client state of `client_id`, decoded as a Tendermint client state.
+    pub async fn tendermint_client_state(
+        &mut self,
+        client_id: &str,
+    ) -> Result<TendermintClientState> {
+        let (client_state, _) = self.client_state_with_proof(client_id).await?;
+        Ok(TendermintClientState::decode(
+            client_state.value.as_slice(),
+        )?)
+    }
+
+    /// Returns whether `client_id` has a consensus state for its counterparty at `height`.
+    pub async fn has_consensus_state(&mut self, client_id: &str, height: &Height) -> Result<bool> {
+        Ok(self
+            .clients
+            .consensus_state(QueryConsensusStateRequest {
+                client_id: client_id.to_string(),
+                revision_number: height.revision_number,
+                revision_height: height.revision_height,
+                latest_height: false,
+            })
+            .await?
+            .into_inner()
+            .consensus_state
+            .is_some())
+    }
+
+    pub async fn client_state_with_proof(&mut self, client_id: &str) -> Result<(Any, Proof)> {
+        let rsp = self
+            .clients
+            .client_state(QueryClientStateRequest {
+                client_id: client_id.to_string(),
+            })
+            .await?
+            .into_inner();
+        let client_state = rsp
+            .client_state
+            .with_context(|| format!("client {client_id} not found on {}", self.chain_id))?;
+        Ok((client_state, Proof::new(rsp.proof, rsp.proof_height)?))
+    }
+
+    pub async fn consensus_state_with_proof(
+        &mut self,
+        client_id: &str,
+        height: &Height,
+    ) -> Result<(Any, Proof)> {
+        let rsp = self
+            .clients
+            .consensus_state(QueryConsensusStateRequest {
+                client_id: client_id.to_string(),
+                revision_number: height.revision_number,
+                revision_height: height.revision_height,
+                latest_height: false,
+            })
+            .await?
+            .into_inner();
+        let consensus_state = rsp.consensus_state.with_context(|| {
+            format!(
+                "client {client_id} has no consensus state at height {}-{} on {}",
+                height.revision_number, height.revision_height, self.chain_id
+            )
+        })?;
+        Ok((consensus_state, Proof::new(rsp.proof, rsp.proof_height)?))
+    }
+
+    pub async fn connection_with_proof(
+        &mut self,
+        connection_id: &str,
+    ) -> Result<(ConnectionEnd, Proof)> {
+        let rsp = self
+            .connections
+            .connection(QueryConnectionRequest {
+                connection_id: connection_id.to_string(),
+            })
+            .await?
+            .into_inner();
+        let connection = rsp.connection.with_context(|| {
+            format!("connection {connection_id} not found on {}", self.chain_id)
+        })?;
+        Ok((connection, Proof::new(rsp.proof, rsp.proof_height)?))
+    }
+
+    pub async fn channel_with_proof(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<(ChannelEnd, Proof)> {
+        let rsp = self
+            .channels
+            .channel(QueryChannelRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            })
+            .await?
+            .into_inner();
+        let channel = rsp.channel.with_context(|| {
+            format!(
+                "channel {channel_id} on port {port_id} not found on {}",
+                self.chain_id
+            )
+        })?;
+        Ok((channel, Proof::new(rsp.proof, rsp.proof_height)?))
+    }
+
+    pub async fn packet_commitment_proof(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<Proof> {
+        let rsp = self
+            .channels
+            .packet_commitment(QueryPacketCommitmentRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                sequence,
+            })
+            .await?
+            .into_inner();
+        Proof::new(rsp.proof, rsp.proof_height)
+    }
+
+    pub async fn packet_acknowledgement_proof(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<Proof> {
+        let rsp = self
+            .channels
+            .packet_acknowledgement(QueryPacketAcknowledgementRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                sequence,
+            })
+            .await?
+            .into_inner();
+        Proof::new(rsp.proof, rsp.proof_height)
+    }
+
+    pub async fn packet_receipt_proof(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<Proof> {
+        let rsp = self
+            .channels
+            .packet_receipt(QueryPacketReceiptRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                sequence,
+            })
+            .await?
+            .into_inner();
+        Proof::new(rsp.proof, rsp.proof_height)
+    }
+
+    pub async fn next_sequence_receive_with_proof(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<(u64, Proof)> {
+        let rsp = self
+            .channels
+            .next_sequence_receive(QueryNextSequenceReceiveRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            })
+            .await?
+            .into_inner();
+        Ok((
+            rsp.next_sequence_receive,
+            Proof::new(rsp.proof, rsp.proof_height)?,
+        ))
+    }
+
+    /// Returns the sequences of the packets sent over a channel that have not yet been
+    /// acknowledged or timed out.
+    pub async fn packet_commitments(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<Vec<u64>> {
+        Ok(self
+            .channels
+            .packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                pagination: None,
+            })
+            .await?
+            .into_inner()
+            .commitments
+            .into_iter()
+            .map(|commitment| commitment.sequence)
+            .collect())
+    }
+
+    /// Returns which of `sequences` this chain has not yet received over a channel.
+    pub async fn unreceived_packets(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+        sequences: Vec<u64>,
+    ) -> Result<Vec<u64>> {
+        Ok(self
+            .channels
+            .unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                packet_commitment_sequences: sequences,
+            })
+            .await?
+            .into_inner()
+            .sequences)
+    }
+
+    /// Returns which of `sequences`, sent by this chain over a channel, are still awaiting an
+    /// acknowledgement.
+    pub async fn unreceived_acks(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+        sequences: Vec<u64>,
+    ) -> Result<Vec<u64>> {
+        Ok(self
+            .channels
+            .unreceived_acks(QueryUnreceivedAcksRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                packet_ack_sequences: sequences,
+            })
+            .await?
+            .into_inner()
+            .sequences)
+    }
+
+    /// Starts scanning this chain for sent packets and written acknowledgements at `height`,
+    /// rather than at its first block, so that those from before it are never relayed.
+    pub fn start_scan_at(&mut self, height: u64) {
+        self.scanned_height = height.saturating_sub(1);
+    }
+
+    /// Scans the blocks committed since the last scan for sent packets and written
+    /// acknowledgements.
+    pub async fn scan(&mut self) -> Result<()> {
+        let (latest_height, _) = self.latest_block().await?;
+        for height in (self.scanned_height + 1)..=latest_height {
+            let block = self.fetch_block(height).await?;
+            for tx in block.data.map(|data| data.txs).unwrap_or_default() {
+                for event in self.tx_events(Sha256::digest(&tx).to_vec()).await? {
+                    match event.kind.as_str() {
+                        "send_packet" => {
+                            let packet = packet_from_event(&event)?;
+                            self.sent_packets.insert(
+                                (
+                                    packet.source_port.clone(),
+                                    packet.source_channel.clone(),
+                                    packet.sequence,
+                                ),
+                                packet,
+                            );
+                        }
+                        "write_acknowledgement" => {
+                            let packet = packet_from_event(&event)?;
+                            let ack = hex::decode(attribute(&event, "packet_ack_hex")?)?;
+                            self.written_acks.insert(
+                                (
+                                    packet.destination_port.clone(),
+                                    packet.destination_channel.clone(),
+                                    packet.sequence,
+                                ),
+                                (packet, ack),
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            self.scanned_height = height;
+        }
+        Ok(())
+    }
+
+    /// Returns the packets sent over a channel that have been seen by [`Chain::scan`], and
+    /// forgets those whose sequence is not in `pending`.
+    pub fn sent_packets(
+        &mut self,
+        port_id: &str,
+        channel_id: &str,
+        pending: &[u64],
+    ) -> BTreeMap<u64, Packet> {
+        self.sent_packets.retain(|(port, channel, sequence), _| {
+            port != port_id || channel != channel_id || pending.contains(sequence)
+        });
+        self.sent_packets
+            .iter()
+            .filter(|((port, channel, _), _)| port == port_id && channel == channel_id)
+            .map(|((_, _, sequence), packet)| (*sequence, packet.clone()))
+            .collect()
+    }
+
+    /// Returns the acknowledgements written for packets received over a channel that have been
+    /// seen by [`Chain::scan`].
+    pub fn written_acks(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> BTreeMap<u64, (Packet, Vec<u8>)> {
+        self.written_acks
+            .iter()
+            .filter(|((port, channel, _), _)| port == port_id && channel == channel_id)
+            .map(|((_, _, sequence), ack)| (*sequence, ack.clone()))
+            .collect()
+    }
+
+    /// Forgets the acknowledgement written for a packet, once it has been relayed.
+    pub fn forget_ack(&mut self, port_id: &str, channel_id: &str, sequence: u64) {
+        self.written_acks
+            .remove(&(port_id.to_string(), channel_id.to_string(), sequence));
+    }
+
+    async fn tx_events(&mut self, hash: Vec<u8>) -> Result<Vec<Event>> {
+        Ok(self
+            .tendermint
+            .get_tx(GetTxRequest { hash, prove: false })
+            .await?
+            .into_inner()
+            .tx_result
+            .map(|result| result.events)
+            .unwrap_or_default())
+    }
+
+    /// Submits a transaction performing `actions` in order, waits for it to be included in a
+    /// block, and returns the events it emitted.
+    ///
+    /// IBC actions don't move any value, so the transaction has no spends and pays no fee, and
+    /// can be built without a view of the relayer's wallet.
+    pub async fn submit(&mut self, actions: Vec<IbcAction>) -> Result<Vec<Event>> {
+        let plan = TransactionPlan {
+            actions: actions.into_iter().map(ActionPlan::IbcAction).collect(),
+            chain_id: self.chain_id.clone(),
+            ..Default::default()
+        };
+        let effect_hash = plan.effect_hash(&self.fvk);
+        let transaction = plan
+            .build(
+                &self.fvk,
+                WitnessData {
+                    anchor: penumbra_tct::Tree::new().root(),
+                    state_commitment_proofs: Default::default(),
+                },
+            )?
+            .authorize(
+                &mut OsRng,
+                &AuthorizationData {
+                    effect_hash,
+                    spend_auths: Default::default(),
+                    delegator_vote_auths: Default::default(),
+                },
+            )?;
+
+        let rsp = self
+            .tendermint
+            .broadcast_tx_sync(BroadcastTxSyncRequest {
+                params: transaction.encode_to_vec(),
+                req_id: OsRng.gen(),
+            })
+            .await?
+            .into_inner();
+        if rsp.code != 0 {
+            anyhow::bail!(
+                "error submitting transaction to {}: code {}, log: {}",
+                self.chain_id,
+                rsp.code,
+                rsp.log,
+            );
+        }
+
+        let id = transaction.id();
+        for _ in 0..MAX_INCLUSION_POLLS {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            if let Ok(events) = self.tx_events(id.0.to_vec()).await {
+                return Ok(events);
+            }
+        }
+        anyhow::bail!(
+            "transaction {id} was not included in a block on {}",
+            self.chain_id
+        )
+    }
+}
+
+/// Returns the value of the attribute `key` of `event`.
+pub fn attribute(event: &Event, key: &str) -> Result<String> {
+    let attribute = event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key.as_bytes())
+        .with_context(|| format!("{} event is missing its {key} attribute", event.kind))?;
+    Ok(String::from_utf8(attribute.value.clone())?)
+}
+
+/// Reconstructs the packet described by a `send_packet` or `write_acknowledgement` event.
+fn packet_from_event(event: &Event) -> Result<Packet> {
+    let timeout_height = attribute(event, "packet_timeout_height")?;
+    let (revision_number, revision_height) = timeout_height
+        .split_once('-')
+        .with_context(|| format!("invalid timeout height {timeout_height}"))?;
+
+    Ok(Packet {
+        sequence: attribute(event, "packet_sequence")?.parse()?,
+        source_port: attribute(event, "packet_src_port")?,
+        source_channel: attribute(event, "packet_src_channel")?,
+        destination_port: attribute(event, "packet_dst_port")?,
+        destination_channel: attribute(event, "packet_dst_channel")?,
+        data: hex::decode(attribute(event, "packet_data_hex")?)?,
+        timeout_height: Some(Height {
+            revision_number: revision_number.parse()?,
+            revision_height: revision_height.parse()?,
+        }),
+        timeout_timestamp: attribute(event, "packet_timeout_timestamp")?.parse()?,
+    })
+}
+
+/// Re-encodes a message as another message type with the same wire format.
+///
+/// The proxied Tendermint types are defined separately in `penumbra-proto`, `tendermint-proto`
+/// and `ics23`, so this is how we move between them.
+fn transcode<T: Message, U: Message + Default>(message: &T) -> Result<U> {
+    Ok(U::decode(message.encode_to_vec().as_slice())?)
+}
+
+fn proto_duration(duration: Duration) -> ProtoDuration {
+    ProtoDuration {
+        seconds: duration.as_secs() as i64,
+        nanos: duration.subsec_nanos() as i32,
+    }
+}
+
+/// Drops the lowest heights from a cache once it has grown past [`HEADER_CACHE_SIZE`].
+fn prune<V>(cache: &mut BTreeMap<u64, V>) {
+    while cache.len() > HEADER_CACHE_SIZE {
+        cache.pop_first();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_proto::client::v1alpha1::Tag;
+
+    use super::*;
+
+    fn event(kind: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            kind: kind.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| Tag {
+                    key: key.as_bytes().to_vec(),
+                    value: value.as_bytes().to_vec(),
+                    index: false,
+                })
+                .collect(),
+        }
+    }
+
+    fn packet_attributes() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("packet_sequence", "3"),
+            ("packet_src_port", "transfer"),
+            ("packet_src_channel", "channel-0"),
+            ("packet_dst_port", "transfer"),
+            ("packet_dst_channel", "channel-1"),
+            ("packet_data_hex", "7b7d"),
+            ("packet_timeout_height", "0-100"),
+            ("packet_timeout_timestamp", "1700000000000000000"),
+        ]
+    }
+
+    #[test]
+    fn packets_are_parsed_from_events() {
+        let packet = packet_from_event(&event("send_packet", &packet_attributes())).unwrap();
+        assert_eq!(
+            packet,
+            Packet {
+                sequence: 3,
+                source_port: "transfer".to_string(),
+                source_channel: "channel-0".to_string(),
+                destination_port: "transfer".to_string(),
+                destination_channel: "channel-1".to_string(),
+                data: b"{}".to_vec(),
+                timeout_height: Some(Height {
+                    revision_number: 0,
+                    revision_height: 100,
+                }),
+                timeout_timestamp: 1_700_000_000_000_000_000,
+            }
+        );
+
+        let mut attributes = packet_attributes();
+        attributes.push(("packet_ack_hex", "7b22726573756c74223a2241513d3d227d"));
+        let ack = event("write_acknowledgement", &attributes);
+        assert_eq!(
+            hex::decode(attribute(&ack, "packet_ack_hex").unwrap()).unwrap(),
+            br#"{"result":"AQ=="}"#
+        );
+    }
+
+    #[test]
+    fn malformed_packet_events_are_rejected() {
+        let missing: Vec<_> = packet_attributes()
+            .into_iter()
+            .filter(|(key, _)| *key != "packet_data_hex")
+            .collect();
+        let error = packet_from_event(&event("send_packet", &missing)).unwrap_err();
+        assert!(error.to_string().contains("packet_data_hex"));
+
+        let mut bad_height = packet_attributes();
+        bad_height.retain(|(key, _)| *key != "packet_timeout_height");
+        bad_height.push(("packet_timeout_height", "100"));
+        assert!(packet_from_event(&event("send_packet", &bad_height)).is_err());
+
+        let mut bad_data = packet_attributes();
+        bad_data.retain(|(key, _)| *key != "packet_data_hex");
+        bad_data.push(("packet_data_hex", "not hex"));
+        assert!(packet_from_event(&event("send_packet", &bad_data)).is_err());
+    }
+
+    #[test]
+    fn caches_keep_the_highest_heights() {
+        let mut cache: BTreeMap<u64, ()> = (1..=HEADER_CACHE_SIZE as u64 + 5)
+            .map(|height| (height, ()))
+            .collect();
+        prune(&mut cache);
+        assert_eq!(cache.len(), HEADER_CACHE_SIZE);
+        assert_eq!(cache.keys().next(), Some(&6));
+        assert_eq!(
+            cache.keys().next_back(),
+            Some(&(HEADER_CACHE_SIZE as u64 + 5))
+        );
+
+        let mut small: BTreeMap<u64, ()> = [(1, ()), (2, ())].into_iter().collect();
+        prune(&mut small);
+        assert_eq!(small.len(), 2);
+    }
+}
@@ -11,7 +11,10 @@ use ark_ff::UniformRand;
 use decaf377::{Fq, Fr};
 use ibc_types::core::{channel::ChannelId, client::Height as IbcHeight};
 use penumbra_asset::{asset, asset::DenomMetadata, Value, STAKING_TOKEN_ASSET_ID};
-use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
+use penumbra_dex::{
+    lp::position::{self, Position},
+    swap_claim::SwapClaimPlan,
+};
 use penumbra_fee::Fee;
 use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
 use penumbra_ibc::Ics20Withdrawal;
@@ -23,10 +26,11 @@ use penumbra_proto::{
         ProposalInfoResponse, ProposalRateDataRequest, ValidatorPenaltyRequest,
     },
     core::component::dex::v1alpha1::PositionId,
+    DomainType,
 };
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::memo::MemoPlaintext;
+use penumbra_transaction::{memo::MemoPlaintext, plan::TransactionPlan};
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
 use rand_core::OsRng;
@@ -35,7 +39,7 @@ use regex::Regex;
 use crate::App;
 
 mod proposal;
-use proposal::ProposalCmd;
+use proposal::{DaoPositionCmd, DaoProposalArgs, ProposalCmd};
 
 mod liquidity_position;
 use liquidity_position::PositionCmd;
@@ -111,6 +115,10 @@ pub enum TxCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
+        /// The minimum output to accept, written as a typed value of the `into` denomination,
+        /// e.g. `10gm`. If the swap would output less, its input is refunded instead.
+        #[clap(long, display_order = 150)]
+        min_output: Option<String>,
         /// The transaction fee (paid in upenumbra).
         ///
         /// A swap generates two transactions; the fee will be split equally over both.
@@ -348,11 +356,24 @@ impl TxCmd {
             TxCmd::Swap {
                 input,
                 into,
+                min_output,
                 fee,
                 source,
             } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
+                let min_output = match min_output {
+                    Some(min_output) => {
+                        let min_output = min_output.parse::<Value>()?;
+                        if min_output.asset_id != into.id() {
+                            anyhow::bail!(
+                                "minimum output must be denominated in the asset being swapped into"
+                            );
+                        }
+                        min_output.amount
+                    }
+                    None => Amount::zero(),
+                };
 
                 // Since the swap command consists of two transactions (the swap and the swap claim),
                 // the fee is split equally over both for now.
@@ -368,7 +389,13 @@ impl TxCmd {
 
                 let mut planner = Planner::new(OsRng);
                 planner.fee(swap_fee);
-                planner.swap(input, into.id(), swap_claim_fee.clone(), claim_address)?;
+                planner.swap(
+                    input,
+                    into.id(),
+                    min_output,
+                    swap_claim_fee.clone(),
+                    claim_address,
+                )?;
 
                 let account_group_id = app.fvk.account_group_id();
                 let plan = planner
@@ -396,11 +423,17 @@ impl TxCmd {
 
                 let asset_cache = app.view().assets().await?;
 
-                let pro_rata_outputs = swap_record.output_data.pro_rata_outputs((
-                    swap_plaintext.delta_1_i.into(),
-                    swap_plaintext.delta_2_i.into(),
-                ));
+                let deltas = (swap_plaintext.delta_1_i, swap_plaintext.delta_2_i);
+                let pro_rata_outputs = swap_record
+                    .output_data
+                    .swap_outputs(deltas, swap_plaintext.min_output);
                 println!("Swap submitted and batch confirmed!");
+                if !swap_record
+                    .output_data
+                    .min_output_met(deltas, swap_plaintext.min_output)
+                {
+                    println!("The batch price did not meet the minimum output, so the swap input will be refunded.");
+                }
                 println!(
                     "You will receive outputs of {} and {}. Claiming now...",
                     Value {
@@ -652,6 +685,55 @@ impl TxCmd {
                 .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Proposal(ProposalCmd::DaoSpend {
+                recipients,
+                proposal: args,
+            }) => {
+                let outputs = proposal::read_dao_recipients(recipients)?;
+                let chain_id = app.view().chain_params().await?.chain_id;
+                let transaction_plan = proposal::dao_spend_plan(outputs, chain_id);
+                submit_dao_spend_proposal(app, transaction_plan, args).await?;
+            }
+            TxCmd::Proposal(ProposalCmd::DaoPosition(cmd)) => {
+                let chain_id = app.view().chain_params().await?.chain_id;
+                let mut client = app.specific_client().await?;
+                let transaction_plan = match cmd {
+                    DaoPositionCmd::Buy { .. } | DaoPositionCmd::Sell { .. } => {
+                        let position = cmd
+                            .position(OsRng)?
+                            .context("buy and sell orders open a position")?;
+                        proposal::dao_position_open_plan(position, chain_id)
+                    }
+                    DaoPositionCmd::Close { position_ids, .. } => {
+                        // The DAO's custody of the positions is checked with the rest of its balance.
+                        proposal::dao_position_close_plan(position_ids, chain_id)
+                    }
+                    DaoPositionCmd::Withdraw { position_ids, .. } => {
+                        let mut positions = Vec::new();
+                        for position_id in position_ids {
+                            let position: Position = client
+                                .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                                    chain_id: chain_id.clone(),
+                                    position_id: Some((*position_id).into()),
+                                })
+                                .await?
+                                .into_inner()
+                                .data
+                                .with_context(|| format!("position {position_id} not found"))?
+                                .try_into()?;
+                            anyhow::ensure!(
+                                position.state == position::State::Closed,
+                                "position {} must be closed before it is withdrawn, but it is {}",
+                                position_id,
+                                position.state
+                            );
+                            positions.push(position);
+                        }
+                        proposal::dao_position_withdraw_plan(&positions, chain_id)
+                    }
+                };
+                submit_dao_spend_proposal(app, transaction_plan, cmd.proposal()).await?;
+            }
             TxCmd::Proposal(ProposalCmd::Withdraw {
                 proposal_id,
                 fee,
@@ -1105,3 +1187,82 @@ impl TxCmd {
         Ok(())
     }
 }
+
+/// Check that the DAO can currently afford a DAO transaction plan, then submit a DAO spend
+/// proposal for it, or print the proposal if this is a dry run.
+///
+/// The chain's stateful checks of the proposal, which execute the DAO transaction against the
+/// current chain state, are not run here: only DAO spends being enabled and the DAO's balance
+/// are checked against the chain state.
+async fn submit_dao_spend_proposal(
+    app: &mut App,
+    transaction_plan: TransactionPlan,
+    args: &DaoProposalArgs,
+) -> Result<()> {
+    let chain_params = app.view().chain_params().await?;
+    let asset_cache = app.view().assets().await?;
+    anyhow::ensure!(
+        chain_params.dao_spend_proposals_enabled,
+        "DAO spend proposals are not enabled"
+    );
+
+    // Check that the DAO can currently afford every spend.
+    let mut client = app.specific_client().await?;
+    for (asset_id, amount) in proposal::dao_spend_totals(&transaction_plan) {
+        let balance: Amount = client
+            .key_domain(penumbra_dao::component::state_key::balance_for_asset(
+                asset_id,
+            ))
+            .await?
+            .unwrap_or_default();
+        anyhow::ensure!(
+            balance >= amount,
+            "DAO balance of {} is insufficient to pay out {}",
+            Value {
+                amount: balance,
+                asset_id
+            }
+            .format(&asset_cache),
+            Value { amount, asset_id }.format(&asset_cache),
+        );
+    }
+
+    let next_proposal_id: u64 = client
+        .key_proto(penumbra_governance::state_key::next_proposal_id())
+        .await?
+        .context("there are no proposals yet")?;
+
+    let proposal = penumbra_governance::Proposal {
+        id: next_proposal_id,
+        title: args.title.clone(),
+        description: args.description.clone(),
+        payload: penumbra_governance::ProposalPayload::DaoSpend {
+            transaction_plan: transaction_plan.encode_to_vec(),
+        },
+    };
+    proposal::check_proposal(&proposal, chain_params.proposal_deposit_amount).await?;
+
+    if args.dry_run {
+        eprintln!(
+            "note: the DAO transaction was only checked statelessly and against the DAO's \
+             balance; the chain will execute it against its current state on submission"
+        );
+        println!("{}", toml::to_string_pretty(&ProposalToml::from(proposal))?);
+        return Ok(());
+    }
+
+    let fee = Fee::from_staking_token_amount(args.fee.into());
+    let plan = plan::proposal_submit(
+        app.fvk.account_group_id(),
+        app.view
+            .as_mut()
+            .context("view service must be initialized")?,
+        OsRng,
+        proposal,
+        fee,
+        AddressIndex::new(args.source),
+    )
+    .await?;
+    app.build_and_submit_transaction(plan).await?;
+    Ok(())
+}
@@ -4,7 +4,7 @@ use penumbra_asset::asset;
 use penumbra_dex::{
     lp::{
         position::{self, Position},
-        BuyOrder, SellOrder,
+        BuyOrder, SellOrder, TriggerKind,
     },
     TradingPair,
 };
@@ -122,6 +122,54 @@ pub enum OrderCmd {
         #[clap(long)]
         limit_order: bool,
     },
+    /// Place an order that only becomes active once the price moves against it: a sell
+    /// once the price falls to the trigger price, or a buy once it rises to it.
+    #[clap(subcommand)]
+    Stop(TriggeredOrderCmd),
+    /// Place an order that only becomes active once the price moves in its favor: a sell
+    /// once the price rises to the trigger price, or a buy once it falls to it.
+    #[clap(subcommand)]
+    TakeProfit(TriggeredOrderCmd),
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum TriggeredOrderCmd {
+    Buy {
+        /// The desired purchase and its trigger price, formatted as a string, e.g.
+        /// `100penumbra@1.2gm^1.1gm` would attempt to purchase 100 penumbra at a price of
+        /// 1.2 gm per 1penumbra, once penumbra has traded at 1.1 gm.
+        ///
+        /// An optional suffix of the form `/10bps` may be added to the order before the
+        /// trigger price to specify a fee spread for the resulting position.
+        buy_order: String,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+        /// When set, tags the position as being a limit-sell order.
+        #[clap(long)]
+        limit_order: bool,
+    },
+    Sell {
+        /// The desired sale and its trigger price, formatted as a string, e.g.
+        /// `100penumbra@1.2gm^1.3gm` would attempt to sell 100 penumbra at a price of
+        /// 1.2 gm per 1penumbra, once penumbra has traded at 1.3 gm.
+        ///
+        /// An optional suffix of the form `/10bps` may be added to the order before the
+        /// trigger price to specify a fee spread for the resulting position.
+        sell_order: String,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+        /// When set, tags the position as being a limit-sell order.
+        #[clap(long)]
+        limit_order: bool,
+    },
 }
 
 impl OrderCmd {
@@ -129,6 +177,7 @@ impl OrderCmd {
         match self {
             OrderCmd::Buy { fee, .. } => *fee,
             OrderCmd::Sell { fee, .. } => *fee,
+            OrderCmd::Stop(order) | OrderCmd::TakeProfit(order) => order.fee(),
         }
     }
 
@@ -136,6 +185,7 @@ impl OrderCmd {
         match self {
             OrderCmd::Buy { source, .. } => *source,
             OrderCmd::Sell { source, .. } => *source,
+            OrderCmd::Stop(order) | OrderCmd::TakeProfit(order) => order.source(),
         }
     }
 
@@ -143,6 +193,7 @@ impl OrderCmd {
         match self {
             OrderCmd::Buy { limit_order, .. } => *limit_order,
             OrderCmd::Sell { limit_order, .. } => *limit_order,
+            OrderCmd::Stop(order) | OrderCmd::TakeProfit(order) => order.limit_order(),
         }
     }
 
@@ -163,6 +214,8 @@ impl OrderCmd {
                 let order = SellOrder::parse_str(&sell_order)?;
                 order.into_position(rng)
             }
+            OrderCmd::Stop(order) => order.into_position(TriggerKind::Stop, rng)?,
+            OrderCmd::TakeProfit(order) => order.into_position(TriggerKind::TakeProfit, rng)?,
         };
         tracing::info!(?position);
 
@@ -173,3 +226,41 @@ impl OrderCmd {
         Ok(position)
     }
 }
+
+impl TriggeredOrderCmd {
+    pub fn fee(&self) -> u64 {
+        match self {
+            TriggeredOrderCmd::Buy { fee, .. } => *fee,
+            TriggeredOrderCmd::Sell { fee, .. } => *fee,
+        }
+    }
+
+    pub fn source(&self) -> u32 {
+        match self {
+            TriggeredOrderCmd::Buy { source, .. } => *source,
+            TriggeredOrderCmd::Sell { source, .. } => *source,
+        }
+    }
+
+    pub fn limit_order(&self) -> bool {
+        match self {
+            TriggeredOrderCmd::Buy { limit_order, .. } => *limit_order,
+            TriggeredOrderCmd::Sell { limit_order, .. } => *limit_order,
+        }
+    }
+
+    fn into_position<R: CryptoRngCore>(&self, kind: TriggerKind, rng: R) -> Result<Position> {
+        match self {
+            TriggeredOrderCmd::Buy { buy_order, .. } => {
+                tracing::info!(?buy_order, ?kind, "parsing triggered buy order");
+                let order = BuyOrder::parse_triggered_str(&buy_order, kind)?;
+                Ok(order.into_position(rng))
+            }
+            TriggeredOrderCmd::Sell { sell_order, .. } => {
+                tracing::info!(?sell_order, ?kind, "parsing triggered sell order");
+                let order = SellOrder::parse_triggered_str(&sell_order, kind)?;
+                Ok(order.into_position(rng))
+            }
+        }
+    }
+}
@@ -1,9 +1,29 @@
+use std::collections::BTreeMap;
+
 use anyhow::{Context, Result};
 
-use penumbra_chain::params::ChainParameters;
-use penumbra_governance::{Proposal, ProposalPayload};
+use ibc_types::core::{
+    channel::{ChannelId, PortId},
+    client::ClientId,
+    connection::ConnectionId,
+};
+use penumbra_app::ActionHandler;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::params::{change::ChainParameterChange, ChainParameters};
+use penumbra_dao::{DaoDeposit, DaoOutput, DaoSpend};
+use penumbra_dex::lp::{
+    action::{PositionClose, PositionOpen},
+    plan::PositionWithdrawPlan,
+    position::{self, Position},
+    BuyOrder, LpNft, SellOrder,
+};
+use penumbra_governance::{Proposal, ProposalPayload, ProposalSubmit};
+use penumbra_ibc::Ics20RateLimit;
+use penumbra_keys::Address;
+use penumbra_num::Amount;
 use penumbra_proto::DomainType;
-use penumbra_transaction::plan::TransactionPlan;
+use penumbra_transaction::plan::{ActionPlan, TransactionPlan};
+use rand_core::CryptoRngCore;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ProposalCmd {
@@ -12,7 +32,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or dao-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, dao-spend, upgrade-plan, dao-disbursement, or cancel-dao-disbursement].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -28,6 +48,24 @@ pub enum ProposalCmd {
         #[clap(long, default_value = "0")]
         source: u32,
     },
+    /// Build and submit a DAO spend proposal paying out to a list of recipients.
+    ///
+    /// The recipients are read from a CSV file with `address` and `amount` columns, where each
+    /// amount is a typed value like `10penumbra`. The DAO must currently hold enough of each
+    /// asset to cover the total paid out.
+    DaoSpend {
+        /// The CSV file listing the recipients and amounts to pay them.
+        #[clap(long)]
+        recipients: camino::Utf8PathBuf,
+        #[clap(flatten)]
+        proposal: DaoProposalArgs,
+    },
+    /// Build and submit a DAO spend proposal managing a liquidity position held by the DAO.
+    ///
+    /// The position's NFT is deposited back into the DAO at every step, so the DAO keeps custody
+    /// of the position until it is withdrawn and its reserves are returned to the DAO.
+    #[clap(subcommand)]
+    DaoPosition(DaoPositionCmd),
     /// Withdraw a governance proposal that you previously submitted.
     Withdraw {
         /// The transaction fee (paid in upenumbra).
@@ -60,6 +98,95 @@ pub enum ProposalCmd {
     },
 }
 
+#[derive(Debug, clap::Subcommand)]
+pub enum DaoPositionCmd {
+    /// Propose opening a position funded by the DAO, which buys an asset from the market.
+    Buy {
+        /// The desired purchase, formatted as a string, e.g. `100penumbra@1.2gm` would attempt
+        /// to purchase 100 penumbra at a price of 1.2 gm per 1penumbra.
+        ///
+        /// An optional suffix of the form `/10bps` may be added to specify a fee spread for the
+        /// resulting position.
+        buy_order: String,
+        #[clap(flatten)]
+        proposal: DaoProposalArgs,
+    },
+    /// Propose opening a position funded by the DAO, which sells an asset to the market.
+    Sell {
+        /// The desired sale, formatted as a string, e.g. `100penumbra@1.2gm` would attempt
+        /// to sell 100 penumbra at a price of 1.2 gm per 1penumbra.
+        ///
+        /// An optional suffix of the form `/10bps` may be added to specify a fee spread for the
+        /// resulting position.
+        sell_order: String,
+        #[clap(flatten)]
+        proposal: DaoProposalArgs,
+    },
+    /// Propose closing positions held by the DAO.
+    Close {
+        /// The [`position::Id`]s of the positions to close.
+        #[clap(required = true)]
+        position_ids: Vec<position::Id>,
+        #[clap(flatten)]
+        proposal: DaoProposalArgs,
+    },
+    /// Propose withdrawing the final reserves of closed positions held by the DAO into the DAO.
+    ///
+    /// The positions must already be closed, so that their final reserves are known when the
+    /// proposal is submitted.
+    Withdraw {
+        /// The [`position::Id`]s of the positions to withdraw.
+        #[clap(required = true)]
+        position_ids: Vec<position::Id>,
+        #[clap(flatten)]
+        proposal: DaoProposalArgs,
+    },
+}
+
+impl DaoPositionCmd {
+    pub fn proposal(&self) -> &DaoProposalArgs {
+        match self {
+            DaoPositionCmd::Buy { proposal, .. }
+            | DaoPositionCmd::Sell { proposal, .. }
+            | DaoPositionCmd::Close { proposal, .. }
+            | DaoPositionCmd::Withdraw { proposal, .. } => proposal,
+        }
+    }
+
+    /// The position to open, if this opens one.
+    pub fn position<R: CryptoRngCore>(&self, rng: R) -> Result<Option<Position>> {
+        match self {
+            DaoPositionCmd::Buy { buy_order, .. } => {
+                Ok(Some(BuyOrder::parse_str(buy_order)?.into_position(rng)))
+            }
+            DaoPositionCmd::Sell { sell_order, .. } => {
+                Ok(Some(SellOrder::parse_str(sell_order)?.into_position(rng)))
+            }
+            DaoPositionCmd::Close { .. } | DaoPositionCmd::Withdraw { .. } => Ok(None),
+        }
+    }
+}
+
+/// The options shared by the commands which build and submit a DAO spend proposal.
+#[derive(Debug, clap::Args)]
+pub struct DaoProposalArgs {
+    /// A short title for the proposal (at most 80 characters).
+    #[clap(long)]
+    pub title: String,
+    /// A longer description of the proposal (at most 10,000 characters).
+    #[clap(long, default_value = "")]
+    pub description: String,
+    /// The transaction fee (paid in upenumbra).
+    #[clap(long, default_value = "0")]
+    pub fee: u64,
+    /// Only spend funds originally received by the given account.
+    #[clap(long, default_value = "0")]
+    pub source: u32,
+    /// Print the validated proposal in TOML format instead of submitting it.
+    #[clap(long)]
+    pub dry_run: bool,
+}
+
 #[derive(Debug, clap::Subcommand)]
 pub enum ProposalKindCmd {
     /// Generate a template for a signaling proposal.
@@ -79,6 +206,57 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade propopsal,
     UpgradePlan,
+    /// Generate a template for a recurring DAO disbursement proposal.
+    DaoDisbursement {
+        /// The address to receive the disbursements.
+        recipient: Address,
+    },
+    /// Generate a template for a proposal cancelling a recurring DAO disbursement.
+    CancelDaoDisbursement {
+        /// The ID of the proposal which scheduled the disbursement.
+        proposal: u64,
+    },
+    /// Generate a template for a proposal recovering a frozen or expired IBC client.
+    RecoverIbcClient {
+        /// The ID of the frozen or expired client to recover, e.g. `07-tendermint-0`.
+        subject_client_id: ClientId,
+        /// The ID of the active client of the same chain whose state replaces the subject's.
+        substitute_client_id: ClientId,
+    },
+    /// Generate a template for a proposal freezing an IBC client.
+    FreezeIbcClient {
+        /// The ID of the client to freeze, e.g. `07-tendermint-0`.
+        client_id: ClientId,
+    },
+    /// Generate a template for a proposal closing an IBC channel.
+    CloseIbcChannel {
+        /// The ID of the channel to close, e.g. `channel-0`.
+        channel_id: ChannelId,
+        /// The port the channel is bound to.
+        #[clap(long, default_value = "transfer")]
+        port_id: PortId,
+    },
+    /// Generate a template for a proposal setting the rate limit on an asset over an ICS-20
+    /// channel.
+    SetIcs20RateLimit {
+        /// The ID of the channel to limit, e.g. `channel-0`.
+        channel_id: ChannelId,
+        /// The denomination to limit, as it is known on this chain, e.g. `upenumbra`.
+        denom: String,
+        /// Remove the existing rate limit, rather than setting a new one.
+        #[clap(long)]
+        remove: bool,
+    },
+    /// Generate a template for a proposal registering an interchain account for the DAO.
+    RegisterDaoInterchainAccount {
+        /// The ID of the connection to the host chain, e.g. `connection-0`.
+        connection_id: ConnectionId,
+    },
+    /// Generate a template for a proposal sending a transaction to the DAO's interchain account.
+    DaoInterchainAccountTx {
+        /// The ID of the connection to the host chain, e.g. `connection-0`.
+        connection_id: ConnectionId,
+    },
 }
 
 impl ProposalKindCmd {
@@ -90,8 +268,11 @@ impl ProposalKindCmd {
             ProposalKindCmd::Signaling => ProposalPayload::Signaling { commit: None },
             ProposalKindCmd::Emergency => ProposalPayload::Emergency { halt_chain: false },
             ProposalKindCmd::ParameterChange => ProposalPayload::ParameterChange {
-                old: Box::new(chain_params.clone()),
-                new: Box::new(chain_params.clone()),
+                // An example change, which leaves the parameter at its current value:
+                changes: vec![ChainParameterChange {
+                    parameter: "proposal_voting_blocks".to_string(),
+                    value: chain_params.proposal_voting_blocks.to_string(),
+                }],
             },
             ProposalKindCmd::DaoSpend { transaction_plan } => {
                 if let Some(file) = transaction_plan {
@@ -112,6 +293,64 @@ impl ProposalKindCmd {
                 }
             }
             ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::DaoDisbursement { recipient } => ProposalPayload::DaoDisbursement {
+                value: Value {
+                    amount: 0u64.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                address: *recipient,
+                epoch_interval: 1,
+                payouts: 1,
+            },
+            ProposalKindCmd::CancelDaoDisbursement { proposal } => {
+                ProposalPayload::CancelDaoDisbursement {
+                    proposal: *proposal,
+                }
+            }
+            ProposalKindCmd::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::RecoverIbcClient {
+                subject_client_id: subject_client_id.clone(),
+                substitute_client_id: substitute_client_id.clone(),
+            },
+            ProposalKindCmd::FreezeIbcClient { client_id } => ProposalPayload::FreezeIbcClient {
+                client_id: client_id.clone(),
+            },
+            ProposalKindCmd::CloseIbcChannel {
+                channel_id,
+                port_id,
+            } => ProposalPayload::CloseIbcChannel {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            },
+            ProposalKindCmd::SetIcs20RateLimit {
+                channel_id,
+                denom,
+                remove,
+            } => ProposalPayload::SetIcs20RateLimit {
+                channel_id: channel_id.clone(),
+                denom: denom.as_str().try_into()?,
+                // An example limit, with windows as long as an epoch, to be filled in:
+                rate_limit: (!remove).then_some(Ics20RateLimit {
+                    window_blocks: chain_params.epoch_duration,
+                    max_inflow: 0u64.into(),
+                    max_outflow: 0u64.into(),
+                }),
+            },
+            ProposalKindCmd::RegisterDaoInterchainAccount { connection_id } => {
+                ProposalPayload::RegisterDaoInterchainAccount {
+                    connection_id: connection_id.clone(),
+                }
+            }
+            ProposalKindCmd::DaoInterchainAccountTx { connection_id } => {
+                ProposalPayload::DaoInterchainAccountTx {
+                    connection_id: connection_id.clone(),
+                    // An empty message, to be filled in with its type URL and encoding:
+                    messages: vec![Default::default()],
+                    memo: String::new(),
+                }
+            }
         };
 
         Ok(Proposal {
@@ -128,8 +367,560 @@ impl ProposalCmd {
         match self {
             ProposalCmd::Template { .. } => false,
             ProposalCmd::Submit { .. } => false,
+            ProposalCmd::DaoSpend { .. } => false,
+            ProposalCmd::DaoPosition(_) => false,
             ProposalCmd::Withdraw { .. } => false,
             ProposalCmd::DepositClaim { .. } => false,
         }
     }
 }
+
+/// A single row of a DAO spend recipients file.
+#[derive(Debug, serde::Deserialize)]
+struct DaoRecipient {
+    address: String,
+    amount: String,
+}
+
+/// Read the outputs of a DAO spend from a CSV file with `address` and `amount` columns.
+///
+/// Each address may be paid each asset at most once, since a repeated row is most likely a
+/// mistake in the file.
+pub fn read_dao_recipients(file: &camino::Utf8Path) -> Result<Vec<DaoOutput>> {
+    let mut reader = csv::Reader::from_path(file)
+        .with_context(|| format!("failed to open recipients file {file:?}"))?;
+
+    let mut outputs = Vec::new();
+    let mut seen = BTreeMap::new();
+    for (row, record) in reader.deserialize().enumerate() {
+        // Rows are numbered from 2, counting the header, to match what a spreadsheet shows.
+        let row = row + 2;
+        let DaoRecipient { address, amount } =
+            record.with_context(|| format!("failed to parse row {row} of {file:?}"))?;
+        let address: Address = address
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid address in row {row} of {file:?}"))?;
+        let value: Value = amount
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid amount in row {row} of {file:?}"))?;
+        anyhow::ensure!(
+            value.amount > Amount::zero(),
+            "amount in row {row} of {file:?} must be nonzero"
+        );
+        if let Some(first) = seen.insert((address.to_string(), value.asset_id), row) {
+            anyhow::bail!(
+                "row {row} of {file:?} pays the same asset to the same address as row {first}"
+            );
+        }
+        outputs.push(DaoOutput { value, address });
+    }
+
+    anyhow::ensure!(!outputs.is_empty(), "no recipients found in {file:?}");
+    Ok(outputs)
+}
+
+/// The total value of each asset paid out by a set of DAO outputs.
+pub fn dao_output_totals(outputs: &[DaoOutput]) -> BTreeMap<asset::Id, Amount> {
+    let mut totals = BTreeMap::<asset::Id, Amount>::new();
+    for output in outputs {
+        *totals.entry(output.value.asset_id).or_default() += output.value.amount;
+    }
+    totals
+}
+
+/// The total value of each asset withdrawn from the DAO by a DAO transaction plan.
+pub fn dao_spend_totals(plan: &TransactionPlan) -> BTreeMap<asset::Id, Amount> {
+    let mut totals = BTreeMap::<asset::Id, Amount>::new();
+    for action in &plan.actions {
+        if let ActionPlan::DaoSpend(DaoSpend { value }) = action {
+            *totals.entry(value.asset_id).or_default() += value.amount;
+        }
+    }
+    totals
+}
+
+/// Construct a balanced DAO transaction plan paying out the given outputs, with one `DaoSpend`
+/// per asset withdrawing exactly the total paid out in that asset.
+pub fn dao_spend_plan(outputs: Vec<DaoOutput>, chain_id: String) -> TransactionPlan {
+    let spends = dao_output_totals(&outputs)
+        .into_iter()
+        .map(|(asset_id, amount)| {
+            ActionPlan::DaoSpend(DaoSpend {
+                value: Value { amount, asset_id },
+            })
+        });
+
+    TransactionPlan {
+        actions: spends
+            .chain(outputs.into_iter().map(ActionPlan::DaoOutput))
+            .collect(),
+        chain_id,
+        ..Default::default()
+    }
+}
+
+/// Construct a balanced DAO transaction plan opening a position funded by the DAO, and depositing
+/// the opened position's NFT into the DAO.
+pub fn dao_position_open_plan(position: Position, chain_id: String) -> TransactionPlan {
+    let reserves = [
+        Value {
+            amount: position.reserves.r1,
+            asset_id: position.phi.pair.asset_1(),
+        },
+        Value {
+            amount: position.reserves.r2,
+            asset_id: position.phi.pair.asset_2(),
+        },
+    ];
+    let opened_nft = Value {
+        amount: 1u64.into(),
+        asset_id: LpNft::new(position.id(), position::State::Opened).asset_id(),
+    };
+
+    let spends = reserves
+        .into_iter()
+        .filter(|value| value.amount > Amount::zero())
+        .map(|value| ActionPlan::DaoSpend(DaoSpend { value }));
+
+    TransactionPlan {
+        actions: spends
+            .chain([
+                ActionPlan::PositionOpen(PositionOpen { position }),
+                ActionPlan::DaoDeposit(DaoDeposit { value: opened_nft }),
+            ])
+            .collect(),
+        chain_id,
+        ..Default::default()
+    }
+}
+
+/// Construct a balanced DAO transaction plan closing positions held by the DAO, swapping each
+/// opened position NFT in the DAO for a closed one.
+pub fn dao_position_close_plan(position_ids: &[position::Id], chain_id: String) -> TransactionPlan {
+    let mut actions = Vec::new();
+    for &position_id in position_ids {
+        let nft = |state| Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(position_id, state).asset_id(),
+        };
+        actions.extend([
+            ActionPlan::DaoSpend(DaoSpend {
+                value: nft(position::State::Opened),
+            }),
+            ActionPlan::PositionClose(PositionClose { position_id }),
+            ActionPlan::DaoDeposit(DaoDeposit {
+                value: nft(position::State::Closed),
+            }),
+        ]);
+    }
+
+    TransactionPlan {
+        actions,
+        chain_id,
+        ..Default::default()
+    }
+}
+
+/// Construct a balanced DAO transaction plan withdrawing closed positions held by the DAO,
+/// depositing their final reserves and withdrawn position NFTs into the DAO.
+pub fn dao_position_withdraw_plan(positions: &[Position], chain_id: String) -> TransactionPlan {
+    let mut actions = Vec::new();
+    for position in positions {
+        let position_id = position.id();
+        let pair = position.phi.pair;
+        let nft = |state| Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(position_id, state).asset_id(),
+        };
+        actions.extend([
+            ActionPlan::DaoSpend(DaoSpend {
+                value: nft(position::State::Closed),
+            }),
+            ActionPlan::PositionWithdraw(PositionWithdrawPlan::new(
+                position.reserves.clone(),
+                position_id,
+                pair,
+            )),
+            ActionPlan::DaoDeposit(DaoDeposit {
+                value: nft(position::State::Withdrawn),
+            }),
+        ]);
+        let reserves = [
+            Value {
+                amount: position.reserves.r1,
+                asset_id: pair.asset_1(),
+            },
+            Value {
+                amount: position.reserves.r2,
+                asset_id: pair.asset_2(),
+            },
+        ];
+        actions.extend(
+            reserves
+                .into_iter()
+                .filter(|value| value.amount > Amount::zero())
+                .map(|value| ActionPlan::DaoDeposit(DaoDeposit { value })),
+        );
+    }
+
+    TransactionPlan {
+        actions,
+        chain_id,
+        ..Default::default()
+    }
+}
+
+/// Run the checks that the chain performs on a proposal submission which can be done without
+/// access to the chain state.
+///
+/// For DAO spend proposals, this also builds the DAO transaction exactly as the chain would, and
+/// checks it is well-formed and balanced.
+///
+/// The stateful checks of `ProposalSubmit` are *not* run: in particular, the DAO transaction is
+/// only checked and executed against the chain state when the proposal is submitted.
+pub async fn check_proposal(proposal: &Proposal, deposit_amount: Amount) -> Result<()> {
+    ProposalSubmit {
+        proposal: proposal.clone(),
+        deposit_amount,
+    }
+    .check_stateless(())
+    .await
+    .context("proposal failed stateless checks")?;
+
+    if let ProposalPayload::DaoSpend { transaction_plan } = &proposal.payload {
+        let plan = TransactionPlan::decode(&transaction_plan[..])
+            .context("transaction plan was malformed")?;
+        penumbra_app::build_dao_transaction(plan)
+            .await
+            .context("failed to build DAO spend transaction")?
+            .check_stateless(())
+            .await
+            .context("DAO spend transaction failed stateless checks")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use penumbra_asset::asset::REGISTRY;
+    use penumbra_dex::{lp::Reserves, DirectedTradingPair, TradingPair};
+    use penumbra_keys::keys::{SeedPhrase, SpendKey};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn address() -> Address {
+        let spend_key = SpendKey::from_seed_phrase_bip39(SeedPhrase::generate(OsRng), 0);
+        spend_key.full_viewing_key().payment_address(0u32.into()).0
+    }
+
+    fn read(contents: &str) -> Result<Vec<DaoOutput>> {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        read_dao_recipients(camino::Utf8Path::from_path(file.path()).unwrap())
+    }
+
+    fn gm() -> asset::Id {
+        REGISTRY.parse_unit("gm").base().id()
+    }
+
+    #[test]
+    fn recipients_are_parsed() {
+        let (alice, bob) = (address(), address());
+        let outputs = read(&format!(
+            "address,amount\n{alice},1.5penumbra\n {bob} , 100gm \n{bob},20upenumbra\n"
+        ))
+        .unwrap();
+
+        let values = outputs
+            .iter()
+            .map(|output| (output.address.to_string(), output.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                (
+                    alice.to_string(),
+                    Value {
+                        amount: 1_500_000u64.into(),
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                ),
+                (
+                    bob.to_string(),
+                    Value {
+                        amount: 100_000_000u64.into(),
+                        asset_id: gm(),
+                    }
+                ),
+                (
+                    bob.to_string(),
+                    Value {
+                        amount: 20u64.into(),
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_recipients_are_rejected() {
+        let alice = address();
+        let cases = [
+            ("empty file", "address,amount\n".to_string()),
+            ("missing column", format!("address,amount\n{alice}\n")),
+            (
+                "bad address",
+                "address,amount\npenumbra1nope,1penumbra\n".to_string(),
+            ),
+            ("missing denom", format!("address,amount\n{alice},100\n")),
+            (
+                "bad amount",
+                format!("address,amount\n{alice},onepenumbra\n"),
+            ),
+            (
+                "too many decimals",
+                format!("address,amount\n{alice},0.0000001penumbra\n"),
+            ),
+            (
+                "zero amount",
+                format!("address,amount\n{alice},0penumbra\n"),
+            ),
+            (
+                "duplicate recipient",
+                format!("address,amount\n{alice},1penumbra\n{alice},2penumbra\n"),
+            ),
+        ];
+
+        for (name, contents) in cases {
+            assert!(read(&contents).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn dao_spend_plan_withdraws_the_total_of_each_asset() {
+        let (alice, bob) = (address(), address());
+        let value = |amount: u64, asset_id| Value {
+            amount: amount.into(),
+            asset_id,
+        };
+        let outputs = vec![
+            DaoOutput {
+                value: value(10, *STAKING_TOKEN_ASSET_ID),
+                address: alice,
+            },
+            DaoOutput {
+                value: value(5, gm()),
+                address: alice,
+            },
+            DaoOutput {
+                value: value(7, *STAKING_TOKEN_ASSET_ID),
+                address: bob,
+            },
+        ];
+        let totals = dao_output_totals(&outputs);
+        let plan = dao_spend_plan(outputs, "penumbra-test".to_string());
+
+        assert_eq!(plan.chain_id, "penumbra-test");
+        assert_eq!(
+            totals,
+            BTreeMap::from([(*STAKING_TOKEN_ASSET_ID, 17u64.into()), (gm(), 5u64.into())])
+        );
+        assert_eq!(dao_spend_totals(&plan), totals);
+        assert_eq!(
+            plan.actions
+                .iter()
+                .filter(|action| matches!(action, ActionPlan::DaoSpend(_)))
+                .count(),
+            2
+        );
+        assert_eq!(
+            plan.actions
+                .iter()
+                .filter(|action| matches!(action, ActionPlan::DaoOutput(_)))
+                .count(),
+            3
+        );
+    }
+
+    /// A position on the canonical pair, so that its reserves are not flipped.
+    fn dao_position(r1: u64, r2: u64) -> Position {
+        let pair = TradingPair::new(*STAKING_TOKEN_ASSET_ID, gm());
+        Position::new(
+            OsRng,
+            DirectedTradingPair::new(pair.asset_1(), pair.asset_2()),
+            30,
+            2u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: r1.into(),
+                r2: r2.into(),
+            },
+        )
+    }
+
+    /// The values withdrawn from and deposited into the DAO by a DAO transaction plan.
+    fn dao_flows(plan: &TransactionPlan) -> (Vec<Value>, Vec<Value>) {
+        let mut spends = Vec::new();
+        let mut deposits = Vec::new();
+        for action in &plan.actions {
+            match action {
+                ActionPlan::DaoSpend(DaoSpend { value }) => spends.push(*value),
+                ActionPlan::DaoDeposit(DaoDeposit { value }) => deposits.push(*value),
+                _ => {}
+            }
+        }
+        (spends, deposits)
+    }
+
+    fn nft(position: &Position, state: position::State) -> Value {
+        Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(position.id(), state).asset_id(),
+        }
+    }
+
+    fn dao_spend_proposal(transaction_plan: TransactionPlan) -> Proposal {
+        Proposal {
+            id: 0,
+            title: "Manage DAO liquidity".to_string(),
+            description: String::new(),
+            payload: ProposalPayload::DaoSpend {
+                transaction_plan: transaction_plan.encode_to_vec(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn dao_position_open_plan_funds_the_position_and_deposits_its_nft() {
+        let position = dao_position(100, 0);
+        let plan = dao_position_open_plan(position.clone(), "penumbra-test".to_string());
+
+        let (spends, deposits) = dao_flows(&plan);
+        // Only the side of the position with reserves is funded.
+        assert_eq!(
+            spends,
+            [Value {
+                amount: 100u64.into(),
+                asset_id: position.phi.pair.asset_1(),
+            }]
+        );
+        assert_eq!(deposits, [nft(&position, position::State::Opened)]);
+        assert!(plan.actions.iter().any(|action| matches!(
+            action,
+            ActionPlan::PositionOpen(PositionOpen { position: opened }) if opened.id() == position.id()
+        )));
+
+        check_proposal(&dao_spend_proposal(plan), 10u64.into())
+            .await
+            .unwrap();
+
+        let plan = dao_position_open_plan(dao_position(100, 50), "penumbra-test".to_string());
+        assert_eq!(dao_flows(&plan).0.len(), 2);
+        check_proposal(&dao_spend_proposal(plan), 10u64.into())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn dao_position_close_plan_swaps_opened_nfts_for_closed_ones() {
+        let positions = [dao_position(100, 0), dao_position(0, 50)];
+        let plan = dao_position_close_plan(
+            &positions.iter().map(Position::id).collect::<Vec<_>>(),
+            "penumbra-test".to_string(),
+        );
+
+        let (spends, deposits) = dao_flows(&plan);
+        assert_eq!(
+            spends,
+            positions
+                .iter()
+                .map(|position| nft(position, position::State::Opened))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            deposits,
+            positions
+                .iter()
+                .map(|position| nft(position, position::State::Closed))
+                .collect::<Vec<_>>()
+        );
+
+        check_proposal(&dao_spend_proposal(plan), 10u64.into())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn dao_position_withdraw_plan_deposits_the_final_reserves() {
+        let positions = [dao_position(100, 0), dao_position(30, 70)];
+        let plan = dao_position_withdraw_plan(&positions, "penumbra-test".to_string());
+
+        let (spends, deposits) = dao_flows(&plan);
+        assert_eq!(
+            spends,
+            positions
+                .iter()
+                .map(|position| nft(position, position::State::Closed))
+                .collect::<Vec<_>>()
+        );
+        let reserve = |amount: u64, asset_id| Value {
+            amount: amount.into(),
+            asset_id,
+        };
+        let pair = positions[0].phi.pair;
+        assert_eq!(
+            deposits,
+            [
+                nft(&positions[0], position::State::Withdrawn),
+                reserve(100, pair.asset_1()),
+                nft(&positions[1], position::State::Withdrawn),
+                reserve(30, pair.asset_1()),
+                reserve(70, pair.asset_2()),
+            ]
+        );
+
+        check_proposal(&dao_spend_proposal(plan), 10u64.into())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn dao_spend_proposals_are_checked_statelessly() {
+        let proposal = |transaction_plan: TransactionPlan| Proposal {
+            id: 0,
+            title: "Pay contributors".to_string(),
+            description: String::new(),
+            payload: ProposalPayload::DaoSpend {
+                transaction_plan: transaction_plan.encode_to_vec(),
+            },
+        };
+        let outputs = vec![DaoOutput {
+            value: Value {
+                amount: 10u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            address: address(),
+        }];
+
+        let balanced = dao_spend_plan(outputs.clone(), "penumbra-test".to_string());
+        check_proposal(&proposal(balanced), 10u64.into())
+            .await
+            .unwrap();
+
+        let unbalanced = TransactionPlan {
+            actions: outputs.into_iter().map(ActionPlan::DaoOutput).collect(),
+            chain_id: "penumbra-test".to_string(),
+            ..Default::default()
+        };
+        assert!(check_proposal(&proposal(unbalanced), 10u64.into())
+            .await
+            .is_err());
+    }
+}
@@ -2,8 +2,9 @@ use crate::{warning, App};
 use std::path::PathBuf;
 
 use crate::dex_utils;
-use crate::dex_utils::replicate::debug;
+use crate::dex_utils::replicate::{debug, ticks::Tick};
 use anyhow::{anyhow, bail, Context, Result};
+use comfy_table::{presets, Table};
 use dialoguer::Confirm;
 use penumbra_asset::Value;
 use penumbra_dex::{lp::position::Position, DirectedUnitPair};
@@ -19,12 +20,24 @@ use std::io::Write;
 pub enum ReplicateCmd {
     #[clap(visible_alias = "xyk")]
     ConstantProduct(ConstantProduct),
+    /// Concentrate liquidity in a bounded price range.
+    #[clap(visible_alias = "range")]
+    ConcentratedLiquidity(ConcentratedLiquidity),
+    /// Spread liquidity evenly over a ladder of prices.
+    #[clap(visible_alias = "ladder")]
+    Linear(Linear),
+    /// Concentrate liquidity around a peg, like a stable-swap curve.
+    #[clap(visible_alias = "stableswap")]
+    StableSwap(StableSwap),
 }
 
 impl ReplicateCmd {
     pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
         match self {
             ReplicateCmd::ConstantProduct(xyk_cmd) => xyk_cmd.exec(app).await?,
+            ReplicateCmd::ConcentratedLiquidity(range_cmd) => range_cmd.exec(app).await?,
+            ReplicateCmd::Linear(linear_cmd) => linear_cmd.exec(app).await?,
+            ReplicateCmd::StableSwap(stableswap_cmd) => stableswap_cmd.exec(app).await?,
         };
         Ok(())
     }
@@ -32,6 +45,9 @@ impl ReplicateCmd {
     pub fn offline(&self) -> bool {
         match self {
             ReplicateCmd::ConstantProduct(_) => false,
+            ReplicateCmd::ConcentratedLiquidity(_) => false,
+            ReplicateCmd::Linear(_) => false,
+            ReplicateCmd::StableSwap(_) => false,
         }
     }
 }
@@ -50,6 +66,10 @@ pub struct ConstantProduct {
     #[clap(short, long)]
     pub yes: bool,
 
+    /// Print the depth chart of the resulting positions, without submitting them.
+    #[clap(long)]
+    pub dry_run: bool,
+
     #[clap(short, long, hide(true))]
     pub debug_file: Option<PathBuf>,
     #[clap(long, default_value = "0", hide(true))]
@@ -72,62 +92,14 @@ impl ConstantProduct {
             self.fee_bps,
         )?;
 
-        let (amount_start, amount_end) =
-            positions
-                .iter()
-                .fold((Amount::zero(), Amount::zero()), |acc, pos| {
-                    (
-                        acc.0
-                            + pos
-                                .reserves_for(pair.start.id())
-                                .expect("start is part of position"),
-                        acc.1
-                            + pos
-                                .reserves_for(pair.end.id())
-                                .expect("end is part of position"),
-                    )
-                });
-        let amount_start = pair.start.format_value(amount_start);
-        let amount_end = pair.end.format_value(amount_end);
-
-        warning::rmm();
-
-        if !self.yes
-            && !Confirm::new()
-                .with_prompt("In the solemn voice of Mandos, he who sets the fates of all, you hear a question,\nechoing like a whisper through the Halls of Waiting:\n\"Do you, in your heart of hearts, truly wish to proceed?\"")
-                .interact()?
-        {
+        if self.dry_run {
+            println!("{}", render_depth_chart(&pair, &positions));
+            return Ok(());
+        }
+
+        if !print_summary(app, &pair, &positions, self.yes).await? {
             return Ok(());
         }
-        println!("");
-        println!("so it shall be...");
-        println!("");
-        println!("");
-        println!(
-            "#################################################################################"
-        );
-        println!(
-            "########################### LIQUIDITY SUMMARY ###################################"
-        );
-        println!(
-            "#################################################################################"
-        );
-        println!("");
-        println!(
-            "You want to provide liquidity on the pair {}",
-            pair.to_string()
-        );
-        println!("You will need:",);
-        println!(" -> {amount_start}{}", pair.start.to_string());
-        println!(" -> {amount_end}{}", pair.end.to_string());
-        // TODO(erwan): would be nice to print current balance?
-
-        println!("You will create the following pools:");
-        let asset_cache = app.view().assets().await?;
-        println!(
-            "{}",
-            crate::command::utils::render_positions(&asset_cache, &positions),
-        );
 
         if let Some(debug_file) = &self.debug_file {
             Self::write_debug_data(
@@ -140,32 +112,7 @@ impl ConstantProduct {
             return Ok(());
         }
 
-        if !self.yes
-            && !Confirm::new()
-                .with_prompt("Do you want to open those liquidity positions on-chain?")
-                .interact()?
-        {
-            return Ok(());
-        }
-
-        let mut planner = Planner::new(OsRng);
-        positions.iter().for_each(|position| {
-            planner.position_open(position.clone());
-        });
-
-        let plan = planner
-            .plan(
-                app.view
-                    .as_mut()
-                    .context("view service must be initialized")?,
-                app.fvk.account_group_id(),
-                AddressIndex::new(self.source),
-            )
-            .await?;
-        let tx_id = app.build_and_submit_transaction(plan).await?;
-        println!("posted with transaction id: {tx_id}");
-
-        Ok(())
+        open_positions(app, &positions, self.yes, self.source).await
     }
 
     fn validate(&self) -> anyhow::Result<()> {
@@ -186,33 +133,7 @@ impl ConstantProduct {
     }
 
     async fn get_spread(&self, app: &mut App) -> Result<f64> {
-        let mut specific_client = app.specific_client().await?;
-        let spread_data = specific_client
-            .spread(SpreadRequest {
-                chain_id: "".to_string(),
-                trading_pair: Some(self.pair.into_directed_trading_pair().to_canonical().into()),
-            })
-            .await?
-            .into_inner();
-
-        tracing::debug!(
-            ?spread_data,
-            pair = self.pair.to_string(),
-            "fetched spread for pair"
-        );
-
-        if spread_data.best_1_to_2_position.is_none() || spread_data.best_2_to_1_position.is_none()
-        {
-            bail!("couldn't find a market price for the specified assets, you can manually specify a price using --current-price <price>")
-        }
-
-        if self.input.asset_id == self.pair.start.id() {
-            Ok(spread_data.approx_effective_price_1_to_2)
-        } else if self.input.asset_id == self.pair.end.id() {
-            Ok(spread_data.approx_effective_price_2_to_1)
-        } else {
-            bail!("the supplied liquidity must be on the pair")
-        }
+        get_spread(app, &self.pair, &self.input).await
     }
 
     pub(crate) fn write_debug_data(
@@ -281,3 +202,426 @@ impl ConstantProduct {
         Ok(())
     }
 }
+
+/// The arguments shared by the replication strategies built from a set of ticks.
+#[derive(Debug, Clone, clap::Args)]
+pub struct StrategyArgs {
+    /// The directed pair to provide liquidity on, e.g. `gm:gn`.
+    pub pair: DirectedUnitPair,
+    /// The liquidity to provide, in either asset of the pair, e.g. `100gm`.
+    ///
+    /// It is deployed on the side of the current price that sells this asset, and sets the
+    /// amount of the other asset needed on the other side.
+    pub input: Value,
+    /// The current price, in units of the end asset per unit of the start asset.
+    /// If omitted, it is approximated from the best positions on the chain.
+    #[clap(short, long)]
+    pub current_price: Option<f64>,
+    /// The fee tiers to split the liquidity across, in basis points, e.g. `5,30,100`.
+    #[clap(long, value_delimiter = ',', default_value = "0")]
+    pub fee_tiers: Vec<u32>,
+    /// `--yes` means all prompt interaction are skipped and agreed.
+    #[clap(short, long)]
+    pub yes: bool,
+    /// Print the depth chart of the resulting positions, without submitting them.
+    #[clap(long)]
+    pub dry_run: bool,
+    #[clap(long, default_value = "0", hide(true))]
+    pub source: u32,
+}
+
+impl StrategyArgs {
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.input.asset_id != self.pair.start.id() && self.input.asset_id != self.pair.end.id()
+        {
+            anyhow::bail!("you must supply liquidity with an asset that's part of the market")
+        } else if self.input.amount == 0u64.into() {
+            anyhow::bail!("the quantity of liquidity supplied must be non-zero.",)
+        } else if self.fee_tiers.is_empty() {
+            anyhow::bail!("at least one fee tier is required")
+        } else if self.fee_tiers.iter().any(|fee_bps| *fee_bps > 5000) {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        } else if self.current_price.is_some()
+            && self.current_price.expect("current price is Some") <= 0.0
+        {
+            anyhow::bail!("the supplied current price must be positive")
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn current_price(&self, app: &mut App) -> Result<f64> {
+        match self.current_price {
+            Some(user_supplied_price) => Ok(user_supplied_price),
+            None => get_spread(app, &self.pair, &self.input).await,
+        }
+    }
+
+    /// Builds the positions for `ticks`, then either prints their depth chart or opens them.
+    async fn exec_ticks(&self, app: &mut App, current_price: f64, ticks: &[Tick]) -> Result<()> {
+        let positions = dex_utils::replicate::ticks::positions_from_ticks(
+            OsRng,
+            &self.pair,
+            &self.input,
+            current_price,
+            ticks,
+            &self.fee_tiers,
+        )?;
+
+        if self.dry_run {
+            println!("{}", render_depth_chart(&self.pair, &positions));
+            return Ok(());
+        }
+
+        if !print_summary(app, &self.pair, &positions, self.yes).await? {
+            return Ok(());
+        }
+
+        open_positions(app, &positions, self.yes, self.source).await
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct ConcentratedLiquidity {
+    #[clap(flatten)]
+    pub args: StrategyArgs,
+    /// The lower bound of the price range, in units of the end asset per unit of the start asset.
+    #[clap(long)]
+    pub lower_price: f64,
+    /// The upper bound of the price range, in units of the end asset per unit of the start asset.
+    #[clap(long)]
+    pub upper_price: f64,
+    /// The number of positions used to approximate the range.
+    #[clap(long, default_value_t = 20)]
+    pub num_positions: usize,
+}
+
+impl ConcentratedLiquidity {
+    pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
+        self.args.validate()?;
+        validate_price_range(self.lower_price, self.upper_price, self.num_positions)?;
+        let current_price = self.args.current_price(app).await?;
+
+        let ticks = dex_utils::replicate::range::ticks(
+            self.lower_price,
+            self.upper_price,
+            current_price,
+            self.num_positions,
+        );
+        self.args.exec_ticks(app, current_price, &ticks).await
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct Linear {
+    #[clap(flatten)]
+    pub args: StrategyArgs,
+    /// The lowest price of the ladder, in units of the end asset per unit of the start asset.
+    #[clap(long)]
+    pub lower_price: f64,
+    /// The highest price of the ladder, in units of the end asset per unit of the start asset.
+    #[clap(long)]
+    pub upper_price: f64,
+    /// The number of positions in the ladder.
+    #[clap(long, default_value_t = 20)]
+    pub num_positions: usize,
+}
+
+impl Linear {
+    pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
+        self.args.validate()?;
+        validate_price_range(self.lower_price, self.upper_price, self.num_positions)?;
+        let current_price = self.args.current_price(app).await?;
+
+        let ticks = dex_utils::replicate::linear::ticks(
+            self.lower_price,
+            self.upper_price,
+            self.num_positions,
+        );
+        self.args.exec_ticks(app, current_price, &ticks).await
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct StableSwap {
+    #[clap(flatten)]
+    pub args: StrategyArgs,
+    /// The price to concentrate liquidity around, in units of the end asset per unit of the
+    /// start asset. Defaults to the current price.
+    #[clap(long)]
+    pub peg_price: Option<f64>,
+    /// How far from the peg to provide liquidity, as a fraction of the peg price.
+    #[clap(long, default_value_t = 0.05)]
+    pub width: f64,
+    /// How strongly liquidity is concentrated around the peg.
+    #[clap(long, default_value_t = 100.0)]
+    pub amplification: f64,
+    /// The number of positions used to approximate the curve.
+    #[clap(long, default_value_t = 21)]
+    pub num_positions: usize,
+}
+
+impl StableSwap {
+    pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
+        self.args.validate()?;
+        if self.width <= 0.0 || self.width >= 1.0 {
+            anyhow::bail!("the width must be strictly between 0 and 1")
+        } else if self.amplification < 0.0 {
+            anyhow::bail!("the amplification must not be negative")
+        } else if self.num_positions == 0 {
+            anyhow::bail!("at least one position is required")
+        } else if self.peg_price.is_some() && self.peg_price.expect("peg price is Some") <= 0.0 {
+            anyhow::bail!("the peg price must be positive")
+        }
+        let current_price = self.args.current_price(app).await?;
+        let peg_price = self.peg_price.unwrap_or(current_price);
+
+        let ticks = dex_utils::replicate::stableswap::ticks(
+            peg_price,
+            self.width,
+            self.amplification,
+            self.num_positions,
+        );
+        self.args.exec_ticks(app, current_price, &ticks).await
+    }
+}
+
+fn validate_price_range(lower_price: f64, upper_price: f64, num_positions: usize) -> Result<()> {
+    if lower_price <= 0.0 {
+        anyhow::bail!("the lower price must be positive")
+    } else if upper_price <= lower_price {
+        anyhow::bail!("the upper price must be above the lower price")
+    } else if num_positions == 0 {
+        anyhow::bail!("at least one position is required")
+    } else {
+        Ok(())
+    }
+}
+
+/// Approximates the current price of `pair` from the best positions on the chain.
+async fn get_spread(app: &mut App, pair: &DirectedUnitPair, input: &Value) -> Result<f64> {
+    let mut specific_client = app.specific_client().await?;
+    let spread_data = specific_client
+        .spread(SpreadRequest {
+            chain_id: "".to_string(),
+            trading_pair: Some(pair.into_directed_trading_pair().to_canonical().into()),
+        })
+        .await?
+        .into_inner();
+
+    tracing::debug!(
+        ?spread_data,
+        pair = pair.to_string(),
+        "fetched spread for pair"
+    );
+
+    if spread_data.best_1_to_2_position.is_none() || spread_data.best_2_to_1_position.is_none() {
+        bail!("couldn't find a market price for the specified assets, you can manually specify a price using --current-price <price>")
+    }
+
+    if input.asset_id == pair.start.id() {
+        Ok(spread_data.approx_effective_price_1_to_2)
+    } else if input.asset_id == pair.end.id() {
+        Ok(spread_data.approx_effective_price_2_to_1)
+    } else {
+        bail!("the supplied liquidity must be on the pair")
+    }
+}
+
+/// Prints the liquidity needed by `positions` and the positions themselves, after a warning.
+///
+/// Returns `false` if the user declined to proceed.
+async fn print_summary(
+    app: &mut App,
+    pair: &DirectedUnitPair,
+    positions: &[Position],
+    yes: bool,
+) -> Result<bool> {
+    let (amount_start, amount_end) =
+        positions
+            .iter()
+            .fold((Amount::zero(), Amount::zero()), |acc, pos| {
+                (
+                    acc.0
+                        + pos
+                            .reserves_for(pair.start.id())
+                            .expect("start is part of position"),
+                    acc.1
+                        + pos
+                            .reserves_for(pair.end.id())
+                            .expect("end is part of position"),
+                )
+            });
+    let amount_start = pair.start.format_value(amount_start);
+    let amount_end = pair.end.format_value(amount_end);
+
+    warning::rmm();
+
+    if !yes
+        && !Confirm::new()
+            .with_prompt("In the solemn voice of Mandos, he who sets the fates of all, you hear a question,\nechoing like a whisper through the Halls of Waiting:\n\"Do you, in your heart of hearts, truly wish to proceed?\"")
+            .interact()?
+    {
+        return Ok(false);
+    }
+    println!("");
+    println!("so it shall be...");
+    println!("");
+    println!("");
+    println!("#################################################################################");
+    println!("########################### LIQUIDITY SUMMARY ###################################");
+    println!("#################################################################################");
+    println!("");
+    println!(
+        "You want to provide liquidity on the pair {}",
+        pair.to_string()
+    );
+    println!("You will need:",);
+    println!(" -> {amount_start}{}", pair.start.to_string());
+    println!(" -> {amount_end}{}", pair.end.to_string());
+    // TODO(erwan): would be nice to print current balance?
+
+    println!("You will create the following pools:");
+    let asset_cache = app.view().assets().await?;
+    println!(
+        "{}",
+        crate::command::utils::render_positions(&asset_cache, positions),
+    );
+
+    Ok(true)
+}
+
+/// Opens `positions` on-chain, after confirmation.
+async fn open_positions(
+    app: &mut App,
+    positions: &[Position],
+    yes: bool,
+    source: u32,
+) -> Result<()> {
+    if !yes
+        && !Confirm::new()
+            .with_prompt("Do you want to open those liquidity positions on-chain?")
+            .interact()?
+    {
+        return Ok(());
+    }
+
+    let mut planner = Planner::new(OsRng);
+    positions.iter().for_each(|position| {
+        planner.position_open(position.clone());
+    });
+
+    let plan = planner
+        .plan(
+            app.view
+                .as_mut()
+                .context("view service must be initialized")?,
+            app.fvk.account_group_id(),
+            AddressIndex::new(source),
+        )
+        .await?;
+    let tx_id = app.build_and_submit_transaction(plan).await?;
+    println!("posted with transaction id: {tx_id}");
+
+    Ok(())
+}
+
+/// Renders the liquidity of `positions` as a depth chart, with the price levels selling the
+/// start asset above the levels selling the end asset, and the best prices in the middle.
+///
+/// Prices are in units of the end asset per unit of the start asset, before fees.
+fn render_depth_chart(pair: &DirectedUnitPair, positions: &[Position]) -> String {
+    const BAR_WIDTH: f64 = 40.0;
+
+    // Aggregate the reserves of each side by price level.
+    let mut asks: Vec<(f64, Amount)> = Vec::new();
+    let mut bids: Vec<(f64, Amount)> = Vec::new();
+    for position in positions {
+        let Some(phi) = position.phi.orient_start(pair.start.id()) else {
+            continue;
+        };
+        let p: f64 = U128x128::ratio(phi.p.value(), pair.end.unit_amount().value())
+            .expect("denom unit is not 0")
+            .into();
+        let q: f64 = U128x128::ratio(phi.q.value(), pair.start.unit_amount().value())
+            .expect("denom unit is not 0")
+            .into();
+        let price = p / q;
+
+        let reserves_start = position
+            .reserves_for(pair.start.id())
+            .expect("start is part of position");
+        let reserves_end = position
+            .reserves_for(pair.end.id())
+            .expect("end is part of position");
+        if reserves_start > Amount::zero() {
+            asks.push((price, reserves_start));
+        }
+        if reserves_end > Amount::zero() {
+            bids.push((price, reserves_end));
+        }
+    }
+    let aggregate = |mut levels: Vec<(f64, Amount)>| {
+        levels.sort_by(|a, b| a.0.total_cmp(&b.0));
+        levels.dedup_by(|level, previous| {
+            if level.0 == previous.0 {
+                previous.1 += level.1;
+                true
+            } else {
+                false
+            }
+        });
+        levels
+    };
+    // Asks are best at the lowest price, and bids at the highest.
+    let asks = aggregate(asks);
+    let mut bids = aggregate(bids);
+    bids.reverse();
+
+    let cumulative = |levels: &[(f64, Amount)]| {
+        let mut total = Amount::zero();
+        levels
+            .iter()
+            .map(|(price, reserves)| {
+                total += *reserves;
+                (*price, *reserves, total)
+            })
+            .collect::<Vec<_>>()
+    };
+    let asks = cumulative(&asks);
+    let bids = cumulative(&bids);
+
+    let mut table = Table::new();
+    table.load_preset(presets::NOTHING);
+    table.set_header(vec!["Side", "Price", "Reserves", "Cumulative", "Depth"]);
+
+    let mut add_rows =
+        |side: &str, levels: &[(f64, Amount, Amount)], unit: &penumbra_asset::asset::Unit| {
+            let max_total = levels
+                .last()
+                .map(|(_, _, total)| total.value() as f64)
+                .unwrap_or_default();
+            for (price, reserves, total) in levels {
+                let bar_len = if max_total > 0.0 {
+                    (total.value() as f64 / max_total * BAR_WIDTH).ceil() as usize
+                } else {
+                    0
+                };
+                table.add_row(vec![
+                    side.to_string(),
+                    format!("{price:.6}"),
+                    format!("{}{}", unit.format_value(*reserves), unit),
+                    format!("{}{}", unit.format_value(*total), unit),
+                    "█".repeat(bar_len),
+                ]);
+            }
+        };
+    let asks_top_down: Vec<_> = asks.into_iter().rev().collect();
+    add_rows("sell", &asks_top_down, &pair.start);
+    add_rows("buy", &bids, &pair.end);
+
+    format!(
+        "Depth of the positions on {}, priced in {} per {}:\n{}",
+        pair, pair.end, pair.start, table
+    )
+}
@@ -4,6 +4,8 @@ mod balance;
 use balance::BalanceCmd;
 mod address;
 use address::AddressCmd;
+mod history;
+use history::HistoryCmd;
 mod staked;
 
 use staked::StakedCmd;
@@ -34,6 +36,8 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Exports the per-asset balance changes of every transaction, as CSV or JSON.
+    History(HistoryCmd),
 }
 
 impl ViewCmd {
@@ -46,6 +50,7 @@ impl ViewCmd {
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::History(history_cmd) => history_cmd.offline(),
         }
     }
 
@@ -57,6 +62,9 @@ impl ViewCmd {
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
+            ViewCmd::History(history_cmd) => {
+                history_cmd.exec(app).await?;
+            }
             ViewCmd::ListTransactionHashes(transactions_cmd) => {
                 let view_client = app.view();
                 transactions_cmd
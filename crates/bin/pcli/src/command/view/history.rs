@@ -0,0 +1,582 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use futures::TryStreamExt;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_dex::{swap_claim::SwapClaimView, DirectedTradingPair, SwapExecution};
+use penumbra_keys::FullViewingKey;
+use penumbra_num::Amount;
+use penumbra_proto::client::v1alpha1::{
+    specific_query_service_client::SpecificQueryServiceClient, SwapExecutionsRequest,
+};
+use penumbra_shielded_pool::NoteView;
+use penumbra_stake::IdentityKey;
+use penumbra_transaction::{
+    view::action_view::{OutputView, SpendView},
+    ActionView,
+};
+use penumbra_view::{TransactionInfo, ViewClient};
+use serde::Serialize;
+use tonic::transport::Channel;
+
+use crate::App;
+
+/// The format to export the transaction history in.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum HistoryFormat {
+    Csv,
+    Json,
+}
+
+impl Default for HistoryFormat {
+    fn default() -> Self {
+        Self::Csv
+    }
+}
+
+/// Exports the wallet's transaction history, one entry per asset whose balance changed.
+#[derive(Debug, clap::Args)]
+pub struct HistoryCmd {
+    /// The format to write the history in.
+    #[clap(long, value_enum, default_value_t)]
+    format: HistoryFormat,
+    /// Only include transactions at or after this height.
+    #[clap(long)]
+    start_height: Option<u64>,
+    /// Only include transactions at or before this height.
+    #[clap(long)]
+    end_height: Option<u64>,
+    /// If set, value each entry in this reference asset (e.g. `test_usd`), using the most
+    /// recent DEX execution price at or before the transaction's height.
+    #[clap(long)]
+    price_in: Option<String>,
+}
+
+/// A single row of the exported history: the change in one asset's balance caused by one
+/// transaction.
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryEntry {
+    pub height: u64,
+    pub transaction_id: String,
+    /// A comma-separated summary of the kinds of actions in the transaction.
+    pub actions: String,
+    pub asset_id: String,
+    /// The unit the amounts in this entry are denominated in.
+    pub denom: String,
+    /// The signed change in this asset's balance, including any fee paid.
+    pub delta: String,
+    /// The fee paid by this transaction, if it was paid by this wallet and in this asset.
+    pub fee: String,
+    /// The staking rewards realized by undelegations in this transaction, if any.
+    pub staking_reward: String,
+    /// The price of one unit of this asset in the reference asset, if requested.
+    pub price: String,
+    /// The value of `delta` in the reference asset, if requested.
+    pub value: String,
+}
+
+/// The per-asset balance changes of a single transaction.
+#[derive(Debug, Default)]
+struct Deltas {
+    /// The net change in each asset's balance, in base units.
+    balances: BTreeMap<asset::Id, i128>,
+    /// The fee paid by this wallet, if any.
+    fee: Option<Value>,
+    /// The staking rewards realized by undelegating, in base units of the staking token.
+    staking_reward: i128,
+}
+
+/// Delegations not yet undelegated, used to attribute staking rewards at undelegation time.
+///
+/// Each validator's delegation tokens are tracked at their average cost, in staking tokens.
+#[derive(Debug, Default)]
+struct DelegationCostBasis {
+    by_validator: BTreeMap<IdentityKey, (u128, u128)>,
+}
+
+impl DelegationCostBasis {
+    fn delegate(&mut self, validator: IdentityKey, delegation_tokens: Amount, cost: Amount) {
+        let (tokens, basis) = self.by_validator.entry(validator).or_default();
+        *tokens += delegation_tokens.value();
+        *basis += cost.value();
+    }
+
+    /// Records an undelegation, returning the reward realized relative to the cost basis.
+    ///
+    /// Only the share of the proceeds paid for delegation tokens with a known cost is counted.
+    fn undelegate(
+        &mut self,
+        validator: IdentityKey,
+        delegation_tokens: Amount,
+        proceeds: Amount,
+    ) -> i128 {
+        let (tokens, basis) = self.by_validator.entry(validator).or_default();
+        let undelegated = delegation_tokens.value().min(*tokens);
+        // Tokens delegated before the start of the exported history have no known cost, so
+        // no reward can be attributed to them.
+        if undelegated == 0 {
+            return 0;
+        }
+        let cost = *basis * undelegated / *tokens;
+        let proceeds = proceeds.value() * undelegated / delegation_tokens.value();
+        *tokens -= undelegated;
+        *basis -= cost;
+        proceeds as i128 - cost as i128
+    }
+}
+
+/// The execution prices of assets in terms of a reference asset, indexed by height.
+struct PriceHistory {
+    reference: asset::DenomMetadata,
+    prices: BTreeMap<asset::Id, BTreeMap<u64, f64>>,
+}
+
+impl PriceHistory {
+    async fn fetch(
+        client: &mut SpecificQueryServiceClient<Channel>,
+        reference: asset::DenomMetadata,
+        assets: impl IntoIterator<Item = asset::Id>,
+        end_height: u64,
+    ) -> Result<Self> {
+        let mut prices = BTreeMap::new();
+        for asset_id in assets {
+            if asset_id == reference.id() {
+                continue;
+            }
+            let mut history = BTreeMap::new();
+            // Executions in either direction give us a price for the asset.
+            for (pair, inverted) in [
+                (DirectedTradingPair::new(asset_id, reference.id()), false),
+                (DirectedTradingPair::new(reference.id(), asset_id), true),
+            ] {
+                let mut stream = client
+                    .swap_executions(SwapExecutionsRequest {
+                        start_height: 0,
+                        end_height,
+                        trading_pair: Some(pair.into()),
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner();
+                while let Some(rsp) = stream.try_next().await? {
+                    let execution: SwapExecution = rsp
+                        .swap_execution
+                        .context("missing swap execution in response")?
+                        .try_into()?;
+                    let input = execution.input.amount.value() as f64;
+                    let output = execution.output.amount.value() as f64;
+                    if input == 0.0 || output == 0.0 {
+                        continue;
+                    }
+                    let price = if inverted {
+                        input / output
+                    } else {
+                        output / input
+                    };
+                    history.insert(rsp.height, price);
+                }
+            }
+            prices.insert(asset_id, history);
+        }
+        Ok(Self { reference, prices })
+    }
+
+    /// The price of one base unit of `asset_id` in base units of the reference asset, as of
+    /// the last execution at or before `height`.
+    fn price_at(&self, asset_id: &asset::Id, height: u64) -> Option<f64> {
+        if *asset_id == self.reference.id() {
+            return Some(1.0);
+        }
+        self.prices
+            .get(asset_id)?
+            .range(..=height)
+            .next_back()
+            .map(|(_, price)| *price)
+    }
+}
+
+fn action_kind(action_view: &ActionView) -> &'static str {
+    match action_view {
+        ActionView::Spend(_) => "spend",
+        ActionView::Output(_) => "output",
+        ActionView::Swap(_) => "swap",
+        ActionView::SwapClaim(_) => "swap_claim",
+        ActionView::Delegate(_) => "delegate",
+        ActionView::Undelegate(_) => "undelegate",
+        ActionView::UndelegateClaim(_) => "undelegate_claim",
+        ActionView::ValidatorDefinition(_) => "validator_definition",
+        ActionView::IbcAction(_) => "ibc_action",
+        ActionView::ProposalSubmit(_) => "proposal_submit",
+        ActionView::ProposalWithdraw(_) => "proposal_withdraw",
+        ActionView::ProposalDepositClaim(_) => "proposal_deposit_claim",
+        ActionView::ValidatorVote(_) => "validator_vote",
+        ActionView::DelegatorVote(_) => "delegator_vote",
+        ActionView::PositionOpen(_) => "position_open",
+        ActionView::PositionClose(_) => "position_close",
+        ActionView::PositionWithdraw(_) => "position_withdraw",
+        ActionView::PositionRewardClaim(_) => "position_reward_claim",
+        ActionView::Ics20Withdrawal(_) => "ics20_withdrawal",
+        ActionView::DaoDeposit(_) => "dao_deposit",
+        ActionView::DaoSpend(_) => "dao_spend",
+        ActionView::DaoOutput(_) => "dao_output",
+    }
+}
+
+/// Derives the balance changes of a transaction from the notes visible to this wallet.
+fn transaction_deltas(
+    fvk: &FullViewingKey,
+    tx_info: &TransactionInfo,
+    cost_basis: &mut DelegationCostBasis,
+) -> Deltas {
+    let mut deltas = Deltas::default();
+    let mut add_note = |note: &NoteView, sign: i128| {
+        if fvk.incoming().views_address(&note.address.address()) {
+            let value = note.value.value();
+            *deltas.balances.entry(value.asset_id).or_default() +=
+                sign * value.amount.value() as i128;
+        }
+    };
+
+    let mut spent_own_notes = false;
+    let mut staking_reward = 0;
+    for action_view in tx_info.view.body_view.action_views.iter() {
+        match action_view {
+            ActionView::Spend(SpendView::Visible { note, .. }) => {
+                spent_own_notes |= fvk.incoming().views_address(&note.address.address());
+                add_note(note, -1);
+            }
+            ActionView::Output(OutputView::Visible { note, .. }) => add_note(note, 1),
+            ActionView::SwapClaim(SwapClaimView::Visible {
+                output_1, output_2, ..
+            }) => {
+                add_note(output_1, 1);
+                add_note(output_2, 1);
+            }
+            ActionView::Delegate(delegate) => cost_basis.delegate(
+                delegate.validator_identity,
+                delegate.delegation_amount,
+                delegate.unbonded_amount,
+            ),
+            ActionView::Undelegate(undelegate) => {
+                staking_reward += cost_basis.undelegate(
+                    undelegate.validator_identity,
+                    undelegate.delegation_amount,
+                    undelegate.unbonded_amount,
+                )
+            }
+            _ => {}
+        }
+    }
+
+    // Only transactions spending our own notes had their fees paid by us.
+    if spent_own_notes {
+        deltas.fee = Some(tx_info.view.body_view.fee.value());
+    }
+    deltas.staking_reward = staking_reward;
+    deltas
+}
+
+/// Formats a signed amount of base units in the given asset's default unit.
+fn format_signed(cache: &asset::Cache, asset_id: &asset::Id, amount: i128) -> String {
+    let formatted = match cache.get(asset_id) {
+        Some(denom) => denom
+            .default_unit()
+            .format_value(Amount::from(amount.unsigned_abs())),
+        None => amount.unsigned_abs().to_string(),
+    };
+    if amount < 0 {
+        format!("-{formatted}")
+    } else {
+        formatted
+    }
+}
+
+fn denom_string(cache: &asset::Cache, asset_id: &asset::Id) -> String {
+    cache
+        .get(asset_id)
+        .map(|denom| denom.default_unit().to_string())
+        .unwrap_or_else(|| asset_id.to_string())
+}
+
+impl HistoryCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let fvk = app.fvk.clone();
+        let view = app.view();
+        let cache = view.assets().await?;
+
+        let mut txs = view
+            .transaction_info(self.start_height, self.end_height)
+            .await?;
+        txs.sort_by_key(|tx_info| tx_info.height);
+
+        let mut cost_basis = DelegationCostBasis::default();
+        let deltas = txs
+            .iter()
+            .map(|tx_info| transaction_deltas(&fvk, tx_info, &mut cost_basis))
+            .collect::<Vec<_>>();
+
+        let prices = match &self.price_in {
+            Some(reference) => {
+                let reference = asset::REGISTRY.parse_unit(reference.as_str()).base();
+                let assets = deltas
+                    .iter()
+                    .flat_map(|d| d.balances.keys().copied())
+                    .collect::<std::collections::BTreeSet<_>>();
+                let end_height = txs.last().map(|tx_info| tx_info.height).unwrap_or(0);
+                let mut client = app.specific_client().await?;
+                Some(PriceHistory::fetch(&mut client, reference, assets, end_height).await?)
+            }
+            None => None,
+        };
+
+        let mut entries = Vec::new();
+        for (tx_info, deltas) in txs.iter().zip(deltas) {
+            let actions = tx_info
+                .view
+                .body_view
+                .action_views
+                .iter()
+                .map(action_kind)
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let mut balances = deltas.balances;
+            // A staking reward is reported on the staking token's row, even if this
+            // transaction did not change that balance.
+            if deltas.staking_reward != 0 {
+                balances.entry(*STAKING_TOKEN_ASSET_ID).or_default();
+            }
+            for (asset_id, delta) in balances {
+                if delta == 0
+                    && !(asset_id == *STAKING_TOKEN_ASSET_ID && deltas.staking_reward != 0)
+                {
+                    continue;
+                }
+                let fee = match deltas.fee {
+                    Some(fee) if fee.asset_id == asset_id => {
+                        format_signed(&cache, &asset_id, fee.amount.value() as i128)
+                    }
+                    _ => String::new(),
+                };
+                let staking_reward =
+                    if asset_id == *STAKING_TOKEN_ASSET_ID && deltas.staking_reward != 0 {
+                        format_signed(&cache, &asset_id, deltas.staking_reward)
+                    } else {
+                        String::new()
+                    };
+                let (price, value) = match prices
+                    .as_ref()
+                    .and_then(|p| Some((p, p.price_at(&asset_id, tx_info.height)?)))
+                {
+                    Some((prices, price)) => {
+                        let reference = prices.reference.default_unit();
+                        let scale =
+                            |base_units: f64| base_units / 10f64.powi(reference.exponent() as i32);
+                        let unit_scale = cache
+                            .get(&asset_id)
+                            .map(|denom| 10f64.powi(denom.default_unit().exponent() as i32))
+                            .unwrap_or(1.0);
+                        (
+                            format!("{}{}", scale(price * unit_scale), reference),
+                            format!("{}{}", scale(price * delta as f64), reference),
+                        )
+                    }
+                    None => (String::new(), String::new()),
+                };
+
+                entries.push(HistoryEntry {
+                    height: tx_info.height,
+                    transaction_id: hex::encode(&tx_info.id),
+                    actions: actions.clone(),
+                    asset_id: asset_id.to_string(),
+                    denom: denom_string(&cache, &asset_id),
+                    delta: format_signed(&cache, &asset_id, delta),
+                    fee,
+                    staking_reward,
+                    price,
+                    value,
+                });
+            }
+        }
+
+        match self.format {
+            HistoryFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(std::io::stdout());
+                for entry in entries {
+                    writer.serialize(entry)?;
+                }
+                writer.flush()?;
+            }
+            HistoryFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&entries)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn validator() -> IdentityKey {
+        IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into())
+    }
+
+    enum Op {
+        Delegate {
+            tokens: u128,
+            cost: u128,
+        },
+        Undelegate {
+            tokens: u128,
+            proceeds: u128,
+            reward: i128,
+        },
+    }
+
+    #[test]
+    fn cost_basis_attributes_rewards_to_undelegations() {
+        use Op::*;
+
+        let cases: &[(&str, &[Op])] = &[
+            (
+                "full undelegation",
+                &[
+                    Delegate {
+                        tokens: 100,
+                        cost: 100,
+                    },
+                    Undelegate {
+                        tokens: 100,
+                        proceeds: 110,
+                        reward: 10,
+                    },
+                ],
+            ),
+            (
+                "partial undelegations",
+                &[
+                    Delegate {
+                        tokens: 100,
+                        cost: 100,
+                    },
+                    Undelegate {
+                        tokens: 40,
+                        proceeds: 48,
+                        reward: 8,
+                    },
+                    Undelegate {
+                        tokens: 60,
+                        proceeds: 72,
+                        reward: 12,
+                    },
+                ],
+            ),
+            (
+                "delegations at different rates are averaged",
+                &[
+                    Delegate {
+                        tokens: 100,
+                        cost: 100,
+                    },
+                    Delegate {
+                        tokens: 100,
+                        cost: 120,
+                    },
+                    Undelegate {
+                        tokens: 100,
+                        proceeds: 130,
+                        reward: 20,
+                    },
+                    Undelegate {
+                        tokens: 100,
+                        proceeds: 130,
+                        reward: 20,
+                    },
+                ],
+            ),
+            (
+                "tokens delegated before the history have no reward",
+                &[Undelegate {
+                    tokens: 100,
+                    proceeds: 110,
+                    reward: 0,
+                }],
+            ),
+            (
+                "only the tokens with a known cost are counted",
+                &[
+                    Delegate {
+                        tokens: 50,
+                        cost: 50,
+                    },
+                    Undelegate {
+                        tokens: 100,
+                        proceeds: 120,
+                        reward: 10,
+                    },
+                    Undelegate {
+                        tokens: 10,
+                        proceeds: 12,
+                        reward: 0,
+                    },
+                ],
+            ),
+            (
+                "a loss is a negative reward",
+                &[
+                    Delegate {
+                        tokens: 100,
+                        cost: 100,
+                    },
+                    Undelegate {
+                        tokens: 50,
+                        proceeds: 45,
+                        reward: -5,
+                    },
+                ],
+            ),
+        ];
+
+        for (name, ops) in cases {
+            let validator = validator();
+            let mut cost_basis = DelegationCostBasis::default();
+            for op in ops.iter() {
+                match *op {
+                    Delegate { tokens, cost } => {
+                        cost_basis.delegate(validator, tokens.into(), cost.into())
+                    }
+                    Undelegate {
+                        tokens,
+                        proceeds,
+                        reward,
+                    } => assert_eq!(
+                        cost_basis.undelegate(validator, tokens.into(), proceeds.into()),
+                        reward,
+                        "{name}"
+                    ),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cost_basis_is_tracked_per_validator() {
+        let (a, b) = (validator(), validator());
+        let mut cost_basis = DelegationCostBasis::default();
+        cost_basis.delegate(a, 100u64.into(), 100u64.into());
+        cost_basis.delegate(b, 100u64.into(), 200u64.into());
+
+        assert_eq!(cost_basis.undelegate(b, 100u64.into(), 210u64.into()), 10);
+        assert_eq!(cost_basis.undelegate(a, 100u64.into(), 110u64.into()), 10);
+    }
+}
@@ -2,6 +2,7 @@
 /// The acceptable amount of difference between a value and its approximation.
 const APPROXIMATION_TOLERANCE: f64 = 1e-8;
 
+pub use penumbra_dex::lp::replicate::{linear, range, stableswap, ticks};
 pub mod xyk;
 pub mod balancer {}
 pub mod volatility {}
@@ -1,5 +1,5 @@
 mod tests {
-    use crate::dex_utils::replicate::xyk;
+    use crate::dex_utils::replicate::{range, xyk};
     const PRECISION_BOUND: f64 = 0.0001;
 
     fn approx_eq(a: f64, b: f64) -> bool {
@@ -36,4 +36,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    /// Tests that the ticks of a range above the current price hold the same amount of the
+    /// start asset as the constant product curve restricted to that range.
+    fn test_range_ticks() {
+        let (lower_price, upper_price) = (4.0, 16.0);
+        let ticks = range::ticks(lower_price, upper_price, 1.0, 10);
+
+        assert_eq!(ticks.len(), 10);
+        assert!(ticks
+            .iter()
+            .all(|tick| tick.price > lower_price && tick.price < upper_price));
+
+        let total_weight: f64 = ticks.iter().map(|tick| tick.weight).sum();
+        let expected = 1.0 / f64::sqrt(lower_price) - 1.0 / f64::sqrt(upper_price);
+        assert!(approx_eq(total_weight, expected));
+    }
 }
@@ -12,7 +12,7 @@ use penumbra_dex::{
 use penumbra_fee::Fee;
 use penumbra_governance::DelegatorVoteProof;
 use penumbra_keys::keys::{SeedPhrase, SpendKey};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proof_params::{
     DELEGATOR_VOTE_PROOF_PROVING_KEY, DELEGATOR_VOTE_PROOF_VERIFICATION_KEY,
     NULLIFIER_DERIVATION_PROOF_PROVING_KEY, NULLIFIER_DERIVATION_PROOF_VERIFICATION_KEY,
@@ -211,7 +211,13 @@ fn swap_proof_parameters_vs_current_swap_circuit() {
     )
     .expect("can create proof");
 
-    let proof_result = proof.verify(vk, balance_commitment, swap_commitment, fee_commitment);
+    let proof_result = proof.verify(
+        vk,
+        balance_commitment,
+        swap_commitment,
+        fee_commitment,
+        Amount::zero(),
+    );
 
     assert!(proof_result.is_ok());
 }
@@ -267,6 +273,8 @@ fn swap_claim_parameters_vs_current_swap_claim_circuit() {
         height: height.into(),
         trading_pair: swap_plaintext.trading_pair,
         epoch_starting_height: (epoch_duration * position.epoch()).into(),
+        limit_price_1: U128x128::default(),
+        limit_price_2: U128x128::default(),
     };
     let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
 
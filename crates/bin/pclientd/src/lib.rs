@@ -6,17 +6,21 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
+use metrics_exporter_prometheus::PrometheusBuilder;
 use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
 use penumbra_keys::keys::{SeedPhrase, SpendKey};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::{
     client::v1alpha1::oblivious_query_service_client::ObliviousQueryServiceClient,
+    client::v1alpha1::specific_query_service_client::SpecificQueryServiceClient,
     client::v1alpha1::ChainParametersRequest,
+    custody::v1alpha1::custody_protocol_service_client::CustodyProtocolServiceClient,
     custody::v1alpha1::custody_protocol_service_server::CustodyProtocolServiceServer,
+    view::v1alpha1::view_protocol_service_client::ViewProtocolServiceClient,
     view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer,
 };
-use penumbra_view::{Storage, ViewService};
+use penumbra_view::{AutoClaimConfig, Storage, ViewService};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -30,6 +34,9 @@ use url::Url;
 mod proxy;
 pub use proxy::{ObliviousQueryProxy, SpecificQueryProxy, TendermintProxyProxy};
 
+mod rebalance;
+pub use rebalance::{RebalanceConfig, Rebalancer, Strategy};
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PclientdConfig {
@@ -40,8 +47,15 @@ pub struct PclientdConfig {
     pub grpc_url: Url,
     /// The address to bind to serve gRPC.
     pub bind_addr: SocketAddr,
+    /// If set, the address to bind to serve Prometheus metrics.
+    pub metrics_bind_addr: Option<SocketAddr>,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// If set, swap outputs are claimed automatically, with the given retry settings.
+    pub auto_claim: Option<AutoClaimConfig>,
+    /// If set, the liquidity positions owned on a pair are kept centered on its market price.
+    /// Requires custody mode.
+    pub rebalance: Option<RebalanceConfig>,
 }
 
 impl PclientdConfig {
@@ -271,6 +285,9 @@ impl Opt {
                     full_viewing_key,
                     grpc_url: grpc_url.clone(),
                     bind_addr: bind_addr.clone(),
+                    auto_claim: None,
+                    rebalance: None,
+                    metrics_bind_addr: None,
                 };
 
                 let encoded = toml::to_string_pretty(&client_config)
@@ -306,9 +323,39 @@ impl Opt {
                 let specific_query_proxy = SpecificQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service = ViewProtocolServiceServer::new(
-                    ViewService::new(storage, config.grpc_url).await?,
-                );
+                if let Some(metrics_bind_addr) = config.metrics_bind_addr {
+                    PrometheusBuilder::new()
+                        .with_http_listener(metrics_bind_addr)
+                        .install()
+                        .context("failed to install prometheus exporter")?;
+                    penumbra_view::register_metrics();
+                }
+
+                let view_service = ViewService::new(storage, config.grpc_url).await?;
+                if let Some(auto_claim) = config.auto_claim.clone() {
+                    tracing::info!(?auto_claim, "automatically claiming swap outputs");
+                    view_service.spawn_auto_claim(auto_claim);
+                }
+                if let Some(rebalance) = config.rebalance.clone() {
+                    let kms_config = config
+                        .kms_config
+                        .as_ref()
+                        .context("rebalancing positions requires custody mode")?;
+                    tracing::info!(?rebalance, "rebalancing liquidity positions");
+                    let rebalancer = Rebalancer::new(
+                        rebalance,
+                        config.full_viewing_key.clone(),
+                        ViewProtocolServiceClient::new(ViewProtocolServiceServer::new(
+                            view_service.clone(),
+                        )),
+                        CustodyProtocolServiceClient::new(CustodyProtocolServiceServer::new(
+                            SoftKms::new(kms_config.spend_key.clone().into()),
+                        )),
+                        SpecificQueryServiceClient::new(proxy_channel.clone()),
+                    )?;
+                    tokio::spawn(rebalancer.run());
+                }
+                let view_service = ViewProtocolServiceServer::new(view_service);
                 let custody_service = config.kms_config.as_ref().map(|kms_config| {
                     CustodyProtocolServiceServer::new(SoftKms::new(
                         kms_config.spend_key.clone().into(),
@@ -0,0 +1,695 @@
+use std::{collections::BTreeSet, time::Duration};
+
+use anyhow::{Context, Result};
+use penumbra_asset::Value;
+use penumbra_custody::CustodyClient;
+use penumbra_dex::{
+    lp::{
+        position::{self, Position},
+        replicate::{linear, range, stableswap, ticks},
+    },
+    DirectedTradingPair, DirectedUnitPair,
+};
+use penumbra_keys::{keys::AddressIndex, FullViewingKey};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::client::v1alpha1::{
+    specific_query_service_client::SpecificQueryServiceClient, LiquidityPositionByIdRequest,
+    LiquidityPositionsByPriceRequest,
+};
+use penumbra_view::{Planner, ViewClient};
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+use tonic::transport::Channel;
+
+/// The shape of the liquidity laid out around the market price.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Strategy {
+    /// A concentrated liquidity range, spaced geometrically around the market price.
+    Range,
+    /// A ladder of equally sized positions, evenly spaced around the market price.
+    Linear,
+    /// A stable-swap-like curve, concentrating liquidity close to the market price.
+    StableSwap,
+}
+
+impl Strategy {
+    /// The bounds of the price range laid out around `center`.
+    fn bounds(&self, center: f64, width: f64) -> (f64, f64) {
+        match self {
+            Strategy::Range => (center / (1.0 + width), center * (1.0 + width)),
+            Strategy::Linear | Strategy::StableSwap => {
+                (center * (1.0 - width), center * (1.0 + width))
+            }
+        }
+    }
+
+    /// Recovers the price a ladder was centered on from its lowest and highest prices.
+    fn center(&self, lowest_price: f64, highest_price: f64) -> f64 {
+        match self {
+            Strategy::Range => f64::sqrt(lowest_price * highest_price),
+            Strategy::Linear | Strategy::StableSwap => (lowest_price + highest_price) / 2.0,
+        }
+    }
+
+    fn ticks(&self, center: f64, config: &RebalanceConfig) -> Vec<ticks::Tick> {
+        let (lower_price, upper_price) = self.bounds(center, config.width);
+        match self {
+            Strategy::Range => range::ticks(lower_price, upper_price, center, config.num_positions),
+            Strategy::Linear => linear::ticks(lower_price, upper_price, config.num_positions),
+            Strategy::StableSwap => stableswap::ticks(
+                center,
+                config.width,
+                config.amplification,
+                config.num_positions,
+            ),
+        }
+    }
+}
+
+/// Configuration for keeping the liquidity positions owned on a pair centered on the market price.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RebalanceConfig {
+    /// The directed pair to provide liquidity on, e.g. `gm:gn`.
+    pub pair: String,
+    /// The most of the start asset to keep in positions, e.g. `100gm`.
+    pub start_budget: String,
+    /// The most of the end asset to keep in positions, e.g. `100gn`.
+    pub end_budget: String,
+    /// The shape of the liquidity laid out around the market price.
+    #[serde(default = "default_strategy")]
+    pub strategy: Strategy,
+    /// How far from the market price to provide liquidity, as a fraction of the price.
+    #[serde(default = "default_width")]
+    pub width: f64,
+    /// The number of price levels to provide liquidity at.
+    #[serde(default = "default_num_positions")]
+    pub num_positions: usize,
+    /// How strongly the `stable_swap` strategy concentrates liquidity close to the market price.
+    #[serde(default = "default_amplification")]
+    pub amplification: f64,
+    /// The fee tiers to split the liquidity across, in basis points.
+    #[serde(default = "default_fee_tiers")]
+    pub fee_tiers: Vec<u32>,
+    /// How far the market price may drift from the center of the positions before they are
+    /// rebalanced, as a fraction of the price.
+    #[serde(default = "default_threshold")]
+    pub threshold: f64,
+    /// How often to check the market, in seconds.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// The least time to wait between two rebalances, in seconds.
+    #[serde(default = "default_min_interval_secs")]
+    pub min_interval_secs: u64,
+    /// Only spend funds originally received by the given address index.
+    #[serde(default)]
+    pub source: u32,
+}
+
+fn default_strategy() -> Strategy {
+    Strategy::Range
+}
+
+fn default_width() -> f64 {
+    0.1
+}
+
+fn default_num_positions() -> usize {
+    10
+}
+
+fn default_amplification() -> f64 {
+    100.0
+}
+
+fn default_fee_tiers() -> Vec<u32> {
+    vec![30]
+}
+
+fn default_threshold() -> f64 {
+    0.02
+}
+
+fn default_poll_interval_secs() -> u64 {
+    30
+}
+
+fn default_min_interval_secs() -> u64 {
+    600
+}
+
+impl RebalanceConfig {
+    /// Parses the pair and budgets, checking the configuration is usable.
+    fn parse(&self) -> Result<(DirectedUnitPair, Value, Value)> {
+        let pair: DirectedUnitPair = self.pair.parse().context("invalid pair")?;
+        let start_budget: Value = self.start_budget.parse().context("invalid start budget")?;
+        let end_budget: Value = self.end_budget.parse().context("invalid end budget")?;
+
+        if start_budget.asset_id != pair.start.id() {
+            anyhow::bail!("the start budget must be in {}", pair.start)
+        } else if end_budget.asset_id != pair.end.id() {
+            anyhow::bail!("the end budget must be in {}", pair.end)
+        } else if self.width <= 0.0 || self.width >= 1.0 {
+            anyhow::bail!("the width must be strictly between 0 and 1")
+        } else if self.amplification < 0.0 {
+            anyhow::bail!("the amplification must not be negative")
+        } else if self.threshold <= 0.0 {
+            anyhow::bail!("the threshold must be positive")
+        } else if self.num_positions < 2 {
+            anyhow::bail!("at least two positions are required")
+        } else if self.fee_tiers.is_empty() {
+            anyhow::bail!("at least one fee tier is required")
+        } else if self.fee_tiers.iter().any(|fee_bps| *fee_bps > 5000) {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        } else if self.poll_interval_secs == 0 {
+            anyhow::bail!("the poll interval must be positive")
+        }
+
+        Ok((pair, start_budget, end_budget))
+    }
+}
+
+/// Keeps the positions owned on a pair centered on the market price.
+///
+/// The market is polled, and whenever its price drifts too far from the price the owned
+/// positions are centered on, they are closed, withdrawn, and reopened around the new price.
+/// All the positions owned on the pair are managed, including ones opened by other means.
+pub struct Rebalancer<V, C> {
+    config: RebalanceConfig,
+    pair: DirectedUnitPair,
+    start_budget: Value,
+    end_budget: Value,
+    fvk: FullViewingKey,
+    view: V,
+    custody: C,
+    specific_client: SpecificQueryServiceClient<Channel>,
+}
+
+impl<V, C> Rebalancer<V, C>
+where
+    V: ViewClient + Send + 'static,
+    C: CustodyClient + Send + 'static,
+{
+    pub fn new(
+        config: RebalanceConfig,
+        fvk: FullViewingKey,
+        view: V,
+        custody: C,
+        specific_client: SpecificQueryServiceClient<Channel>,
+    ) -> Result<Self> {
+        let (pair, start_budget, end_budget) =
+            config.parse().context("invalid rebalance config")?;
+        Ok(Self {
+            config,
+            pair,
+            start_budget,
+            end_budget,
+            fvk,
+            view,
+            custody,
+            specific_client,
+        })
+    }
+
+    pub async fn run(mut self) {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs));
+        let mut last_rebalance: Option<Instant> = None;
+        loop {
+            interval.tick().await;
+
+            let cooling_down = last_rebalance.map_or(false, |last_rebalance| {
+                last_rebalance.elapsed() < Duration::from_secs(self.config.min_interval_secs)
+            });
+            if cooling_down {
+                continue;
+            }
+            match self.needs_rebalance().await {
+                Ok(Some(market_price)) => {
+                    // Rate-limit attempts whether or not they succeed, so that a failing
+                    // rebalance is not retried in a tight loop.
+                    last_rebalance = Some(Instant::now());
+                    if let Err(e) = self.rebalance(market_price).await {
+                        tracing::warn!(?e, "failed to rebalance positions");
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(?e, "failed to check positions"),
+            }
+        }
+    }
+
+    /// Returns the market price if the owned positions should be recentered on it.
+    async fn needs_rebalance(&mut self) -> Result<Option<f64>> {
+        let market_price = self.market_price().await?;
+
+        let opened = self
+            .owned_positions(&[position::State::Opened])
+            .await?
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect::<Vec<_>>();
+        let Some(center) = center(&self.pair, self.config.strategy, &opened) else {
+            tracing::info!(market_price, pair = %self.pair.to_string(), "no open positions");
+            return Ok(Some(market_price));
+        };
+
+        let drift = drift(center, market_price);
+        tracing::debug!(market_price, center, drift, "checked positions");
+        if drift > self.config.threshold {
+            tracing::info!(
+                market_price,
+                center,
+                drift,
+                "market price drifted from the positions"
+            );
+            Ok(Some(market_price))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Closes and withdraws the owned positions, then reopens positions centered on `market_price`.
+    async fn rebalance(&mut self, market_price: f64) -> Result<()> {
+        let opened = self.owned_positions(&[position::State::Opened]).await?;
+        if !opened.is_empty() {
+            tracing::info!(count = opened.len(), "closing positions");
+            let mut planner = Planner::new(OsRng);
+            for (id, _) in &opened {
+                planner.position_close(*id);
+            }
+            // This waits for the closes to be detected, so the positions are closed on chain
+            // before they are withdrawn.
+            self.submit(planner).await?;
+        }
+
+        // Withdraw the positions just closed, along with any that were already closed. Their
+        // reserves are fetched again, since the positions may have traded before closing.
+        let mut to_withdraw = opened
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<BTreeSet<_>>();
+        to_withdraw.extend(
+            self.view
+                .owned_position_ids(Some(position::State::Closed), Some(self.trading_pair()))
+                .await?,
+        );
+        let mut closed = Vec::new();
+        for id in to_withdraw {
+            let position = self.position(id).await?;
+            if position.state == position::State::Closed {
+                closed.push((id, position));
+            } else {
+                tracing::warn!(%id, state = %position.state, "not withdrawing position");
+            }
+        }
+        if !closed.is_empty() {
+            tracing::info!(count = closed.len(), "withdrawing positions");
+            let mut planner = Planner::new(OsRng);
+            for (id, position) in &closed {
+                planner.position_withdraw(*id, position.reserves.clone(), position.phi.pair);
+            }
+            self.submit(planner).await?;
+        }
+
+        let positions = self.layout(market_price).await?;
+        if positions.is_empty() {
+            tracing::warn!(pair = %self.pair.to_string(), "no funds are available to provide liquidity");
+            return Ok(());
+        }
+        tracing::info!(count = positions.len(), market_price, "opening positions");
+        let mut planner = Planner::new(OsRng);
+        for position in positions {
+            planner.position_open(position);
+        }
+        self.submit(planner).await
+    }
+
+    /// Lays out new positions around `market_price`, within what is left of the budget after the
+    /// funds still held in owned positions, and within the balance available.
+    async fn layout(&mut self, market_price: f64) -> Result<Vec<Position>> {
+        let ticks = self.config.strategy.ticks(market_price, &self.config);
+        let deployed = self
+            .owned_positions(&[position::State::Opened, position::State::Closed])
+            .await?;
+
+        let mut positions = Vec::new();
+        for (budget, asset) in [
+            (self.start_budget, self.pair.start.clone()),
+            (self.end_budget, self.pair.end.clone()),
+        ] {
+            let deployed = deployed
+                .iter()
+                .filter_map(|(_, position)| position.reserves_for(asset.id()))
+                .fold(Amount::zero(), |total, amount| total + amount);
+            let balance = self
+                .view
+                .balances(AddressIndex::new(self.config.source), Some(asset.id()))
+                .await?
+                .into_iter()
+                .fold(Amount::zero(), |total, (_, amount)| total + amount);
+            let amount = available(budget.amount, deployed, balance);
+            if amount == Amount::zero() {
+                tracing::warn!(%asset, %deployed, %balance, "no funds available to provide liquidity");
+                continue;
+            }
+
+            // Each side is scaled to its own budget, so only keep the positions on that side.
+            let input = Value {
+                amount,
+                asset_id: asset.id(),
+            };
+            positions.extend(
+                ticks::positions_from_ticks(
+                    OsRng,
+                    &self.pair,
+                    &input,
+                    market_price,
+                    &ticks,
+                    &self.config.fee_tiers,
+                )?
+                .into_iter()
+                .filter(|position| {
+                    position
+                        .reserves_for(asset.id())
+                        .map_or(false, |reserves| reserves > Amount::zero())
+                }),
+            );
+        }
+
+        Ok(positions)
+    }
+
+    /// Returns the market price of the pair, from the best positions on either side of the book
+    /// which are not owned by this wallet.
+    async fn market_price(&mut self) -> Result<f64> {
+        let owned = self
+            .view
+            .owned_position_ids(None, Some(self.trading_pair()))
+            .await?
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        let pair = self.pair.into_directed_trading_pair();
+        let bid = self.best_price(pair, &owned).await?;
+        let ask = self.best_price(pair.flip(), &owned).await?;
+
+        let market_price = mid_price(bid, ask).with_context(|| {
+            format!(
+                "no positions owned by others quote a price for {}",
+                self.pair.to_string()
+            )
+        })?;
+        if market_price <= 0.0 {
+            anyhow::bail!(
+                "the market price of {} is not positive",
+                self.pair.to_string()
+            );
+        }
+        Ok(market_price)
+    }
+
+    /// Returns the price of the best position trading in the direction of `pair` which is not
+    /// among the `owned` positions.
+    async fn best_price(
+        &mut self,
+        pair: DirectedTradingPair,
+        owned: &BTreeSet<position::Id>,
+    ) -> Result<Option<f64>> {
+        let mut stream = self
+            .specific_client
+            .liquidity_positions_by_price(LiquidityPositionsByPriceRequest {
+                chain_id: String::new(),
+                trading_pair: Some(pair.into()),
+                // At most all of the owned positions are ahead of the best one not owned.
+                limit: owned.len() as u64 + 1,
+            })
+            .await?
+            .into_inner();
+        while let Some(response) = stream.message().await? {
+            let position: Position = response
+                .data
+                .context("missing position in response")?
+                .try_into()?;
+            if !owned.contains(&position.id()) {
+                return Ok(price(&self.pair, &position));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the owned positions on the pair in any of the given `states`.
+    async fn owned_positions(
+        &mut self,
+        states: &[position::State],
+    ) -> Result<Vec<(position::Id, Position)>> {
+        let mut positions = Vec::new();
+        for state in states {
+            let position_ids = self
+                .view
+                .owned_position_ids(Some(*state), Some(self.trading_pair()))
+                .await?;
+            for position_id in position_ids {
+                let position = self.position(position_id).await?;
+                positions.push((position_id, position));
+            }
+        }
+        Ok(positions)
+    }
+
+    async fn position(&mut self, position_id: position::Id) -> Result<Position> {
+        self.specific_client
+            .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                chain_id: String::new(),
+                position_id: Some(position_id.into()),
+            })
+            .await?
+            .into_inner()
+            .data
+            .with_context(|| format!("position {position_id} not found"))?
+            .try_into()
+    }
+
+    fn trading_pair(&self) -> penumbra_dex::TradingPair {
+        self.pair.into_directed_trading_pair().to_canonical()
+    }
+
+    /// Plans, authorizes and builds a transaction, then broadcasts it and waits for it to be
+    /// detected.
+    async fn submit(&mut self, mut planner: Planner<OsRng>) -> Result<()> {
+        let plan = planner
+            .plan(
+                &mut self.view,
+                self.fvk.account_group_id(),
+                AddressIndex::new(self.config.source),
+            )
+            .await?;
+        let transaction = penumbra_wallet::build_transaction(
+            &self.fvk,
+            &mut self.view,
+            &mut self.custody,
+            OsRng,
+            plan,
+        )
+        .await?;
+        let (id, height) = self.view.broadcast_transaction(transaction, true).await?;
+        tracing::info!(%id, height, "rebalancing transaction detected");
+        Ok(())
+    }
+}
+
+/// Returns the price of a position, in units of the end asset per unit of the start asset.
+fn price(pair: &DirectedUnitPair, position: &Position) -> Option<f64> {
+    let phi = position.phi.orient_start(pair.start.id())?;
+    let p: f64 = U128x128::ratio(phi.p.value(), pair.end.unit_amount().value())
+        .ok()?
+        .into();
+    let q: f64 = U128x128::ratio(phi.q.value(), pair.start.unit_amount().value())
+        .ok()?
+        .into();
+    Some(p / q)
+}
+
+/// Returns the price the `positions` laid out with `strategy` are centered on, if any.
+fn center(pair: &DirectedUnitPair, strategy: Strategy, positions: &[Position]) -> Option<f64> {
+    let (lowest_price, highest_price) = positions
+        .iter()
+        .filter_map(|position| price(pair, position))
+        .fold(None, |bounds, price| match bounds {
+            None => Some((price, price)),
+            Some((lowest, highest)) => Some((f64::min(lowest, price), f64::max(highest, price))),
+        })?;
+    Some(strategy.center(lowest_price, highest_price))
+}
+
+/// The relative distance of the market price from the center of the positions.
+fn drift(center: f64, market_price: f64) -> f64 {
+    (market_price / center - 1.0).abs()
+}
+
+/// The market price between the best prices quoted on either side of the book.
+fn mid_price(bid: Option<f64>, ask: Option<f64>) -> Option<f64> {
+    match (bid, ask) {
+        (Some(bid), Some(ask)) => Some((bid + ask) / 2.0),
+        (Some(price), None) | (None, Some(price)) => Some(price),
+        (None, None) => None,
+    }
+}
+
+/// The amount that can be added to positions, given the `budget`, the amount `deployed` in
+/// positions already, and the `balance` available.
+fn available(budget: Amount, deployed: Amount, balance: Amount) -> Amount {
+    let remaining = if deployed >= budget {
+        Amount::zero()
+    } else {
+        budget - deployed
+    };
+    std::cmp::min(remaining, balance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(strategy: Strategy) -> RebalanceConfig {
+        RebalanceConfig {
+            pair: "gm:gn".to_string(),
+            start_budget: "100gm".to_string(),
+            end_budget: "100gn".to_string(),
+            strategy,
+            width: default_width(),
+            num_positions: default_num_positions(),
+            amplification: default_amplification(),
+            fee_tiers: vec![30, 100],
+            threshold: default_threshold(),
+            poll_interval_secs: default_poll_interval_secs(),
+            min_interval_secs: default_min_interval_secs(),
+            source: 0,
+        }
+    }
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-6 * b.abs()
+    }
+
+    const STRATEGIES: [Strategy; 3] = [Strategy::Range, Strategy::Linear, Strategy::StableSwap];
+
+    #[test]
+    fn bounds_are_centered_on_the_price() {
+        for strategy in STRATEGIES {
+            let (lower, upper) = strategy.bounds(2.0, 0.1);
+            assert!(lower < 2.0 && 2.0 < upper, "{strategy:?}");
+            assert!(
+                approx_eq(strategy.center(lower, upper), 2.0),
+                "{strategy:?}"
+            );
+        }
+        assert_eq!(Strategy::Linear.bounds(2.0, 0.5), (1.0, 3.0));
+        assert_eq!(Strategy::Range.bounds(2.0, 1.0), (1.0, 4.0));
+    }
+
+    #[test]
+    fn laid_out_positions_are_centered_on_the_market_price() {
+        for strategy in STRATEGIES {
+            let config = config(strategy);
+            let (pair, start_budget, end_budget) = config.parse().unwrap();
+            let market_price = 2.5;
+            let ticks = strategy.ticks(market_price, &config);
+            let (lower, upper) = strategy.bounds(market_price, config.width);
+            assert!(
+                ticks
+                    .iter()
+                    .all(|tick| tick.price >= lower * (1.0 - 1e-9)
+                        && tick.price <= upper * (1.0 + 1e-9)),
+                "{strategy:?}"
+            );
+
+            let mut positions = Vec::new();
+            for input in [start_budget, end_budget] {
+                positions.extend(
+                    ticks::positions_from_ticks(
+                        OsRng,
+                        &pair,
+                        &input,
+                        market_price,
+                        &ticks,
+                        &config.fee_tiers,
+                    )
+                    .unwrap()
+                    .into_iter()
+                    .filter(|position| {
+                        position
+                            .reserves_for(input.asset_id)
+                            .map_or(false, |reserves| reserves > Amount::zero())
+                    }),
+                );
+            }
+
+            let center = center(&pair, strategy, &positions).unwrap();
+            assert!(
+                drift(center, market_price) < 1e-6,
+                "{strategy:?} centered on {center}"
+            );
+            assert!(drift(center, market_price * 1.05) > config.threshold);
+        }
+    }
+
+    #[test]
+    fn there_is_no_center_without_positions() {
+        let pair: DirectedUnitPair = "gm:gn".parse().unwrap();
+        assert_eq!(center(&pair, Strategy::Range, &[]), None);
+    }
+
+    #[test]
+    fn market_price_is_between_the_best_prices() {
+        assert_eq!(mid_price(Some(1.0), Some(3.0)), Some(2.0));
+        assert_eq!(mid_price(Some(1.0), None), Some(1.0));
+        assert_eq!(mid_price(None, Some(3.0)), Some(3.0));
+        assert_eq!(mid_price(None, None), None);
+    }
+
+    #[test]
+    fn deployed_funds_count_against_the_budget() {
+        let amount = |amount: u64| Amount::from(amount);
+        assert_eq!(available(amount(100), amount(0), amount(500)), amount(100));
+        assert_eq!(available(amount(100), amount(40), amount(500)), amount(60));
+        assert_eq!(available(amount(100), amount(40), amount(30)), amount(30));
+        assert_eq!(available(amount(100), amount(150), amount(500)), amount(0));
+    }
+
+    #[test]
+    fn invalid_configs_are_rejected() {
+        let valid = config(Strategy::StableSwap);
+        assert!(valid.parse().is_ok());
+
+        let invalid = [
+            RebalanceConfig {
+                start_budget: "100gn".to_string(),
+                ..valid.clone()
+            },
+            RebalanceConfig {
+                width: 1.0,
+                ..valid.clone()
+            },
+            RebalanceConfig {
+                amplification: -1.0,
+                ..valid.clone()
+            },
+            RebalanceConfig {
+                num_positions: 1,
+                ..valid.clone()
+            },
+            RebalanceConfig {
+                fee_tiers: vec![],
+                ..valid.clone()
+            },
+            RebalanceConfig {
+                poll_interval_secs: 0,
+                ..valid.clone()
+            },
+        ];
+        for config in invalid {
+            assert!(config.parse().is_err(), "{config:?}");
+        }
+    }
+}
@@ -38,6 +38,9 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
         }),
+        auto_claim: None,
+        rebalance: None,
+        metrics_bind_addr: None,
     })
 }
 
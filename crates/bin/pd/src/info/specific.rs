@@ -4,17 +4,21 @@ use std::sync::Arc;
 use async_stream::try_stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use ibc_types::core::channel::ChannelId;
 use penumbra_asset::{asset, Value};
 use penumbra_chain::component::AppHashRead;
 use penumbra_chain::component::StateReadExt as _;
+use penumbra_dex::component::router::PathSearch;
 use penumbra_dex::component::router::RouteAndFill;
 use penumbra_dex::component::router::RoutingParams;
 use penumbra_dex::{
-    component::{PositionRead, StateReadExt},
+    component::{PositionManager, PositionRead, StateReadExt},
     lp::{position, position::Position},
-    DirectedTradingPair, SwapExecution, TradingPair,
+    CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 use penumbra_governance::StateReadExt as _;
+use penumbra_ibc::component::RateLimitStateReadExt as _;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
@@ -36,14 +40,20 @@ use penumbra_storage::StateRead;
 use proto::client::v1alpha1::simulate_trade_request::routing;
 use proto::client::v1alpha1::simulate_trade_request::routing::Setting;
 use proto::client::v1alpha1::simulate_trade_request::Routing;
+use proto::client::v1alpha1::simulate_trade_response;
 use proto::client::v1alpha1::ArbExecutionRequest;
 use proto::client::v1alpha1::ArbExecutionResponse;
 use proto::client::v1alpha1::ArbExecutionsRequest;
 use proto::client::v1alpha1::ArbExecutionsResponse;
 use proto::client::v1alpha1::BatchSwapOutputDataResponse;
+use proto::client::v1alpha1::CandlesticksRequest;
+use proto::client::v1alpha1::CandlesticksResponse;
 use proto::client::v1alpha1::CurrentValidatorRateRequest;
 use proto::client::v1alpha1::CurrentValidatorRateResponse;
 use proto::client::v1alpha1::DenomMetadataByIdResponse;
+use proto::client::v1alpha1::DepthLevel;
+use proto::client::v1alpha1::Ics20RateLimitRequest;
+use proto::client::v1alpha1::Ics20RateLimitResponse;
 use proto::client::v1alpha1::LiquidityPositionByIdRequest;
 use proto::client::v1alpha1::LiquidityPositionByIdResponse;
 use proto::client::v1alpha1::LiquidityPositionsByIdRequest;
@@ -52,6 +62,8 @@ use proto::client::v1alpha1::LiquidityPositionsByPriceRequest;
 use proto::client::v1alpha1::LiquidityPositionsByPriceResponse;
 use proto::client::v1alpha1::LiquidityPositionsRequest;
 use proto::client::v1alpha1::LiquidityPositionsResponse;
+use proto::client::v1alpha1::MarketDepthRequest;
+use proto::client::v1alpha1::MarketDepthResponse;
 use proto::client::v1alpha1::NextValidatorRateRequest;
 use proto::client::v1alpha1::NextValidatorRateResponse;
 use proto::client::v1alpha1::PrefixValueRequest;
@@ -64,6 +76,8 @@ use proto::client::v1alpha1::SwapExecutionRequest;
 use proto::client::v1alpha1::SwapExecutionResponse;
 use proto::client::v1alpha1::SwapExecutionsRequest;
 use proto::client::v1alpha1::SwapExecutionsResponse;
+use proto::client::v1alpha1::TimeWeightedAveragePriceRequest;
+use proto::client::v1alpha1::TimeWeightedAveragePriceResponse;
 use proto::client::v1alpha1::TransactionByNoteRequest;
 use proto::client::v1alpha1::TransactionByNoteResponse;
 use proto::client::v1alpha1::ValidatorPenaltyRequest;
@@ -80,6 +94,12 @@ use tracing::instrument;
 
 use super::Info;
 
+/// The most hypothetical positions a trade simulation may open.
+const MAX_HYPOTHETICAL_POSITIONS: usize = 32;
+
+/// The most other inputs a trade simulation may be repeated with.
+const MAX_INPUT_LADDER_STEPS: usize = 32;
+
 #[tonic::async_trait]
 impl SpecificQueryService for Info {
     type LiquidityPositionsStream = Pin<
@@ -135,6 +155,49 @@ impl SpecificQueryService for Info {
                 tonic::Status::invalid_argument(format!("error parsing output id: {:#}", e))
             })?;
 
+        let min_output: Option<Amount> = request
+            .min_output
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing min output: {:#}", e))
+            })?;
+
+        // Each hypothetical position and ladder step makes the simulation more expensive.
+        if request.hypothetical_positions.len() > MAX_HYPOTHETICAL_POSITIONS {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {} hypothetical positions are allowed",
+                MAX_HYPOTHETICAL_POSITIONS
+            )));
+        }
+        if request.input_ladder.len() > MAX_INPUT_LADDER_STEPS {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {} input ladder steps are allowed",
+                MAX_INPUT_LADDER_STEPS
+            )));
+        }
+
+        let hypothetical_positions = request
+            .hypothetical_positions
+            .into_iter()
+            .map(Position::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing hypothetical position: {:#}",
+                    e
+                ))
+            })?;
+
+        let input_ladder = request
+            .input_ladder
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Amount>, _>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing input ladder: {:#}", e))
+            })?;
+
         let routing_params = match routing_strategy {
             Setting::Default(_) => RoutingParams::default(),
             Setting::SingleHop(_) => RoutingParams {
@@ -143,15 +206,97 @@ impl SpecificQueryService for Info {
             },
         };
 
-        let state = self.storage.latest_snapshot();
-        let mut state_tx = Arc::new(StateDelta::new(state));
-        let swap_execution = state_tx
-            .route_and_fill(input.asset_id, output_id, input.amount, routing_params)
+        // Open the hypothetical positions on top of the latest state, so that every
+        // simulation below sees them.
+        let mut state = StateDelta::new(self.storage.latest_snapshot());
+        for position in hypothetical_positions {
+            if position.state != position::State::Opened {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "hypothetical position {} is not opened",
+                    position.id()
+                )));
+            }
+            // Hold hypothetical positions to the same checks as positions opened on chain.
+            position.check_stateless().map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "invalid hypothetical position {}: {:#}",
+                    position.id(),
+                    e
+                ))
+            })?;
+            state.put_position(position).await.map_err(|e| {
+                tonic::Status::internal(format!("error opening hypothetical position: {:#}", e))
+            })?;
+        }
+        let state = Arc::new(state);
+
+        let (best_path, _) = state
+            .priced_path_search(input.asset_id, output_id, routing_params.clone())
             .await
-            .map_err(|e| tonic::Status::internal(format!("error simulating trade: {:#}", e)))?;
+            .map_err(|e| tonic::Status::internal(format!("error finding best path: {:#}", e)))?;
+        let spot_price = best_path.map(|(_, price)| price);
+
+        let (swap_execution, positions_consumed) =
+            simulate_execution(&state, input, output_id, routing_params.clone())
+                .await
+                .map_err(|e| tonic::Status::internal(format!("error simulating trade: {:#}", e)))?;
+
+        let below_min_output = min_output
+            .map(|min_output| swap_execution.output.amount < min_output)
+            .unwrap_or(false);
+
+        let paths = swap_execution
+            .paths()
+            .into_iter()
+            .map(|path| simulate_trade_response::Path {
+                // A hop without a price, because it had no output, is reported as NaN.
+                hop_prices: path
+                    .hop_prices()
+                    .into_iter()
+                    .map(|price| price.map(Into::into).unwrap_or(f64::NAN))
+                    .collect(),
+                route: path.route.into_iter().map(Into::into).collect(),
+                input: Some(path.input.into()),
+                output: Some(path.output.into()),
+            })
+            .collect::<Vec<_>>();
+
+        let mut ladder = Vec::with_capacity(input_ladder.len());
+        for amount in input_ladder {
+            let input = Value {
+                amount,
+                asset_id: input.asset_id,
+            };
+            let (execution, _) =
+                simulate_execution(&state, input, output_id, routing_params.clone())
+                    .await
+                    .map_err(|e| {
+                        tonic::Status::internal(format!("error simulating input ladder: {:#}", e))
+                    })?;
+            let (effective_price, price_impact) = execution_prices(&execution, spot_price)
+                .map_err(|e| tonic::Status::internal(format!("error pricing trade: {:#}", e)))?;
+            ladder.push(simulate_trade_response::LadderStep {
+                input: Some(input.into()),
+                output: Some(execution.output.into()),
+                effective_price,
+                price_impact,
+            });
+        }
+
+        let (effective_price, price_impact) = execution_prices(&swap_execution, spot_price)
+            .map_err(|e| tonic::Status::internal(format!("error pricing trade: {:#}", e)))?;
+        let unfilled = input.amount - swap_execution.input.amount;
 
         Ok(tonic::Response::new(SimulateTradeResponse {
             output: Some(swap_execution.into()),
+            below_min_output,
+            paths,
+            spot_price: spot_price.map(Into::into).unwrap_or_default(),
+            effective_price,
+            price_impact,
+            unfilled: Some(unfilled.into()),
+            positions_consumed: positions_consumed.into_iter().map(Into::into).collect(),
+            ladder,
         }))
     }
 
@@ -221,6 +366,168 @@ impl SpecificQueryService for Info {
         }))
     }
 
+    #[instrument(skip(self, request))]
+    async fn market_depth(
+        &self,
+        request: tonic::Request<MarketDepthRequest>,
+    ) -> Result<tonic::Response<MarketDepthResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| {
+                tonic::Status::invalid_argument(format!("missing directed trading pair"))
+            })?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing directed trading pair: {:#}",
+                    e
+                ))
+            })?;
+
+        let limit = if request.limit != 0 {
+            request.limit as usize
+        } else {
+            usize::MAX
+        };
+
+        let start_to_end = depth_levels(&state, &pair, limit)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error computing depth: {:#}", e)))?;
+        let end_to_start = depth_levels(&state, &pair.flip(), limit)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error computing depth: {:#}", e)))?;
+
+        Ok(tonic::Response::new(MarketDepthResponse {
+            start_to_end,
+            end_to_start,
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn candlesticks(
+        &self,
+        request: tonic::Request<CandlesticksRequest>,
+    ) -> Result<tonic::Response<CandlesticksResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| {
+                tonic::Status::invalid_argument(format!("missing directed trading pair"))
+            })?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing directed trading pair: {:#}",
+                    e
+                ))
+            })?;
+
+        let start_height = request.start_height;
+        let end_height = if request.end_height != 0 {
+            request.end_height
+        } else {
+            state
+                .get_block_height()
+                .await
+                .map_err(|e| tonic::Status::internal(format!("error getting height: {:#}", e)))?
+        };
+        if start_height > end_height {
+            return Err(tonic::Status::invalid_argument(format!(
+                "start height {} is after end height {}",
+                start_height, end_height
+            )));
+        }
+        let interval = request.interval.max(1);
+
+        // Merge the per-block candles into one candle per interval, skipping empty intervals.
+        let mut candles: Vec<CandlestickData> = Vec::new();
+        let mut block_candles = state.candlesticks(&pair, start_height, end_height);
+        while let Some(block_candle) = block_candles.next().await {
+            let block_candle = block_candle.map_err(|e| {
+                tonic::Status::internal(format!("error reading candlesticks: {:#}", e))
+            })?;
+            let interval_start =
+                start_height + (block_candle.start_height - start_height) / interval * interval;
+            match candles.last_mut() {
+                Some(candle) if candle.start_height == interval_start => {
+                    candle.merge(&block_candle)
+                }
+                _ => candles.push(CandlestickData {
+                    start_height: interval_start,
+                    ..block_candle
+                }),
+            }
+        }
+        // Each candle covers its whole interval, up to the end of the requested range.
+        for candle in candles.iter_mut() {
+            candle.end_height = candle
+                .start_height
+                .saturating_add(interval - 1)
+                .min(end_height);
+        }
+
+        Ok(tonic::Response::new(CandlesticksResponse {
+            data: candles.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn time_weighted_average_price(
+        &self,
+        request: tonic::Request<TimeWeightedAveragePriceRequest>,
+    ) -> Result<tonic::Response<TimeWeightedAveragePriceResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+        let request = request.into_inner();
+
+        let pair: TradingPair = request
+            .trading_pair
+            .ok_or_else(|| tonic::Status::invalid_argument(format!("missing trading pair")))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing trading pair: {:#}", e))
+            })?;
+
+        let end_height = if request.end_height != 0 {
+            request.end_height
+        } else {
+            state
+                .get_block_height()
+                .await
+                .map_err(|e| tonic::Status::internal(format!("error getting height: {:#}", e)))?
+        };
+
+        let price = state
+            .time_weighted_average_price(&pair, request.start_height, end_height)
+            .await
+            .map_err(|e| {
+                tonic::Status::failed_precondition(format!(
+                    "error computing time-weighted average price: {:#}",
+                    e
+                ))
+            })?;
+
+        Ok(tonic::Response::new(TimeWeightedAveragePriceResponse {
+            price: price.into(),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions_by_price(
         &self,
@@ -700,6 +1007,48 @@ impl SpecificQueryService for Info {
         Ok(tonic::Response::new(rsp))
     }
 
+    #[instrument(skip(self, request))]
+    async fn ics20_rate_limit(
+        &self,
+        request: tonic::Request<Ics20RateLimitRequest>,
+    ) -> Result<tonic::Response<Ics20RateLimitResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let request = request.into_inner();
+        let channel_id: ChannelId = request
+            .channel_id
+            .parse()
+            .map_err(|e| Status::invalid_argument(format!("could not parse channel_id: {e}")))?;
+        let asset_id: asset::Id = request
+            .asset_id
+            .ok_or_else(|| Status::invalid_argument("missing asset_id"))?
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("could not parse asset_id: {e}")))?;
+
+        let rate_limit = state
+            .ics20_rate_limit(&channel_id, &asset_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let flow = match &rate_limit {
+            Some(rate_limit) => Some(
+                state
+                    .ics20_flow(&channel_id, &asset_id, rate_limit)
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?,
+            ),
+            None => None,
+        };
+
+        Ok(tonic::Response::new(Ics20RateLimitResponse {
+            rate_limit: rate_limit.map(Into::into),
+            flow: flow.map(Into::into),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn proposal_info(
         &self,
@@ -932,3 +1281,112 @@ impl SpecificQueryService for Info {
         ))
     }
 }
+
+/// Aggregates the liquidity available when trading `pair.start` for `pair.end` into price levels,
+/// best price first, returning at most `limit` levels.
+async fn depth_levels<S: StateRead>(
+    state: &S,
+    pair: &DirectedTradingPair,
+    limit: usize,
+) -> anyhow::Result<Vec<DepthLevel>> {
+    let mut levels: Vec<(U128x128, Amount)> = Vec::new();
+    let mut positions_by_price = state.positions_by_price(pair);
+    while let Some(id) = positions_by_price.next().await.transpose()? {
+        let position = state
+            .position_by_id(&id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("indexed position not found in state: {}", id))?;
+        let price = position
+            .phi
+            .orient_start(pair.start)
+            .ok_or_else(|| anyhow::anyhow!("position {} is not on the pair {:?}", id, pair))?
+            .effective_price();
+        let reserves = position
+            .reserves_for(pair.end)
+            .ok_or_else(|| anyhow::anyhow!("position {} is not on the pair {:?}", id, pair))?;
+
+        match levels.last_mut() {
+            Some((level_price, level_reserves)) if *level_price == price => {
+                *level_reserves += reserves
+            }
+            _ if levels.len() == limit => break,
+            _ => levels.push((price, reserves)),
+        }
+    }
+
+    let mut cumulative_reserves = Amount::zero();
+    Ok(levels
+        .into_iter()
+        .map(|(price, reserves)| {
+            cumulative_reserves += reserves;
+            DepthLevel {
+                price: price.into(),
+                reserves: Some(reserves.into()),
+                cumulative_reserves: Some(cumulative_reserves.into()),
+            }
+        })
+        .collect())
+}
+
+/// Simulates routing `input` into `output_id` on top of `state`, returning the execution
+/// along with the positions it filled against.
+async fn simulate_execution<S: StateRead + 'static>(
+    state: &Arc<S>,
+    input: Value,
+    output_id: asset::Id,
+    params: RoutingParams,
+) -> anyhow::Result<(SwapExecution, Vec<position::Id>)> {
+    let mut state_tx = Arc::new(StateDelta::new(state.clone()));
+    let execution = state_tx
+        .route_and_fill(input.asset_id, output_id, input.amount, params)
+        .await?;
+
+    let mut pairs: Vec<DirectedTradingPair> = Vec::new();
+    for path in execution.paths() {
+        for hop in path.route.windows(2) {
+            let pair = DirectedTradingPair::new(hop[0], hop[1]);
+            if !pairs.contains(&pair) {
+                pairs.push(pair);
+            }
+        }
+    }
+
+    let mut positions_consumed = Vec::new();
+    for pair in pairs {
+        // Positions are filled best price first, so the first position left
+        // untouched on a pair is where the fills on that pair stopped.
+        let mut positions_by_price = state.positions_by_price(&pair);
+        while let Some(id) = positions_by_price.next().await.transpose()? {
+            let (Some(before), Some(after)) = (
+                state.position_by_id(&id).await?,
+                state_tx.position_by_id(&id).await?,
+            ) else {
+                continue;
+            };
+            if before.reserves.r1 == after.reserves.r1 && before.reserves.r2 == after.reserves.r2 {
+                break;
+            }
+            positions_consumed.push(id);
+        }
+    }
+
+    Ok((execution, positions_consumed))
+}
+
+/// Returns the effective price of an execution, in units of input per unit of output,
+/// and how much worse it is than the `spot_price`, as a fraction of the spot price.
+fn execution_prices(
+    execution: &SwapExecution,
+    spot_price: Option<U128x128>,
+) -> anyhow::Result<(f64, f64)> {
+    if execution.output.amount == Amount::zero() {
+        return Ok((0.0, 0.0));
+    }
+    let effective_price: f64 =
+        U128x128::ratio(execution.input.amount, execution.output.amount)?.into();
+    let price_impact = match spot_price.map(f64::from) {
+        Some(spot_price) if spot_price > 0.0 => effective_price / spot_price - 1.0,
+        _ => 0.0,
+    };
+    Ok((effective_price, price_impact))
+}
@@ -20,7 +20,8 @@ use pd::testnet::{
     generate::TestnetConfig,
     join::testnet_join,
 };
-use pd::upgrade::{self, Upgrade};
+use pd::upgrade::{self, MigrateOptions, Upgrade};
+use penumbra_chain::component::AppHash;
 use penumbra_proto::client::v1alpha1::{
     oblivious_query_service_server::ObliviousQueryServiceServer,
     specific_query_service_server::SpecificQueryServiceServer,
@@ -34,7 +35,7 @@ use rand_core::OsRng;
 use tendermint_config::net::Address as TendermintAddress;
 use tokio::{net::TcpListener, runtime};
 use tonic::transport::Server;
-use tracing_subscriber::{prelude::*, EnvFilter};
+use tracing_subscriber::{fmt::writer::BoxMakeWriter, prelude::*, EnvFilter};
 use url::Url;
 
 use penumbra_tower_trace::v034::RequestExt;
@@ -143,10 +144,28 @@ enum RootCommand {
     },
     /// Run a migration on the exported storage state of the full node,
     /// and create a genesis file.
-    Upgrade {
+    #[clap(alias = "upgrade")]
+    Migrate {
+        /// The version of `pd` to migrate the state to, or the name of a migration.
+        #[clap(long, display_order = 100)]
+        target: Upgrade,
         /// The directory containing the exported state.
         #[clap(long, display_order = 200)]
         export_path: PathBuf,
+        /// Run the migration against a temporary copy of the exported state, leaving it untouched,
+        /// and print the resulting genesis file instead of writing it.
+        #[clap(long, display_order = 300)]
+        dry_run: bool,
+        /// Fail unless the exported state has this (hex-encoded) app hash, which should be the
+        /// app hash the chain halted with.
+        #[clap(long, display_order = 400)]
+        expected_pre_app_hash: Option<String>,
+        /// Fail unless the migrated state has this (hex-encoded) app hash.
+        #[clap(long, display_order = 500)]
+        expected_app_hash: Option<String>,
+        /// Run the migration even if the state did not halt at a scheduled upgrade height.
+        #[clap(long, display_order = 600)]
+        force: bool,
     },
 }
 
@@ -238,15 +257,24 @@ async fn main() -> anyhow::Result<()> {
     let metrics_layer = MetricsLayer::new();
     // The ConsoleLayer enables collection of data for `tokio-console`.
     let console_layer = ConsoleLayer::builder().with_default_env().spawn();
-    // The `FmtLayer` is used to print to the console.
-    let fmt_layer = tracing_subscriber::fmt::layer()
-        .with_ansi(atty::is(atty::Stream::Stdout))
-        .with_target(true);
+    let opt = Opt::parse();
+
+    // The `FmtLayer` is used to print to the console. A dry-run migration prints the genesis file
+    // to stdout, so logs go to stderr instead.
+    let log_to_stderr = matches!(opt.cmd, RootCommand::Migrate { dry_run: true, .. });
+    let fmt_layer = if log_to_stderr {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(atty::is(atty::Stream::Stderr))
+            .with_writer(BoxMakeWriter::new(std::io::stderr))
+    } else {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(atty::is(atty::Stream::Stdout))
+            .with_writer(BoxMakeWriter::new(std::io::stdout))
+    }
+    .with_target(true);
     // The `EnvFilter` layer is used to filter events based on `RUST_LOG`.
     let filter_layer = EnvFilter::try_from_default_env().or_else(|_| EnvFilter::try_new("info"))?;
 
-    let opt = Opt::parse();
-
     // Register the tracing subscribers, conditionally enabling tokio console support
     let registry = tracing_subscriber::registry()
         .with(filter_layer)
@@ -620,11 +648,46 @@ async fn main() -> anyhow::Result<()> {
             // - apply checks: root hash, size, etc.
             todo!()
         }
-        RootCommand::Upgrade { export_path } => {
-            tracing::info!("upgrading state from {}", export_path.display());
-            let _ = upgrade::migrate(export_path.clone(), Upgrade::Testnet60)
-                .await
-                .context("failed to upgrade state")?;
+        RootCommand::Migrate {
+            target,
+            export_path,
+            dry_run,
+            expected_pre_app_hash,
+            expected_app_hash,
+            force,
+        } => {
+            tracing::info!(
+                migration = target.name(),
+                dry_run,
+                "upgrading state from {}",
+                export_path.display()
+            );
+            let parse_app_hash = |app_hash: String| -> anyhow::Result<AppHash> {
+                let bytes = hex::decode(app_hash).context("app hash must be hex-encoded")?;
+                Ok(AppHash(bytes.try_into().map_err(|_| {
+                    anyhow::anyhow!("app hash must be 32 bytes")
+                })?))
+            };
+            let expected_pre_app_hash = expected_pre_app_hash.map(parse_app_hash).transpose()?;
+            let expected_app_hash = expected_app_hash.map(parse_app_hash).transpose()?;
+            let app_hash = upgrade::migrate(
+                export_path.clone(),
+                target,
+                MigrateOptions {
+                    dry_run,
+                    expected_pre_app_hash,
+                    expected_app_hash,
+                    force,
+                },
+            )
+            .await
+            .context("failed to upgrade state")?;
+            // A dry run prints the genesis file to stdout, which must not be mixed with anything else.
+            if dry_run {
+                eprintln!("migrated state app hash: {}", hex::encode(app_hash.0));
+            } else {
+                println!("migrated state app hash: {}", hex::encode(app_hash.0));
+            }
         }
     }
     Ok(())
@@ -1,80 +1,361 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
+use anyhow::Context;
 use penumbra_chain::{
     component::{AppHash, StateReadExt, StateWriteExt},
     genesis::Content,
+    state_key,
 };
+use penumbra_sct::{component::TreeWriter, state_key as sct_state_key};
 use penumbra_stake::StateReadExt as _;
-use penumbra_storage::{StateDelta, StateWrite, Storage};
+use penumbra_storage::{Snapshot, StateDelta, StateRead, StateWrite, Storage};
+use penumbra_tct as tct;
 
 use crate::testnet::generate::TestnetConfig;
 
+/// A named, versioned migration of the chain state.
+///
+/// Migrations are run by `pd migrate` against the storage of a node which halted at the height of
+/// a passed `UpgradePlan` proposal, turning it into the initial state of a chain running the
+/// target version of `pd`, which restarts from a regenerated checkpoint genesis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Upgrade {
     /// No-op migration
     Noop,
     /// Testnet 60 migration
     Testnet60,
+    /// Move the state commitment tree from a single serialized blob to incremental storage
+    SctStorage,
 }
 
-pub async fn migrate(path_to_export: PathBuf, upgrade: Upgrade) -> anyhow::Result<()> {
-    match upgrade {
-        Upgrade::Noop => (),
-        Upgrade::Testnet60 => {
-            let mut db_path = path_to_export.clone();
-            db_path.push("rocksdb");
-            let storage = Storage::load(db_path).await?;
-            let export_state = storage.latest_snapshot();
-            let root_hash = export_state.root_hash().await.expect("can get root hash");
-            let app_hash_pre_migration: AppHash = root_hash.into();
-            let height = export_state
-                .get_block_height()
-                .await
-                .expect("can get block height");
-            let post_ugprade_height = height.wrapping_add(1);
-
-            /* --------- writing to the jmt  ------------ */
-            tracing::info!(?app_hash_pre_migration, "app hash pre upgrade");
-            let mut delta = StateDelta::new(export_state);
-            delta.put_raw("testnet_60_forked".to_string(), "done".into());
-            delta.put_block_height(0u64);
-            let root_hash = storage.commit_in_place(delta).await?;
-            let app_hash_post_migration: AppHash = root_hash.into();
-            tracing::info!(?app_hash_post_migration, "app hash post upgrade");
-
-            /* --------- collecting genesis data -------- */
-            tracing::info!("generating genesis");
-            let migrated_state = storage.latest_snapshot();
-            let root_hash = migrated_state.root_hash().await.expect("can get root hash");
-            let app_hash: AppHash = root_hash.into();
-            tracing::info!(?root_hash, "root hash post upgrade2");
-            let chain_params = migrated_state
-                .get_chain_params()
-                .await
-                .expect("can get chain params");
-
-            /* ---------- genereate genesis ------------  */
-            let validators = migrated_state.validator_list().await?;
-            let mut app_state = Content::default();
-            app_state.chain_params = chain_params;
-            app_state.validators = validators.into_iter().map(Into::into).collect();
-            let mut genesis =
-                TestnetConfig::make_genesis(app_state.clone()).expect("can make genesis");
-            genesis.app_hash = app_hash
-                .0
-                .to_vec()
-                .try_into()
-                .expect("infaillible conversion");
-            genesis.initial_height = post_ugprade_height as i64;
-            genesis.genesis_time = tendermint::time::Time::now();
-            let checkpoint = [32u8; 32].to_vec();
-            let genesis = TestnetConfig::make_checkpoint(genesis, Some(checkpoint));
-
-            let genesis_json = serde_json::to_string(&genesis).expect("can serialize genesis");
-            tracing::info!("genesis: {}", genesis_json);
-            let mut genesis_path = path_to_export.clone();
-            genesis_path.push("genesis.json");
-            std::fs::write(genesis_path, genesis_json).expect("can write genesis");
+impl Upgrade {
+    /// Every known migration, in the order they were introduced.
+    pub const ALL: &'static [Upgrade] = &[Upgrade::Noop, Upgrade::Testnet60, Upgrade::SctStorage];
+
+    /// The name of the migration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Upgrade::Noop => "noop",
+            Upgrade::Testnet60 => "testnet-60",
+            Upgrade::SctStorage => "sct-storage",
+        }
+    }
+
+    /// The version of `pd` whose state this migration produces, if it targets a specific one.
+    pub fn target_version(&self) -> Option<&'static str> {
+        match self {
+            Upgrade::Noop | Upgrade::SctStorage => None,
+            Upgrade::Testnet60 => Some("0.60.0"),
+        }
+    }
+
+    /// Apply the changes specific to this migration to the halted chain state.
+    async fn migrate_state(&self, delta: &mut StateDelta<Snapshot>) -> anyhow::Result<()> {
+        match self {
+            Upgrade::Noop => {}
+            Upgrade::Testnet60 => {
+                delta.put_raw("testnet_60_forked".to_string(), "done".into());
+            }
+            Upgrade::SctStorage => {
+                // The tree used to be rewritten in full every block; write it out once into the
+                // incremental storage, which only needs the changes from then on.
+                let legacy_key = sct_state_key::state_commitment_tree().as_bytes();
+                let bytes = delta
+                    .nonverifiable_get_raw(legacy_key)
+                    .await?
+                    .context("exported state has no serialized state commitment tree")?;
+                let tree: tct::Tree = bincode::deserialize(&bytes)
+                    .context("can deserialize state commitment tree")?;
+                tree.to_async_writer(&mut TreeWriter(&mut *delta)).await?;
+                delta.nonverifiable_delete(legacy_key.to_vec());
+                tracing::info!(position = ?tree.position(), "migrated state commitment tree");
+            }
         }
+        Ok(())
+    }
+}
+
+impl FromStr for Upgrade {
+    type Err = anyhow::Error;
+
+    /// Look up a migration by its name or by its target version (with or without a leading `v`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let target = s.strip_prefix('v').unwrap_or(s);
+        Upgrade::ALL
+            .iter()
+            .copied()
+            .find(|upgrade| upgrade.name() == s || upgrade.target_version() == Some(target))
+            .ok_or_else(|| {
+                let known = Upgrade::ALL
+                    .iter()
+                    .map(|upgrade| match upgrade.target_version() {
+                        Some(version) => format!("{} ({version})", upgrade.name()),
+                        None => upgrade.name().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow::anyhow!("no migration targets {s}; known migrations are: {known}")
+            })
+    }
+}
+
+/// Apply `upgrade` to the halted chain state, and clear the halt which stopped the chain at the
+/// upgrade height.
+async fn prepare_upgraded_state(
+    upgrade: Upgrade,
+    delta: &mut StateDelta<Snapshot>,
+) -> anyhow::Result<()> {
+    upgrade.migrate_state(delta).await?;
+    delta.put_block_height(0u64);
+
+    // The node signaled a halt when reaching the upgrade height, to prevent it from restarting
+    // without intervention. Running the migration *is* that intervention, so clear that halt (and
+    // the now-reached upgrade height) to let the upgraded node start. Earlier halts are left
+    // alone: those are acknowledged by bumping the total halt count in a new release instead.
+    let halt_count = delta.chain_halt_count().await?;
+    if let Some(current_halt) = halt_count.checked_sub(1) {
+        delta.nonverifiable_delete(state_key::halted(current_halt));
     }
+    delta.nonverifiable_delete(state_key::next_upgrade().as_bytes().to_vec());
+
     Ok(())
 }
+
+/// Options controlling how a migration is run by [`migrate`].
+#[derive(Clone, Debug, Default)]
+pub struct MigrateOptions {
+    /// Run the migration against a temporary copy of the exported state, leaving it untouched,
+    /// and print the regenerated genesis instead of writing it.
+    pub dry_run: bool,
+    /// Fail unless the exported state has this app hash, i.e. unless it is the state the chain
+    /// halted with.
+    pub expected_pre_app_hash: Option<AppHash>,
+    /// Fail unless the migrated state has this app hash.
+    pub expected_app_hash: Option<AppHash>,
+    /// Run the migration even if the state did not halt at a scheduled upgrade height.
+    pub force: bool,
+}
+
+/// Run a migration in place against the exported state at `path_to_export`, verify the resulting
+/// app hash, and write a checkpoint genesis for the upgraded chain next to it, returning the app
+/// hash of the migrated state.
+pub async fn migrate(
+    path_to_export: PathBuf,
+    upgrade: Upgrade,
+    options: MigrateOptions,
+) -> anyhow::Result<AppHash> {
+    // In dry-run mode, work on a scratch copy of the exported state, which is removed once the
+    // migration is done.
+    let scratch_dir = if options.dry_run {
+        let scratch_dir = tempfile::tempdir().context("can create scratch directory")?;
+        let mut export_db_path = path_to_export.clone();
+        export_db_path.push("rocksdb");
+        fs_extra::dir::copy(
+            &export_db_path,
+            scratch_dir.path(),
+            &fs_extra::dir::CopyOptions::new(),
+        )
+        .context("can copy exported state to scratch directory")?;
+        Some(scratch_dir)
+    } else {
+        None
+    };
+    let mut db_path = scratch_dir
+        .as_ref()
+        .map(|dir| dir.path().to_path_buf())
+        .unwrap_or_else(|| path_to_export.clone());
+    db_path.push("rocksdb");
+
+    let storage = Storage::load(db_path).await?;
+    let export_state = storage.latest_snapshot();
+    let root_hash = export_state.root_hash().await.expect("can get root hash");
+    let app_hash_pre_migration: AppHash = root_hash.into();
+    let height = export_state
+        .get_block_height()
+        .await
+        .expect("can get block height");
+    let post_upgrade_height = height.wrapping_add(1);
+
+    if let Some(expected_pre_app_hash) = options.expected_pre_app_hash {
+        anyhow::ensure!(
+            app_hash_pre_migration == expected_pre_app_hash,
+            "exported state has app hash {app_hash_pre_migration:?}, but {expected_pre_app_hash:?} was expected"
+        );
+    }
+
+    /* --------- checking the halt  ------------- */
+    // The node commits the block before the upgrade height and then halts, so the exported state
+    // should be exactly one block short of the scheduled upgrade.
+    if !export_state.is_upgrade_height().await? {
+        if options.force {
+            tracing::warn!(
+                ?height,
+                "exported state did not halt at a scheduled upgrade height, migrating anyway"
+            );
+        } else {
+            anyhow::bail!(
+                "exported state at height {height} did not halt at a scheduled upgrade height (use --force to migrate anyway)"
+            );
+        }
+    }
+
+    /* --------- writing to the jmt  ------------ */
+    tracing::info!(
+        ?app_hash_pre_migration,
+        migration = upgrade.name(),
+        "app hash pre upgrade"
+    );
+    let mut delta = StateDelta::new(export_state);
+    prepare_upgraded_state(upgrade, &mut delta).await?;
+
+    let root_hash = storage.commit_in_place(delta).await?;
+    let app_hash: AppHash = root_hash.into();
+    tracing::info!(app_hash_post_migration = ?app_hash, "app hash post upgrade");
+
+    /* --------- verifying the app hash --------- */
+    let migrated_state = storage.latest_snapshot();
+    if let Some(expected_app_hash) = options.expected_app_hash {
+        anyhow::ensure!(
+            app_hash == expected_app_hash,
+            "migrated state has app hash {app_hash:?}, but {expected_app_hash:?} was expected"
+        );
+    }
+
+    /* --------- collecting genesis data -------- */
+    tracing::info!("generating genesis");
+    let chain_params = migrated_state
+        .get_chain_params()
+        .await
+        .expect("can get chain params");
+
+    /* ---------- genereate genesis ------------  */
+    let validators = migrated_state.validator_list().await?;
+    let mut app_state = Content::default();
+    app_state.chain_params = chain_params;
+    app_state.validators = validators.into_iter().map(Into::into).collect();
+    let mut genesis = TestnetConfig::make_genesis(app_state.clone()).expect("can make genesis");
+    genesis.app_hash = app_hash
+        .0
+        .to_vec()
+        .try_into()
+        .expect("infaillible conversion");
+    genesis.initial_height = post_upgrade_height as i64;
+    genesis.genesis_time = tendermint::time::Time::now();
+    let checkpoint = [32u8; 32].to_vec();
+    let genesis = TestnetConfig::make_checkpoint(genesis, Some(checkpoint));
+
+    let genesis_json = serde_json::to_string(&genesis).expect("can serialize genesis");
+    if options.dry_run {
+        println!("{genesis_json}");
+    } else {
+        tracing::info!("genesis: {}", genesis_json);
+        let mut genesis_path = path_to_export.clone();
+        genesis_path.push("genesis.json");
+        std::fs::write(genesis_path, genesis_json).expect("can write genesis");
+    }
+
+    Ok(app_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_chain::params::ChainParameters;
+    use penumbra_storage::TempStorage;
+
+    use super::*;
+
+    /// Writes the state of a chain which halted at an upgrade scheduled for `upgrade_height`,
+    /// having also halted once before, and returns its app hash.
+    async fn halt_for_upgrade(storage: &Storage, upgrade_height: u64) -> anyhow::Result<AppHash> {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_chain_params(ChainParameters::default());
+        delta.put_block_height(upgrade_height - 1);
+        // An earlier emergency halt, since acknowledged by a new release...
+        delta.signal_halt().await?;
+        // ...and the halt signaled on reaching the upgrade height.
+        delta.signal_upgrade(upgrade_height).await?;
+        delta.signal_halt().await?;
+        Ok(storage.commit(delta).await?.into())
+    }
+
+    #[tokio::test]
+    async fn migration_clears_only_the_upgrade_halt() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        halt_for_upgrade(&storage, 10).await?;
+
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        prepare_upgraded_state(Upgrade::Noop, &mut delta).await?;
+        storage.commit_in_place(delta).await?;
+
+        let state = storage.latest_snapshot();
+        assert_eq!(state.get_block_height().await?, 0);
+        assert!(!state.is_upgrade_height().await?);
+        assert!(
+            state
+                .nonverifiable_get_raw(&state_key::halted(1))
+                .await?
+                .is_none(),
+            "the halt for the upgrade is cleared"
+        );
+        assert!(
+            state
+                .nonverifiable_get_raw(&state_key::halted(0))
+                .await?
+                .is_some(),
+            "earlier halts are left in place"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn migration_checks_the_exported_app_hash() -> anyhow::Result<()> {
+        let export_dir = tempfile::tempdir()?;
+        let storage = Storage::load(export_dir.path().join("rocksdb")).await?;
+        let app_hash = halt_for_upgrade(&storage, 10).await?;
+
+        let wrong_app_hash = AppHash([1u8; 32]);
+        assert_ne!(app_hash, wrong_app_hash);
+        let result = migrate(
+            export_dir.path().to_path_buf(),
+            Upgrade::Noop,
+            MigrateOptions {
+                dry_run: true,
+                expected_pre_app_hash: Some(wrong_app_hash),
+                ..Default::default()
+            },
+        )
+        .await;
+        assert!(
+            result.is_err(),
+            "a mismatched exported app hash is rejected"
+        );
+
+        let migrated_app_hash = migrate(
+            export_dir.path().to_path_buf(),
+            Upgrade::Noop,
+            MigrateOptions {
+                dry_run: true,
+                expected_pre_app_hash: Some(app_hash),
+                ..Default::default()
+            },
+        )
+        .await?;
+        assert_ne!(migrated_app_hash, app_hash);
+
+        // The same migration of the same state always produces the same app hash.
+        migrate(
+            export_dir.path().to_path_buf(),
+            Upgrade::Noop,
+            MigrateOptions {
+                dry_run: true,
+                expected_pre_app_hash: Some(app_hash),
+                expected_app_hash: Some(migrated_app_hash),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+}
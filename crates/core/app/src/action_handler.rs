@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use penumbra_storage::{StateRead, StateWrite};
 
 mod actions;
+pub use actions::build_dao_transaction;
 mod transaction;
 
 /// Stub: to be replaced with impls of penumbra_component::ActionHandler
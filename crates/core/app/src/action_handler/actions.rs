@@ -8,6 +8,7 @@ use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::Action;
 
 mod submit;
+pub use submit::build_dao_transaction;
 
 use super::ActionHandler;
 use penumbra_component::ActionHandler as _;
@@ -5,9 +5,14 @@ use ark_ff::PrimeField;
 use async_trait::async_trait;
 use decaf377::Fq;
 use decaf377_rdsa::{VerificationKey, VerificationKeyBytes};
+use ibc_types::core::channel::PortId;
 use once_cell::sync::Lazy;
 use penumbra_asset::STAKING_TOKEN_DENOM;
-use penumbra_chain::component::StateReadExt as _;
+use penumbra_chain::{component::StateReadExt as _, params::ChainParameters};
+use penumbra_dao::component::StateReadExt as _;
+use penumbra_ibc::component::{
+    ChannelStateReadExt as _, ClientStateReadExt as _, ConnectionStateReadExt as _,
+};
 use penumbra_keys::keys::{FullViewingKey, NullifierKey};
 use penumbra_proto::DomainType;
 use penumbra_sct::component::StateReadExt as _;
@@ -70,7 +75,13 @@ impl ActionHandler for ProposalSubmit {
         match payload {
             Signaling { commit: _ } => { /* all signaling proposals are valid */ }
             Emergency { halt_chain: _ } => { /* all emergency proposals are valid */ }
-            ParameterChange { old, new } => {
+            ParameterChange { changes } => {
+                // Whether the new parameters are valid depends on the parameters the changes are
+                // applied to, which is checked when the proposal passes:
+                ChainParameters::check_changes(changes)
+                    .context("invalid change to chain parameters")?;
+            }
+            LegacyParameterChange { old, new } => {
                 old.check_valid_update(new)
                     .context("invalid change to chain parameters")?;
             }
@@ -121,6 +132,54 @@ impl ActionHandler for ProposalSubmit {
                 }
             }
             UpgradePlan { .. } => {}
+            DaoDisbursement {
+                value,
+                address: _,
+                epoch_interval,
+                payouts,
+            } => {
+                anyhow::ensure!(
+                    value.amount > 0u64.into(),
+                    "DAO disbursement value must be nonzero"
+                );
+                anyhow::ensure!(
+                    *epoch_interval > 0,
+                    "DAO disbursement epoch interval must be nonzero"
+                );
+                anyhow::ensure!(
+                    *payouts > 0,
+                    "DAO disbursement must make at least one payout"
+                );
+            }
+            CancelDaoDisbursement { .. } => { /* checked statefully */ }
+            RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                anyhow::ensure!(
+                    subject_client_id != substitute_client_id,
+                    "IBC client can't be substituted for itself"
+                );
+            }
+            FreezeIbcClient { .. } | CloseIbcChannel { .. } => { /* checked statefully */ }
+            SetIcs20RateLimit { rate_limit, .. } => {
+                if let Some(rate_limit) = rate_limit {
+                    rate_limit.validate()?;
+                }
+            }
+            RegisterDaoInterchainAccount { .. } => { /* checked statefully */ }
+            DaoInterchainAccountTx { messages, .. } => {
+                anyhow::ensure!(
+                    !messages.is_empty(),
+                    "interchain account transaction must have at least one message"
+                );
+                for message in messages {
+                    anyhow::ensure!(
+                        !message.type_url.is_empty(),
+                        "interchain account message must have a type URL"
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -157,7 +216,8 @@ impl ActionHandler for ProposalSubmit {
         match &proposal.payload {
             ProposalPayload::Signaling { .. } => { /* no stateful checks for signaling */ }
             ProposalPayload::Emergency { .. } => { /* no stateful checks for emergency */ }
-            ProposalPayload::ParameterChange { .. } => {
+            ProposalPayload::ParameterChange { .. }
+            | ProposalPayload::LegacyParameterChange { .. } => {
                 /* no stateful checks for parameter change (checks are applied when proposal finishes) */
             }
             ProposalPayload::DaoSpend { transaction_plan } => {
@@ -190,6 +250,59 @@ impl ActionHandler for ProposalSubmit {
             ProposalPayload::UpgradePlan { .. } => {
                 // TODO(erwan): no stateful checks for upgrade plan.
             }
+            ProposalPayload::DaoDisbursement { .. } => {
+                // Disbursements spend DAO funds, so they are gated the same way as DAO spends. The
+                // DAO's balance is checked at each payout, since it may change in the meantime.
+                anyhow::ensure!(
+                    chain_parameters.dao_spend_proposals_enabled,
+                    "DAO spend proposals are not enabled",
+                );
+            }
+            ProposalPayload::CancelDaoDisbursement { proposal } => {
+                // The disbursement may still finish before this proposal passes, but there's no
+                // point in allowing proposals to cancel disbursements that don't exist.
+                anyhow::ensure!(
+                    state.dao_disbursement(*proposal).await?.is_some(),
+                    "no DAO disbursement is scheduled by proposal {proposal}",
+                );
+            }
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                // Whether the clients are frozen or expired may change before the proposal passes,
+                // so that is only checked when it is enacted, but both clients must exist.
+                state.get_client_state(subject_client_id).await?;
+                state.get_client_state(substitute_client_id).await?;
+            }
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                state.get_client_state(client_id).await?;
+            }
+            ProposalPayload::CloseIbcChannel {
+                port_id,
+                channel_id,
+            } => {
+                anyhow::ensure!(
+                    state.get_channel(channel_id, port_id).await?.is_some(),
+                    "channel {channel_id} not found on port {port_id}",
+                );
+            }
+            ProposalPayload::SetIcs20RateLimit { channel_id, .. } => {
+                let port_id = PortId::transfer();
+                anyhow::ensure!(
+                    state.get_channel(channel_id, &port_id).await?.is_some(),
+                    "channel {channel_id} not found on port {port_id}",
+                );
+            }
+            ProposalPayload::RegisterDaoInterchainAccount { connection_id }
+            | ProposalPayload::DaoInterchainAccountTx { connection_id, .. } => {
+                // The DAO's account may be registered while the proposal is being voted on, so
+                // that is only checked when it is enacted, but the connection must exist.
+                anyhow::ensure!(
+                    state.get_connection(connection_id).await?.is_some(),
+                    "connection {connection_id} not found",
+                );
+            }
         }
 
         Ok(())
@@ -317,7 +430,9 @@ static DAO_FULL_VIEWING_KEY: Lazy<FullViewingKey> = Lazy::new(|| {
 /// constructs a byte-for-byte identical transaction.
 const DAO_TRANSACTION_RNG_SEED: &[u8; 32] = b"Penumbra DAO's tx build rng seed";
 
-async fn build_dao_transaction(transaction_plan: TransactionPlan) -> Result<Transaction> {
+/// Build the transaction described by a DAO spend proposal's transaction plan, exactly as it will
+/// be built by every node when the proposal is submitted and when it is enacted.
+pub async fn build_dao_transaction(transaction_plan: TransactionPlan) -> Result<Transaction> {
     let effect_hash = transaction_plan.effect_hash(&DAO_FULL_VIEWING_KEY);
     transaction_plan
         .build(
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_chain::params::FmdParameters;
+use penumbra_ibc::component::RateLimitStateReadExt as _;
 use penumbra_sct::component::StateReadExt as _;
 use penumbra_storage::StateRead;
 use penumbra_transaction::Transaction;
@@ -33,6 +34,14 @@ pub(super) async fn fmd_parameters_valid<S: StateRead>(
     )
 }
 
+pub(super) async fn ics20_withdrawals_within_rate_limits<S: StateRead>(
+    state: S,
+    transaction: &Transaction,
+) -> Result<()> {
+    let withdrawals: Vec<_> = transaction.ics20_withdrawals().collect();
+    state.ics20_withdrawals_check(&withdrawals).await
+}
+
 const FMD_GRACE_PERIOD_BLOCKS: u64 = 10;
 
 pub fn fmd_precision_within_grace_period(
@@ -9,6 +9,7 @@ use penumbra_chain::{
 use penumbra_compact_block::component::StateWriteExt as _;
 use penumbra_compact_block::CompactBlock;
 use penumbra_component::Component;
+use penumbra_dao::component::Dao;
 use penumbra_dex::component::{Dex, SwapManager};
 use penumbra_distributions::component::Distributions;
 use penumbra_governance::component::{Governance, StateReadExt as _};
@@ -118,6 +119,7 @@ impl App {
                 IBCComponent::init_chain(&mut state_tx, &()).await;
                 Dex::init_chain(&mut state_tx, &()).await;
                 Governance::init_chain(&mut state_tx, &()).await;
+                Dao::init_chain(&mut state_tx, &()).await;
                 ShieldedPool::init_chain(&mut state_tx, app_state).await;
             }
             genesis::AppState::Checkpoint(_) => {
@@ -142,14 +144,18 @@ impl App {
 
         // If a chain parameter change is scheduled for this block, apply it here, before any other
         // component has executed. This ensures that chain parameter changes are consistently
-        // applied precisely at the boundary between blocks:
+        // applied precisely at the boundary between blocks. Changes to the shape of epochs are
+        // deferred until the end of the current epoch:
         if let Some(chain_params) = state_tx
             .pending_chain_parameters()
             .await
             .expect("chain params should always be readable")
         {
             tracing::info!(?chain_params, "applying pending chain parameters");
-            state_tx.put_chain_params(chain_params);
+            state_tx
+                .update_chain_params(chain_params)
+                .await
+                .expect("able to update chain params");
         }
 
         // Run each of the begin block handlers for each component, in sequence:
@@ -158,6 +164,7 @@ impl App {
         Staking::begin_block(&mut arc_state_tx, begin_block).await;
         IBCComponent::begin_block(&mut arc_state_tx, begin_block).await;
         Governance::begin_block(&mut arc_state_tx, begin_block).await;
+        Dao::begin_block(&mut arc_state_tx, begin_block).await;
         ShieldedPool::begin_block(&mut arc_state_tx, begin_block).await;
 
         let state_tx = Arc::try_unwrap(arc_state_tx)
@@ -263,6 +270,7 @@ impl App {
         IBCComponent::end_block(&mut arc_state_tx, end_block).await;
         Dex::end_block(&mut arc_state_tx, end_block).await;
         Governance::end_block(&mut arc_state_tx, end_block).await;
+        Dao::end_block(&mut arc_state_tx, end_block).await;
         ShieldedPool::end_block(&mut arc_state_tx, end_block).await;
         let mut state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components did not retain copies of shared state");
@@ -294,6 +302,14 @@ impl App {
         if is_end_epoch || is_chain_upgrade {
             tracing::info!(?current_height, "ending epoch");
 
+            // Changes to the shape of epochs take effect at the epoch boundary, before the
+            // components end the epoch, so that e.g. the new validator set is chosen using the
+            // new active validator limit:
+            state_tx
+                .apply_next_epoch_chain_params()
+                .await
+                .expect("able to apply next epoch chain params");
+
             let mut arc_state_tx = Arc::new(state_tx);
 
             Distributions::end_epoch(&mut arc_state_tx)
@@ -311,6 +327,9 @@ impl App {
             Governance::end_epoch(&mut arc_state_tx)
                 .await
                 .expect("able to call end_epoch on Governance component");
+            Dao::end_epoch(&mut arc_state_tx)
+                .await
+                .expect("able to call end_epoch on DAO component");
             ShieldedPool::end_epoch(&mut arc_state_tx)
                 .await
                 .expect("able to call end_epoch on shielded pool component");
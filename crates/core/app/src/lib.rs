@@ -4,7 +4,7 @@ mod dao_ext;
 mod mock_client;
 mod temp_storage_ext;
 
-pub use action_handler::ActionHandler;
+pub use action_handler::{build_dao_transaction, ActionHandler};
 pub use dao_ext::DaoStateReadExt;
 pub use mock_client::MockClient;
 pub use temp_storage_ext::TempStorageExt;
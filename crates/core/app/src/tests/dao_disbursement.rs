@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use crate::TempStorageExt;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::{component::StateWriteExt as _, test_keys, Epoch};
+use penumbra_dao::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_governance::{ProposalPayload, StateWriteExt as _};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, StateWrite, TempStorage};
+
+/// Moves the state to the first block of the epoch with the given index.
+fn enter_epoch<S: StateWrite>(state: &mut S, index: u64) {
+    let height = index * 10 + 1;
+    state.put_block_height(height);
+    state.put_epoch_by_height(
+        height,
+        Epoch {
+            index,
+            start_height: height,
+        },
+    );
+}
+
+fn payout() -> Value {
+    Value {
+        amount: 100u64.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    }
+}
+
+#[tokio::test]
+async fn dao_disbursement_pays_out_on_schedule() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 0);
+    state_tx
+        .dao_deposit(Value {
+            amount: 250u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
+        .await?;
+    // Passing the proposal schedules the first payout two epochs from now.
+    state_tx
+        .enact_proposal(
+            7,
+            &ProposalPayload::DaoDisbursement {
+                value: payout(),
+                address: *test_keys::ADDRESS_0,
+                epoch_interval: 2,
+                payouts: 3,
+            },
+        )
+        .await?
+        .expect("proposal enacts");
+    state_tx.apply();
+
+    let disbursement = state.dao_disbursement(7).await?.expect("scheduled");
+    assert_eq!(disbursement.next_epoch, 2);
+    assert_eq!(disbursement.payouts_remaining, 3);
+
+    // Nothing is due at the end of epoch 1.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 1);
+    state_tx.pay_dao_disbursements().await?;
+    state_tx.apply();
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        250u64.into()
+    );
+
+    // The first payout is made at the end of epoch 2, and the next is due two epochs later.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 2);
+    state_tx.pay_dao_disbursements().await?;
+    state_tx.apply();
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        150u64.into()
+    );
+    let disbursement = state.dao_disbursement(7).await?.expect("still scheduled");
+    assert_eq!(disbursement.next_epoch, 4);
+    assert_eq!(disbursement.payouts_remaining, 2);
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 4);
+    state_tx.pay_dao_disbursements().await?;
+    state_tx.apply();
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        50u64.into()
+    );
+
+    // The DAO can't cover the last payout, so it is retried the next epoch instead.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 6);
+    state_tx.pay_dao_disbursements().await?;
+    state_tx.apply();
+    let disbursement = state.dao_disbursement(7).await?.expect("still scheduled");
+    assert_eq!(disbursement.next_epoch, 7);
+    assert_eq!(disbursement.payouts_remaining, 1);
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 7);
+    state_tx.dao_deposit(payout()).await?;
+    state_tx.pay_dao_disbursements().await?;
+    state_tx.apply();
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        50u64.into()
+    );
+
+    // Once the last payout is made, the disbursement is removed.
+    assert!(state.dao_disbursement(7).await?.is_none());
+    assert!(state.dao_disbursements().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dao_disbursement_can_be_cancelled() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 0);
+    state_tx.dao_deposit(payout()).await?;
+    state_tx
+        .enact_proposal(
+            3,
+            &ProposalPayload::DaoDisbursement {
+                value: payout(),
+                address: *test_keys::ADDRESS_0,
+                epoch_interval: 1,
+                payouts: 5,
+            },
+        )
+        .await?
+        .expect("proposal enacts");
+    state_tx
+        .enact_proposal(4, &ProposalPayload::CancelDaoDisbursement { proposal: 3 })
+        .await?
+        .expect("cancellation enacts");
+    state_tx.apply();
+
+    assert!(state.dao_disbursement(3).await?.is_none());
+
+    // No payout is made once the disbursement is cancelled.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    enter_epoch(&mut state_tx, 1);
+    state_tx.pay_dao_disbursements().await?;
+    state_tx.apply();
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        100u64.into()
+    );
+
+    // Cancelling a disbursement that no longer exists fails the proposal, without failing the
+    // epoch transition.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let result = state_tx
+        .enact_proposal(5, &ProposalPayload::CancelDaoDisbursement { proposal: 3 })
+        .await?;
+    assert!(result.is_err());
+
+    Ok(())
+}
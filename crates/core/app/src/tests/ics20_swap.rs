@@ -0,0 +1,174 @@
+use ark_ff::UniformRand;
+use std::{ops::Deref, sync::Arc};
+
+use crate::{app::App, MockClient, TempStorageExt};
+use decaf377::Fq;
+use ibc_types::{
+    core::{
+        channel::{
+            channel::{Order as ChannelOrder, State as ChannelState},
+            msgs::MsgRecvPacket,
+            ChannelEnd, ChannelId, Counterparty, Packet, PortId, Version,
+        },
+        client::Height,
+        commitment::MerkleProof,
+        connection::ConnectionId,
+    },
+    timestamp::Timestamp,
+};
+use penumbra_chain::{
+    component::{StateReadExt, StateWriteExt},
+    test_keys,
+};
+use penumbra_compact_block::{component::StateReadExt as _, StatePayload};
+use penumbra_component::{ActionHandler, Component};
+use penumbra_dex::{component::Dex, component::StateReadExt as _, swap_claim::SwapClaimPlan};
+use penumbra_ibc::component::{AppHandlerExecute, ChannelStateWriteExt as _, Ics20Transfer};
+use penumbra_keys::keys::{SeedPhrase, SpendKey};
+use penumbra_proto::penumbra::core::component::ibc::v1alpha1::FungibleTokenPacketData;
+use penumbra_shielded_pool::component::ShieldedPool;
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+use penumbra_transaction::Transaction;
+use rand_core::SeedableRng;
+use tendermint::abci;
+
+#[tokio::test]
+async fn ics20_transfer_is_received_swapped_and_claimed() -> anyhow::Result<()> {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let height = 1;
+
+    // 1. Simulate BeginBlock, with a transfer channel open to a counterparty chain
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_epoch_by_height(
+        height,
+        penumbra_chain::Epoch {
+            index: 0,
+            start_height: 0,
+        },
+    );
+    state_tx.put_block_height(height);
+    state_tx.put_channel(
+        &ChannelId::new(0),
+        &PortId::transfer(),
+        ChannelEnd {
+            state: ChannelState::Open,
+            ordering: ChannelOrder::Unordered,
+            remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(9))),
+            connection_hops: vec![ConnectionId::new(0)],
+            version: Version::new("ics20-1".to_string()),
+        },
+    );
+    state_tx.apply();
+
+    // 2. Receive a transfer whose memo asks for it to be swapped
+
+    let packet_data = FungibleTokenPacketData {
+        denom: "uatom".to_string(),
+        amount: "100000".to_string(),
+        sender: "cosmos1sender".to_string(),
+        receiver: test_keys::ADDRESS_0_STR.to_string(),
+        memo: r#"{"swap": {"asset": "upenumbra"}}"#.to_string(),
+    };
+    let msg = MsgRecvPacket {
+        packet: Packet {
+            sequence: 1u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(9),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(0),
+            data: serde_json::to_vec(&packet_data)?,
+            timeout_height_on_b: Height::new(0, 1_000)?.into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1)?,
+        },
+        proof_commitment_on_a: MerkleProof { proofs: vec![] },
+        proof_height_on_a: Height::new(0, 1)?,
+        signer: String::new(),
+    };
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    Ics20Transfer::recv_packet_execute(&mut state_tx, &msg).await;
+    state_tx.apply();
+
+    // 3. Execute EndBlock (where the swap is actually executed)
+
+    let end_block = abci::request::EndBlock {
+        height: height.try_into().unwrap(),
+    };
+    // Execute EndBlock for the Dex, to actually execute the swaps...
+    Dex::end_block(&mut state, &end_block).await;
+    ShieldedPool::end_block(&mut state, &end_block).await;
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    // ... and for the App, call `finish_block` to correctly write out the SCT with the data we'll use next.
+    App::finish_block(&mut state_tx).await;
+
+    state_tx.apply();
+
+    // 4. Find the swap the chain made while scanning
+
+    let compact_block = state.compact_block(height).await?.unwrap();
+    let commitment = compact_block
+        .state_payloads
+        .iter()
+        .find_map(|payload| match payload {
+            StatePayload::Swap { swap, .. } => Some(swap.commitment),
+            _ => None,
+        })
+        .expect("transfer was swapped");
+
+    let epoch_duration = state.get_epoch_duration().await?;
+    let mut client = MockClient::new(test_keys::FULL_VIEWING_KEY.clone());
+    client.sync_to(1, state.deref()).await?;
+
+    let swap_auth_path = client.witness(commitment).unwrap();
+    let plaintext = client.swap_by_commitment(&commitment).unwrap();
+    assert_eq!(plaintext.claim_address, *test_keys::ADDRESS_0);
+    assert_eq!(plaintext.delta_1_i + plaintext.delta_2_i, 100_000u64.into());
+
+    // Anyone can decrypt the swap, but only its claim address finds it.
+    let other_fvk = SpendKey::from_seed_phrase_bip39(SeedPhrase::generate(&mut rng), 0)
+        .full_viewing_key()
+        .clone();
+    let mut other_client = MockClient::new(other_fvk);
+    other_client.sync_to(1, state.deref()).await?;
+    assert!(other_client.swap_by_commitment(&commitment).is_none());
+
+    // 5. Create a SwapClaim action
+
+    let output_data = state
+        .output_data(height, plaintext.trading_pair)
+        .await?
+        .unwrap();
+
+    let claim_plan = SwapClaimPlan {
+        swap_plaintext: plaintext,
+        position: swap_auth_path.position(),
+        output_data,
+        epoch_duration,
+        proof_blinding_r: Fq::rand(&mut rng),
+        proof_blinding_s: Fq::rand(&mut rng),
+    };
+    let claim = claim_plan.swap_claim(&test_keys::FULL_VIEWING_KEY, &swap_auth_path);
+
+    // 6. Execute the SwapClaim action
+
+    // The SwapClaim ActionHandler uses the transaction's anchor to check proofs:
+    let context = &Transaction {
+        anchor: client.latest_height_and_sct_root().1,
+        ..Default::default()
+    }
+    .context();
+
+    claim.check_stateless(context.clone()).await?;
+    claim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    claim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    Ok(())
+}
@@ -1,2 +1,5 @@
+mod dao_disbursement;
+mod ics20_swap;
+mod parameter_change;
 mod spend;
 mod swap_and_swap_claim;
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::TempStorageExt;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_governance::{ProposalPayload, StateReadExt as _, StateWriteExt as _};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+
+#[tokio::test]
+async fn legacy_parameter_change_replaces_matching_parameters() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let old = state.get_chain_params().await?;
+    let mut new = old.clone();
+    new.proposal_voting_blocks += 1;
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .enact_proposal(
+            0,
+            &ProposalPayload::LegacyParameterChange {
+                old: Box::new(old),
+                new: Box::new(new.clone()),
+            },
+        )
+        .await?
+        .expect("proposal enacts");
+    state_tx.apply();
+
+    assert_eq!(
+        state.next_block_pending_chain_parameters().await?,
+        Some(new)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn legacy_parameter_change_fails_if_parameters_changed() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let mut old = state.get_chain_params().await?;
+    old.proposal_voting_blocks += 1;
+    let mut new = old.clone();
+    new.proposal_voting_blocks += 1;
+
+    // The proposal was drafted against parameters that no longer match the chain's, so it
+    // fails without touching them.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    assert!(state_tx
+        .enact_proposal(
+            0,
+            &ProposalPayload::LegacyParameterChange {
+                old: Box::new(old),
+                new: Box::new(new),
+            },
+        )
+        .await?
+        .is_err());
+    state_tx.apply();
+
+    assert_eq!(state.next_block_pending_chain_parameters().await?, None);
+    Ok(())
+}
@@ -26,6 +26,12 @@ pub trait StateReadExt: StateRead {
             .ok_or_else(|| anyhow!("Missing ChainParameters"))
     }
 
+    /// Gets the chain parameters which will take effect at the next epoch boundary, if an update
+    /// to the shape of epochs is waiting for it.
+    async fn get_next_epoch_chain_params(&self) -> Result<Option<ChainParameters>> {
+        self.get(state_key::next_epoch_chain_params()).await
+    }
+
     /// Gets the current epoch for the chain.
     async fn get_current_epoch(&self) -> Result<Epoch> {
         // Get the height
@@ -189,6 +195,35 @@ pub trait StateWriteExt: StateWrite {
         // Change the chain parameters:
         self.put(state_key::chain_params().into(), params)
     }
+
+    /// Updates the chain parameters, deferring any change to the shape of epochs (see
+    /// [`ChainParameters::changes_epoch_shape`]) until the next epoch boundary.
+    async fn update_chain_params(&mut self, new: ChainParameters) -> Result<()> {
+        let current = self.get_chain_params().await?;
+
+        if current.changes_epoch_shape(&new) {
+            self.put(state_key::next_epoch_chain_params().into(), new.clone());
+        } else {
+            // A later update which restores the current shape cancels any pending shape change:
+            self.delete(state_key::next_epoch_chain_params().into());
+        }
+
+        self.put_chain_params(new.with_epoch_shape_of(&current));
+        Ok(())
+    }
+
+    /// Applies the change to the shape of epochs which was deferred by
+    /// [`StateWriteExt::update_chain_params`], if any.
+    ///
+    /// This should be called at the end of an epoch, before the components end it.
+    async fn apply_next_epoch_chain_params(&mut self) -> Result<()> {
+        if let Some(next) = self.get_next_epoch_chain_params().await? {
+            let current = self.get_chain_params().await?;
+            self.put_chain_params(current.with_epoch_shape_of(&next));
+            self.delete(state_key::next_epoch_chain_params().into());
+        }
+        Ok(())
+    }
     /// Writes the block height to the JMT
     fn put_block_height(&mut self, height: u64) {
         self.put_proto("block_height".into(), height)
@@ -11,6 +11,7 @@ pub enum NoteSource {
     FundingStreamReward { epoch_index: u64 },
     DaoOutput,
     Ics20Transfer,
+    Ics20Swap,
 }
 
 impl Default for NoteSource {
@@ -54,6 +55,11 @@ impl NoteSource {
                 bytes[CODE_INDEX] = 4;
                 bytes
             }
+            Self::Ics20Swap => {
+                let mut bytes = [0u8; 32];
+                bytes[CODE_INDEX] = 5;
+                bytes
+            }
         }
     }
 }
@@ -74,6 +80,7 @@ impl TryFrom<[u8; 32]> for NoteSource {
                 }
                 (3, &[0, 0, 0, 0, 0, 0, 0, 0]) => Ok(Self::DaoOutput),
                 (4, &[0, 0, 0, 0, 0, 0, 0, 0]) => Ok(Self::Ics20Transfer),
+                (5, &[0, 0, 0, 0, 0, 0, 0, 0]) => Ok(Self::Ics20Swap),
                 (code, data) => Err(anyhow!(
                     "unknown note source with code {} and data {:?}",
                     code,
@@ -134,6 +141,7 @@ impl std::fmt::Debug for NoteSource {
             )),
             NoteSource::DaoOutput => f.write_fmt(format_args!("NoteSource::DaoOutput")),
             NoteSource::Ics20Transfer => f.write_fmt(format_args!("NoteSource::Ics20Transfer")),
+            NoteSource::Ics20Swap => f.write_fmt(format_args!("NoteSource::Ics20Swap")),
         }
     }
 }
@@ -269,6 +269,9 @@ impl FromStr for Ratio {
         if parts.next().is_some() {
             anyhow::bail!("too many parts");
         }
+        if denominator == 0 {
+            anyhow::bail!("denominator must be nonzero");
+        }
         Ok(Ratio {
             numerator,
             denominator,
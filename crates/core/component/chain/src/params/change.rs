@@ -1,9 +1,123 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 
 use super::{ChainParameters, Ratio};
 
+/// A change to a single chain parameter, naming the parameter as it is named in
+/// [`ChainParameters`], and giving its new value in text form.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainParameterChange {
+    pub parameter: String,
+    pub value: String,
+}
+
+impl ChainParameters {
+    /// Apply a list of changes to these parameters, returning the changed parameters.
+    ///
+    /// This fails if a change names an unknown parameter or one which can never be changed, if the
+    /// same parameter is changed twice, or if a new value can't be parsed. It does not check that
+    /// the resulting parameters are valid: use [`ChainParameters::check_valid_update`] for that.
+    pub fn apply_changes(&self, changes: &[ChainParameterChange]) -> Result<ChainParameters> {
+        if changes.is_empty() {
+            anyhow::bail!("parameter change must change at least one parameter");
+        }
+
+        let mut new = self.clone();
+        let ChainParameters {
+            chain_id: _,
+            epoch_duration,
+            unbonding_epochs,
+            active_validator_limit,
+            base_reward_rate,
+            slashing_penalty_misbehavior,
+            slashing_penalty_downtime,
+            signed_blocks_window_len,
+            missed_blocks_maximum,
+            ibc_enabled,
+            inbound_ics20_transfers_enabled,
+            outbound_ics20_transfers_enabled,
+            proposal_voting_blocks,
+            proposal_deposit_amount,
+            proposal_valid_quorum,
+            proposal_pass_threshold,
+            proposal_slash_threshold,
+            dao_spend_proposals_enabled,
+            // IMPORTANT: Don't use `..` here! We want every new field to be considered!
+        } = &mut new;
+
+        let mut seen = BTreeSet::new();
+        for ChainParameterChange { parameter, value } in changes {
+            if !seen.insert(parameter.as_str()) {
+                anyhow::bail!("parameter {parameter} is changed more than once");
+            }
+            match parameter.as_str() {
+                "chain_id" => anyhow::bail!("chain ID can't be changed"),
+                "epoch_duration" => *epoch_duration = parse(parameter, value)?,
+                "unbonding_epochs" => *unbonding_epochs = parse(parameter, value)?,
+                "active_validator_limit" => *active_validator_limit = parse(parameter, value)?,
+                "base_reward_rate" => *base_reward_rate = parse(parameter, value)?,
+                "slashing_penalty_misbehavior" => {
+                    *slashing_penalty_misbehavior = parse(parameter, value)?
+                }
+                "slashing_penalty_downtime" => {
+                    *slashing_penalty_downtime = parse(parameter, value)?
+                }
+                "signed_blocks_window_len" => *signed_blocks_window_len = parse(parameter, value)?,
+                "missed_blocks_maximum" => *missed_blocks_maximum = parse(parameter, value)?,
+                "ibc_enabled" => *ibc_enabled = parse(parameter, value)?,
+                "inbound_ics20_transfers_enabled" => {
+                    *inbound_ics20_transfers_enabled = parse(parameter, value)?
+                }
+                "outbound_ics20_transfers_enabled" => {
+                    *outbound_ics20_transfers_enabled = parse(parameter, value)?
+                }
+                "proposal_voting_blocks" => *proposal_voting_blocks = parse(parameter, value)?,
+                "proposal_deposit_amount" => {
+                    *proposal_deposit_amount = parse::<u128>(parameter, value)?.into()
+                }
+                "proposal_valid_quorum" => *proposal_valid_quorum = parse(parameter, value)?,
+                "proposal_pass_threshold" => *proposal_pass_threshold = parse(parameter, value)?,
+                "proposal_slash_threshold" => *proposal_slash_threshold = parse(parameter, value)?,
+                "dao_spend_proposals_enabled" => {
+                    *dao_spend_proposals_enabled = parse(parameter, value)?
+                }
+                _ => anyhow::bail!("unknown chain parameter {parameter}"),
+            }
+        }
+
+        Ok(new)
+    }
+
+    /// Check that a list of changes is well-formed, without reference to the current parameters.
+    pub fn check_changes(changes: &[ChainParameterChange]) -> Result<()> {
+        ChainParameters::default()
+            .apply_changes(changes)
+            .map(|_| ())
+    }
+
+    /// Whether updating to the `new` parameters changes the shape of epochs: their duration, the
+    /// size of the active validator set, or the window over which validator uptime is tracked.
+    ///
+    /// Changes to these parameters only take effect at the next epoch boundary.
+    pub fn changes_epoch_shape(&self, new: &ChainParameters) -> bool {
+        *self != self.with_epoch_shape_of(new)
+    }
+
+    /// These parameters, with the epoch shape (see [`ChainParameters::changes_epoch_shape`]) of
+    /// the `other` parameters.
+    pub fn with_epoch_shape_of(&self, other: &ChainParameters) -> ChainParameters {
+        ChainParameters {
+            epoch_duration: other.epoch_duration,
+            active_validator_limit: other.active_validator_limit,
+            signed_blocks_window_len: other.signed_blocks_window_len,
+            missed_blocks_maximum: other.missed_blocks_maximum,
+            ..self.clone()
+        }
+    }
+}
+
 // The checks below validate that a parameter change is valid, since some parameter settings or
 // combinations are nonsensical and should be rejected outright, regardless of governance.
 
@@ -14,58 +128,28 @@ impl ChainParameters {
 
         let ChainParameters {
             chain_id,
-            epoch_duration,
+            epoch_duration: _,
             unbonding_epochs: _,
-            active_validator_limit,
+            active_validator_limit: _,
             base_reward_rate: _,
             slashing_penalty_misbehavior: _,
             slashing_penalty_downtime: _,
-            signed_blocks_window_len,
+            signed_blocks_window_len: _,
             missed_blocks_maximum: _,
             ibc_enabled: _,
             inbound_ics20_transfers_enabled: _,
             outbound_ics20_transfers_enabled: _,
             proposal_voting_blocks: _,
             proposal_deposit_amount: _,
-            proposal_valid_quorum,
-            proposal_pass_threshold,
-            proposal_slash_threshold,
+            proposal_valid_quorum: _,
+            proposal_pass_threshold: _,
+            proposal_slash_threshold: _,
             dao_spend_proposals_enabled: _,
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
         // Ensure that certain parameters are not changed by the update:
         check_invariant([(chain_id, &new.chain_id, "chain ID")])?;
-        check_invariant([
-            (epoch_duration, &new.epoch_duration, "epoch duration"),
-            (
-                active_validator_limit,
-                &new.active_validator_limit,
-                "active validator limit",
-            ),
-            (
-                signed_blocks_window_len,
-                &new.signed_blocks_window_len,
-                "signed blocks window length",
-            ),
-        ])?;
-        check_invariant([
-            (
-                proposal_valid_quorum,
-                &new.proposal_valid_quorum,
-                "proposal valid quorum",
-            ),
-            (
-                proposal_pass_threshold,
-                &new.proposal_pass_threshold,
-                "proposal pass threshold",
-            ),
-            (
-                proposal_slash_threshold,
-                &new.proposal_slash_threshold,
-                "proposal slash threshold",
-            ),
-        ])?;
 
         Ok(())
     }
@@ -135,6 +219,10 @@ impl ChainParameters {
                 *missed_blocks_maximum >= 1,
                 "missed blocks maximum must be at least 1",
             ),
+            (
+                *missed_blocks_maximum < *signed_blocks_window_len,
+                "missed blocks maximum must be less than the signed blocks window length",
+            ),
             (
                 (!*inbound_ics20_transfers_enabled && !*outbound_ics20_transfers_enabled)
                     || *ibc_enabled,
@@ -164,6 +252,17 @@ impl ChainParameters {
     }
 }
 
+/// Parse the new value of a parameter, naming the parameter if it can't be parsed.
+fn parse<T>(parameter: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| anyhow!("invalid value {value:?} for {parameter}: {e}"))
+}
+
 /// Ensure all of the booleans are true, and if any are false, generate an error describing which
 /// failed, based on the provided descriptions.
 fn check_all<'a>(checks: impl IntoIterator<Item = (bool, impl Display + 'a)>) -> Result<()> {
@@ -196,3 +295,50 @@ fn check_invariant<'a, T: Eq + 'a>(
             .map(|(old, new, name)| ((*old == *new), format!("{name} can't be changed"))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(parameter: &str, value: &str) -> ChainParameterChange {
+        ChainParameterChange {
+            parameter: parameter.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn applies_changes() {
+        let old = ChainParameters::default();
+        let new = old
+            .apply_changes(&[
+                change("epoch_duration", "1440"),
+                change("proposal_pass_threshold", "6/10"),
+                change("proposal_deposit_amount", "1000"),
+            ])
+            .unwrap();
+
+        assert_eq!(new.epoch_duration, 1440);
+        assert_eq!(new.proposal_pass_threshold, Ratio::new(6, 10));
+        assert_eq!(new.proposal_deposit_amount, 1000u64.into());
+        assert!(old.changes_epoch_shape(&new));
+        assert_eq!(
+            new.with_epoch_shape_of(&old).epoch_duration,
+            old.epoch_duration
+        );
+    }
+
+    #[test]
+    fn rejects_bad_changes() {
+        let old = ChainParameters::default();
+        assert!(old.apply_changes(&[]).is_err());
+        assert!(old.apply_changes(&[change("chain_id", "other")]).is_err());
+        assert!(old
+            .apply_changes(&[change("not_a_parameter", "1")])
+            .is_err());
+        assert!(old.apply_changes(&[change("ibc_enabled", "yes")]).is_err());
+        assert!(old
+            .apply_changes(&[change("epoch_duration", "1"), change("epoch_duration", "2")])
+            .is_err());
+    }
+}
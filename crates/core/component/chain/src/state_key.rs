@@ -4,6 +4,10 @@ pub fn chain_params() -> &'static str {
     "chain_params"
 }
 
+pub fn next_epoch_chain_params() -> &'static str {
+    "chain/next_epoch_params"
+}
+
 pub fn block_height() -> &'static str {
     "block_height"
 }
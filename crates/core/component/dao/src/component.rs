@@ -1,8 +1,48 @@
-/// The DAO isn't really a "component" because it doesn't really execute anything by itself. It's
-/// just a collection of state that is modified by DaoSpend and DaoDeposit actions.
+/// The DAO is mostly a collection of state that is modified by DaoSpend and DaoDeposit actions,
+/// but it also executes the recurring disbursements scheduled by passed proposals at the end of
+/// each epoch.
 pub mod state_key;
 
 mod action_handler;
 mod view;
 
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_component::Component;
+use penumbra_storage::StateWrite;
+use tendermint::v0_34::abci;
+use tracing::instrument;
+
 pub use view::{StateReadExt, StateWriteExt};
+
+pub struct Dao {}
+
+#[async_trait]
+impl Component for Dao {
+    type AppState = ();
+
+    #[instrument(name = "dao", skip(_state, _app_state))]
+    async fn init_chain<S: StateWrite>(_state: S, _app_state: &()) {}
+
+    #[instrument(name = "dao", skip(_state, _begin_block))]
+    async fn begin_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &abci::request::BeginBlock,
+    ) {
+    }
+
+    #[instrument(name = "dao", skip(_state, _end_block))]
+    async fn end_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _end_block: &abci::request::EndBlock,
+    ) {
+    }
+
+    #[instrument(name = "dao", skip(state))]
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        state.pay_dao_disbursements().await
+    }
+}
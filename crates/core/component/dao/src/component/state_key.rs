@@ -8,3 +8,12 @@ pub fn all_assets_balance() -> &'static str {
     // Note: this must be the prefix of the above.
     "dao/asset/"
 }
+
+pub fn disbursement(proposal_id: u64) -> String {
+    format!("dao/disbursement/{proposal_id:020}")
+}
+
+pub fn all_disbursements() -> &'static str {
+    // Note: this must be the prefix of the above.
+    "dao/disbursement/"
+}
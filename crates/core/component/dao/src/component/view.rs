@@ -5,10 +5,15 @@ use async_trait::async_trait;
 
 use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
+use penumbra_chain::{component::StateReadExt as _, NoteSource};
+use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_shielded_pool::component::NoteManager;
 use penumbra_storage::{StateRead, StateWrite};
 
+use crate::DaoDisbursement;
+
 use super::state_key;
 
 #[async_trait]
@@ -32,6 +37,19 @@ pub trait StateReadExt: StateRead {
             .try_collect()
             .await
     }
+
+    /// Get the disbursement scheduled by the given proposal, if it has payouts remaining.
+    async fn dao_disbursement(&self, proposal_id: u64) -> Result<Option<DaoDisbursement>> {
+        self.get(&state_key::disbursement(proposal_id)).await
+    }
+
+    /// Get all disbursements which have payouts remaining, ordered by proposal ID.
+    async fn dao_disbursements(&self) -> Result<Vec<DaoDisbursement>> {
+        self.prefix(state_key::all_disbursements())
+            .map(|result| result.map(|(_key, disbursement)| disbursement))
+            .try_collect()
+            .await
+    }
 }
 
 impl<T> StateReadExt for T where T: StateRead + ?Sized {}
@@ -63,6 +81,93 @@ pub trait StateWriteExt: StateWrite {
         }
         Ok(())
     }
+
+    /// Schedule a recurring disbursement from the DAO on behalf of the given proposal, with the
+    /// first payout due `epoch_interval` epochs after the current one.
+    async fn schedule_dao_disbursement(
+        &mut self,
+        proposal_id: u64,
+        value: Value,
+        address: Address,
+        epoch_interval: u64,
+        payouts: u64,
+    ) -> Result<()> {
+        anyhow::ensure!(epoch_interval > 0, "epoch interval must be nonzero");
+        let current_epoch = self.epoch().await?;
+        self.put_dao_disbursement(DaoDisbursement {
+            proposal: proposal_id,
+            value,
+            address,
+            epoch_interval,
+            payouts_remaining: payouts,
+            next_epoch: current_epoch.index + epoch_interval,
+        });
+        Ok(())
+    }
+
+    /// Record the state of a disbursement, removing it once it has no payouts remaining.
+    fn put_dao_disbursement(&mut self, disbursement: DaoDisbursement) {
+        let key = state_key::disbursement(disbursement.proposal);
+        if disbursement.payouts_remaining > 0 {
+            self.put(key, disbursement);
+        } else {
+            self.delete(key);
+        }
+    }
+
+    /// Cancel all remaining payouts of the disbursement scheduled by the given proposal.
+    async fn cancel_dao_disbursement(&mut self, proposal_id: u64) -> Result<()> {
+        let key = state_key::disbursement(proposal_id);
+        if self.get::<DaoDisbursement>(&key).await?.is_none() {
+            anyhow::bail!("no DAO disbursement is scheduled by proposal {proposal_id}");
+        }
+        self.delete(key);
+        Ok(())
+    }
+
+    /// Pay out every disbursement which is due by the end of the current epoch.
+    ///
+    /// If the DAO does not hold enough funds to cover a payout, that payout is skipped and retried
+    /// at the end of the next epoch, rather than failing the epoch transition.
+    async fn pay_dao_disbursements(&mut self) -> Result<()> {
+        let current_epoch = self.epoch().await?;
+        for mut disbursement in self.dao_disbursements().await? {
+            if disbursement.next_epoch > current_epoch.index {
+                continue;
+            }
+
+            let balance = self.dao_asset_balance(disbursement.value.asset_id).await?;
+            if balance < disbursement.value.amount {
+                tracing::warn!(
+                    proposal = disbursement.proposal,
+                    ?balance,
+                    value = ?disbursement.value,
+                    "insufficient DAO balance for scheduled disbursement, retrying next epoch"
+                );
+                disbursement.next_epoch = current_epoch.index + 1;
+                self.put_dao_disbursement(disbursement);
+                continue;
+            }
+
+            self.dao_withdraw(disbursement.value).await?;
+            self.mint_note(
+                disbursement.value,
+                &disbursement.address,
+                NoteSource::DaoOutput,
+            )
+            .await?;
+            tracing::debug!(
+                proposal = disbursement.proposal,
+                value = ?disbursement.value,
+                "paid scheduled DAO disbursement"
+            );
+
+            disbursement.payouts_remaining -= 1;
+            disbursement.next_epoch = current_epoch.index + disbursement.epoch_interval;
+            self.put_dao_disbursement(disbursement);
+        }
+        Ok(())
+    }
 }
 
 impl<T> StateWriteExt for T where T: StateWrite + ?Sized {}
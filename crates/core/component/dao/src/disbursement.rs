@@ -0,0 +1,88 @@
+use anyhow::{Context, Error};
+use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
+
+use penumbra_asset::Value;
+use penumbra_keys::Address;
+use penumbra_proto::{penumbra::core::component::governance::v1alpha1 as pb, DomainType, TypeUrl};
+
+/// A recurring disbursement from the DAO, scheduled by a passed DAO disbursement proposal.
+///
+/// At the end of every epoch whose index is at least `next_epoch`, `value` is paid out to
+/// `address`, and the next payout is rescheduled `epoch_interval` epochs later, until no payouts
+/// remain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::DaoDisbursement", into = "pb::DaoDisbursement")]
+pub struct DaoDisbursement {
+    /// The ID of the proposal which scheduled this disbursement.
+    pub proposal: u64,
+    /// The value paid out at each disbursement.
+    pub value: Value,
+    /// The address each disbursement is sent to.
+    pub address: Address,
+    /// The number of epochs between disbursements.
+    pub epoch_interval: u64,
+    /// The number of disbursements still to be made.
+    pub payouts_remaining: u64,
+    /// The index of the epoch at whose end the next disbursement is due.
+    pub next_epoch: u64,
+}
+
+impl DaoDisbursement {
+    /// The total value still to be paid out by this disbursement.
+    pub fn value_remaining(&self) -> Value {
+        Value {
+            amount: u128::from(self.value.amount)
+                .saturating_mul(self.payouts_remaining.into())
+                .into(),
+            asset_id: self.value.asset_id,
+        }
+    }
+}
+
+impl TypeUrl for DaoDisbursement {
+    const TYPE_URL: &'static str = "/penumbra.core.governance.v1alpha1.DaoDisbursement";
+}
+
+impl DomainType for DaoDisbursement {
+    type Proto = pb::DaoDisbursement;
+}
+
+impl From<DaoDisbursement> for pb::DaoDisbursement {
+    fn from(msg: DaoDisbursement) -> Self {
+        pb::DaoDisbursement {
+            proposal: msg.proposal,
+            value: Some(msg.value.into()),
+            address: Some(msg.address.into()),
+            epoch_interval: msg.epoch_interval,
+            payouts_remaining: msg.payouts_remaining,
+            next_epoch: msg.next_epoch,
+        }
+    }
+}
+
+impl TryFrom<pb::DaoDisbursement> for DaoDisbursement {
+    type Error = Error;
+
+    fn try_from(proto: pb::DaoDisbursement) -> anyhow::Result<Self, Self::Error> {
+        let value = proto
+            .value
+            .ok_or_else(|| anyhow::anyhow!("missing value"))?
+            .try_into()
+            .context("malformed value")?;
+        let address = proto
+            .address
+            .ok_or_else(|| anyhow::anyhow!("missing address"))?
+            .try_into()
+            .context("malformed address")?;
+
+        Ok(DaoDisbursement {
+            proposal: proto.proposal,
+            value,
+            address,
+            epoch_interval: proto.epoch_interval,
+            payouts_remaining: proto.payouts_remaining,
+            next_epoch: proto.next_epoch,
+        })
+    }
+}
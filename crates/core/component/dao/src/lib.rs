@@ -9,3 +9,6 @@ pub mod event;
 
 mod action;
 pub use action::{DaoDeposit, DaoOutput, DaoSpend};
+
+mod disbursement;
+pub use disbursement::DaoDisbursement;
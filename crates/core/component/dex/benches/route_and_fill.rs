@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use penumbra_asset::asset;
+use penumbra_dex::{
+    component::{
+        router::{RouteAndFill, RoutingParams},
+        PositionManager,
+    },
+    lp::{position::Position, Reserves},
+    DirectedUnitPair,
+};
+use penumbra_num::Amount;
+use penumbra_storage::StateDelta;
+use rand::{rngs::StdRng, SeedableRng};
+use tokio::runtime::Runtime;
+
+/// Create a position buying `quantity` of the start asset of `market` for `price` units
+/// of its end asset each.
+fn limit_buy(rng: &mut StdRng, market: &DirectedUnitPair, quantity: u64, price: u64) -> Position {
+    Position::new(
+        rng,
+        market.into_directed_trading_pair(),
+        0u32,
+        Amount::from(price) * market.end.unit_amount(),
+        market.start.unit_amount(),
+        Reserves {
+            r1: Amount::zero(),
+            r2: Amount::from(quantity * price) * market.end.unit_amount(),
+        },
+    )
+}
+
+/// The positions of the router tests' scenarios of two disjoint routes from gn to penumbra,
+/// a direct one and one through test_usd, given as the gn each route fills at each price.
+fn scenario(direct: &[(u64, u64)], through_usd: &[(u64, u64)]) -> Vec<Position> {
+    let cache = asset::Cache::with_known_assets();
+    let gn = cache.get_unit("gn").unwrap();
+    let penumbra = cache.get_unit("penumbra").unwrap();
+    let test_usd = cache.get_unit("test_usd").unwrap();
+
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    let pair_gn_usd = DirectedUnitPair::new(gn, test_usd.clone());
+    let pair_usd_penumbra = DirectedUnitPair::new(test_usd, penumbra);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut positions = Vec::new();
+    for (quantity, price) in direct {
+        positions.push(limit_buy(&mut rng, &pair_gn_penumbra, *quantity, *price));
+    }
+    for (quantity, price) in through_usd {
+        positions.push(limit_buy(&mut rng, &pair_gn_usd, *quantity, *price));
+    }
+    positions.push(limit_buy(&mut rng, &pair_usd_penumbra, 1000, 1));
+    positions
+}
+
+/// Compare filling a trade along one route at a time with splitting it across both routes,
+/// with equal and with asymmetric liquidity on them.
+fn benchmarks(c: &mut Criterion) {
+    let rt = Runtime::new().expect("can start runtime");
+    let cache = asset::Cache::with_known_assets();
+    let gn = cache.get_unit("gn").unwrap();
+    let penumbra = cache.get_unit("penumbra").unwrap();
+    let input = Amount::from(20u64) * gn.unit_amount();
+
+    let scenarios = [
+        ("disjoint", scenario(&[(10, 2)], &[(10, 2)])),
+        (
+            "asymmetric",
+            scenario(&[(10, 2), (10, 1)], &[(4, 2), (20, 1)]),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("route_and_fill");
+    for (name, positions) in scenarios {
+        let mut state = StateDelta::new(());
+        for position in positions {
+            rt.block_on(state.put_position(position))
+                .expect("can put position");
+        }
+        let state = Arc::new(state);
+
+        for max_paths in [1, 4] {
+            let params = RoutingParams {
+                max_paths,
+                ..Default::default()
+            };
+            group.bench_with_input(BenchmarkId::new(name, max_paths), &params, |b, params| {
+                b.iter_batched(
+                    || Arc::new(StateDelta::new(state.clone())),
+                    |mut fork| {
+                        rt.block_on(fork.route_and_fill(
+                            gn.id(),
+                            penumbra.id(),
+                            input,
+                            params.clone(),
+                        ))
+                        .expect("can route and fill")
+                    },
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
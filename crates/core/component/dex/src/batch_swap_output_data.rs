@@ -41,9 +41,59 @@ pub struct BatchSwapOutputData {
     pub trading_pair: TradingPair,
     /// The starting block height of the epoch for which the batch swap data is valid.
     pub epoch_starting_height: u64,
+    /// The limit price (asset 2 per asset 1) for 1=>2 swaps with a minimum output.
+    ///
+    /// A swap requiring more than `limit_price_1 * delta_1_i` of asset 2 was excluded
+    /// from the batch, and is refunded when claimed.
+    pub limit_price_1: U128x128,
+    /// The limit price (asset 1 per asset 2) for 2=>1 swaps with a minimum output.
+    ///
+    /// A swap requiring more than `limit_price_2 * delta_2_i` of asset 1 was excluded
+    /// from the batch, and is refunded when claimed.
+    pub limit_price_2: U128x128,
 }
 
 impl BatchSwapOutputData {
+    /// Returns whether a swap with inputs `(delta_1_i, delta_2_i)` requiring at least
+    /// `min_output` of the other asset was included in the batch.
+    pub fn min_output_met(
+        &self,
+        (delta_1_i, delta_2_i): (Amount, Amount),
+        min_output: Amount,
+    ) -> bool {
+        // Swaps without a minimum output are always included.
+        if min_output == Amount::zero() {
+            return true;
+        }
+
+        // A swap with a minimum output only has one nonzero input, so only one of these terms
+        // contributes to the threshold.
+        let threshold = self
+            .limit_price_1
+            .checked_mul(&U128x128::from(delta_1_i))
+            .and_then(|term_1| {
+                self.limit_price_2
+                    .checked_mul(&U128x128::from(delta_2_i))
+                    .and_then(|term_2| term_1.checked_add(&term_2))
+            });
+
+        match threshold {
+            Ok(threshold) => U128x128::from(min_output) <= threshold,
+            Err(_) => false,
+        }
+    }
+
+    /// Given a user's inputs `(delta_1_i, delta_2_i)` and minimum output, compute the
+    /// amounts `(lambda_1_i, lambda_2_i)` they are entitled to claim: their pro rata share
+    /// of the batch output if the swap was included in the batch, or their inputs otherwise.
+    pub fn swap_outputs(&self, deltas: (Amount, Amount), min_output: Amount) -> (Amount, Amount) {
+        if self.min_output_met(deltas, min_output) {
+            self.pro_rata_outputs(deltas)
+        } else {
+            deltas
+        }
+    }
+
     /// Given a user's inputs `(delta_1_i, delta_2_i)`, compute their pro rata share
     /// of the batch output `(lambda_1_i, lambda_2_i)`.
     pub fn pro_rata_outputs(&self, (delta_1_i, delta_2_i): (Amount, Amount)) -> (Amount, Amount) {
@@ -134,6 +184,16 @@ impl ToConstraintField<Fq> for BatchSwapOutputData {
                 .to_field_elements()
                 .expect("Fq types are Bls12-377 field members"),
         );
+        public_inputs.extend(
+            self.limit_price_1
+                .to_field_elements()
+                .expect("U128x128 types are Bls12-377 field members"),
+        );
+        public_inputs.extend(
+            self.limit_price_2
+                .to_field_elements()
+                .expect("U128x128 types are Bls12-377 field members"),
+        );
         Some(public_inputs)
     }
 }
@@ -148,6 +208,8 @@ pub struct BatchSwapOutputDataVar {
     pub height: FqVar,
     pub trading_pair: TradingPairVar,
     pub epoch_starting_height: FqVar,
+    pub limit_price_1: U128x128Var,
+    pub limit_price_2: U128x128Var,
 }
 
 impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
@@ -179,10 +241,16 @@ impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
             || Ok(output_data.trading_pair),
             mode,
         )?;
-        let epoch_starting_height =
-            FqVar::new_variable(cs, || Ok(Fq::from(output_data.epoch_starting_height)), mode)?;
+        let epoch_starting_height = FqVar::new_variable(
+            cs.clone(),
+            || Ok(Fq::from(output_data.epoch_starting_height)),
+            mode,
+        )?;
         // Check the epoch starting height is 64 bits
         let _ = bit_constrain(epoch_starting_height.clone(), 64);
+        let limit_price_1 =
+            U128x128Var::new_variable(cs.clone(), || Ok(output_data.limit_price_1), mode)?;
+        let limit_price_2 = U128x128Var::new_variable(cs, || Ok(output_data.limit_price_2), mode)?;
 
         Ok(Self {
             delta_1,
@@ -194,6 +262,8 @@ impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
             trading_pair,
             height,
             epoch_starting_height,
+            limit_price_1,
+            limit_price_2,
         })
     }
 }
@@ -218,6 +288,8 @@ impl From<BatchSwapOutputData> for pb::BatchSwapOutputData {
             height: s.height,
             epoch_starting_height: s.epoch_starting_height,
             trading_pair: Some(s.trading_pair.into()),
+            limit_price_1: s.limit_price_1.into(),
+            limit_price_2: s.limit_price_2.into(),
         }
     }
 }
@@ -270,6 +342,42 @@ impl BatchSwapOutputDataVar {
 
         Ok((lambda_1_i_rounded.into(), lambda_2_i_rounded.into()))
     }
+
+    /// Compute the amounts a swap with inputs `(delta_1_i, delta_2_i)` and minimum output
+    /// `min_output` is entitled to claim: its pro rata share of the batch output if it was
+    /// included in the batch, or its inputs if it was refunded.
+    pub fn swap_outputs(
+        &self,
+        delta_1_i: AmountVar,
+        delta_2_i: AmountVar,
+        min_output: AmountVar,
+        cs: ConstraintSystemRef<Fq>,
+    ) -> Result<(AmountVar, AmountVar), SynthesisError> {
+        // The swap was excluded iff min_output > limit_price_1 * delta_1_i + limit_price_2 * delta_2_i.
+        // This is never the case for a zero min_output.
+        let threshold_1 = self
+            .limit_price_1
+            .clone()
+            .checked_mul(&U128x128Var::from_amount_var(delta_1_i.clone())?)?;
+        let threshold_2 = self
+            .limit_price_2
+            .clone()
+            .checked_mul(&U128x128Var::from_amount_var(delta_2_i.clone())?)?;
+        let threshold = threshold_1.checked_add(&threshold_2)?;
+        let refunded = U128x128Var::from_amount_var(min_output)?.is_gt(&threshold)?;
+
+        // An excluded swap's inputs are not part of the batch, so its pro rata share is
+        // meaningless and may overflow: compute it for zero inputs instead.
+        let zero = AmountVar::new_constant(cs.clone(), Amount::zero())?;
+        let batch_delta_1_i = AmountVar::conditionally_select(&refunded, &zero, &delta_1_i)?;
+        let batch_delta_2_i = AmountVar::conditionally_select(&refunded, &zero, &delta_2_i)?;
+        let (pro_rata_1_i, pro_rata_2_i) =
+            self.pro_rata_outputs(batch_delta_1_i, batch_delta_2_i, cs)?;
+
+        let lambda_1_i = AmountVar::conditionally_select(&refunded, &delta_1_i, &pro_rata_1_i)?;
+        let lambda_2_i = AmountVar::conditionally_select(&refunded, &delta_2_i, &pro_rata_2_i)?;
+        Ok((lambda_1_i, lambda_2_i))
+    }
 }
 
 impl From<BatchSwapOutputData> for BatchSwapOutputDataResponse {
@@ -314,6 +422,17 @@ impl TryFrom<pb::BatchSwapOutputData> for BatchSwapOutputData {
                 .ok_or_else(|| anyhow!("Missing trading_pair"))?
                 .try_into()?,
             epoch_starting_height: s.epoch_starting_height,
+            // Batches without swaps with a minimum output don't record limit prices.
+            limit_price_1: if s.limit_price_1.is_empty() {
+                U128x128::default()
+            } else {
+                s.limit_price_1.as_slice().try_into()?
+            },
+            limit_price_2: if s.limit_price_2.is_empty() {
+                U128x128::default()
+            } else {
+                s.limit_price_2.as_slice().try_into()?
+            },
         })
     }
 }
@@ -431,6 +550,8 @@ mod tests {
                     height: 1,
                     trading_pair,
                     epoch_starting_height: 1,
+                    limit_price_1: U128x128::from(1u64),
+                    limit_price_2: U128x128::from(1u64),
                 },
             }
         }
@@ -452,6 +573,8 @@ mod tests {
             height: 0u64,
             trading_pair,
             epoch_starting_height: 0u64,
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         };
 
         // Now suppose our user's contribution is:
@@ -0,0 +1,121 @@
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{penumbra::core::component::dex::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+
+use crate::{DirectedTradingPair, SwapExecution};
+
+/// The open, high, low, close and volume of the executions of a [`DirectedTradingPair`]
+/// over a range of block heights.
+///
+/// Prices are quoted in units of the pair's `end` asset per unit of its `start` asset,
+/// and the volume is the amount of the `start` asset traded in either direction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "pb::CandlestickData", into = "pb::CandlestickData")]
+pub struct CandlestickData {
+    /// The first height covered by the candle.
+    pub start_height: u64,
+    /// The last height covered by the candle.
+    pub end_height: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: Amount,
+}
+
+impl CandlestickData {
+    /// Summarizes the executions of `pair` in the batch at `height`, given in execution order.
+    ///
+    /// Executions in either direction of the pair are included, and executions that did not
+    /// trade anything are skipped. Returns `None` if nothing was traded.
+    pub fn from_executions<'a>(
+        height: u64,
+        pair: DirectedTradingPair,
+        executions: impl IntoIterator<Item = &'a SwapExecution>,
+    ) -> Option<Self> {
+        let mut candle: Option<CandlestickData> = None;
+        for execution in executions {
+            let (start_amount, end_amount) = if execution.input.asset_id == pair.start
+                && execution.output.asset_id == pair.end
+            {
+                (execution.input.amount, execution.output.amount)
+            } else if execution.input.asset_id == pair.end
+                && execution.output.asset_id == pair.start
+            {
+                (execution.output.amount, execution.input.amount)
+            } else {
+                continue;
+            };
+
+            // Skip executions that did not trade anything.
+            if start_amount == Amount::zero() || end_amount == Amount::zero() {
+                continue;
+            }
+            let price = U128x128::ratio(end_amount, start_amount).expect("start amount is nonzero");
+
+            let execution_candle = CandlestickData {
+                start_height: height,
+                end_height: height,
+                open: price.into(),
+                high: price.into(),
+                low: price.into(),
+                close: price.into(),
+                volume: start_amount,
+            };
+            match candle.as_mut() {
+                Some(candle) => candle.merge(&execution_candle),
+                None => candle = Some(execution_candle),
+            }
+        }
+        candle
+    }
+
+    /// Extends this candle with a candle covering later executions.
+    pub fn merge(&mut self, later: &CandlestickData) {
+        self.end_height = later.end_height;
+        self.high = self.high.max(later.high);
+        self.low = self.low.min(later.low);
+        self.close = later.close;
+        self.volume += later.volume;
+    }
+}
+
+impl TypeUrl for CandlestickData {
+    const TYPE_URL: &'static str = "/penumbra.core.dex.v1alpha1.CandlestickData";
+}
+
+impl DomainType for CandlestickData {
+    type Proto = pb::CandlestickData;
+}
+
+impl From<CandlestickData> for pb::CandlestickData {
+    fn from(cd: CandlestickData) -> Self {
+        Self {
+            start_height: cd.start_height,
+            end_height: cd.end_height,
+            open: cd.open,
+            high: cd.high,
+            low: cd.low,
+            close: cd.close,
+            volume: Some(cd.volume.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::CandlestickData> for CandlestickData {
+    type Error = anyhow::Error;
+    fn try_from(cd: pb::CandlestickData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            start_height: cd.start_height,
+            end_height: cd.end_height,
+            open: cd.open,
+            high: cd.high,
+            low: cd.low,
+            close: cd.close,
+            volume: cd
+                .volume
+                .ok_or_else(|| anyhow::anyhow!("missing volume"))?
+                .try_into()?,
+        })
+    }
+}
@@ -8,7 +8,7 @@ use penumbra_storage::{StateRead, StateWrite};
 use crate::{
     component::{PositionManager, PositionRead},
     event,
-    lp::action::PositionOpen,
+    lp::{action::PositionOpen, position},
 };
 
 #[async_trait]
@@ -36,7 +36,12 @@ impl ActionHandler for PositionOpen {
     }
 
     async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
-        state.put_position(self.position.clone()).await?;
+        let mut position = self.position.clone();
+        // Positions with a trigger can't be traded against until it is met.
+        if position.trigger.is_some() {
+            position.state = position::State::Pending;
+        }
+        state.put_position(position).await?;
         state.record(event::position_open(&self));
         Ok(())
     }
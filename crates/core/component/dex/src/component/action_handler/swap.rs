@@ -3,11 +3,12 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_component::ActionHandler;
+use penumbra_num::Amount;
 use penumbra_proof_params::SWAP_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::{
-    component::{metrics, StateReadExt, StateWriteExt, SwapManager},
+    component::{metrics, SwapManager},
     event,
     swap::Swap,
 };
@@ -21,11 +22,19 @@ impl ActionHandler for Swap {
             anyhow::bail!("Trading pair must be distinct");
         }
 
+        // Check that a swap with a minimum output only swaps in one direction.
+        if self.body.min_output > Amount::zero()
+            && (self.body.delta_1_i == Amount::zero()) == (self.body.delta_2_i == Amount::zero())
+        {
+            anyhow::bail!("Swap with a minimum output must have exactly one nonzero input");
+        }
+
         self.proof.verify(
             &SWAP_PROOF_VERIFICATION_KEY,
             self.balance_commitment_inner(),
             self.body.payload.commitment,
             self.body.fee_commitment,
+            self.body.min_output,
         )?;
 
         Ok(())
@@ -39,17 +48,11 @@ impl ActionHandler for Swap {
         let swap_start = std::time::Instant::now();
         let swap = self;
 
-        // All swaps will be tallied for the block so the
-        // BatchSwapOutputData for the trading pair/block height can
-        // be set during `end_block`.
-        let mut swap_flow = state.swap_flow(&swap.body.trading_pair);
-
-        // Add the amount of each asset being swapped to the batch swap flow.
-        swap_flow.0 += swap.body.delta_1_i;
-        swap_flow.1 += swap.body.delta_2_i;
-
-        // Set the batch swap flow for the trading pair.
-        state.put_swap_flow(&swap.body.trading_pair, swap_flow);
+        state.add_swap_flow(
+            &swap.body.trading_pair,
+            (swap.body.delta_1_i, swap.body.delta_2_i),
+            swap.body.min_output,
+        );
 
         // Record the swap commitment in the state.
         let source = state.object_get("source").unwrap_or_default();
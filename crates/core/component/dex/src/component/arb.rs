@@ -42,6 +42,8 @@ pub trait Arbitrage: StateWrite + Sized {
             max_hops: 5,
             price_limit: Some(1u64.into()),
             fixed_candidates: Arc::new(fixed_candidates),
+            // Cycles are filled one at a time, re-checking profitability after each fill.
+            max_paths: 1,
         };
 
         // Create a flash-loan 2^64 of the arb token to ourselves.
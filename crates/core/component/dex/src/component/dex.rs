@@ -1,18 +1,25 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    pin::Pin,
+    sync::Arc,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::{Stream, StreamExt};
 use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_component::Component;
-use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
 use penumbra_storage::{StateRead, StateWrite};
 use tendermint::v0_34::abci;
 use tracing::instrument;
 
 use crate::{
-    component::flow::SwapFlow, state_key, BatchSwapOutputData, DirectedTradingPair, SwapExecution,
-    TradingPair,
+    component::flow::{LimitSwaps, SwapFlow},
+    state_key, BatchSwapOutputData, CandlestickData, DirectedTradingPair, PriceAccumulator,
+    SwapExecution, TradingPair,
 };
 
 use super::{
@@ -44,12 +51,19 @@ impl Component for Dex {
         let current_epoch = state.epoch().await.expect("epoch is set");
 
         // For each batch swap during the block, calculate clearing prices and set in the JMT.
-        for (trading_pair, swap_flows) in state.swap_flows() {
+        // Trading pairs with only swaps with a minimum output still need a batch, to refund them.
+        let swap_flows = state.swap_flows();
+        let trading_pairs = swap_flows
+            .keys()
+            .chain(state.limit_swaps().keys())
+            .copied()
+            .collect::<BTreeSet<_>>();
+        for &trading_pair in &trading_pairs {
             let batch_start = std::time::Instant::now();
             state
                 .handle_batch_swaps(
                     trading_pair,
-                    swap_flows,
+                    swap_flows.get(&trading_pair).cloned().unwrap_or_default(),
                     end_block
                         .height
                         .try_into()
@@ -69,6 +83,38 @@ impl Component for Dex {
             );
         }
 
+        // Record the prices of the batches in the price accumulators, and activate the
+        // positions whose triggers they meet, before arbitrage moves them.
+        let height = end_block
+            .height
+            .try_into()
+            .expect("height is part of the end block data");
+        for trading_pair in trading_pairs {
+            let output_data = state
+                .output_data(height, trading_pair)
+                .await
+                .expect("must be able to read output data");
+            if let Some(output_data) = output_data {
+                Arc::get_mut(state)
+                    .expect("state should be uniquely referenced after batch swaps complete")
+                    .observe_batch_price(&output_data)
+                    .await
+                    .expect("must be able to update price accumulator");
+            }
+
+            let prices = state
+                .execution_prices(height, trading_pair)
+                .await
+                .expect("must be able to read swap executions");
+            if !prices.is_empty() {
+                Arc::get_mut(state)
+                    .expect("state should be uniquely referenced after batch swaps complete")
+                    .activate_triggered_positions(&trading_pair, &prices)
+                    .await
+                    .expect("must be able to activate triggered positions");
+            }
+        }
+
         // Then, perform arbitrage:
         let arb_burn = state
             .arbitrage(
@@ -148,10 +194,127 @@ pub trait StateReadExt: StateRead {
             .await
     }
 
+    /// Returns the prices of the swap executions on `trading_pair` at `height`, in units of
+    /// asset 2 per unit of asset 1, in either direction.
+    async fn execution_prices(
+        &self,
+        height: u64,
+        trading_pair: TradingPair,
+    ) -> Result<Vec<U128x128>> {
+        let (asset_1, asset_2) = (trading_pair.asset_1(), trading_pair.asset_2());
+        let mut prices = Vec::new();
+        for pair in [
+            DirectedTradingPair::new(asset_1, asset_2),
+            DirectedTradingPair::new(asset_2, asset_1),
+        ] {
+            let Some(execution) = self.swap_execution(height, pair).await? else {
+                continue;
+            };
+            let (amount_1, amount_2) = if pair.start == asset_1 {
+                (execution.input.amount, execution.output.amount)
+            } else {
+                (execution.output.amount, execution.input.amount)
+            };
+            if amount_1 != 0u64.into() && amount_2 != 0u64.into() {
+                prices.push(U128x128::ratio(amount_2, amount_1)?);
+            }
+        }
+        Ok(prices)
+    }
+
     async fn arb_execution(&self, height: u64) -> Result<Option<SwapExecution>> {
         self.get(&state_key::arb_execution(height)).await
     }
 
+    /// Returns a stream of the per-block [`CandlestickData`] of `pair`, for the heights
+    /// between `start_height` and `end_height` (inclusive) at which it was traded.
+    ///
+    /// The stream is empty if `start_height` is after `end_height`.
+    fn candlesticks(
+        &self,
+        pair: &DirectedTradingPair,
+        start_height: u64,
+        end_height: u64,
+    ) -> Pin<Box<dyn Stream<Item = Result<CandlestickData>> + Send + 'static>> {
+        if start_height > end_height {
+            return futures::stream::empty().boxed();
+        }
+        match self.nonverifiable_range_raw(
+            Some(&state_key::internal::candlesticks::prefix(pair)),
+            start_height.to_be_bytes().to_vec()
+                ..end_height.saturating_add(1).to_be_bytes().to_vec(),
+        ) {
+            Ok(stream) => stream
+                .map(|entry| match entry {
+                    Ok((_, v)) => CandlestickData::decode(&*v),
+                    Err(e) => Err(e),
+                })
+                .boxed(),
+            Err(e) => futures::stream::once(async move { Err(e) }).boxed(),
+        }
+    }
+
+    /// Returns the latest price accumulator of `trading_pair`, if its price was ever observed.
+    async fn price_accumulator(
+        &self,
+        trading_pair: &TradingPair,
+    ) -> Result<Option<PriceAccumulator>> {
+        self.get(&state_key::price_accumulator(trading_pair)).await
+    }
+
+    /// Returns the price accumulator of `trading_pair` as of the last observation at or before `height`.
+    async fn price_accumulator_at(
+        &self,
+        trading_pair: &TradingPair,
+        height: u64,
+    ) -> Result<Option<PriceAccumulator>> {
+        let mut observations = self
+            .nonverifiable_range_raw(
+                Some(&state_key::internal::price_accumulators::prefix(
+                    trading_pair,
+                )),
+                state_key::internal::price_accumulators::inverted_height(height).to_vec()..,
+            )?
+            .boxed();
+        match observations.next().await.transpose()? {
+            Some((_, v)) => Ok(Some(PriceAccumulator::decode(&*v)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the time-weighted average price of `trading_pair`, in units of asset 2 per
+    /// unit of asset 1, over the heights from `start_height` up to (excluding) `end_height`.
+    async fn time_weighted_average_price(
+        &self,
+        trading_pair: &TradingPair,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<U128x128> {
+        if end_height <= start_height {
+            anyhow::bail!(
+                "end height {} must be after start height {}",
+                end_height,
+                start_height
+            );
+        }
+        let mut cumulative_prices = Vec::with_capacity(2);
+        for height in [start_height, end_height] {
+            let accumulator = self
+                .price_accumulator_at(trading_pair, height)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "no price of {:?} was observed at or before height {}",
+                        trading_pair,
+                        height
+                    )
+                })?;
+            cumulative_prices.push(accumulator.cumulative_price_at(height)?);
+        }
+        let price = cumulative_prices[1].checked_sub(&cumulative_prices[0])?;
+        Ok(price.checked_div(&U128x128::from(end_height - start_height))?)
+    }
+
     /// Get the swap flow for the given trading pair accumulated in this block so far.
     fn swap_flow(&self, pair: &TradingPair) -> SwapFlow {
         self.swap_flows().get(pair).cloned().unwrap_or_default()
@@ -161,6 +324,12 @@ pub trait StateReadExt: StateRead {
         self.object_get::<BTreeMap<TradingPair, SwapFlow>>(state_key::swap_flows())
             .unwrap_or_default()
     }
+
+    /// Get the swaps with a minimum output accumulated in this block so far.
+    fn limit_swaps(&self) -> BTreeMap<TradingPair, LimitSwaps> {
+        self.object_get::<BTreeMap<TradingPair, LimitSwaps>>(state_key::limit_swaps())
+            .unwrap_or_default()
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
@@ -179,6 +348,22 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         let trading_pair = output_data.trading_pair;
         self.put(state_key::output_data(height, trading_pair), output_data);
 
+        // Index the market data of both directions of the pair, for market data queries.
+        let executions = [&swap_execution_1_for_2, &swap_execution_2_for_1];
+        for pair in [
+            DirectedTradingPair::new(trading_pair.asset_1, trading_pair.asset_2),
+            DirectedTradingPair::new(trading_pair.asset_2, trading_pair.asset_1),
+        ] {
+            if let Some(candle) =
+                CandlestickData::from_executions(height, pair, executions.into_iter().flatten())
+            {
+                self.nonverifiable_put_raw(
+                    state_key::internal::candlesticks::key(&pair, height).to_vec(),
+                    candle.encode_to_vec(),
+                );
+            }
+        }
+
         // Store the swap executions for both directions in the state as well.
         if let Some(swap_execution) = swap_execution_1_for_2 {
             let tp_1_for_2 = DirectedTradingPair::new(trading_pair.asset_1, trading_pair.asset_2);
@@ -203,6 +388,26 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         self.object_put(state_key::pending_outputs(), outputs);
     }
 
+    /// Records the price of a batch swap in the price accumulator of its trading pair.
+    async fn observe_batch_price(&mut self, output_data: &BatchSwapOutputData) -> Result<()> {
+        let Some(price) = PriceAccumulator::batch_price(output_data) else {
+            return Ok(());
+        };
+        let trading_pair = output_data.trading_pair;
+        let accumulator = match self.price_accumulator(&trading_pair).await? {
+            Some(accumulator) => accumulator.observe(output_data.height, price)?,
+            None => PriceAccumulator::new(output_data.height, price),
+        };
+
+        self.put(state_key::price_accumulator(&trading_pair), accumulator);
+        self.nonverifiable_put_raw(
+            state_key::internal::price_accumulators::key(&trading_pair, accumulator.height)
+                .to_vec(),
+            accumulator.encode_to_vec(),
+        );
+        Ok(())
+    }
+
     fn set_arb_execution(&mut self, height: u64, execution: SwapExecution) {
         self.put(state_key::arb_execution(height), execution);
     }
@@ -213,6 +418,23 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         swap_flows.insert(*trading_pair, swap_flow);
         self.object_put(state_key::swap_flows(), swap_flows)
     }
+
+    /// Record a swap with a minimum output, to be included in the batch only if it receives it.
+    fn put_limit_swap(
+        &mut self,
+        trading_pair: &TradingPair,
+        (delta_1_i, delta_2_i): (Amount, Amount),
+        min_output: Amount,
+    ) {
+        let mut limit_swaps = self.limit_swaps();
+        let pair_swaps = limit_swaps.entry(*trading_pair).or_default();
+        if delta_1_i > Amount::zero() {
+            pair_swaps.one_for_two.push((delta_1_i, min_output));
+        } else {
+            pair_swaps.two_for_one.push((delta_2_i, min_output));
+        }
+        self.object_put(state_key::limit_swaps(), limit_swaps)
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
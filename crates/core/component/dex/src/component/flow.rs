@@ -21,3 +21,14 @@ impl DerefMut for SwapFlow {
         &mut self.0
     }
 }
+
+/// The swaps with a minimum output submitted for a trading pair, as `(input, min_output)` pairs.
+///
+/// These are not accounted for in the [`SwapFlow`], since they may be excluded from the batch.
+#[derive(Default, Clone, Debug)]
+pub struct LimitSwaps {
+    /// Swaps of asset 1 for asset 2.
+    pub one_for_two: Vec<(Amount, Amount)>,
+    /// Swaps of asset 2 for asset 1.
+    pub two_for_one: Vec<(Amount, Amount)>,
+}
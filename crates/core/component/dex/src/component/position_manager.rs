@@ -7,15 +7,16 @@ use async_trait::async_trait;
 use futures::Stream;
 use futures::StreamExt;
 use penumbra_asset::asset;
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::DomainType;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_storage::{EscapedByteSlice, StateRead, StateWrite};
 
 use crate::lp::position::State;
+use crate::lp::TriggerDirection;
 use crate::{
     lp::position::{self, Position},
-    state_key, DirectedTradingPair,
+    state_key, DirectedTradingPair, TradingPair,
 };
 
 const DYNAMIC_ASSET_LIMIT: usize = 10;
@@ -56,6 +57,45 @@ pub trait PositionRead: StateRead {
             .boxed()
     }
 
+    /// Returns a stream of the [`position::Id`]s of the pending positions on a trading pair.
+    fn pending_positions(
+        &self,
+        pair: &TradingPair,
+    ) -> Pin<Box<dyn Stream<Item = Result<position::Id>> + Send + 'static>> {
+        let prefix = state_key::internal::pending_positions::prefix(pair);
+        self.nonverifiable_prefix_raw(&prefix)
+            .map(|entry| match entry {
+                Ok((k, _)) => {
+                    let raw_id = <&[u8; 32]>::try_from(&k[104..136])?.to_owned();
+                    Ok(position::Id(raw_id))
+                }
+                Err(e) => Err(e),
+            })
+            .boxed()
+    }
+
+    /// Returns a stream of the [`position::Id`]s of the pending positions on a trading pair
+    /// whose trigger in `direction` is met by `price`, in units of asset 2 per unit of asset 1.
+    fn triggered_positions(
+        &self,
+        pair: &TradingPair,
+        direction: TriggerDirection,
+        price: U128x128,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<position::Id>> + Send + 'static>>> {
+        let prefix = state_key::internal::pending_positions::direction_prefix(pair, direction);
+        let start = state_key::internal::pending_positions::price(price, direction);
+        Ok(self
+            .nonverifiable_range_raw(Some(&prefix), start.to_vec()..)?
+            .map(|entry| match entry {
+                Ok((k, _)) => {
+                    let raw_id = <&[u8; 32]>::try_from(&k[104..136])?.to_owned();
+                    Ok(position::Id(raw_id))
+                }
+                Err(e) => Err(e),
+            })
+            .boxed())
+    }
+
     async fn position_by_id(&self, id: &position::Id) -> Result<Option<position::Position>> {
         self.get(&state_key::position_by_id(id)).await
     }
@@ -128,6 +168,53 @@ pub trait PositionManager: StateWrite + PositionRead {
         self.object_delete(state_key::pending_position_closures());
     }
 
+    /// Opens the pending positions on a trading pair whose trigger is met by any of the
+    /// execution `prices`, in units of asset 2 per unit of asset 1.
+    ///
+    /// Only the pending positions whose trigger price was crossed are read from the index, since
+    /// a trigger met by prices above it is met by any of `prices` exactly when it is met by the
+    /// highest one, and one met by prices below it exactly when it is met by the lowest one.
+    async fn activate_triggered_positions(
+        &mut self,
+        pair: &TradingPair,
+        prices: &[U128x128],
+    ) -> Result<()> {
+        let (Some(highest), Some(lowest)) =
+            (prices.iter().max().copied(), prices.iter().min().copied())
+        else {
+            return Ok(());
+        };
+
+        let mut triggered_ids: Vec<position::Id> = Vec::new();
+        for (direction, price) in [
+            (TriggerDirection::Above, highest),
+            (TriggerDirection::Below, lowest),
+        ] {
+            let ids: Vec<position::Id> = self
+                .triggered_positions(pair, direction, price)?
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<Result<_>>()?;
+            triggered_ids.extend(ids);
+        }
+
+        for id in triggered_ids {
+            let position = self
+                .position_by_id(&id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("pending position {} not found", id))?;
+            tracing::debug!(?id, "position trigger met, opening position");
+            self.put_position(Position {
+                state: position::State::Opened,
+                ..position
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
     /// Writes a position to the state, updating all necessary indexes.
     #[tracing::instrument(level = "debug", skip(self, position), fields(id = ?position.id()))]
     async fn put_position(&mut self, position: position::Position) -> Result<()> {
@@ -143,12 +230,25 @@ pub trait PositionManager: StateWrite + PositionRead {
         // Clear any existing indexes of the position, since changes to the
         // reserves or the position state might have invalidated them.
         self.deindex_position_by_price(&position);
+        if let Some(trigger) = &position.trigger {
+            self.nonverifiable_delete(
+                state_key::internal::pending_positions::key(&position.phi.pair, trigger, &id)
+                    .to_vec(),
+            );
+        }
 
         let position = self.handle_limit_order(&prev, position);
 
-        // Only index the position's liquidity if it is active.
-        if position.state == position::State::Opened {
-            self.index_position_by_price(&position);
+        // Only index the position's liquidity if it is active, and keep track
+        // of pending positions so their triggers can be checked.
+        match (position.state, &position.trigger) {
+            (position::State::Opened, _) => self.index_position_by_price(&position),
+            (position::State::Pending, Some(trigger)) => self.nonverifiable_put_raw(
+                state_key::internal::pending_positions::key(&position.phi.pair, trigger, &id)
+                    .to_vec(),
+                vec![],
+            ),
+            _ => {}
         }
 
         // Update the available liquidity for this position's trading pair.
@@ -168,7 +268,8 @@ pub trait PositionManager: StateWrite + PositionRead {
     ) -> Position {
         let id = position.id();
         match prev_position {
-            Some(_) if position.close_on_fill => {
+            // A limit order being activated from the pending state hasn't been filled yet.
+            Some(prev) if position.close_on_fill && prev.state != position::State::Pending => {
                 // It's technically possible for a limit order to be partially filled,
                 // and unfilled on the other side. In this case, we would close it prematurely.
                 // However, because of the arbitrage dynamics we expect that in practice an order
@@ -407,8 +508,12 @@ pub(super) trait Inner: StateWrite {
 
                 (new_a_from_b, current_a_from_b)
             }
-            (State::Withdrawn, _) | (State::Claimed, _) | (State::Closed, None) => {
+            (State::Withdrawn, _)
+            | (State::Claimed, _)
+            | (State::Closed, None)
+            | (State::Pending, _) => {
                 // The position already went through the `Closed` state or was opened in the `Closed` state, so its contribution has already been subtracted.
+                // Pending positions are not tradeable, so they never contribute.
                 return Ok(());
             }
         };
@@ -446,6 +551,12 @@ pub(super) trait Inner: StateWrite {
         // need to be calculated and stored for both the A -> B and B -> A directions.
         let (a, b) = (position.phi.pair.asset_1(), position.phi.pair.asset_2());
 
+        // A pending position never contributed to the indices, so treat it as absent.
+        let prev_position = &prev_position
+            .as_ref()
+            .filter(|prev| prev.state != State::Pending)
+            .cloned();
+
         // A -> B
         self.update_liquidity_index(DirectedTradingPair::new(a, b), position, prev_position)
             .await?;
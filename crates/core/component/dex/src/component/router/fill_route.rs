@@ -81,6 +81,17 @@ pub trait FillRoute: StateWrite + Sized {
 
 impl<S: StateWrite> FillRoute for S {}
 
+/// Like [`FillRoute::fill_route`], but never fills past the `spill_price` to make progress,
+/// so that simulating it finds exactly how much of the `input` the route fills below it.
+pub(super) async fn fill_route_exact<S: StateWrite + Sized>(
+    state: S,
+    input: Value,
+    hops: &[asset::Id],
+    spill_price: Option<U128x128>,
+) -> Result<SwapExecution, FillError> {
+    fill_route_inner(state, input, hops, spill_price, false).await
+}
+
 async fn fill_route_inner<S: StateWrite + Sized>(
     state: S,
     mut input: Value,
@@ -8,6 +8,11 @@ pub struct RoutingParams {
     pub price_limit: Option<U128x128>,
     pub fixed_candidates: Arc<Vec<asset::Id>>,
     pub max_hops: usize,
+    /// The most paths that a trade is split across simultaneously.
+    ///
+    /// The paths share no trading pair, so that filling one doesn't move the
+    /// prices of the others.
+    pub max_paths: usize,
 }
 
 impl Default for RoutingParams {
@@ -45,6 +50,7 @@ impl Default for RoutingParams {
                     .id(),
             ]),
             max_hops: 4,
+            max_paths: 4,
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -9,7 +9,7 @@ use penumbra_storage::{StateDelta, StateRead};
 use tokio::task::JoinSet;
 use tracing::{instrument, Instrument};
 
-use crate::component::PositionManager;
+use crate::{component::PositionManager, TradingPair};
 
 use super::{Path, PathCache, PathEntry, RoutingParams, SharedPathCache};
 
@@ -18,17 +18,33 @@ pub trait PathSearch: StateRead + Clone + 'static {
     /// Find the best route from `src` to `dst` with estimated price strictly less
     /// than `params.price_limit`, also returning the spill price for the next-best
     /// route, if one exists.
-    #[instrument(skip(self, src, dst, params), fields(max_hops = params.max_hops))]
     async fn path_search(
         &self,
         src: asset::Id,
         dst: asset::Id,
         params: RoutingParams,
     ) -> Result<(Option<Vec<asset::Id>>, Option<U128x128>)> {
+        let (path, spill_price) = self
+            .priced_path_search(src, dst, params, Default::default())
+            .await?;
+        Ok((path.map(|(nodes, _)| nodes), spill_price))
+    }
+
+    /// Like [`PathSearch::path_search`], but also returns the estimated price of the best route,
+    /// and only considers routes which trade on none of the `excluded_pairs`.
+    #[instrument(skip(self, src, dst, params, excluded_pairs), fields(max_hops = params.max_hops))]
+    async fn priced_path_search(
+        &self,
+        src: asset::Id,
+        dst: asset::Id,
+        params: RoutingParams,
+        excluded_pairs: Arc<BTreeSet<TradingPair>>,
+    ) -> Result<(Option<(Vec<asset::Id>, U128x128)>, Option<U128x128>)> {
         let RoutingParams {
             max_hops,
             fixed_candidates,
             price_limit,
+            ..
         } = params;
 
         // Initialize some metrics for calculating time spent on path searching
@@ -42,7 +58,12 @@ pub trait PathSearch: StateRead + Clone + 'static {
 
         let cache = PathCache::begin(src, state);
         for i in 0..max_hops {
-            relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
+            relax_active_paths(
+                cache.clone(),
+                fixed_candidates.clone(),
+                excluded_pairs.clone(),
+            )
+            .await?;
             tracing::debug!(i, "finished relaxing all active paths");
         }
 
@@ -71,7 +92,7 @@ pub trait PathSearch: StateRead + Clone + 'static {
                 tracing::debug!(price = %path.price, price_limit = %price_limit, "path too expensive");
                 Ok((None, None))
             }
-            _ => Ok((Some(nodes), spill_price)),
+            _ => Ok((Some((nodes, path.price)), spill_price)),
         }
     }
 }
@@ -81,6 +102,7 @@ impl<S> PathSearch for S where S: StateRead + Clone + 'static {}
 async fn relax_active_paths<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
+    excluded_pairs: Arc<BTreeSet<TradingPair>>,
 ) -> Result<()> {
     let active_paths = cache.lock().extract_active();
     let mut js = JoinSet::new();
@@ -89,7 +111,12 @@ async fn relax_active_paths<S: StateRead + 'static>(
         "relaxing active paths"
     );
     for path in active_paths {
-        js.spawn(relax_path(cache.clone(), path, fixed_candidates.clone()));
+        js.spawn(relax_path(
+            cache.clone(),
+            path,
+            fixed_candidates.clone(),
+            excluded_pairs.clone(),
+        ));
     }
     // Wait for all relaxations to complete.
     while let Some(task) = js.join_next().await {
@@ -102,6 +129,7 @@ async fn relax_path<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     mut path: Path<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
+    excluded_pairs: Arc<BTreeSet<TradingPair>>,
 ) -> Result<()> {
     let mut candidates = path
         .state
@@ -116,10 +144,14 @@ async fn relax_path<S: StateRead + 'static>(
     // while let Some(new_end) = candidates {
 
     while let Some(new_end) = candidates.inner_mut().next().await {
+        let new_end = new_end?;
+        if excluded_pairs.contains(&TradingPair::new(*path.end(), new_end)) {
+            continue;
+        }
         let new_path = path.fork();
         let cache2 = cache.clone();
         js.spawn(async move {
-            if let Some(new_path) = new_path.extend_to(new_end?).await? {
+            if let Some(new_path) = new_path.extend_to(new_end).await? {
                 cache2.lock().consider(new_path)
             }
             anyhow::Ok(())
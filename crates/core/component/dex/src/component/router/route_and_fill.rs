@@ -1,23 +1,31 @@
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
-use penumbra_storage::StateWrite;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
 use tracing::instrument;
 
 use crate::{
     component::{
         flow::SwapFlow,
         router::{FillRoute, PathSearch, RoutingParams},
-        PositionManager, StateWriteExt,
+        PositionManager, StateReadExt, StateWriteExt,
     },
     lp::position::MAX_RESERVE_AMOUNT,
     BatchSwapOutputData, SwapExecution, TradingPair,
 };
 
-use super::fill_route::FillError;
+use super::fill_route::{fill_route_exact, FillError};
+
+/// The most batch executions simulated to choose the limit price for one direction of a trading
+/// pair in a block, which bounds the limit price search to this many halvings of the candidates.
+const MAX_LIMIT_PRICE_SIMULATIONS: usize = 16;
+
+/// The most times the range of marginal prices is halved to split an input across paths, each
+/// of which simulates filling every path.
+const MAX_SPLIT_PRICE_ITERATIONS: usize = 24;
 
 /// Ties together the routing and filling logic, to process
 /// a block's batch swap flows.
@@ -43,9 +51,30 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
     where
         Self: 'static,
     {
-        let (delta_1, delta_2) = (batch_data.0, batch_data.1);
+        let limit_swaps = self
+            .limit_swaps()
+            .get(&trading_pair)
+            .cloned()
+            .unwrap_or_default();
+
+        tracing::debug!(
+            delta_1 = ?batch_data.0,
+            delta_2 = ?batch_data.1,
+            ?trading_pair,
+            "decrypted batch swaps"
+        );
 
-        tracing::debug!(?delta_1, ?delta_2, ?trading_pair, "decrypted batch swaps");
+        // Swaps with a minimum output are only included in the batch up to a limit price
+        // at which they all receive it; the others are refunded when claimed.
+        let (delta_1, limit_price_1) = self
+            .limit_price(
+                trading_pair.asset_1(),
+                trading_pair.asset_2(),
+                batch_data.0,
+                &limit_swaps.one_for_two,
+                params.clone(),
+            )
+            .await?;
 
         let swap_execution_1_for_2 = if delta_1.value() > 0 {
             Some(
@@ -62,6 +91,16 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             None
         };
 
+        let (delta_2, limit_price_2) = self
+            .limit_price(
+                trading_pair.asset_2(),
+                trading_pair.asset_1(),
+                batch_data.1,
+                &limit_swaps.two_for_one,
+                params.clone(),
+            )
+            .await?;
+
         let swap_execution_2_for_1 = if delta_2.value() > 0 {
             Some(
                 self.route_and_fill(
@@ -101,6 +140,8 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             lambda_2,
             unfilled_1,
             unfilled_2,
+            limit_price_1,
+            limit_price_2,
         };
 
         // Fetch the swap execution object that should have been modified during the routing and filling.
@@ -115,10 +156,167 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
 
         Ok(())
     }
+
+    /// Choose the limit price for the swaps of `asset_1` into `asset_2` with a minimum output,
+    /// returning it along with the total input of the swaps included in the batch.
+    ///
+    /// A swap requiring more than `limit_price * input` of `asset_2` is excluded from the batch.
+    /// The limit price is the highest price required by one of the swaps at which every included
+    /// swap receives its minimum output, as found by simulating the execution on a fork of the
+    /// state. If there is none, only the swaps without a minimum output are included.
+    ///
+    /// Raising the limit price includes more swaps, which both adds input to the batch (lowering
+    /// the price it executes at) and adds swaps requiring a higher price, so the prices at which
+    /// every included swap is filled are the lowest ones. That lets us bisect for the highest of
+    /// them, with at most [`MAX_LIMIT_PRICE_SIMULATIONS`] simulations per direction.
+    #[instrument(skip(self, asset_1, asset_2, unlimited_input, limit_swaps, params))]
+    async fn limit_price(
+        self: &mut Arc<Self>,
+        asset_1: asset::Id,
+        asset_2: asset::Id,
+        unlimited_input: Amount,
+        limit_swaps: &[(Amount, Amount)],
+        params: RoutingParams,
+    ) -> Result<(Amount, U128x128)>
+    where
+        Self: 'static,
+    {
+        let total_input = limit_swaps
+            .iter()
+            .fold(unlimited_input, |total, (input, _)| total + *input);
+        // Every swap claim checks its minimum output against `limit_price * input` in-circuit,
+        // so limit prices at which that could overflow are never chosen.
+        let max_threshold = U128x128::from(1u128 << 127);
+
+        let mut candidates = limit_swaps
+            .iter()
+            .filter_map(|swap| required_price(*swap))
+            .filter(|limit_price| {
+                limit_price
+                    .checked_mul(&U128x128::from(total_input))
+                    .map(|threshold| threshold < max_threshold)
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates.dedup();
+
+        // Bisect for the last candidate at which every included swap is filled: every candidate
+        // below `low` fills its swaps, and none from `high` on does.
+        let (mut low, mut high) = (0, candidates.len());
+        let mut chosen = None;
+        for _ in 0..MAX_LIMIT_PRICE_SIMULATIONS {
+            if low >= high {
+                break;
+            }
+            let mid = low + (high - low) / 2;
+            let limit_price = candidates[mid];
+            match self
+                .fill_at_limit_price(
+                    asset_1,
+                    asset_2,
+                    unlimited_input,
+                    limit_swaps,
+                    limit_price,
+                    params.clone(),
+                )
+                .await?
+            {
+                Some(batch_input) => {
+                    chosen = Some((batch_input, limit_price));
+                    low = mid + 1;
+                }
+                None => high = mid,
+            }
+        }
+
+        match chosen {
+            Some((batch_input, limit_price)) => {
+                tracing::debug!(?limit_price, ?batch_input, "chose limit price");
+                Ok((batch_input, limit_price))
+            }
+            None => {
+                if !limit_swaps.is_empty() {
+                    tracing::debug!(
+                        "no limit price fills every included swap, excluding all limit swaps"
+                    );
+                }
+                Ok((unlimited_input, U128x128::default()))
+            }
+        }
+    }
+
+    /// Simulate the batch execution with the swaps included at `limit_price`, on a fork of the
+    /// state, returning the batch input if every included swap receives its minimum output.
+    async fn fill_at_limit_price(
+        self: &mut Arc<Self>,
+        asset_1: asset::Id,
+        asset_2: asset::Id,
+        unlimited_input: Amount,
+        limit_swaps: &[(Amount, Amount)],
+        limit_price: U128x128,
+        params: RoutingParams,
+    ) -> Result<Option<Amount>>
+    where
+        Self: 'static,
+    {
+        let included = limit_swaps
+            .iter()
+            .copied()
+            .filter(|swap| includes(limit_price, *swap))
+            .collect::<Vec<_>>();
+        if included.is_empty() {
+            return Ok(None);
+        }
+        let batch_input = included
+            .iter()
+            .fold(unlimited_input, |total, (input, _)| total + *input);
+
+        // Simulate the batch execution, discarding its effects.
+        let mut fork = Arc::new(StateDelta::new(self.clone()));
+        let execution = fork
+            .route_and_fill(asset_1, asset_2, batch_input, params)
+            .await?;
+        let batch_output = U128x128::from(execution.output.amount);
+        drop(fork);
+
+        let all_filled = included.iter().all(|(input, min_output)| {
+            // This is the pro rata output computed when the swap is claimed.
+            let output = (U128x128::from(*input) / U128x128::from(batch_input)) * batch_output;
+            output
+                .map(|output| output.round_down() >= U128x128::from(*min_output))
+                .unwrap_or(false)
+        });
+
+        Ok(all_filled.then_some(batch_input))
+    }
 }
 
 impl<T: PositionManager> HandleBatchSwaps for T {}
 
+/// Whether a swap of `input` requiring `min_output` is included in the batch at `limit_price`,
+/// exactly as checked when it is claimed.
+fn includes(limit_price: U128x128, (input, min_output): (Amount, Amount)) -> bool {
+    limit_price
+        .checked_mul(&U128x128::from(input))
+        .map(|threshold| U128x128::from(min_output) <= threshold)
+        .unwrap_or(false)
+}
+
+/// The lowest limit price which includes a swap of `input` requiring `min_output`.
+fn required_price((input, min_output): (Amount, Amount)) -> Option<U128x128> {
+    let price = U128x128::ratio(min_output, input).ok()?;
+    if includes(price, (input, min_output)) {
+        Some(price)
+    } else {
+        // The ratio was rounded down, so step up to the next representable price, which is
+        // enough since the error is less than one unit in the last place.
+        let mut ulp = [0u8; 32];
+        ulp[31] = 1;
+        price.checked_add(&U128x128::from_bytes(ulp)).ok()
+    }
+}
+
 /// Lower-level trait that ties together the routing and filling logic.
 #[async_trait]
 pub trait RouteAndFill: StateWrite + Sized {
@@ -151,77 +349,86 @@ pub trait RouteAndFill: StateWrite + Sized {
         // 3. We have reached the `RoutingParams` specified price limit
 
         loop {
-            // Find the best route between the two assets in the trading pair.
-            let (path, spill_price) = self
-                .path_search(asset_1, asset_2, params.clone())
+            // Find the best routes between the two assets in the trading pair.
+            let (paths, spill_price) = find_paths(self, asset_1, asset_2, params.clone())
                 .await
                 .context("error finding best path")?;
 
-            let Some(path) = path else {
+            if paths.is_empty() {
                 tracing::debug!("no path found, exiting route_and_fill");
                 break;
-            };
-
-            if path.is_empty() {
-                tracing::debug!("empty path found, exiting route_and_fill");
-                break;
             }
 
-            let delta_1 = Value {
+            let input_1 = Value {
                 amount: total_unfilled_1.min(max_delta_1),
                 asset_id: asset_1,
             };
+            let allocations = if paths.len() == 1 {
+                vec![input_1.amount]
+            } else {
+                split_input(self, input_1, &paths, spill_price).await?
+            };
 
-            tracing::debug!(?path, delta_1 = ?delta_1.amount, "found path, filling up to spill price");
-
-            let execution = Arc::get_mut(self)
-                .expect("expected state to have no other refs")
-                .fill_route(delta_1, &path, spill_price)
-                .await;
-
-            let execution = match execution {
-                Ok(execution) => execution,
-                Err(FillError::ExecutionOverflow(position_id)) => {
-                    // We have encountered an overflow during the execution of the route.
-                    // To route around this, we will close the position and try to route and fill again.
-                    tracing::debug!(culprit = ?position_id, "overflow detected during routing execution");
-                    Arc::get_mut(self)
-                        .expect("expected state to have no other refs")
-                        .close_position_by_id(&position_id)
-                        .await
-                        .expect("the position still exists");
+            // The highest price of the executions along each path.
+            let mut max_price: Option<U128x128> = None;
+            let mut overflowed = false;
+            for (path, amount) in paths.iter().zip(allocations) {
+                if amount == Amount::zero() {
                     continue;
                 }
-                Err(e) => {
-                    // We have encountered an error during the execution of the route,
-                    // there are no clear ways to route around this, so we propagate the error.
-                    // `fill_route` is transactional and will have rolled back the state.
-                    anyhow::bail!("error filling route: {:?}", e);
-                }
-            };
-
-            // Immediately track the execution in the state.
-            (total_output_2, total_unfilled_1) = {
-                let lambda_2 = execution.output;
-                let unfilled_1 = Value {
-                    amount: total_unfilled_1
-                        .checked_sub(&execution.input.amount)
-                        .expect("unable to subtract unfilled input from total input"),
+                let delta_1 = Value {
+                    amount,
                     asset_id: asset_1,
                 };
-                tracing::debug!(input = ?delta_1.amount, output = ?lambda_2.amount, unfilled = ?unfilled_1.amount, "filled along best path");
+
+                tracing::debug!(?path, delta_1 = ?delta_1.amount, "found path, filling up to spill price");
+
+                let execution = Arc::get_mut(self)
+                    .expect("expected state to have no other refs")
+                    .fill_route(delta_1, path, spill_price)
+                    .await;
+
+                let execution = match execution {
+                    Ok(execution) => execution,
+                    Err(FillError::ExecutionOverflow(position_id)) => {
+                        // We have encountered an overflow during the execution of the route.
+                        // To route around this, we will close the position and try to route and fill again.
+                        tracing::debug!(culprit = ?position_id, "overflow detected during routing execution");
+                        Arc::get_mut(self)
+                            .expect("expected state to have no other refs")
+                            .close_position_by_id(&position_id)
+                            .await
+                            .expect("the position still exists");
+                        overflowed = true;
+                        break;
+                    }
+                    Err(e) => {
+                        // We have encountered an error during the execution of the route,
+                        // there are no clear ways to route around this, so we propagate the error.
+                        // `fill_route` is transactional and will have rolled back the state.
+                        anyhow::bail!("error filling route: {:?}", e);
+                    }
+                };
+
+                // Immediately track the execution in the state.
+                let lambda_2 = execution.output;
+                total_unfilled_1 = total_unfilled_1
+                    .checked_sub(&execution.input.amount)
+                    .expect("unable to subtract unfilled input from total input");
+                tracing::debug!(input = ?delta_1.amount, output = ?lambda_2.amount, unfilled = ?total_unfilled_1, "filled along path");
 
                 assert_eq!(lambda_2.asset_id, asset_2);
-                assert_eq!(unfilled_1.asset_id, asset_1);
+                total_output_2 = total_output_2 + lambda_2.amount;
 
                 // Append the traces from this execution to the outer traces.
                 traces.append(&mut execution.traces.clone());
 
-                (
-                    total_output_2 + lambda_2.amount,
-                    total_unfilled_1 - delta_1.amount + unfilled_1.amount,
-                )
-            };
+                max_price = max_price.max(execution.max_price()?);
+            }
+
+            if overflowed {
+                continue;
+            }
 
             if total_unfilled_1.value() == 0 {
                 tracing::debug!("filled all input, exiting route_and_fill");
@@ -229,7 +436,7 @@ pub trait RouteAndFill: StateWrite + Sized {
             }
 
             // Ensure that we've actually executed, or else bail out.
-            let Some(accurate_max_price) = execution.max_price()? else {
+            let Some(accurate_max_price) = max_price else {
                 tracing::debug!("no traces in execution, exiting route_and_fill");
                 break;
             };
@@ -262,3 +469,186 @@ pub trait RouteAndFill: StateWrite + Sized {
 }
 
 impl<T: HandleBatchSwaps> RouteAndFill for T {}
+
+/// Finds up to `params.max_paths` routes from `asset_1` to `asset_2` that share no trading
+/// pair, best first, along with the spill price up to which they should be filled.
+///
+/// A single route is filled up to the price of the next best route, which may share
+/// trading pairs with it. Several routes are all filled up to the estimated price of the
+/// next route sharing no trading pair with them, which equalizes their marginal prices.
+async fn find_paths<S: StateRead + 'static>(
+    state: &Arc<S>,
+    asset_1: asset::Id,
+    asset_2: asset::Id,
+    params: RoutingParams,
+) -> Result<(Vec<Vec<asset::Id>>, Option<U128x128>)> {
+    let (path, spill_price) = state.path_search(asset_1, asset_2, params.clone()).await?;
+    let Some(path) = path.filter(|path| !path.is_empty()) else {
+        return Ok((Vec::new(), None));
+    };
+    let mut paths = vec![path];
+    if params.max_paths <= 1 {
+        return Ok((paths, spill_price));
+    }
+
+    let mut next_price = None;
+    loop {
+        // Searching without the pairs along the paths found so far finds only disjoint paths.
+        let (path, _) = state
+            .priced_path_search(
+                asset_1,
+                asset_2,
+                params.clone(),
+                Arc::new(pairs_along(asset_1, &paths)),
+            )
+            .await?;
+        let Some((path, price)) = path.filter(|(path, _)| !path.is_empty()) else {
+            break;
+        };
+        if paths.len() == params.max_paths {
+            next_price = Some(price);
+            break;
+        }
+        paths.push(path);
+    }
+
+    if paths.len() == 1 {
+        Ok((paths, spill_price))
+    } else {
+        tracing::debug!(?paths, ?next_price, "found disjoint paths");
+        Ok((paths, params.clamp_to_limit(next_price).0))
+    }
+}
+
+/// Returns the trading pairs traded on along the `paths` from `start`.
+fn pairs_along(start: asset::Id, paths: &[Vec<asset::Id>]) -> BTreeSet<TradingPair> {
+    paths
+        .iter()
+        .flat_map(|path| {
+            std::iter::once(start)
+                .chain(path.iter().copied())
+                .collect::<Vec<_>>()
+                .windows(2)
+                .map(|hop| TradingPair::new(hop[0], hop[1]))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Splits `input` across disjoint `paths`, so that they are all filled up to a common
+/// marginal price no higher than `spill_price`.
+///
+/// If the paths can fill all of the input, each gets as much as it can fill. Otherwise, we
+/// bisect for the lowest price up to which the paths fill the input together, with at most
+/// [`MAX_SPLIT_PRICE_ITERATIONS`] simulations of each path. Each path is allocated what it
+/// fills just below that price, and the rest of the input is split in proportion to how much
+/// more each fills just above it.
+async fn split_input<S: StateRead + 'static>(
+    state: &Arc<S>,
+    input: Value,
+    paths: &[Vec<asset::Id>],
+    spill_price: Option<U128x128>,
+) -> Result<Vec<Amount>> {
+    let simulations = simulate_paths(state, input, paths, spill_price).await?;
+    let capacities = simulations
+        .iter()
+        .map(|(capacity, _)| *capacity)
+        .collect::<Vec<_>>();
+
+    let total_capacity = total(&capacities);
+    if total_capacity == Amount::zero() {
+        let mut allocations = vec![Amount::zero(); paths.len()];
+        allocations[0] = input.amount;
+        return Ok(allocations);
+    } else if total_capacity <= input.amount {
+        return Ok(capacities);
+    }
+
+    // The paths fill none of the input up to a price of zero, and more than all of it up to
+    // the spill price, or the highest price they reached without one.
+    let mut low_price = U128x128::default();
+    let mut low_capacities = vec![Amount::zero(); paths.len()];
+    let mut high_price = match spill_price {
+        Some(spill_price) => spill_price,
+        None => simulations
+            .iter()
+            .filter_map(|(_, price)| *price)
+            .max()
+            .unwrap_or_default(),
+    };
+    let mut high_capacities = capacities;
+
+    for _ in 0..MAX_SPLIT_PRICE_ITERATIONS {
+        let step = ((high_price - low_price) / U128x128::from(2u64))?;
+        if step == U128x128::default() {
+            break;
+        }
+        let price = (low_price + step)?;
+        let capacities = simulate_paths(state, input, paths, Some(price))
+            .await?
+            .into_iter()
+            .map(|(capacity, _)| capacity)
+            .collect::<Vec<_>>();
+        let total_capacity = total(&capacities);
+        if total_capacity == input.amount {
+            return Ok(capacities);
+        } else if total_capacity < input.amount {
+            (low_price, low_capacities) = (price, capacities);
+        } else {
+            (high_price, high_capacities) = (price, capacities);
+        }
+    }
+
+    let extra_capacities = low_capacities
+        .iter()
+        .zip(&high_capacities)
+        .map(|(low, high)| high.checked_sub(low).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let total_extra = total(&extra_capacities);
+    let remainder = input.amount - total(&low_capacities);
+
+    let mut allocations = low_capacities
+        .iter()
+        .zip(&extra_capacities)
+        .map(|(low, extra)| {
+            let share = U128x128::ratio(*extra, total_extra)?;
+            let allocation = (share * U128x128::from(remainder))?.round_down();
+            Ok(*low + allocation.try_into().expect("rounded to integer"))
+        })
+        .collect::<Result<Vec<Amount>>>()?;
+    // Rounding leaves a remainder, which goes to the best path.
+    let allocated = total(&allocations);
+    allocations[0] = allocations[0] + (input.amount - allocated);
+    Ok(allocations)
+}
+
+/// Simulates filling each of the disjoint `paths` with `input` up to `spill_price`, returning
+/// how much of the input each one fills and the marginal price it reaches.
+async fn simulate_paths<S: StateRead + 'static>(
+    state: &Arc<S>,
+    input: Value,
+    paths: &[Vec<asset::Id>],
+    spill_price: Option<U128x128>,
+) -> Result<Vec<(Amount, Option<U128x128>)>> {
+    // Since the paths are disjoint, each one can be simulated independently.
+    let mut simulations = Vec::with_capacity(paths.len());
+    for path in paths {
+        let mut fork = StateDelta::new(state.clone());
+        let simulation = match fill_route_exact(&mut fork, input, path, spill_price).await {
+            Ok(execution) => (execution.input.amount, execution.max_price()?),
+            Err(e) => {
+                // The error will surface again if the path is filled.
+                tracing::debug!(?e, ?path, "failed to simulate filling path");
+                (Amount::zero(), None)
+            }
+        };
+        simulations.push(simulation);
+    }
+    Ok(simulations)
+}
+
+fn total(amounts: &[Amount]) -> Amount {
+    amounts
+        .iter()
+        .fold(Amount::zero(), |total, amount| total + *amount)
+}
@@ -11,7 +11,7 @@ use std::sync::Arc;
 use crate::lp::SellOrder;
 use crate::{
     component::{
-        router::{FillRoute, HandleBatchSwaps, Path},
+        router::{FillRoute, HandleBatchSwaps, Path, RouteAndFill},
         tests::TempStorageExt,
         PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
@@ -2183,3 +2183,139 @@ async fn path_compare_node_ids() -> anyhow::Result<()> {
     assert!(path2 < path1);
     Ok(())
 }
+
+/// Sets up two disjoint routes from gn to penumbra, each able to fill 10 gn at a price
+/// of 2 penumbra: a direct one, and one through test_usd.
+async fn create_test_positions_disjoint<S: StateWrite>(s: &mut S) {
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let test_usd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    let pair_gn_usd = DirectedUnitPair::new(gn.clone(), test_usd.clone());
+    let pair_usd_penumbra = DirectedUnitPair::new(test_usd.clone(), penumbra.clone());
+
+    for position in [
+        limit_buy(pair_gn_penumbra, 10u64.into(), 2u64.into()),
+        limit_buy(pair_gn_usd, 10u64.into(), 2u64.into()),
+        limit_buy(pair_usd_penumbra, 100u64.into(), 1u64.into()),
+    ] {
+        s.put_position(position).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn route_and_fill_split_across_disjoint_paths() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let input = Amount::from(20u64) * gn.unit_amount();
+
+    let mut outputs = Vec::new();
+    for max_paths in [1, 4] {
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_test_positions_disjoint(&mut state_tx).await;
+        state_tx.apply();
+
+        let execution = state
+            .route_and_fill(
+                gn.id(),
+                penumbra.id(),
+                input,
+                RoutingParams {
+                    max_paths,
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        assert_eq!(execution.input.amount, input, "all input is filled");
+        if max_paths > 1 {
+            let paths = execution.paths();
+            assert_eq!(paths.len(), 2, "input is split across both routes");
+            assert_eq!(paths[0].input.amount + paths[1].input.amount, input);
+        }
+        outputs.push(execution.output.amount);
+    }
+
+    // Splitting should never do worse than filling one route at a time.
+    assert!(outputs[1] >= outputs[0]);
+    assert_eq!(outputs[1], Amount::from(40u64) * penumbra.unit_amount());
+
+    Ok(())
+}
+
+/// Sets up two disjoint routes from gn to penumbra with asymmetric liquidity: a direct one
+/// that fills 10 gn at a price of 2 penumbra and 10 more at 1, and one through test_usd that
+/// fills only 4 gn at 2 penumbra but 20 more at 1.
+async fn create_test_positions_asymmetric<S: StateWrite>(s: &mut S) {
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let test_usd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    let pair_gn_usd = DirectedUnitPair::new(gn.clone(), test_usd.clone());
+    let pair_usd_penumbra = DirectedUnitPair::new(test_usd.clone(), penumbra.clone());
+
+    for position in [
+        limit_buy(pair_gn_penumbra.clone(), 10u64.into(), 2u64.into()),
+        limit_buy(pair_gn_penumbra, 10u64.into(), 1u64.into()),
+        limit_buy(pair_gn_usd.clone(), 4u64.into(), 2u64.into()),
+        limit_buy(pair_gn_usd, 20u64.into(), 1u64.into()),
+        limit_buy(pair_usd_penumbra, 100u64.into(), 1u64.into()),
+    ] {
+        s.put_position(position).await.unwrap();
+    }
+}
+
+#[tokio::test]
+/// Test that the input is split so that both routes are filled up to the same marginal
+/// price, rather than in proportion to how much each route can fill.
+async fn route_and_fill_split_equalizes_marginal_prices() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    create_test_positions_asymmetric(&mut state_tx).await;
+    state_tx.apply();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let input = Amount::from(20u64) * gn.unit_amount();
+
+    let execution = state
+        .route_and_fill(gn.id(), penumbra.id(), input, RoutingParams::default())
+        .await?;
+    assert_eq!(execution.input.amount, input, "all input is filled");
+
+    // Both routes are filled at a price of 2 before either is filled at 1, so 14 gn is sold
+    // for 28 penumbra and the other 6 gn for 6 penumbra. Splitting 20 gn in proportion to the
+    // 20 and 24 gn the routes can fill would sell only 9 gn on the direct route at 2.
+    assert_eq!(
+        execution.output.amount,
+        Amount::from(34u64) * penumbra.unit_amount()
+    );
+    let paths = execution.paths();
+    assert_eq!(paths.len(), 2, "input is split across both routes");
+    for path in paths {
+        let filled_at_best_price = if path.route.len() == 2 { 10u64 } else { 4u64 };
+        assert!(path.input.amount >= Amount::from(filled_at_best_price) * gn.unit_amount());
+    }
+
+    Ok(())
+}
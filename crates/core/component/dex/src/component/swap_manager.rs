@@ -1,11 +1,17 @@
 use async_trait::async_trait;
 use penumbra_chain::NoteSource;
+use penumbra_num::Amount;
 use penumbra_sct::component::SctManager as _;
 use penumbra_storage::StateWrite;
 use penumbra_tct as tct;
 use tracing::instrument;
 
-use crate::{state_key, swap::SwapPayload};
+use crate::{
+    component::{StateReadExt as _, StateWriteExt as _},
+    state_key,
+    swap::{SwapPayload, SwapPlaintext, CHAIN_SWAP_PAYLOAD_KEY},
+    TradingPair,
+};
 
 /// Manages the addition of new notes to the chain state.
 #[async_trait]
@@ -31,6 +37,55 @@ pub trait SwapManager: StateWrite {
         self.object_put(state_key::pending_payloads(), payloads);
     }
 
+    /// Add a swap made by the chain itself on behalf of its claim address, rather than by a
+    /// transaction, to the batch for its trading pair.
+    ///
+    /// Its payload is encrypted with the public [`CHAIN_SWAP_PAYLOAD_KEY`], so that the owner of
+    /// the claim address can find it while scanning, and claim it with a `SwapClaim`.
+    async fn add_chain_swap(&mut self, swap: SwapPlaintext, source: NoteSource)
+    where
+        Self: Sized,
+    {
+        self.add_swap_flow(
+            &swap.trading_pair,
+            (swap.delta_1_i, swap.delta_2_i),
+            swap.min_output,
+        );
+        self.add_swap_payload(
+            swap.encrypt_with_payload_key(&CHAIN_SWAP_PAYLOAD_KEY),
+            source,
+        )
+        .await;
+    }
+
+    /// Add the inputs of a swap to the batch for its trading pair in the current block.
+    fn add_swap_flow(
+        &mut self,
+        trading_pair: &TradingPair,
+        (delta_1_i, delta_2_i): (Amount, Amount),
+        min_output: Amount,
+    ) where
+        Self: Sized,
+    {
+        // All swaps will be tallied for the block so the
+        // BatchSwapOutputData for the trading pair/block height can
+        // be set during `end_block`.
+        if min_output == Amount::zero() {
+            let mut swap_flow = self.swap_flow(trading_pair);
+
+            // Add the amount of each asset being swapped to the batch swap flow.
+            swap_flow.0 += delta_1_i;
+            swap_flow.1 += delta_2_i;
+
+            // Set the batch swap flow for the trading pair.
+            self.put_swap_flow(trading_pair, swap_flow);
+        } else {
+            // Swaps with a minimum output are only added to the batch in `end_block`,
+            // once it's known whether they can receive it.
+            self.put_limit_swap(trading_pair, (delta_1_i, delta_2_i), min_output);
+        }
+    }
+
     async fn pending_swap_payloads(&self) -> im::Vector<(tct::Position, SwapPayload, NoteSource)> {
         self.object_get(state_key::pending_payloads())
             .unwrap_or_default()
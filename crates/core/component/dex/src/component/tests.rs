@@ -2,9 +2,9 @@ use std::sync::Arc;
 
 use anyhow::Ok;
 use async_trait::async_trait;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
 use rand_core::OsRng;
 
@@ -18,7 +18,8 @@ use crate::{
         Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    BatchSwapOutputData, CandlestickData, DirectedTradingPair, DirectedUnitPair, SwapExecution,
+    TradingPair,
 };
 
 // TODO: what's the right way to mock genesis? if component A needs component B,
@@ -713,6 +714,8 @@ async fn swap_execution_tests() -> anyhow::Result<()> {
             height: 0,
             epoch_starting_height: 0,
             trading_pair,
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         }
     );
 
@@ -901,3 +904,377 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
     tracing::info!(?arb_execution, "fetched arb execution!");
     Ok(())
 }
+
+#[tokio::test]
+/// Test that swap executions are indexed into per-block candlesticks for both directions.
+async fn candlesticks_are_indexed() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let trading_pair = TradingPair::new(penumbra.id(), gn.id());
+    let pair_12 = DirectedTradingPair::new(trading_pair.asset_1(), trading_pair.asset_2());
+    let value_1 = |amount: u64| Value {
+        amount: amount.into(),
+        asset_id: trading_pair.asset_1(),
+    };
+    let value_2 = |amount: u64| Value {
+        amount: amount.into(),
+        asset_id: trading_pair.asset_2(),
+    };
+    let execution = |input: Value, output: Value| SwapExecution {
+        traces: vec![vec![input, output]],
+        input,
+        output,
+    };
+    let output_data = |height: u64| BatchSwapOutputData {
+        delta_1: 0u32.into(),
+        delta_2: 0u32.into(),
+        lambda_1: 0u32.into(),
+        lambda_2: 0u32.into(),
+        unfilled_1: 0u32.into(),
+        unfilled_2: 0u32.into(),
+        height,
+        epoch_starting_height: 0,
+        trading_pair,
+        limit_price_1: U128x128::default(),
+        limit_price_2: U128x128::default(),
+    };
+
+    // At height 1, 10 of asset 1 trade for 20 of asset 2, then 30 of asset 2 trade for 10 of asset 1.
+    state.set_output_data(
+        output_data(1),
+        Some(execution(value_1(10), value_2(20))),
+        Some(execution(value_2(30), value_1(10))),
+    );
+    // At height 3, only 10 of asset 1 trade for 10 of asset 2.
+    state.set_output_data(
+        output_data(3),
+        Some(execution(value_1(10), value_2(10))),
+        None,
+    );
+
+    let candles: Vec<CandlestickData> = state.candlesticks(&pair_12, 0, 3).try_collect().await?;
+    assert_eq!(
+        candles,
+        vec![
+            CandlestickData {
+                start_height: 1,
+                end_height: 1,
+                open: 2.0,
+                high: 3.0,
+                low: 2.0,
+                close: 3.0,
+                volume: 20u64.into(),
+            },
+            CandlestickData {
+                start_height: 3,
+                end_height: 3,
+                open: 1.0,
+                high: 1.0,
+                low: 1.0,
+                close: 1.0,
+                volume: 10u64.into(),
+            },
+        ]
+    );
+
+    // The opposite direction quotes the inverse prices, with volume in asset 2.
+    let candles: Vec<CandlestickData> = state
+        .candlesticks(&pair_12.flip(), 1, 2)
+        .try_collect()
+        .await?;
+    assert_eq!(candles.len(), 1);
+    assert_eq!(candles[0].open, 0.5);
+    assert_eq!(candles[0].high, 0.5);
+    assert_eq!(candles[0].volume, 50u64.into());
+
+    // An inverted range is empty rather than an error.
+    let candles: Vec<CandlestickData> = state.candlesticks(&pair_12, 3, 1).try_collect().await?;
+    assert!(candles.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that batch prices are accumulated into time-weighted average prices.
+async fn time_weighted_average_price() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let trading_pair = TradingPair::new(penumbra.id(), gn.id());
+    // A batch at `height` where 10 of asset 1 traded for `lambda_2` of asset 2.
+    let output_data = |height: u64, lambda_2: u64| BatchSwapOutputData {
+        delta_1: 10u32.into(),
+        delta_2: 0u32.into(),
+        lambda_1: 0u32.into(),
+        lambda_2: lambda_2.into(),
+        unfilled_1: 0u32.into(),
+        unfilled_2: 0u32.into(),
+        height,
+        epoch_starting_height: 0,
+        trading_pair,
+        limit_price_1: U128x128::default(),
+        limit_price_2: U128x128::default(),
+    };
+
+    // The price is 2 from height 1, then 4 from height 3.
+    state.observe_batch_price(&output_data(1, 20)).await?;
+    state.observe_batch_price(&output_data(3, 40)).await?;
+
+    let accumulator = state.price_accumulator(&trading_pair).await?.unwrap();
+    assert_eq!(accumulator.height, 3);
+    assert_eq!(accumulator.price, U128x128::from(4u64));
+    assert_eq!(accumulator.cumulative_price, U128x128::from(4u64));
+
+    let (state, trading_pair) = (&state, &trading_pair);
+    let twap = move |start_height, end_height| {
+        state.time_weighted_average_price(trading_pair, start_height, end_height)
+    };
+    assert_eq!(twap(1, 3).await?, U128x128::from(2u64));
+    assert_eq!(twap(3, 10).await?, U128x128::from(4u64));
+    assert_eq!(twap(1, 5).await?, U128x128::from(3u64));
+    assert_eq!(twap(2, 4).await?, U128x128::from(3u64));
+
+    // There is no price before the first observation, and the window must not be empty.
+    assert!(twap(0, 3).await.is_err());
+    assert!(twap(3, 3).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that a triggered position stays out of the order book until an execution
+/// price meets its trigger.
+async fn triggered_position_is_activated() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+    // A stop-loss: sell 1gm at 1gn once the price of gm falls to 1.5gn.
+    let mut position =
+        crate::lp::SellOrder::parse_triggered_str("1gm@1gn^1.5gn", crate::lp::TriggerKind::Stop)?
+            .into_position(OsRng);
+    position.state = crate::lp::position::State::Pending;
+    let id = position.id();
+    state_tx.put_position(position).await?;
+    state_tx.apply();
+
+    let canonical_pair: TradingPair = pair.into();
+    let pending = state
+        .pending_positions(&canonical_pair)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(pending, vec![id]);
+    assert!(state.best_position(&pair.flip()).await?.is_none());
+
+    // Converts a price of gm in gn to the canonical price of the pair.
+    let canonical_price = |gn_per_gm: U128x128| {
+        if canonical_pair.asset_1() == gm.id() {
+            gn_per_gm
+        } else {
+            (U128x128::from(1u64) / gn_per_gm).unwrap()
+        }
+    };
+
+    // An execution above the trigger price leaves the position pending,
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .activate_triggered_positions(&canonical_pair, &[canonical_price(2u64.into())])
+        .await?;
+    state_tx.apply();
+    assert_eq!(
+        state.position_by_id(&id).await?.unwrap().state,
+        crate::lp::position::State::Pending
+    );
+
+    // but one at or below it opens the position.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .activate_triggered_positions(&canonical_pair, &[canonical_price(1u64.into())])
+        .await?;
+    state_tx.apply();
+    assert_eq!(
+        state.position_by_id(&id).await?.unwrap().state,
+        crate::lp::position::State::Opened
+    );
+    assert!(state
+        .pending_positions(&canonical_pair)
+        .try_collect::<Vec<_>>()
+        .await?
+        .is_empty());
+    assert_eq!(
+        state.best_position(&pair.flip()).await?.map(|p| p.id()),
+        Some(id)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that only the pending positions whose trigger price was crossed by the execution
+/// prices are activated.
+async fn only_crossed_triggers_are_activated() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedTradingPair::new(gm.id(), gn.id());
+    let canonical_pair: TradingPair = pair.into();
+
+    // Stop-losses once the price of gm falls to 1.5gn or 1.2gn, and take-profits once it
+    // rises to 3gn or 4gn.
+    let mut ids = Vec::new();
+    for (order, kind) in [
+        ("1gm@1gn^1.5gn", crate::lp::TriggerKind::Stop),
+        ("1gm@1gn^1.2gn", crate::lp::TriggerKind::Stop),
+        ("1gm@5gn^3gn", crate::lp::TriggerKind::TakeProfit),
+        ("1gm@5gn^4gn", crate::lp::TriggerKind::TakeProfit),
+    ] {
+        let mut position =
+            crate::lp::SellOrder::parse_triggered_str(order, kind)?.into_position(OsRng);
+        position.state = crate::lp::position::State::Pending;
+        ids.push(position.id());
+        state_tx.put_position(position).await?;
+    }
+    state_tx.apply();
+
+    // Converts a price of gm in gn to the canonical price of the pair.
+    let canonical_price = |gn_per_gm: U128x128| {
+        if canonical_pair.asset_1() == gm.id() {
+            gn_per_gm
+        } else {
+            (U128x128::from(1u64) / gn_per_gm).unwrap()
+        }
+    };
+
+    // The price of gm swings between 1.4gn and 3.5gn within the block.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .activate_triggered_positions(
+            &canonical_pair,
+            &[
+                canonical_price(U128x128::ratio(14u64, 10u64)?),
+                canonical_price(U128x128::ratio(35u64, 10u64)?),
+            ],
+        )
+        .await?;
+    state_tx.apply();
+
+    let mut states = Vec::new();
+    for id in &ids {
+        states.push(state.position_by_id(id).await?.unwrap().state);
+    }
+    assert_eq!(
+        states,
+        vec![
+            crate::lp::position::State::Opened,
+            crate::lp::position::State::Pending,
+            crate::lp::position::State::Opened,
+            crate::lp::position::State::Pending,
+        ]
+    );
+
+    let mut pending = state
+        .pending_positions(&canonical_pair)
+        .try_collect::<Vec<_>>()
+        .await?;
+    pending.sort();
+    let mut expected = vec![ids[1], ids[3]];
+    expected.sort();
+    assert_eq!(pending, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that limit swaps are included in the batch alongside plain swaps at the highest limit
+/// price at which all of them receive their minimum output.
+async fn limit_swaps_batched_with_plain_swaps() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // Sell 10gn at 1penumbra each, then 10gn more at 2penumbra each.
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    state_tx
+        .put_position(limit_sell(
+            pair_gn_penumbra.clone(),
+            10u64.into(),
+            1u64.into(),
+        ))
+        .await?;
+    state_tx
+        .put_position(limit_sell(
+            pair_gn_penumbra.clone(),
+            10u64.into(),
+            2u64.into(),
+        ))
+        .await?;
+    state_tx.apply();
+
+    let trading_pair: TradingPair = pair_gn_penumbra.into_directed_trading_pair().into();
+    assert!(trading_pair.asset_1() == penumbra.id());
+
+    // A plain swap of 5penumbra, and three limit swaps of penumbra for gn, requiring prices of
+    // 1gn, 0.6gn and 0.5gn per penumbra.
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    swap_flow.0 += penumbra.value(5u32.into()).amount;
+    let state_mut = Arc::get_mut(&mut state).unwrap();
+    state_mut.put_swap_flow(&trading_pair, swap_flow.clone());
+    for (input, min_output) in [(5u32, 5u32), (5, 3), (10, 5)] {
+        state_mut.put_limit_swap(
+            &trading_pair,
+            (penumbra.value(input.into()).amount, Amount::zero()),
+            gn.value(min_output.into()).amount,
+        );
+    }
+
+    state
+        .handle_batch_swaps(trading_pair, swap_flow, 0, 0, RoutingParams::default())
+        .await
+        .expect("unable to process batch swaps");
+
+    // Including every swap, 25penumbra would buy 17.5gn, short of the 1gn per penumbra the first
+    // limit swap requires. Leaving it out, 20penumbra buys 15gn, which fills the other two: at
+    // 0.75gn per penumbra, they receive 3.75gn and 7.5gn. So the limit price is 0.6gn per
+    // penumbra, the highest that the included swaps require.
+    let output_data = state.output_data(0, trading_pair).await?.unwrap();
+    assert_eq!(output_data.delta_1, penumbra.value(20u32.into()).amount);
+    assert_eq!(output_data.lambda_2, gn.value(15u32.into()).amount);
+    assert_eq!(output_data.unfilled_1, Amount::zero());
+    let limit_price_1 = output_data.limit_price_1;
+    let gn_for = |input: u32| {
+        limit_price_1
+            .checked_mul(&penumbra.value(input.into()).amount.into())
+            .unwrap()
+    };
+    assert!(gn_for(5) >= U128x128::from(gn.value(3u32.into()).amount));
+    assert!(gn_for(5) < U128x128::from(gn.value(5u32.into()).amount));
+    assert_eq!(output_data.limit_price_2, U128x128::default());
+
+    Ok(())
+}
@@ -8,11 +8,15 @@ pub mod event;
 pub mod state_key;
 
 mod batch_swap_output_data;
+mod candlestick;
+mod price_accumulator;
 mod swap_execution;
 mod trading_pair;
 
 pub use batch_swap_output_data::BatchSwapOutputData;
-pub use swap_execution::SwapExecution;
+pub use candlestick::CandlestickData;
+pub use price_accumulator::PriceAccumulator;
+pub use swap_execution::{PathExecution, SwapExecution};
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
 
 pub mod lp;
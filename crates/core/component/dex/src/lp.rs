@@ -2,13 +2,16 @@ mod nft;
 mod order;
 mod reserves;
 mod trading_function;
+mod trigger;
 
 pub mod action;
 pub mod plan;
 pub mod position;
+pub mod replicate;
 
 pub use nft::LpNft;
-pub use order::{BuyOrder, SellOrder};
+pub use order::{BuyOrder, SellOrder, TriggerKind};
 pub use reserves::Reserves;
 pub use trading_function::BareTradingFunction;
 pub use trading_function::TradingFunction;
+pub use trigger::{Direction as TriggerDirection, Trigger};
@@ -7,9 +7,12 @@ use penumbra_num::{fixpoint::U128x128, Amount};
 use rand_core::CryptoRngCore;
 use regex::Regex;
 
-use crate::DirectedTradingPair;
+use crate::{DirectedTradingPair, TradingPair};
 
-use super::position::Position;
+use super::{
+    position::Position,
+    trigger::{Direction, Trigger},
+};
 
 /// Helper structure for constructing a [`Position`] expressing the desire to
 /// buy the `desired` value in exchange for the `offered` value.
@@ -18,6 +21,8 @@ pub struct BuyOrder {
     pub desired: Value,
     pub offered: Value,
     pub fee: u32,
+    /// If set, the order only becomes active once the trigger is met.
+    pub trigger: Option<Trigger>,
 }
 
 /// Helper structure for constructing a [`Position`] expressing the desire to
@@ -27,10 +32,38 @@ pub struct SellOrder {
     pub offered: Value,
     pub desired: Value,
     pub fee: u32,
+    /// If set, the order only becomes active once the trigger is met.
+    pub trigger: Option<Trigger>,
+}
+
+/// The kind of price move that activates a triggered order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerKind {
+    /// Activates the order once the price moves against the trader: a sell order
+    /// once the price falls to the trigger price, or a buy order once it rises to it.
+    Stop,
+    /// Activates the order once the price moves in favor of the trader: a sell order
+    /// once the price rises to the trigger price, or a buy order once it falls to it.
+    TakeProfit,
+}
+
+impl TriggerKind {
+    /// Whether the trigger is met by the price of the traded asset rising to the trigger price.
+    fn rising(&self, selling: bool) -> bool {
+        match self {
+            TriggerKind::Stop => !selling,
+            TriggerKind::TakeProfit => selling,
+        }
+    }
 }
 
 /// This doesn't parse the values yet, because we need to inspect their units.
-fn parse_parts(input: &str) -> Result<(&str, &str, u32)> {
+fn parse_parts(input: &str) -> Result<(&str, &str, u32, Option<&str>)> {
+    let (input, trigger_part) = match input.split_once('^') {
+        Some((input, trigger_part)) => (input, Some(trigger_part)),
+        None => (input, None),
+    };
+
     let (trade_part, fee_part) = match input.split_once('/') {
         Some((trade_part, fee_part)) => (trade_part, fee_part),
         None => (input, "0bps"),
@@ -45,7 +78,79 @@ fn parse_parts(input: &str) -> Result<(&str, &str, u32)> {
         None => anyhow::bail!("could not parse fee string {}", fee_part),
     };
 
-    Ok((val1, val2, fee))
+    Ok((val1, val2, fee, trigger_part))
+}
+
+/// Parses a trigger on the price of the traded asset `base`, expressed in `quote` per
+/// display unit of `base` like the order price, into a trigger on the canonical price
+/// of the trading pair.
+fn parse_trigger(
+    trigger_str: &str,
+    base: &Unit,
+    quote: asset::Id,
+    rising: bool,
+) -> Result<Trigger> {
+    let trigger_price = trigger_str.parse::<Value>()?;
+    if trigger_price.asset_id != quote {
+        anyhow::bail!(
+            "trigger price {} must be in the same asset as the order price",
+            trigger_str
+        );
+    }
+    if trigger_price.amount == 0u64.into() {
+        anyhow::bail!("trigger price {} must be nonzero", trigger_str);
+    }
+
+    let trigger_amount = U128x128::from(trigger_price.amount);
+    let base_unit_amount = U128x128::from(base.unit_amount());
+
+    // The canonical price is in units of asset 2 per unit of asset 1, so if the
+    // traded asset is asset 2, the price is inverted, and so is the direction.
+    let pair = TradingPair::new(base.id(), quote);
+    let (price, above) = if pair.asset_1() == base.id() {
+        ((trigger_amount / base_unit_amount)?, rising)
+    } else {
+        ((base_unit_amount / trigger_amount)?, !rising)
+    };
+
+    Ok(Trigger {
+        price,
+        direction: if above {
+            Direction::Above
+        } else {
+            Direction::Below
+        },
+    })
+}
+
+/// Formats a trigger as a price of the traded asset `base`, in `quote` per display unit of
+/// `base`, inverting [`parse_trigger`].
+fn format_trigger(
+    trigger: &Trigger,
+    base: &Unit,
+    quote: asset::Id,
+    cache: &asset::Cache,
+) -> Result<String> {
+    let base_unit_amount = U128x128::from(base.unit_amount());
+    let pair = TradingPair::new(base.id(), quote);
+    let trigger_amount = if pair.asset_1() == base.id() {
+        (trigger.price * base_unit_amount)?
+    } else {
+        (base_unit_amount / trigger.price)?
+    };
+
+    // Round to the nearest integer, since the canonical price may be slightly off
+    // either way after being inverted.
+    let half = U128x128::ratio(1u64, 2u64)?;
+    Ok(Value {
+        amount: trigger_amount
+            .checked_add(&half)?
+            .round_down()
+            .try_into()
+            .expect("rounded to integer"),
+        asset_id: quote,
+    }
+    .format(cache))
 }
 
 fn extract_unit(input: &str) -> Result<Unit> {
@@ -61,7 +166,17 @@ fn extract_unit(input: &str) -> Result<Unit> {
 impl BuyOrder {
     /// Eventually we'll need to plumb in an asset::Cache so this isn't FromStr
     pub fn parse_str(input: &str) -> Result<Self> {
-        let (desired_str, price_str, fee) = parse_parts(input)?;
+        Self::parse_with_trigger(input, None)
+    }
+
+    /// Parses a buy order that becomes active once the price of the desired asset
+    /// reaches the trigger price, given after the order as in `100penumbra@1.2gm^1.1gm`.
+    pub fn parse_triggered_str(input: &str, kind: TriggerKind) -> Result<Self> {
+        Self::parse_with_trigger(input, Some(kind))
+    }
+
+    fn parse_with_trigger(input: &str, kind: Option<TriggerKind>) -> Result<Self> {
+        let (desired_str, price_str, fee, trigger_str) = parse_parts(input)?;
 
         let desired_unit = extract_unit(desired_str)?;
         let desired = desired_str.parse::<Value>()?;
@@ -87,10 +202,23 @@ impl BuyOrder {
             asset_id: price.asset_id,
         };
 
+        let trigger = match (trigger_str, kind) {
+            (Some(trigger_str), Some(kind)) => Some(parse_trigger(
+                trigger_str,
+                &desired_unit,
+                price.asset_id,
+                kind.rising(false),
+            )?),
+            (None, None) => None,
+            (Some(_), None) => anyhow::bail!("unexpected trigger price in {}", input),
+            (None, Some(_)) => anyhow::bail!("missing trigger price in {}", input),
+        };
+
         Ok(BuyOrder {
             desired,
             offered,
             fee,
+            trigger,
         })
     }
 
@@ -135,18 +263,39 @@ impl BuyOrder {
         let price_str = self.price_str(cache)?;
         let desired_str = self.desired.format(&cache);
 
-        if self.fee != 0 {
-            Ok(format!("{}@{}/{}bps", desired_str, price_str, self.fee))
+        let mut order_str = if self.fee != 0 {
+            format!("{}@{}/{}bps", desired_str, price_str, self.fee)
         } else {
-            Ok(format!("{}@{}", desired_str, price_str))
+            format!("{}@{}", desired_str, price_str)
+        };
+
+        if let Some(trigger) = &self.trigger {
+            let desired_unit = cache
+                .get(&self.desired.asset_id)
+                .map(|d| d.default_unit())
+                .ok_or_else(|| anyhow!("unknown asset {}", self.desired.asset_id))?;
+            let trigger_str = format_trigger(trigger, &desired_unit, self.offered.asset_id, cache)?;
+            order_str = format!("{}^{}", order_str, trigger_str);
         }
+
+        Ok(order_str)
     }
 }
 
 impl SellOrder {
     /// Eventually we'll need to plumb in an asset::Cache so this isn't FromStr
     pub fn parse_str(input: &str) -> Result<Self> {
-        let (offered_str, price_str, fee) = parse_parts(input)?;
+        Self::parse_with_trigger(input, None)
+    }
+
+    /// Parses a sell order that becomes active once the price of the offered asset
+    /// reaches the trigger price, given after the order as in `100penumbra@1.2gm^1.1gm`.
+    pub fn parse_triggered_str(input: &str, kind: TriggerKind) -> Result<Self> {
+        Self::parse_with_trigger(input, Some(kind))
+    }
+
+    fn parse_with_trigger(input: &str, kind: Option<TriggerKind>) -> Result<Self> {
+        let (offered_str, price_str, fee, trigger_str) = parse_parts(input)?;
 
         let offered_unit = extract_unit(offered_str)?;
         let offered = offered_str.parse::<Value>()?;
@@ -172,10 +321,23 @@ impl SellOrder {
             asset_id: price.asset_id,
         };
 
+        let trigger = match (trigger_str, kind) {
+            (Some(trigger_str), Some(kind)) => Some(parse_trigger(
+                trigger_str,
+                &offered_unit,
+                price.asset_id,
+                kind.rising(true),
+            )?),
+            (None, None) => None,
+            (Some(_), None) => anyhow::bail!("unexpected trigger price in {}", input),
+            (None, Some(_)) => anyhow::bail!("missing trigger price in {}", input),
+        };
+
         Ok(SellOrder {
             offered,
             desired,
             fee,
+            trigger,
         })
     }
 
@@ -220,11 +382,22 @@ impl SellOrder {
         let price_str = self.price_str(cache)?;
         let offered_str = self.offered.format(&cache);
 
-        if self.fee != 0 {
-            Ok(format!("{}@{}/{}bps", offered_str, price_str, self.fee))
+        let mut order_str = if self.fee != 0 {
+            format!("{}@{}/{}bps", offered_str, price_str, self.fee)
         } else {
-            Ok(format!("{}@{}", offered_str, price_str))
+            format!("{}@{}", offered_str, price_str)
+        };
+
+        if let Some(trigger) = &self.trigger {
+            let offered_unit = cache
+                .get(&self.offered.asset_id)
+                .map(|d| d.default_unit())
+                .ok_or_else(|| anyhow!("unknown asset {}", self.offered.asset_id))?;
+            let trigger_str = format_trigger(trigger, &offered_unit, self.desired.asset_id, cache)?;
+            order_str = format!("{}^{}", order_str, trigger_str);
         }
+
+        Ok(order_str)
     }
 }
 
@@ -232,6 +405,7 @@ fn into_position_inner<R: CryptoRngCore>(
     offered: Value,
     desired: Value,
     fee: u32,
+    trigger: Option<Trigger>,
     rng: R,
 ) -> Position {
     // We want to compute `p` and `q` that interpolate between two reserves states:
@@ -241,7 +415,7 @@ fn into_position_inner<R: CryptoRngCore>(
     let p = desired.amount;
     let q = offered.amount;
 
-    Position::new(
+    let mut position = Position::new(
         rng,
         DirectedTradingPair {
             start: offered.asset_id,
@@ -254,18 +428,20 @@ fn into_position_inner<R: CryptoRngCore>(
             r1: offered.amount,
             r2: 0u64.into(),
         },
-    )
+    );
+    position.trigger = trigger;
+    position
 }
 
 impl BuyOrder {
     pub fn into_position<R: CryptoRngCore>(&self, rng: R) -> Position {
-        into_position_inner(self.offered, self.desired, self.fee, rng)
+        into_position_inner(self.offered, self.desired, self.fee, self.trigger, rng)
     }
 }
 
 impl SellOrder {
     pub fn into_position<R: CryptoRngCore>(&self, rng: R) -> Position {
-        into_position_inner(self.offered, self.desired, self.fee, rng)
+        into_position_inner(self.offered, self.desired, self.fee, self.trigger, rng)
     }
 }
 
@@ -311,6 +487,7 @@ impl Position {
             offered,
             desired,
             fee: self.phi.component.fee,
+            trigger: self.trigger,
         })
     }
 
@@ -322,6 +499,7 @@ impl Position {
             offered,
             desired,
             fee: self.phi.component.fee,
+            trigger: self.trigger,
         })
     }
 
@@ -373,6 +551,7 @@ mod tests {
                     asset_id: gn.id()
                 },
                 fee: 10,
+                trigger: None,
             }
         );
 
@@ -409,6 +588,7 @@ mod tests {
                     asset_id: gm.id()
                 },
                 fee: 10,
+                trigger: None,
             }
         );
 
@@ -422,4 +602,51 @@ mod tests {
         assert_eq!(sell_position_as_order_1, sell_order_1);
         assert_eq!(sell_position_formatted_1, sell_str_1);
     }
+
+    #[test]
+    fn parse_triggered_orders() {
+        let mut cache = asset::Cache::default();
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        cache.extend([gm.base(), gn.base()]);
+
+        // Converts a price of gm in gn to the canonical price of the pair.
+        let canonical_price = |gn_per_gm: U128x128| {
+            if TradingPair::new(gm.id(), gn.id()).asset_1() == gm.id() {
+                gn_per_gm
+            } else {
+                (U128x128::from(1u64) / gn_per_gm).unwrap()
+            }
+        };
+        let falls_to_1 = canonical_price(U128x128::from(1u64));
+        let rises_to_2 = canonical_price(U128x128::from(2u64));
+
+        // A stop-loss sell of gm triggers once the price of gm falls to 1.5gn.
+        let order_str = "10gm@1.4gn^1.5gn";
+        let stop = SellOrder::parse_triggered_str(order_str, TriggerKind::Stop).unwrap();
+        let trigger = stop.trigger.unwrap();
+        assert!(trigger.is_met(falls_to_1));
+        assert!(!trigger.is_met(rises_to_2));
+        assert_eq!(stop.format(&cache).unwrap(), order_str);
+
+        let position = stop.into_position(rand::thread_rng());
+        assert_eq!(position.trigger, Some(trigger));
+        assert_eq!(position.interpret_as_sell().unwrap(), stop);
+
+        // A take-profit sell triggers once it rises to it.
+        let take_profit =
+            SellOrder::parse_triggered_str(order_str, TriggerKind::TakeProfit).unwrap();
+        assert!(!take_profit.trigger.unwrap().is_met(falls_to_1));
+        assert!(take_profit.trigger.unwrap().is_met(rises_to_2));
+
+        // A stop buy of gm triggers on the same move as a take-profit sell.
+        let buy = BuyOrder::parse_triggered_str(order_str, TriggerKind::Stop).unwrap();
+        assert_eq!(buy.trigger, take_profit.trigger);
+        assert_eq!(buy.format(&cache).unwrap(), order_str);
+
+        // Triggers are only accepted by the triggered parsers, and must be in the price asset.
+        assert!(SellOrder::parse_str(order_str).is_err());
+        assert!(SellOrder::parse_triggered_str("10gm@1.4gn", TriggerKind::Stop).is_err());
+        assert!(SellOrder::parse_triggered_str("10gm@1.4gn^1.5gm", TriggerKind::Stop).is_err());
+    }
 }
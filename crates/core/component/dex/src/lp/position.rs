@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{DirectedTradingPair, TradingPair};
 
-use super::{trading_function::TradingFunction, Reserves};
+use super::{trading_function::TradingFunction, Reserves, Trigger};
 
 /// Reserve amounts for positions must be at most 80 bits wide.
 pub const MAX_RESERVE_AMOUNT: u128 = (1 << 80) - 1;
@@ -35,6 +35,9 @@ pub struct Position {
     /// Set to `true` if a position is a limit-order, meaning that it will be closed
     /// after being filled against.
     pub close_on_fill: bool,
+    /// If set, the position is held in the [`State::Pending`] state when opened, and only
+    /// becomes tradeable once the trigger is met by the execution price of its pair.
+    pub trigger: Option<Trigger>,
 }
 
 impl std::fmt::Debug for Position {
@@ -44,6 +47,7 @@ impl std::fmt::Debug for Position {
             .field("reserves", &self.reserves)
             .field("phi", &self.phi)
             .field("nonce", &hex::encode(&self.nonce))
+            .field("trigger", &self.trigger)
             .finish()
     }
 }
@@ -96,6 +100,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            trigger: None,
         }
     }
 
@@ -111,6 +116,11 @@ impl Position {
         state.update(&self.phi.component.fee.to_le_bytes());
         state.update(&self.phi.component.p.to_le_bytes());
         state.update(&self.phi.component.q.to_le_bytes());
+        // Only commit to the trigger when there is one, so that the IDs of
+        // untriggered positions are unchanged.
+        if let Some(trigger) = &self.trigger {
+            state.update(&trigger.to_bytes());
+        }
 
         let hash = state.finalize();
         let mut bytes = [0; 32];
@@ -212,6 +222,10 @@ pub enum State {
     /// Any retroactive rewards have been claimed. The position is now an inert,
     /// historical artefact.
     Claimed,
+    /// The position has been opened with a trigger that has not been met yet.
+    /// It has reserves, but cannot be traded against until the trigger is met
+    /// and it becomes opened. It can be closed at any time.
+    Pending,
 }
 
 impl std::fmt::Display for State {
@@ -221,6 +235,7 @@ impl std::fmt::Display for State {
             State::Closed => write!(f, "closed"),
             State::Withdrawn => write!(f, "withdrawn"),
             State::Claimed => write!(f, "claimed"),
+            State::Pending => write!(f, "pending"),
         }
     }
 }
@@ -234,6 +249,7 @@ impl std::str::FromStr for State {
             "closed" => Ok(State::Closed),
             "withdrawn" => Ok(State::Withdrawn),
             "claimed" => Ok(State::Claimed),
+            "pending" => Ok(State::Pending),
             _ => Err(anyhow::anyhow!("unknown position state")),
         }
     }
@@ -304,6 +320,7 @@ impl From<State> for pb::PositionState {
                 State::Closed => pb::position_state::PositionStateEnum::Closed,
                 State::Withdrawn => pb::position_state::PositionStateEnum::Withdrawn,
                 State::Claimed => pb::position_state::PositionStateEnum::Claimed,
+                State::Pending => pb::position_state::PositionStateEnum::Pending,
             } as i32,
         }
     }
@@ -322,6 +339,7 @@ impl TryFrom<pb::PositionState> for State {
             pb::position_state::PositionStateEnum::Closed => Ok(State::Closed),
             pb::position_state::PositionStateEnum::Withdrawn => Ok(State::Withdrawn),
             pb::position_state::PositionStateEnum::Claimed => Ok(State::Claimed),
+            pb::position_state::PositionStateEnum::Pending => Ok(State::Pending),
             pb::position_state::PositionStateEnum::Unspecified => {
                 // maps to a missing position state, or one that's set to zero.
                 Err(anyhow!("unspecified position state!"))
@@ -338,6 +356,7 @@ impl From<Position> for pb::Position {
             phi: Some(p.phi.into()),
             nonce: p.nonce.to_vec(),
             close_on_fill: p.close_on_fill,
+            trigger: p.trigger.map(Into::into),
         }
     }
 }
@@ -364,6 +383,7 @@ impl TryFrom<pb::Position> for Position {
                 .try_into()
                 .context("expected 32-byte nonce")?,
             close_on_fill: p.close_on_fill,
+            trigger: p.trigger.map(TryInto::try_into).transpose()?,
         })
     }
 }
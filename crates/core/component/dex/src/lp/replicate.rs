@@ -0,0 +1,27 @@
+//! Ladders of positions approximating liquidity curves around a price.
+
+pub mod linear;
+pub mod range;
+pub mod stableswap;
+pub mod ticks;
+
+mod math_utils {
+    /// Sample `num_points` evenly spaced from `lower` to `upper`, inclusive.
+    pub(super) fn sample_linear(lower: f64, upper: f64, num_points: usize) -> Vec<f64> {
+        if num_points == 1 {
+            return vec![lower];
+        }
+        let step = (upper - lower) / ((num_points - 1) as f64);
+
+        (0..num_points).map(|i| lower + (i as f64) * step).collect()
+    }
+
+    /// Sample `num_points + 1` boundaries spaced geometrically from `lower` to `upper`, inclusive.
+    pub(super) fn sample_geometric(lower: f64, upper: f64, num_points: usize) -> Vec<f64> {
+        let ratio = (upper / lower).powf(1.0 / (num_points as f64));
+
+        (0..=num_points)
+            .map(|i| lower * ratio.powi(i as i32))
+            .collect()
+    }
+}
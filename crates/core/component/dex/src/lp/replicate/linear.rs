@@ -0,0 +1,10 @@
+use super::{math_utils, ticks::Tick};
+
+/// Builds a ladder of `num_positions` equally sized ticks, evenly spaced from `lower_price`
+/// to `upper_price`.
+pub fn ticks(lower_price: f64, upper_price: f64, num_positions: usize) -> Vec<Tick> {
+    math_utils::sample_linear(lower_price, upper_price, num_positions)
+        .into_iter()
+        .map(|price| Tick { price, weight: 1.0 })
+        .collect()
+}
@@ -0,0 +1,32 @@
+use super::{math_utils, ticks::Tick};
+
+/// Approximates a concentrated liquidity position over the range from `lower_price` to
+/// `upper_price` with `num_positions` ticks.
+///
+/// The range is split into segments spaced geometrically, and each tick provides the
+/// liquidity that a constant product curve restricted to the range holds over its segment:
+/// `L * (1/sqrt(a) - 1/sqrt(b))` of the start asset above the current price, and
+/// `L * (sqrt(b) - sqrt(a))` of the end asset below it, for a segment from `a` to `b`.
+pub fn ticks(
+    lower_price: f64,
+    upper_price: f64,
+    current_price: f64,
+    num_positions: usize,
+) -> Vec<Tick> {
+    let boundaries = math_utils::sample_geometric(lower_price, upper_price, num_positions);
+
+    boundaries
+        .windows(2)
+        .map(|segment| {
+            let (a, b) = (segment[0], segment[1]);
+            let price = f64::sqrt(a * b);
+            let weight = if price >= current_price {
+                1.0 / a.sqrt() - 1.0 / b.sqrt()
+            } else {
+                // Tick weights are measured in units of the start asset.
+                (b.sqrt() - a.sqrt()) / price
+            };
+            Tick { price, weight }
+        })
+        .collect()
+}
@@ -0,0 +1,23 @@
+use super::{math_utils, ticks::Tick};
+
+/// Builds `num_positions` ticks evenly spaced within `width` (a fraction of the peg) of the
+/// `peg_price`, concentrating liquidity around the peg like a stable-swap curve.
+///
+/// The size of a tick at a relative distance `d` from the peg is `1 / (1 + amplification * d)^2`,
+/// so that a higher amplification keeps more of the liquidity close to the peg.
+pub fn ticks(peg_price: f64, width: f64, amplification: f64, num_positions: usize) -> Vec<Tick> {
+    math_utils::sample_linear(
+        peg_price * (1.0 - width),
+        peg_price * (1.0 + width),
+        num_positions,
+    )
+    .into_iter()
+    .map(|price| {
+        let distance = (price / peg_price - 1.0).abs();
+        Tick {
+            price,
+            weight: (1.0 + amplification * distance).powi(-2),
+        }
+    })
+    .collect()
+}
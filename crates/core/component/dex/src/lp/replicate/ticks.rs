@@ -0,0 +1,123 @@
+use anyhow::{anyhow, Context};
+use penumbra_asset::Value;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use rand_core::CryptoRngCore;
+
+use crate::{
+    lp::{position::Position, Reserves},
+    DirectedUnitPair,
+};
+
+/// A price level of a replicated liquidity curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+    /// The price of the tick, in units of the end asset per unit of the start asset.
+    pub price: f64,
+    /// The relative size of the tick, in units of the start asset.
+    pub weight: f64,
+}
+
+/// Builds the positions providing liquidity at each tick, split evenly across the fee tiers.
+///
+/// Ticks at or above the current price sell the start asset, and ticks below it sell the end
+/// asset. The reserves of each tick are proportional to its weight, scaled so that the ticks
+/// on the side of the current price selling the `input` asset use up all of the `input`.
+#[tracing::instrument(name = "replicate_ticks", skip(rng, ticks))]
+pub fn positions_from_ticks<R: CryptoRngCore>(
+    mut rng: R,
+    pair: &DirectedUnitPair,
+    input: &Value,
+    current_price: f64,
+    ticks: &[Tick],
+    fee_tiers: &[u32],
+) -> anyhow::Result<Vec<Position>> {
+    if fee_tiers.is_empty() {
+        anyhow::bail!("at least one fee tier is required");
+    }
+
+    // Work in display units, and only scale into denom units when building the positions.
+    let (input_unit, input_is_start) = if input.asset_id == pair.start.id() {
+        (pair.start.unit_amount(), true)
+    } else if input.asset_id == pair.end.id() {
+        (pair.end.unit_amount(), false)
+    } else {
+        anyhow::bail!("the supplied liquidity must be on the pair")
+    };
+    let input_amount: f64 = U128x128::ratio(input.amount.value(), input_unit.value())
+        .context("denom unit is not 0")?
+        .into();
+
+    // The total size of the ticks selling the input asset, in units of the input asset.
+    let input_side_size: f64 = if input_is_start {
+        ticks
+            .iter()
+            .filter(|tick| tick.price >= current_price)
+            .map(|tick| tick.weight)
+            .sum()
+    } else {
+        ticks
+            .iter()
+            .filter(|tick| tick.price < current_price)
+            .map(|tick| tick.weight * tick.price)
+            .sum()
+    };
+    if input_side_size <= 0.0 {
+        anyhow::bail!(
+            "none of the positions would hold {}, the curve must extend to the {} side of the current price",
+            if input_is_start { &pair.start } else { &pair.end },
+            if input_is_start { "upper" } else { "lower" },
+        );
+    }
+    let scale = input_amount / input_side_size / fee_tiers.len() as f64;
+    tracing::debug!(input_amount, input_side_size, scale, "computed tick scale");
+
+    let unit_start: U128x128 = pair.start.unit_amount().into();
+    let unit_end: U128x128 = pair.end.unit_amount().into();
+    let to_amount = |display_amount: f64, unit: U128x128| -> anyhow::Result<Amount> {
+        let approx: U128x128 = display_amount
+            .try_into()
+            .map_err(|_| anyhow!("invalid amount {display_amount}"))?;
+        let scaled = (approx * unit).context("no overflow when scaling amount")?;
+        Ok(scaled.round_down().try_into()?)
+    };
+
+    let mut positions = Vec::with_capacity(ticks.len() * fee_tiers.len());
+    for tick in ticks {
+        // The trading function's coefficients encode a price of `p / q` units of the end
+        // asset per unit of the start asset.
+        let p = to_amount(tick.price, unit_end)?;
+        if p == Amount::zero() {
+            anyhow::bail!("the price {} is too small to be represented", tick.price);
+        }
+        let q = pair.start.unit_amount();
+
+        let reserves = if tick.price >= current_price {
+            Reserves {
+                r1: to_amount(tick.weight * scale, unit_start)?,
+                r2: Amount::zero(),
+            }
+        } else {
+            Reserves {
+                r1: Amount::zero(),
+                r2: to_amount(tick.weight * tick.price * scale, unit_end)?,
+            }
+        };
+        if reserves.r1 == Amount::zero() && reserves.r2 == Amount::zero() {
+            tracing::debug!(?tick, "skipping tick with no reserves");
+            continue;
+        }
+
+        for &fee_bps in fee_tiers {
+            positions.push(Position::new(
+                &mut rng,
+                pair.into_directed_trading_pair(),
+                fee_bps,
+                p,
+                q,
+                reserves.clone(),
+            ));
+        }
+    }
+
+    Ok(positions)
+}
@@ -0,0 +1,85 @@
+use penumbra_num::fixpoint::U128x128;
+use penumbra_proto::{penumbra::core::component::dex::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+
+/// A condition on the execution price of a position's trading pair, which activates a
+/// [`State::Pending`](super::position::State::Pending) position once it is met.
+///
+/// The trigger is committed to in the position's [`Id`](super::position::Id), so that it
+/// cannot change after the position is opened, and every node activates the position in
+/// the same block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionTrigger", into = "pb::PositionTrigger")]
+pub struct Trigger {
+    /// The trigger price, in units of asset 2 per unit of asset 1 of the position's pair.
+    pub price: U128x128,
+    /// Which execution prices meet the trigger.
+    pub direction: Direction,
+}
+
+/// Which side of the trigger price an execution price must be on to meet a [`Trigger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The trigger is met by execution prices at or above the trigger price.
+    Above,
+    /// The trigger is met by execution prices at or below the trigger price.
+    Below,
+}
+
+impl Trigger {
+    /// Returns whether an execution at `price`, in units of asset 2 per unit of asset 1,
+    /// meets the trigger.
+    pub fn is_met(&self, price: U128x128) -> bool {
+        match self.direction {
+            Direction::Above => price >= self.price,
+            Direction::Below => price <= self.price,
+        }
+    }
+
+    /// The encoding of the trigger committed to in the position ID.
+    pub fn to_bytes(&self) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        bytes[0..32].copy_from_slice(&self.price.to_bytes());
+        bytes[32] = match self.direction {
+            Direction::Above => 1,
+            Direction::Below => 0,
+        };
+        bytes
+    }
+}
+
+impl TypeUrl for Trigger {
+    const TYPE_URL: &'static str = "/penumbra.core.dex.v1alpha1.PositionTrigger";
+}
+
+impl DomainType for Trigger {
+    type Proto = pb::PositionTrigger;
+}
+
+impl From<Trigger> for pb::PositionTrigger {
+    fn from(trigger: Trigger) -> Self {
+        Self {
+            price: trigger.price.into(),
+            above: trigger.direction == Direction::Above,
+        }
+    }
+}
+
+impl TryFrom<pb::PositionTrigger> for Trigger {
+    type Error = anyhow::Error;
+    fn try_from(trigger: pb::PositionTrigger) -> Result<Self, Self::Error> {
+        let price: U128x128 = trigger.price.as_slice().try_into()?;
+        if price == U128x128::default() {
+            anyhow::bail!("trigger price must be nonzero");
+        }
+
+        Ok(Self {
+            price,
+            direction: if trigger.above {
+                Direction::Above
+            } else {
+                Direction::Below
+            },
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{anyhow, Result};
+use penumbra_num::fixpoint::U128x128;
+use penumbra_proto::{penumbra::core::component::dex::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+
+use crate::BatchSwapOutputData;
+
+/// A cumulative price accumulator for a [`TradingPair`](crate::TradingPair), used to compute
+/// time-weighted average prices.
+///
+/// Prices are quoted in units of asset 2 per unit of asset 1. A price observed at the end of
+/// the block at `height` is in effect from `height` onward, so that a price can only be moved
+/// by trades executed in earlier blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PriceAccumulator", into = "pb::PriceAccumulator")]
+pub struct PriceAccumulator {
+    /// The height of the last price observation.
+    pub height: u64,
+    /// The last observed price, in effect from `height` onward.
+    pub price: U128x128,
+    /// The sum of the prices in effect at each height before `height`.
+    pub cumulative_price: U128x128,
+}
+
+impl PriceAccumulator {
+    /// The largest price recorded by the accumulator.
+    ///
+    /// Observed prices are capped at `2^64`, so that the cumulative price cannot overflow
+    /// before the height does.
+    pub fn max_price() -> U128x128 {
+        U128x128::from(u64::MAX)
+    }
+
+    /// Starts an accumulator with a first price observation at `height`.
+    pub fn new(height: u64, price: U128x128) -> Self {
+        Self {
+            height,
+            price: price.min(Self::max_price()),
+            cumulative_price: U128x128::default(),
+        }
+    }
+
+    /// Returns the price of a batch swap, as the ratio of the amounts of asset 2 and asset 1
+    /// it traded in either direction, or `None` if it did not trade anything.
+    pub fn batch_price(output_data: &BatchSwapOutputData) -> Option<U128x128> {
+        let traded_1 = (output_data.delta_1 - output_data.unfilled_1) + output_data.lambda_1;
+        let traded_2 = (output_data.delta_2 - output_data.unfilled_2) + output_data.lambda_2;
+        if traded_1 == 0u64.into() || traded_2 == 0u64.into() {
+            return None;
+        }
+        U128x128::ratio(traded_2, traded_1).ok()
+    }
+
+    /// Returns the cumulative price at `height`, which must not precede the last observation.
+    pub fn cumulative_price_at(&self, height: u64) -> Result<U128x128> {
+        let elapsed = height.checked_sub(self.height).ok_or_else(|| {
+            anyhow!(
+                "height {} precedes the last price observation at {}",
+                height,
+                self.height
+            )
+        })?;
+        let accrued = self.price.checked_mul(&U128x128::from(elapsed))?;
+        Ok(self.cumulative_price.checked_add(&accrued)?)
+    }
+
+    /// Records a new price observation at `height`, which must not precede the last observation.
+    pub fn observe(&self, height: u64, price: U128x128) -> Result<Self> {
+        Ok(Self {
+            height,
+            price: price.min(Self::max_price()),
+            cumulative_price: self.cumulative_price_at(height)?,
+        })
+    }
+}
+
+impl TypeUrl for PriceAccumulator {
+    const TYPE_URL: &'static str = "/penumbra.core.dex.v1alpha1.PriceAccumulator";
+}
+
+impl DomainType for PriceAccumulator {
+    type Proto = pb::PriceAccumulator;
+}
+
+impl From<PriceAccumulator> for pb::PriceAccumulator {
+    fn from(pa: PriceAccumulator) -> Self {
+        Self {
+            height: pa.height,
+            price: pa.price.into(),
+            cumulative_price: pa.cumulative_price.into(),
+        }
+    }
+}
+
+impl TryFrom<pb::PriceAccumulator> for PriceAccumulator {
+    type Error = anyhow::Error;
+    fn try_from(pa: pb::PriceAccumulator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: pa.height,
+            price: pa.price.as_slice().try_into()?,
+            cumulative_price: pa.cumulative_price.as_slice().try_into()?,
+        })
+    }
+}
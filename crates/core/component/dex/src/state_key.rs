@@ -43,10 +43,23 @@ pub fn arb_executions() -> &'static str {
     "dex/arb_execution/"
 }
 
+/// The latest cumulative price accumulator of a trading pair.
+pub fn price_accumulator(trading_pair: &TradingPair) -> String {
+    format!(
+        "dex/price_accumulator/{}/{}",
+        &trading_pair.asset_1(),
+        &trading_pair.asset_2()
+    )
+}
+
 pub fn swap_flows() -> &'static str {
     "dex/swap_flows"
 }
 
+pub fn limit_swaps() -> &'static str {
+    "dex/limit_swaps"
+}
+
 pub fn pending_position_closures() -> &'static str {
     "dex/pending_position_closures"
 }
@@ -102,6 +115,96 @@ pub(crate) mod internal {
         }
     }
 
+    /// Per-block OHLCV data of each directed trading pair, ordered by height.
+    pub mod candlesticks {
+        use super::*;
+
+        pub fn prefix(pair: &DirectedTradingPair) -> [u8; 71] {
+            let mut key = [0u8; 71];
+            key[0..7].copy_from_slice(b"dex/cs/");
+            key[7..7 + 32].copy_from_slice(&pair.start.to_bytes());
+            key[7 + 32..7 + 32 + 32].copy_from_slice(&pair.end.to_bytes());
+            key
+        }
+
+        pub fn key(pair: &DirectedTradingPair, height: u64) -> [u8; 79] {
+            let mut key = [0u8; 79];
+            key[0..71].copy_from_slice(&prefix(pair));
+            key[71..79].copy_from_slice(&height.to_be_bytes());
+            key
+        }
+    }
+
+    /// Every price observation of each trading pair, ordered by descending height.
+    pub mod price_accumulators {
+        use super::*;
+
+        pub fn prefix(pair: &TradingPair) -> [u8; 71] {
+            let mut key = [0u8; 71];
+            key[0..7].copy_from_slice(b"dex/pa/");
+            key[7..7 + 32].copy_from_slice(&pair.asset_1().to_bytes());
+            key[7 + 32..7 + 32 + 32].copy_from_slice(&pair.asset_2().to_bytes());
+            key
+        }
+
+        /// The height is stored inverted, so that a forward range query starting at a height
+        /// finds the latest observation at or before it first.
+        pub fn inverted_height(height: u64) -> [u8; 8] {
+            (u64::MAX - height).to_be_bytes()
+        }
+
+        pub fn key(pair: &TradingPair, height: u64) -> [u8; 79] {
+            let mut key = [0u8; 79];
+            key[0..71].copy_from_slice(&prefix(pair));
+            key[71..79].copy_from_slice(&inverted_height(height));
+            key
+        }
+    }
+
+    /// Positions waiting for their trigger to be met, by trading pair, trigger direction and
+    /// trigger price.
+    pub mod pending_positions {
+        use super::*;
+        use crate::lp::{Trigger, TriggerDirection as Direction};
+        use penumbra_num::fixpoint::U128x128;
+
+        pub fn prefix(pair: &TradingPair) -> [u8; 71] {
+            let mut key = [0u8; 71];
+            key[0..7].copy_from_slice(b"dex/pp/");
+            key[7..7 + 32].copy_from_slice(&pair.asset_1().to_bytes());
+            key[7 + 32..7 + 32 + 32].copy_from_slice(&pair.asset_2().to_bytes());
+            key
+        }
+
+        pub fn direction_prefix(pair: &TradingPair, direction: Direction) -> [u8; 72] {
+            let mut key = [0u8; 72];
+            key[0..71].copy_from_slice(&prefix(pair));
+            key[71] = match direction {
+                Direction::Above => 1,
+                Direction::Below => 0,
+            };
+            key
+        }
+
+        /// The trigger price is stored inverted for triggers met by prices above it, so that
+        /// the triggers met by a price are always a forward range query starting at the price.
+        pub fn price(price: U128x128, direction: Direction) -> [u8; 32] {
+            let bytes = price.to_bytes();
+            match direction {
+                Direction::Above => bytes.map(|byte| !byte),
+                Direction::Below => bytes,
+            }
+        }
+
+        pub fn key(pair: &TradingPair, trigger: &Trigger, id: &position::Id) -> [u8; 136] {
+            let mut key = [0u8; 136];
+            key[0..72].copy_from_slice(&direction_prefix(pair, trigger.direction));
+            key[72..104].copy_from_slice(&price(trigger.price, trigger.direction));
+            key[104..136].copy_from_slice(&id.0);
+            key
+        }
+    }
+
     pub mod price_index {
         use super::*;
 
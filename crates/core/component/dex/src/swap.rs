@@ -1,6 +1,7 @@
 use ark_ff::PrimeField;
 use decaf377::Fq;
 use once_cell::sync::Lazy;
+use penumbra_keys::PayloadKey;
 
 mod action;
 mod ciphertext;
@@ -19,9 +20,26 @@ pub use plan::SwapPlan;
 pub use view::SwapView;
 
 // Swap ciphertext byte length.
-pub const SWAP_CIPHERTEXT_BYTES: usize = 272;
+pub const SWAP_CIPHERTEXT_BYTES: usize = 288;
 // Swap plaintext byte length.
-pub const SWAP_LEN_BYTES: usize = 256;
+pub const SWAP_LEN_BYTES: usize = 272;
+// Swap ciphertext byte length in the legacy layout, used for swaps without a minimum output.
+pub const SWAP_CIPHERTEXT_BYTES_V0: usize = 272;
+// Swap plaintext byte length in the legacy layout, used for swaps without a minimum output.
+pub const SWAP_LEN_BYTES_V0: usize = 256;
 
 pub static DOMAIN_SEPARATOR: Lazy<Fq> =
     Lazy::new(|| Fq::from_le_bytes_mod_order(blake2b_simd::blake2b(b"penumbra.swap").as_bytes()));
+
+/// The payload key for swaps made by the chain itself on behalf of their claim address, such as
+/// swaps of inbound ICS-20 transfers. These swaps are public anyway, so the key is too, which
+/// lets the owner of the claim address find them while scanning.
+pub static CHAIN_SWAP_PAYLOAD_KEY: Lazy<PayloadKey> = Lazy::new(|| {
+    let key: [u8; 32] = blake2b_simd::Params::default()
+        .hash_length(32)
+        .hash(b"penumbra.swap.chain")
+        .as_bytes()
+        .try_into()
+        .expect("hash is 32 bytes");
+    PayloadKey::from(key)
+});
@@ -83,6 +83,7 @@ pub struct Body {
     pub delta_2_i: Amount,
     pub fee_commitment: balance::Commitment,
     pub payload: SwapPayload,
+    pub min_output: Amount,
 }
 
 impl TypeUrl for Body {
@@ -101,6 +102,7 @@ impl From<Body> for pb::SwapBody {
             delta_2_i: Some(s.delta_2_i.into()),
             fee_commitment: Some(s.fee_commitment.into()),
             payload: Some(s.payload.into()),
+            min_output: Some(s.min_output.into()),
         }
     }
 }
@@ -131,6 +133,12 @@ impl TryFrom<pb::SwapBody> for Body {
                 .payload
                 .ok_or_else(|| anyhow::anyhow!("missing payload"))?
                 .try_into()?,
+            // Swaps without a minimum output are filled at any price.
+            min_output: s
+                .min_output
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -3,7 +3,7 @@ use penumbra_keys::FullViewingKey;
 use penumbra_proto::penumbra::core::component::dex::v1alpha1 as pb;
 use serde::{Deserialize, Serialize};
 
-use super::{SwapCiphertext, SwapPlaintext};
+use super::{SwapCiphertext, SwapPlaintext, CHAIN_SWAP_PAYLOAD_KEY};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(try_from = "pb::SwapPayload", into = "pb::SwapPayload")]
@@ -14,11 +14,23 @@ pub struct SwapPayload {
 
 impl SwapPayload {
     pub fn trial_decrypt(&self, fvk: &FullViewingKey) -> Option<SwapPlaintext> {
-        // Try to decrypt the swap ciphertext. If it doesn't decrypt, it wasn't meant for us.
-        let swap = self
-            .encrypted_swap
-            .decrypt(fvk.outgoing(), self.commitment)
-            .ok()?;
+        // Try to decrypt the swap ciphertext as one of our own swaps. If it doesn't decrypt,
+        // it may still be a swap made by the chain on behalf of one of our addresses.
+        let swap = match self.encrypted_swap.decrypt(fvk.outgoing(), self.commitment) {
+            Ok(swap) => swap,
+            Err(_) => {
+                // Anyone can decrypt the swaps made by the chain, so those whose claim address
+                // isn't ours aren't meant for us, and are skipped without logging them.
+                let swap = self
+                    .encrypted_swap
+                    .decrypt_with_payload_key(&CHAIN_SWAP_PAYLOAD_KEY, self.commitment)
+                    .ok()?;
+                if !fvk.incoming().views_address(&swap.claim_address) {
+                    return None;
+                }
+                swap
+            }
+        };
         tracing::debug!(swap_commitment = ?self.commitment, ?swap, "found swap while scanning");
 
         // Before returning, though, we want to perform integrity checks on the
@@ -52,7 +64,7 @@ impl From<SwapPayload> for pb::SwapPayload {
     fn from(msg: SwapPayload) -> Self {
         pb::SwapPayload {
             commitment: Some(msg.commitment.into()),
-            encrypted_swap: msg.encrypted_swap.0.to_vec(),
+            encrypted_swap: msg.encrypted_swap.0,
         }
     }
 }
@@ -65,11 +77,8 @@ impl TryFrom<pb::SwapPayload> for SwapPayload {
             .commitment
             .ok_or_else(|| anyhow!("missing commitment"))?
             .try_into()?;
-        let encrypted_swap = SwapCiphertext(
-            msg.encrypted_swap
-                .try_into()
-                .map_err(|_| anyhow!("expected correct length swap ciphertext"))?,
-        );
+        let encrypted_swap = SwapCiphertext::try_from(&msg.encrypted_swap[..])
+            .map_err(|_| anyhow!("expected correct length swap ciphertext"))?;
         Ok(Self {
             commitment,
             encrypted_swap,
@@ -12,7 +12,7 @@ use penumbra_proto::{
     TypeUrl,
 };
 use penumbra_tct::StateCommitment;
-use poseidon377::{hash_1, hash_4, hash_7};
+use poseidon377::{hash_1, hash_4, hash_5, hash_7};
 use rand_core::{CryptoRng, RngCore};
 
 use decaf377_ka as ka;
@@ -24,7 +24,7 @@ use penumbra_tct::r1cs::StateCommitmentVar;
 
 use crate::{BatchSwapOutputData, TradingPair, TradingPairVar};
 
-use super::{SwapCiphertext, SwapPayload, DOMAIN_SEPARATOR, SWAP_CIPHERTEXT_BYTES, SWAP_LEN_BYTES};
+use super::{SwapCiphertext, SwapPayload, DOMAIN_SEPARATOR, SWAP_LEN_BYTES, SWAP_LEN_BYTES_V0};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SwapPlaintext {
@@ -40,6 +40,8 @@ pub struct SwapPlaintext {
     pub claim_address: Address,
     // Swap rseed
     pub rseed: Rseed,
+    // Minimum output of the other asset for the swap to be filled, or zero for no limit
+    pub min_output: Amount,
 }
 
 pub static OUTPUT_1_BLINDING_DOMAIN_SEPARATOR: Lazy<Fq> = Lazy::new(|| {
@@ -68,7 +70,7 @@ impl SwapPlaintext {
         let (output_1_rseed, output_2_rseed) = self.output_rseeds();
 
         let (lambda_1_i, lambda_2_i) =
-            batch_data.pro_rata_outputs((self.delta_1_i, self.delta_2_i));
+            batch_data.swap_outputs((self.delta_1_i, self.delta_2_i), self.min_output);
 
         let output_1_note = Note::from_parts(
             self.claim_address,
@@ -95,8 +97,33 @@ impl SwapPlaintext {
 
     // Constructs the unique asset ID for a swap as a poseidon hash of the input data for the swap.
     //
+    // Swaps without a minimum output are committed to as they were before minimum outputs were
+    // introduced, so swaps made before then can still be claimed.
+    //
     // https://protocol.penumbra.zone/main/zswap/swap.html#swap-actions
     pub fn swap_commitment(&self) -> StateCommitment {
+        let inner_hash = if self.min_output == Amount::zero() {
+            hash_4(
+                &DOMAIN_SEPARATOR,
+                (
+                    self.trading_pair.asset_1().0,
+                    self.trading_pair.asset_2().0,
+                    self.delta_1_i.into(),
+                    self.delta_2_i.into(),
+                ),
+            )
+        } else {
+            hash_5(
+                &DOMAIN_SEPARATOR,
+                (
+                    self.trading_pair.asset_1().0,
+                    self.trading_pair.asset_2().0,
+                    self.delta_1_i.into(),
+                    self.delta_2_i.into(),
+                    self.min_output.into(),
+                ),
+            )
+        };
         let inner = hash_7(
             &DOMAIN_SEPARATOR,
             (
@@ -108,15 +135,7 @@ impl SwapPlaintext {
                     .vartime_compress_to_field(),
                 *self.claim_address.transmission_key_s(),
                 Fq::from_le_bytes_mod_order(&self.claim_address.clue_key().0[..]),
-                hash_4(
-                    &DOMAIN_SEPARATOR,
-                    (
-                        self.trading_pair.asset_1().0,
-                        self.trading_pair.asset_2().0,
-                        self.delta_1_i.into(),
-                        self.delta_2_i.into(),
-                    ),
-                ),
+                inner_hash,
             ),
         );
 
@@ -132,14 +151,21 @@ impl SwapPlaintext {
     }
 
     pub fn encrypt(&self, ovk: &OutgoingViewingKey) -> SwapPayload {
+        let key = PayloadKey::derive_swap(ovk, self.swap_commitment());
+        self.encrypt_with_payload_key(&key)
+    }
+
+    pub fn encrypt_with_payload_key(&self, key: &PayloadKey) -> SwapPayload {
         let commitment = self.swap_commitment();
-        let key = PayloadKey::derive_swap(ovk, commitment);
         let swap_plaintext: [u8; SWAP_LEN_BYTES] = self.into();
-        let encryption_result = key.encrypt_swap(swap_plaintext.to_vec(), commitment);
-
-        let ciphertext: [u8; SWAP_CIPHERTEXT_BYTES] = encryption_result
-            .try_into()
-            .expect("swap encryption result fits in ciphertext len");
+        // Swaps without a minimum output are encoded in the legacy layout, which omits it, so
+        // they are encrypted exactly as before minimum outputs were introduced.
+        let swap_plaintext = if self.min_output == Amount::zero() {
+            &swap_plaintext[..SWAP_LEN_BYTES_V0]
+        } else {
+            &swap_plaintext[..]
+        };
+        let ciphertext = key.encrypt_swap(swap_plaintext.to_vec(), commitment);
 
         SwapPayload {
             encrypted_swap: SwapCiphertext(ciphertext),
@@ -164,8 +190,15 @@ impl SwapPlaintext {
             claim_fee,
             claim_address,
             rseed,
+            min_output: Amount::zero(),
         }
     }
+
+    /// Require the swap to output at least `min_output` of the other asset,
+    /// refunding its inputs at claim time otherwise.
+    pub fn with_min_output(self, min_output: Amount) -> Self {
+        Self { min_output, ..self }
+    }
 }
 
 pub struct SwapPlaintextVar {
@@ -175,6 +208,7 @@ pub struct SwapPlaintextVar {
     pub delta_2_i: AmountVar,
     pub claim_address: AddressVar,
     pub rseed: FqVar,
+    pub min_output: AmountVar,
 }
 
 impl SwapPlaintextVar {
@@ -212,6 +246,20 @@ impl SwapPlaintextVar {
                 self.delta_2_i.amount.clone(),
             ),
         )?;
+        let inner_hash5 = poseidon377::r1cs::hash_5(
+            cs.clone(),
+            &domain_sep,
+            (
+                self.trading_pair.asset_1.asset_id.clone(),
+                self.trading_pair.asset_2.asset_id.clone(),
+                self.delta_1_i.amount.clone(),
+                self.delta_2_i.amount.clone(),
+                self.min_output.amount.clone(),
+            ),
+        )?;
+        // Swaps without a minimum output are committed to in the legacy form.
+        let no_min_output = self.min_output.amount.is_eq(&FqVar::zero())?;
+        let inner_hash = FqVar::conditionally_select(&no_min_output, &inner_hash4, &inner_hash5)?;
 
         let inner = poseidon377::r1cs::hash_7(
             cs,
@@ -223,7 +271,7 @@ impl SwapPlaintextVar {
                 compressed_g_d,
                 self.claim_address.transmission_key().compress_to_field()?,
                 self.claim_address.clue_key(),
-                inner_hash4,
+                inner_hash,
             ),
         )?;
 
@@ -257,7 +305,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
         let claim_address =
             AddressVar::new_variable(cs.clone(), || Ok(swap_plaintext.claim_address), mode)?;
         let rseed = FqVar::new_variable(
-            cs,
+            cs.clone(),
             || {
                 Ok(Fq::from_le_bytes_mod_order(
                     &swap_plaintext.rseed.to_bytes()[..],
@@ -265,6 +313,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
             },
             mode,
         )?;
+        let min_output = AmountVar::new_variable(cs, || Ok(swap_plaintext.min_output), mode)?;
         Ok(Self {
             claim_fee,
             delta_1_i,
@@ -272,6 +321,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
             delta_2_i,
             claim_address,
             rseed,
+            min_output,
         })
     }
 }
@@ -310,6 +360,12 @@ impl TryFrom<pb::SwapPlaintext> for SwapPlaintext {
                 .ok_or_else(|| anyhow::anyhow!("missing trading pair in SwapPlaintext"))?
                 .try_into()?,
             rseed: Rseed(plaintext.rseed.as_slice().try_into()?),
+            // Swaps without a minimum output are filled at any price.
+            min_output: plaintext
+                .min_output
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -323,6 +379,7 @@ impl From<SwapPlaintext> for pb::SwapPlaintext {
             claim_address: Some(plaintext.claim_address.into()),
             trading_pair: Some(plaintext.trading_pair.into()),
             rseed: plaintext.rseed.to_bytes().to_vec(),
+            min_output: Some(plaintext.min_output.into()),
         }
     }
 }
@@ -338,6 +395,7 @@ impl From<&SwapPlaintext> for [u8; SWAP_LEN_BYTES] {
         let pb_address = pb_keys::Address::from(swap.claim_address);
         bytes[144..224].copy_from_slice(&pb_address.inner);
         bytes[224..256].copy_from_slice(&swap.rseed.to_bytes());
+        bytes[256..272].copy_from_slice(&swap.min_output.to_le_bytes());
         bytes
     }
 }
@@ -352,7 +410,7 @@ impl TryFrom<&[u8]> for SwapPlaintext {
     type Error = Error;
 
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        if bytes.len() != SWAP_LEN_BYTES {
+        if bytes.len() != SWAP_LEN_BYTES && bytes.len() != SWAP_LEN_BYTES_V0 {
             anyhow::bail!("incorrect length for serialized swap plaintext");
         }
 
@@ -381,6 +439,15 @@ impl TryFrom<&[u8]> for SwapPlaintext {
         let rseed: [u8; 32] = bytes[224..256]
             .try_into()
             .map_err(|_| anyhow!("error fetching rseed bytes"))?;
+        // The legacy layout has no minimum output.
+        let min_output = if bytes.len() == SWAP_LEN_BYTES_V0 {
+            Amount::zero()
+        } else {
+            let min_output_bytes: [u8; 16] = bytes[256..272]
+                .try_into()
+                .map_err(|_| anyhow!("error fetching min output bytes"))?;
+            Amount::from_le_bytes(min_output_bytes)
+        };
 
         Ok(SwapPlaintext {
             trading_pair: tp_bytes
@@ -394,6 +461,7 @@ impl TryFrom<&[u8]> for SwapPlaintext {
             }),
             claim_address: pb_address.try_into()?,
             rseed: Rseed(rseed),
+            min_output,
         })
     }
 }
@@ -450,12 +518,83 @@ mod tests {
                     .id(),
             }),
             dest,
-        );
+        )
+        .with_min_output(5u64.into());
 
         let ciphertext = swap.encrypt(ovk).encrypted_swap;
         let plaintext = SwapCiphertext::decrypt(&ciphertext, ovk, swap.swap_commitment())
             .expect("can decrypt swap");
 
         assert_eq!(plaintext, swap);
+        assert_eq!(ciphertext.0.len(), crate::swap::SWAP_CIPHERTEXT_BYTES);
+    }
+
+    fn swap() -> SwapPlaintext {
+        let mut rng = OsRng;
+        let sk = SpendKey::from_seed_phrase_bip39(SeedPhrase::generate(rng), 0);
+        let (dest, _dtk_d) = sk
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+        let cache = asset::Cache::with_known_assets();
+        let upenumbra = cache.get_unit("upenumbra").unwrap().id();
+        SwapPlaintext::new(
+            &mut rng,
+            TradingPair::new(upenumbra, cache.get_unit("nala").unwrap().id()),
+            100000u64.into(),
+            1u64.into(),
+            Fee(Value {
+                amount: 3u64.into(),
+                asset_id: upenumbra,
+            }),
+            dest,
+        )
+    }
+
+    #[test]
+    /// Check swaps without a minimum output are encrypted in the legacy layout.
+    fn swaps_without_min_output_use_the_legacy_layout() {
+        let sk = SpendKey::from_seed_phrase_bip39(SeedPhrase::generate(OsRng), 0);
+        let ovk = sk.full_viewing_key().outgoing();
+        let swap = swap();
+
+        let ciphertext = swap.encrypt(ovk).encrypted_swap;
+        assert_eq!(ciphertext.0.len(), crate::swap::SWAP_CIPHERTEXT_BYTES_V0);
+        let plaintext = SwapCiphertext::decrypt(&ciphertext, ovk, swap.swap_commitment())
+            .expect("can decrypt swap");
+        assert_eq!(plaintext, swap);
+    }
+
+    #[test]
+    /// Check swap plaintexts in the legacy layout are decoded without a minimum output.
+    fn legacy_swap_plaintexts_are_decoded() {
+        let swap = swap();
+        let bytes: [u8; SWAP_LEN_BYTES] = swap.clone().with_min_output(5u64.into()).into();
+
+        let legacy = SwapPlaintext::try_from(&bytes[..SWAP_LEN_BYTES_V0]).unwrap();
+        assert_eq!(legacy, swap);
+        assert!(SwapPlaintext::try_from(&bytes[..SWAP_LEN_BYTES_V0 + 1]).is_err());
+    }
+
+    #[test]
+    /// Check the swap commitment computed in the circuit matches the native one, with and
+    /// without a minimum output.
+    fn swap_commitment_circuit_matches_native() {
+        use ark_relations::r1cs::ConstraintSystem;
+
+        for min_output in [0u64, 5] {
+            let swap = swap().with_min_output(min_output.into());
+            let cs = ConstraintSystem::<Fq>::new_ref();
+            let swap_var = SwapPlaintextVar::new_witness(cs.clone(), || Ok(swap.clone())).unwrap();
+            let commitment = swap_var.commit().unwrap();
+
+            assert_eq!(commitment.inner.value().unwrap(), swap.swap_commitment().0);
+            assert!(cs.is_satisfied().unwrap());
+        }
+        let swap = swap();
+        assert_ne!(
+            swap.swap_commitment(),
+            swap.clone().with_min_output(5u64.into()).swap_commitment()
+        );
     }
 }
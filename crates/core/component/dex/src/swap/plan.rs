@@ -50,6 +50,7 @@ impl SwapPlan {
             delta_2_i: self.swap_plaintext.delta_2_i,
             fee_commitment: self.fee_commitment(),
             payload: self.swap_plaintext.encrypt(fvk.outgoing()),
+            min_output: self.swap_plaintext.min_output,
         }
     }
 
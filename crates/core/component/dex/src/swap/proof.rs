@@ -22,6 +22,7 @@ use penumbra_asset::{
     Value,
 };
 use penumbra_keys::{keys::Diversifier, Address};
+use penumbra_num::{Amount, AmountVar};
 use penumbra_shielded_pool::Rseed;
 
 use crate::{
@@ -75,7 +76,8 @@ impl ConstraintSynthesizer<Fq> for SwapCircuit {
         let claimed_swap_commitment =
             StateCommitmentVar::new_input(cs.clone(), || Ok(self.swap_commitment))?;
         let claimed_fee_commitment =
-            BalanceCommitmentVar::new_input(cs, || Ok(self.fee_commitment))?;
+            BalanceCommitmentVar::new_input(cs.clone(), || Ok(self.fee_commitment))?;
+        let claimed_min_output = AmountVar::new_input(cs, || Ok(self.swap_plaintext.min_output))?;
 
         // Swap commitment integrity check
         let swap_commitment = swap_plaintext_var.commit()?;
@@ -98,6 +100,9 @@ impl ConstraintSynthesizer<Fq> for SwapCircuit {
         // Balance commitment integrity check
         claimed_balance_commitment.enforce_equal(&total_balance_commitment)?;
 
+        // Minimum output integrity check
+        claimed_min_output.enforce_equal(&swap_plaintext_var.min_output)?;
+
         Ok(())
     }
 }
@@ -134,6 +139,7 @@ impl DummyWitness for SwapCircuit {
             }),
             claim_address: address,
             rseed: Rseed([1u8; 32]),
+            min_output: 1u64.into(),
         };
 
         Self {
@@ -183,6 +189,7 @@ impl SwapProof {
     /// * balance commitment,
     /// * swap commitment,
     /// * fee commimtment,
+    /// * minimum output.
     ///
     // Commented out, but this may be useful when debugging proof verification failures,
     // to check that the proof data and verification keys are consistent.
@@ -194,6 +201,7 @@ impl SwapProof {
         balance_commitment: balance::Commitment,
         swap_commitment: tct::StateCommitment,
         fee_commitment: balance::Commitment,
+        min_output: Amount,
     ) -> anyhow::Result<()> {
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
@@ -217,6 +225,11 @@ impl SwapProof {
                 .to_field_elements()
                 .context("fee_commitment should be a Bls12-377 field member")?,
         );
+        public_inputs.extend(
+            min_output
+                .to_field_elements()
+                .context("min_output should be a Bls12-377 field member")?,
+        );
 
         tracing::trace!(?public_inputs);
         let start = std::time::Instant::now();
@@ -263,7 +276,6 @@ mod tests {
     use ark_ff::{PrimeField, UniformRand};
     use penumbra_asset::{Balance, Value};
     use penumbra_keys::keys::{SeedPhrase, SpendKey};
-    use penumbra_num::Amount;
     use penumbra_proof_params::generate_prepared_test_parameters;
     use proptest::prelude::*;
     use rand_core::OsRng;
@@ -334,7 +346,13 @@ mod tests {
         )
         .expect("can create proof");
 
-        let proof_result = proof.verify(&vk, balance_commitment, swap_commitment, fee_commitment);
+        let proof_result = proof.verify(
+            &vk,
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+            Amount::zero(),
+        );
 
         assert!(proof_result.is_ok());
         }
@@ -52,10 +52,10 @@ impl SwapClaimPlan {
         state_commitment_proof: &tct::Proof,
         nk: &NullifierKey,
     ) -> SwapClaimProof {
-        let (lambda_1, lambda_2) = self.output_data.pro_rata_outputs((
-            self.swap_plaintext.delta_1_i.into(),
-            self.swap_plaintext.delta_2_i.into(),
-        ));
+        let (lambda_1, lambda_2) = self.output_data.swap_outputs(
+            (self.swap_plaintext.delta_1_i, self.swap_plaintext.delta_2_i),
+            self.swap_plaintext.min_output,
+        );
         let (output_rseed_1, output_rseed_2) = self.swap_plaintext.output_rseeds();
         let note_blinding_1 = output_rseed_1.derive_note_blinding();
         let note_blinding_2 = output_rseed_2.derive_note_blinding();
@@ -17,7 +17,7 @@ use penumbra_asset::{
     Value, ValueVar,
 };
 use penumbra_keys::keys::{NullifierKey, NullifierKeyVar, SeedPhrase, SpendKey};
-use penumbra_num::{Amount, AmountVar};
+use penumbra_num::{fixpoint::U128x128, Amount, AmountVar};
 use penumbra_sct::{Nullifier, NullifierVar};
 use penumbra_shielded_pool::{
     note::{self, NoteVar},
@@ -167,10 +167,12 @@ impl ConstraintSynthesizer<Fq> for SwapClaimCircuit {
             .trading_pair
             .enforce_equal(&swap_plaintext_var.trading_pair)?;
 
-        // Output amounts integrity
-        let (computed_lambda_1_i, computed_lambda_2_i) = output_data_var.pro_rata_outputs(
+        // Output amounts integrity. A swap whose minimum output was not met by the batch
+        // was excluded from it, and its inputs are refunded instead.
+        let (computed_lambda_1_i, computed_lambda_2_i) = output_data_var.swap_outputs(
             swap_plaintext_var.delta_1_i,
             swap_plaintext_var.delta_2_i,
+            swap_plaintext_var.min_output,
             cs,
         )?;
         computed_lambda_1_i.enforce_equal(&lambda_1_i_var)?;
@@ -238,6 +240,7 @@ impl DummyWitness for SwapClaimCircuit {
             }),
             claim_address: address,
             rseed: Rseed([1u8; 32]),
+            min_output: 1u64.into(),
         };
         let mut sct = tct::Tree::new();
         let swap_commitment = swap_plaintext.swap_commitment();
@@ -259,12 +262,15 @@ impl DummyWitness for SwapClaimCircuit {
             height: 0,
             trading_pair: swap_plaintext.trading_pair,
             epoch_starting_height: 0,
+            limit_price_1: U128x128::from(1u64),
+            limit_price_2: U128x128::from(1u64),
         };
         let note_blinding_1 = Fq::from(1);
         let note_blinding_2 = Fq::from(1);
         let note_commitment_1 = tct::StateCommitment(Fq::from(1));
         let note_commitment_2 = tct::StateCommitment(Fq::from(2));
-        let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+        let (lambda_1, lambda_2) =
+            output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
 
         Self {
             swap_plaintext,
@@ -436,7 +442,6 @@ mod tests {
     use super::*;
     use ark_ff::UniformRand;
     use penumbra_keys::keys::{SeedPhrase, SpendKey};
-    use penumbra_num::Amount;
     use penumbra_proof_params::generate_prepared_test_parameters;
     use proptest::prelude::*;
     use rand_core::OsRng;
@@ -487,6 +492,8 @@ mod tests {
             height: height.into(),
             trading_pair: swap_plaintext.trading_pair,
             epoch_starting_height: (epoch_duration * position.epoch()).into(),
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         };
         let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
 
@@ -580,6 +587,8 @@ mod tests {
             height: height.into(),
             trading_pair: swap_plaintext.trading_pair,
             epoch_starting_height: (epoch_duration * position.epoch()).into(),
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         };
         let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
 
@@ -624,4 +633,105 @@ mod tests {
 
         assert!(proof_result.is_ok());
     }
+
+    #[test]
+    fn swap_claim_proof_happy_path_refunded_below_min_output() {
+        let mut rng = OsRng;
+        let (pk, vk) = generate_prepared_test_parameters::<SwapClaimCircuit>(&mut rng);
+
+        let seed_phrase = SeedPhrase::generate(rng);
+        let sk_recipient = SpendKey::from_seed_phrase_bip39(seed_phrase, 0);
+        let fvk_recipient = sk_recipient.full_viewing_key();
+        let ivk_recipient = fvk_recipient.incoming();
+        let (claim_address, _dtk_d) = ivk_recipient.payment_address(0u32.into());
+        let nk = *sk_recipient.nullifier_key();
+
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let trading_pair = TradingPair::new(gm.id(), gn.id());
+
+        let delta_1_i = Amount::from(100u64);
+        let delta_2_i = Amount::from(0u64);
+        let fee = Fee::default();
+
+        // The swap requires a price of at least 1, but the batch only filled swaps
+        // requiring at most 1/2, so it was excluded and is refunded.
+        let swap_plaintext = SwapPlaintext::new(
+            &mut rng,
+            trading_pair,
+            delta_1_i,
+            delta_2_i,
+            fee,
+            claim_address,
+        )
+        .with_min_output(100u64.into());
+        let fee = swap_plaintext.clone().claim_fee;
+        let mut sct = tct::Tree::new();
+        let swap_commitment = swap_plaintext.swap_commitment();
+        sct.insert(tct::Witness::Keep, swap_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(swap_commitment).unwrap();
+        let position = state_commitment_proof.position();
+        let nullifier = Nullifier::derive(&nk, position, &swap_commitment);
+        let epoch_duration = 20;
+        let height = epoch_duration * position.epoch() + position.block();
+
+        let output_data = BatchSwapOutputData {
+            delta_1: Amount::from(1000u64),
+            delta_2: Amount::from(0u64),
+            lambda_1: Amount::from(0u64),
+            lambda_2: Amount::from(500u64),
+            unfilled_1: Amount::from(0u64),
+            unfilled_2: Amount::from(0u64),
+            height: height.into(),
+            trading_pair: swap_plaintext.trading_pair,
+            epoch_starting_height: (epoch_duration * position.epoch()).into(),
+            limit_price_1: U128x128::ratio(1u64, 2u64).unwrap(),
+            limit_price_2: U128x128::default(),
+        };
+        let (lambda_1, lambda_2) =
+            output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
+        assert_eq!((lambda_1, lambda_2), (delta_1_i, delta_2_i));
+
+        let (output_rseed_1, output_rseed_2) = swap_plaintext.output_rseeds();
+        let note_blinding_1 = output_rseed_1.derive_note_blinding();
+        let note_blinding_2 = output_rseed_2.derive_note_blinding();
+        let (output_1_note, output_2_note) = swap_plaintext.output_notes(&output_data);
+        let note_commitment_1 = output_1_note.commit();
+        let note_commitment_2 = output_2_note.commit();
+
+        let blinding_r = Fq::rand(&mut rng);
+        let blinding_s = Fq::rand(&mut rng);
+
+        let proof = SwapClaimProof::prove(
+            blinding_r,
+            blinding_s,
+            &pk,
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            anchor,
+            nullifier,
+            lambda_1,
+            lambda_2,
+            note_blinding_1,
+            note_blinding_2,
+            note_commitment_1,
+            note_commitment_2,
+            output_data,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(
+            &vk,
+            anchor,
+            nullifier,
+            fee,
+            output_data,
+            note_commitment_1,
+            note_commitment_2,
+        );
+
+        assert!(proof_result.is_ok());
+    }
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use penumbra_asset::Value;
+use penumbra_asset::{asset, Value};
 use penumbra_num::fixpoint::U128x128;
 use penumbra_proto::{penumbra::core::component::dex::v1alpha1 as pb, DomainType, TypeUrl};
 use serde::{Deserialize, Serialize};
@@ -27,7 +27,58 @@ impl SwapExecution {
         let price = U128x128::ratio(input.amount, output.amount)?;
         Ok(Some(price))
     }
+
+    /// Aggregates the execution traces by the route they took, in the order
+    /// each route was first used.
+    pub fn paths(&self) -> Vec<PathExecution> {
+        let mut paths: Vec<PathExecution> = Vec::new();
+        for trace in &self.traces {
+            let (Some(input), Some(output)) = (trace.first(), trace.last()) else {
+                continue;
+            };
+            let route: Vec<asset::Id> = trace.iter().map(|value| value.asset_id).collect();
+            match paths.iter_mut().find(|path| path.route == route) {
+                Some(path) => {
+                    path.input.amount += input.amount;
+                    path.output.amount += output.amount;
+                    for (total, value) in path.trace.iter_mut().zip(trace) {
+                        total.amount += value.amount;
+                    }
+                }
+                None => paths.push(PathExecution {
+                    route,
+                    input: *input,
+                    output: *output,
+                    trace: trace.clone(),
+                }),
+            }
+        }
+        paths
+    }
 }
+
+/// The total input and output of a trade routed along a single path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathExecution {
+    /// The assets along the path, from the input asset to the output asset.
+    pub route: Vec<asset::Id>,
+    pub input: Value,
+    pub output: Value,
+    /// The total amount of each asset along the path, across all the traces that took it.
+    pub trace: Vec<Value>,
+}
+
+impl PathExecution {
+    /// Returns the average price of each hop along the path, in units of the
+    /// hop's input per unit of its output, or `None` for a hop without output.
+    pub fn hop_prices(&self) -> Vec<Option<U128x128>> {
+        self.trace
+            .windows(2)
+            .map(|hop| U128x128::ratio(hop[0].amount, hop[1].amount).ok())
+            .collect()
+    }
+}
+
 impl TypeUrl for SwapExecution {
     const TYPE_URL: &'static str = "/penumbra.core.dex.v1alpha1.SwapExecution";
 }
@@ -11,6 +11,11 @@ use penumbra_chain::{
     component::{StateReadExt as _, StateWriteExt as _},
     params::ChainParameters,
 };
+use penumbra_dao::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_ibc::component::{
+    ChannelStateWriteExt as _, ClientStateWriteExt as _, IcaStateWriteExt as _,
+    RateLimitStateWriteExt as _,
+};
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_sct::Nullifier;
@@ -30,6 +35,10 @@ use crate::{
 };
 use crate::{state_key, tally::Tally};
 
+/// The owner of the DAO's interchain accounts, which are controlled through the
+/// `icacontroller-dao` port.
+const DAO_INTERCHAIN_ACCOUNT_OWNER: &str = "dao";
+
 #[async_trait]
 pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
     /// Get the id of the next proposal in the sequence of ids.
@@ -529,6 +538,23 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             .await?)
     }
 
+    /// Get the latest chain parameters the chain is headed towards, which a newly passed
+    /// parameter change proposal is sequenced on top of, so that parameter updates are
+    /// sequentially consistent.
+    async fn latest_chain_parameters(&self) -> Result<ChainParameters> {
+        if let Some(params) = self.next_block_pending_chain_parameters().await? {
+            // If there is a pending parameter change, sequence the update on top of it
+            Ok(params)
+        } else if let Some(params) = self.get_next_epoch_chain_params().await? {
+            // If a change to the shape of epochs is waiting for the next epoch boundary,
+            // sequence the update on top of that one
+            Ok(params)
+        } else {
+            // Otherwise, use the current parameters
+            self.get_chain_params().await
+        }
+    }
+
     /// Check if any proposal is started in this block.
     fn proposal_started(&self) -> bool {
         self.object_get::<()>(&state_key::proposal_started())
@@ -793,12 +819,15 @@ pub trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    // The inner error is from proposal execution.
     #[instrument(skip(self))]
     async fn enact_proposal(
         &mut self,
         proposal_id: u64,
         payload: &ProposalPayload,
-    ) -> Result<Result<()>> // inner error from proposal execution
+    ) -> Result<Result<()>>
+    where
+        Self: Sized,
     {
         match payload {
             ProposalPayload::Signaling { .. } => {
@@ -814,40 +843,54 @@ pub trait StateWriteExt: StateWrite {
                     self.signal_halt().await?;
                 }
             }
-            ProposalPayload::ParameterChange { old, new } => {
+            ProposalPayload::ParameterChange { changes } => {
                 tracing::info!(
                     "parameter change proposal passed, attempting to update chain parameters"
                 );
 
+                // The changes are applied on top of the latest parameters the chain is headed
+                // towards, so that parameter updates are sequentially consistent:
+                let current = self.latest_chain_parameters().await?;
+
+                // Whether the changes apply cleanly and produce valid parameters depends on the
+                // parameters they're applied to, which may have changed while the proposal was
+                // pending, so a failure here means the proposal fails to enact:
+                let new = match current.apply_changes(changes).and_then(|new| {
+                    current
+                        .check_valid_update(&new)
+                        .context("final check for validity of chain parameter update failed")?;
+                    Ok(new)
+                }) {
+                    Ok(new) => new,
+                    Err(error) => return Ok(Err(error)),
+                };
+
+                // Tell the app to update the chain parameters in the next block
+                self.schedule_chain_params_change(new).await?;
+
+                tracing::info!("chain parameters updated successfully");
+            }
+            ProposalPayload::LegacyParameterChange { old, new } => {
+                tracing::info!(
+                    "legacy parameter change proposal passed, attempting to update chain parameters"
+                );
+
                 // If there has been a chain upgrade while the proposal was pending, the stateless
                 // verification criteria for the parameter change proposal could have changed, so we
                 // should check them again here, just to be sure:
                 old.check_valid_update(new)
                     .context("final check for validity of chain parameter update failed")?;
 
-                // Check that the old parameters are an exact match for the current parameters, or
-                // else abort the update.
-                let current =
-                    // If there is a pending parameter change, sequence the update on top of that
-                    // one (i.e., pretend that those new parameters are the old parameters, since
-                    // chain parameter updates must be sequentially consistent)
-                    if let Some(params) = self.next_block_pending_chain_parameters().await? {
-                        params
-                    } else {
-                        // If no pending parameter change, use the current parameters
-                        self.get_chain_params().await?
-                    };
-
-                // The current parameters (whether pending from a previous passed proposal or just the
+                // The latest parameters (whether pending from a previous passed proposal or just the
                 // current ones, unchanged) have to match the old parameters specified in the
                 // proposal, exactly. This prevents updates from clashing.
-                if **old != current {
+                if **old != self.latest_chain_parameters().await? {
                     return Ok(Err(anyhow::anyhow!(
                         "current chain parameters do not match the old parameters in the proposal"
                     )));
                 }
 
-                // Tell the app to update the chain parameters in the next block
+                // Tell the app to replace the chain parameters in the next block
                 self.schedule_chain_params_change((**new).clone()).await?;
 
                 tracing::info!("chain parameters updated successfully");
@@ -863,6 +906,119 @@ pub trait StateWriteExt: StateWrite {
                 tracing::info!(target_height = height, "upgrade plan proposal passed");
                 self.signal_upgrade(*height).await?;
             }
+            ProposalPayload::DaoDisbursement {
+                value,
+                address,
+                epoch_interval,
+                payouts,
+            } => {
+                tracing::info!(
+                    ?value,
+                    epoch_interval,
+                    payouts,
+                    "DAO disbursement proposal passed, scheduling disbursement"
+                );
+                self.schedule_dao_disbursement(
+                    proposal_id,
+                    *value,
+                    *address,
+                    *epoch_interval,
+                    *payouts,
+                )
+                .await?;
+            }
+            ProposalPayload::CancelDaoDisbursement { proposal } => {
+                // The disbursement may have been exhausted or cancelled while this proposal was
+                // pending, in which case there is nothing left to cancel.
+                if self.dao_disbursement(*proposal).await?.is_none() {
+                    return Ok(Err(anyhow::anyhow!(
+                        "no DAO disbursement is scheduled by proposal {proposal}"
+                    )));
+                }
+                tracing::info!(
+                    disbursement_proposal = proposal,
+                    "cancelling DAO disbursement"
+                );
+                self.cancel_dao_disbursement(*proposal).await?;
+            }
+            // The IBC clients and channels named by these proposals may have changed while the
+            // proposals were pending (e.g. a substitute client may have expired in the meantime),
+            // so failing to apply them means the proposal fails to enact, not that the chain halts.
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                tracing::info!(
+                    %subject_client_id,
+                    %substitute_client_id,
+                    "IBC client recovery proposal passed"
+                );
+                if let Err(error) = self
+                    .recover_client(subject_client_id, substitute_client_id)
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+            }
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                tracing::info!(%client_id, "IBC client freeze proposal passed");
+                if let Err(error) = self.freeze_client(client_id).await {
+                    return Ok(Err(error));
+                }
+            }
+            ProposalPayload::CloseIbcChannel {
+                port_id,
+                channel_id,
+            } => {
+                tracing::info!(%port_id, %channel_id, "IBC channel closure proposal passed");
+                if let Err(error) = self.close_channel(channel_id, port_id).await {
+                    return Ok(Err(error));
+                }
+            }
+            ProposalPayload::SetIcs20RateLimit {
+                channel_id,
+                denom,
+                rate_limit,
+            } => {
+                tracing::info!(
+                    %channel_id,
+                    %denom,
+                    ?rate_limit,
+                    "ICS-20 rate limit proposal passed"
+                );
+                self.put_ics20_rate_limit(channel_id, &denom.id(), *rate_limit);
+            }
+            ProposalPayload::RegisterDaoInterchainAccount { connection_id } => {
+                tracing::info!(%connection_id, "DAO interchain account registration proposal passed");
+                if let Err(error) = self
+                    .register_interchain_account(DAO_INTERCHAIN_ACCOUNT_OWNER, connection_id)
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+            }
+            ProposalPayload::DaoInterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+            } => {
+                tracing::info!(
+                    %connection_id,
+                    messages = messages.len(),
+                    "DAO interchain account transaction proposal passed"
+                );
+                if let Err(error) = self
+                    .send_interchain_tx(
+                        DAO_INTERCHAIN_ACCOUNT_OWNER,
+                        connection_id,
+                        messages.clone(),
+                        memo.clone(),
+                    )
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+            }
         }
 
         Ok(Ok(()))
@@ -1,9 +1,17 @@
 use anyhow::Context;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
-use penumbra_chain::params::ChainParameters;
+use ibc_types::core::{
+    channel::{ChannelId, PortId},
+    client::ClientId,
+    connection::ConnectionId,
+};
+use penumbra_asset::{asset, Value};
+use penumbra_chain::params::{change::ChainParameterChange, ChainParameters};
+use penumbra_ibc::Ics20RateLimit;
+use penumbra_keys::Address;
 use penumbra_proto::{penumbra::core::component::governance::v1alpha1 as pb, DomainType, TypeUrl};
 
 /// A governance proposal.
@@ -47,10 +55,23 @@ impl From<Proposal> for pb::Proposal {
             ProposalPayload::Emergency { halt_chain } => {
                 proposal.emergency = Some(pb::proposal::Emergency { halt_chain });
             }
-            ProposalPayload::ParameterChange { old, new } => {
+            ProposalPayload::ParameterChange { changes } => {
+                proposal.parameter_change = Some(pb::proposal::ParameterChange {
+                    old_parameters: None,
+                    new_parameters: None,
+                    changes: changes
+                        .into_iter()
+                        .map(|ChainParameterChange { parameter, value }| {
+                            pb::proposal::parameter_change::Change { parameter, value }
+                        })
+                        .collect(),
+                });
+            }
+            ProposalPayload::LegacyParameterChange { old, new } => {
                 proposal.parameter_change = Some(pb::proposal::ParameterChange {
                     old_parameters: Some((*old).into()),
                     new_parameters: Some((*new).into()),
+                    changes: Vec::new(),
                 });
             }
             ProposalPayload::DaoSpend { transaction_plan } => {
@@ -64,6 +85,74 @@ impl From<Proposal> for pb::Proposal {
             ProposalPayload::UpgradePlan { height } => {
                 proposal.upgrade_plan = Some(pb::proposal::UpgradePlan { height });
             }
+            ProposalPayload::DaoDisbursement {
+                value,
+                address,
+                epoch_interval,
+                payouts,
+            } => {
+                proposal.dao_disbursement = Some(pb::proposal::DaoDisbursement {
+                    value: Some(value.into()),
+                    address: Some(address.into()),
+                    epoch_interval,
+                    payouts,
+                });
+            }
+            ProposalPayload::CancelDaoDisbursement { proposal: id } => {
+                proposal.cancel_dao_disbursement =
+                    Some(pb::proposal::CancelDaoDisbursement { proposal: id });
+            }
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                proposal.recover_ibc_client = Some(pb::proposal::RecoverIbcClient {
+                    subject_client_id: subject_client_id.to_string(),
+                    substitute_client_id: substitute_client_id.to_string(),
+                });
+            }
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                proposal.freeze_ibc_client = Some(pb::proposal::FreezeIbcClient {
+                    client_id: client_id.to_string(),
+                });
+            }
+            ProposalPayload::CloseIbcChannel {
+                port_id,
+                channel_id,
+            } => {
+                proposal.close_ibc_channel = Some(pb::proposal::CloseIbcChannel {
+                    port_id: port_id.to_string(),
+                    channel_id: channel_id.to_string(),
+                });
+            }
+            ProposalPayload::SetIcs20RateLimit {
+                channel_id,
+                denom,
+                rate_limit,
+            } => {
+                proposal.set_ics20_rate_limit = Some(pb::proposal::SetIcs20RateLimit {
+                    channel_id: channel_id.to_string(),
+                    denom: denom.to_string(),
+                    rate_limit: rate_limit.map(Into::into),
+                });
+            }
+            ProposalPayload::RegisterDaoInterchainAccount { connection_id } => {
+                proposal.register_dao_interchain_account =
+                    Some(pb::proposal::RegisterDaoInterchainAccount {
+                        connection_id: connection_id.to_string(),
+                    });
+            }
+            ProposalPayload::DaoInterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+            } => {
+                proposal.dao_interchain_account_tx = Some(pb::proposal::DaoInterchainAccountTx {
+                    connection_id: connection_id.to_string(),
+                    messages,
+                    memo,
+                });
+            }
         }
         proposal
     }
@@ -90,18 +179,35 @@ impl TryFrom<pb::Proposal> for Proposal {
                     halt_chain: emergency.halt_chain,
                 }
             } else if let Some(parameter_change) = inner.parameter_change {
-                ProposalPayload::ParameterChange {
-                    old: Box::new(
-                        parameter_change
-                            .old_parameters
-                            .ok_or_else(|| anyhow::anyhow!("missing old parameters"))?
-                            .try_into()?,
-                    ),
-                    new: Box::new(
-                        parameter_change
-                            .new_parameters
-                            .ok_or_else(|| anyhow::anyhow!("missing new parameters"))?
-                            .try_into()?,
+                match (
+                    parameter_change.old_parameters,
+                    parameter_change.new_parameters,
+                ) {
+                    // Proposals from before parameter changes named individual parameters carry
+                    // the whole of the old and new parameters instead of changes.
+                    (Some(old), Some(new)) => {
+                        if !parameter_change.changes.is_empty() {
+                            anyhow::bail!(
+                                "legacy parameter change must not also name individual changes"
+                            );
+                        }
+                        ProposalPayload::LegacyParameterChange {
+                            old: Box::new(old.try_into()?),
+                            new: Box::new(new.try_into()?),
+                        }
+                    }
+                    (None, None) => ProposalPayload::ParameterChange {
+                        changes: parameter_change
+                            .changes
+                            .into_iter()
+                            .map(|change| ChainParameterChange {
+                                parameter: change.parameter,
+                                value: change.value,
+                            })
+                            .collect(),
+                    },
+                    _ => anyhow::bail!(
+                        "parameter change must set both or neither of the old and new parameters"
                     ),
                 }
             } else if let Some(dao_spend) = inner.dao_spend {
@@ -123,6 +229,87 @@ impl TryFrom<pb::Proposal> for Proposal {
                 ProposalPayload::UpgradePlan {
                     height: upgrade_plan.height,
                 }
+            } else if let Some(dao_disbursement) = inner.dao_disbursement {
+                ProposalPayload::DaoDisbursement {
+                    value: dao_disbursement
+                        .value
+                        .ok_or_else(|| anyhow::anyhow!("missing disbursement value"))?
+                        .try_into()?,
+                    address: dao_disbursement
+                        .address
+                        .ok_or_else(|| anyhow::anyhow!("missing disbursement address"))?
+                        .try_into()?,
+                    epoch_interval: dao_disbursement.epoch_interval,
+                    payouts: dao_disbursement.payouts,
+                }
+            } else if let Some(cancel_dao_disbursement) = inner.cancel_dao_disbursement {
+                ProposalPayload::CancelDaoDisbursement {
+                    proposal: cancel_dao_disbursement.proposal,
+                }
+            } else if let Some(recover_ibc_client) = inner.recover_ibc_client {
+                ProposalPayload::RecoverIbcClient {
+                    subject_client_id: recover_ibc_client
+                        .subject_client_id
+                        .parse()
+                        .context("invalid subject client ID")?,
+                    substitute_client_id: recover_ibc_client
+                        .substitute_client_id
+                        .parse()
+                        .context("invalid substitute client ID")?,
+                }
+            } else if let Some(freeze_ibc_client) = inner.freeze_ibc_client {
+                ProposalPayload::FreezeIbcClient {
+                    client_id: freeze_ibc_client
+                        .client_id
+                        .parse()
+                        .context("invalid client ID")?,
+                }
+            } else if let Some(close_ibc_channel) = inner.close_ibc_channel {
+                ProposalPayload::CloseIbcChannel {
+                    port_id: close_ibc_channel
+                        .port_id
+                        .parse()
+                        .context("invalid port ID")?,
+                    channel_id: close_ibc_channel
+                        .channel_id
+                        .parse()
+                        .context("invalid channel ID")?,
+                }
+            } else if let Some(set_ics20_rate_limit) = inner.set_ics20_rate_limit {
+                ProposalPayload::SetIcs20RateLimit {
+                    channel_id: set_ics20_rate_limit
+                        .channel_id
+                        .parse()
+                        .context("invalid channel ID")?,
+                    denom: set_ics20_rate_limit
+                        .denom
+                        .as_str()
+                        .try_into()
+                        .context("invalid denom")?,
+                    rate_limit: set_ics20_rate_limit
+                        .rate_limit
+                        .map(TryInto::try_into)
+                        .transpose()
+                        .context("invalid rate limit")?,
+                }
+            } else if let Some(register_dao_interchain_account) =
+                inner.register_dao_interchain_account
+            {
+                ProposalPayload::RegisterDaoInterchainAccount {
+                    connection_id: register_dao_interchain_account
+                        .connection_id
+                        .parse()
+                        .context("invalid connection ID")?,
+                }
+            } else if let Some(dao_interchain_account_tx) = inner.dao_interchain_account_tx {
+                ProposalPayload::DaoInterchainAccountTx {
+                    connection_id: dao_interchain_account_tx
+                        .connection_id
+                        .parse()
+                        .context("invalid connection ID")?,
+                    messages: dao_interchain_account_tx.messages,
+                    memo: dao_interchain_account_tx.memo,
+                }
             } else {
                 anyhow::bail!("missing proposal payload or unknown proposal type");
             },
@@ -191,6 +378,30 @@ pub enum ProposalKind {
     /// An upgrade proposal.
     #[cfg_attr(feature = "clap", clap(display_order = 500))]
     UpgradePlan,
+    /// A DAO disbursement proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 600))]
+    DaoDisbursement,
+    /// A proposal to cancel a DAO disbursement.
+    #[cfg_attr(feature = "clap", clap(display_order = 700))]
+    CancelDaoDisbursement,
+    /// An IBC client recovery proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 800))]
+    RecoverIbcClient,
+    /// An IBC client freeze proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 900))]
+    FreezeIbcClient,
+    /// An IBC channel closure proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 1000))]
+    CloseIbcChannel,
+    /// An ICS-20 rate limit proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 1100))]
+    SetIcs20RateLimit,
+    /// A proposal to register an interchain account for the DAO.
+    #[cfg_attr(feature = "clap", clap(display_order = 1200))]
+    RegisterDaoInterchainAccount,
+    /// A proposal to send a transaction to the DAO's interchain account.
+    #[cfg_attr(feature = "clap", clap(display_order = 1300))]
+    DaoInterchainAccountTx,
 }
 
 impl FromStr for ProposalKind {
@@ -203,6 +414,14 @@ impl FromStr for ProposalKind {
             "parameterchange" => Ok(ProposalKind::ParameterChange),
             "daospend" => Ok(ProposalKind::DaoSpend),
             "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "daodisbursement" => Ok(ProposalKind::DaoDisbursement),
+            "canceldaodisbursement" => Ok(ProposalKind::CancelDaoDisbursement),
+            "recoveribcclient" => Ok(ProposalKind::RecoverIbcClient),
+            "freezeibcclient" => Ok(ProposalKind::FreezeIbcClient),
+            "closeibcchannel" => Ok(ProposalKind::CloseIbcChannel),
+            "setics20ratelimit" => Ok(ProposalKind::SetIcs20RateLimit),
+            "registerdaointerchainaccount" => Ok(ProposalKind::RegisterDaoInterchainAccount),
+            "daointerchainaccounttx" => Ok(ProposalKind::DaoInterchainAccountTx),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -214,9 +433,20 @@ impl Proposal {
         match self.payload {
             ProposalPayload::Signaling { .. } => ProposalKind::Signaling,
             ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
-            ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
+            ProposalPayload::ParameterChange { .. }
+            | ProposalPayload::LegacyParameterChange { .. } => ProposalKind::ParameterChange,
             ProposalPayload::DaoSpend { .. } => ProposalKind::DaoSpend,
             ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
+            ProposalPayload::DaoDisbursement { .. } => ProposalKind::DaoDisbursement,
+            ProposalPayload::CancelDaoDisbursement { .. } => ProposalKind::CancelDaoDisbursement,
+            ProposalPayload::RecoverIbcClient { .. } => ProposalKind::RecoverIbcClient,
+            ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
+            ProposalPayload::CloseIbcChannel { .. } => ProposalKind::CloseIbcChannel,
+            ProposalPayload::SetIcs20RateLimit { .. } => ProposalKind::SetIcs20RateLimit,
+            ProposalPayload::RegisterDaoInterchainAccount { .. } => {
+                ProposalKind::RegisterDaoInterchainAccount
+            }
+            ProposalPayload::DaoInterchainAccountTx { .. } => ProposalKind::DaoInterchainAccountTx,
         }
     }
 }
@@ -237,14 +467,27 @@ pub enum ProposalPayload {
         /// passed.
         halt_chain: bool,
     },
-    /// A parameter change proposal describes a replacement of the chain parameters, which should
+    /// A parameter change proposal describes changes to individual chain parameters, which should
     /// take effect when the proposal is passed.
     ParameterChange {
+        /// The parameters to change, and their new values.
+        ///
+        /// Only the named parameters are changed, so the proposal is applied on top of whatever
+        /// the chain parameters are at the time it passes. Changes to the duration of epochs, the
+        /// active validator limit, or the validator uptime window only take effect at the
+        /// following epoch boundary.
+        changes: Vec<ChainParameterChange>,
+    },
+    /// A parameter change proposal from before proposals named the individual parameters they
+    /// change, which describes a replacement of the chain parameters.
+    ///
+    /// These are kept so that historical proposals keep their encoding, and are enacted by the
+    /// rules they were submitted under. New proposals should use `ParameterChange` instead.
+    LegacyParameterChange {
         /// The old chain parameters to be replaced.
         ///
         /// Even if the proposal passes, the update will not be applied if the chain parameters have
-        /// changed *at all* from these chain parameters. Usually, this should be set to the current
-        /// chain parameters at time of proposal.
+        /// changed *at all* from these chain parameters.
         old: Box<ChainParameters>,
         /// The new chain parameters to be set.
         ///
@@ -265,6 +508,74 @@ pub enum ProposalPayload {
     /// An upgrade plan proposal describes a planned upgrade to the chain. If ratified, the chain
     /// will halt at the specified height, trigger an epoch transition, and halt the chain.
     UpgradePlan { height: u64 },
+    /// A DAO disbursement proposal schedules a recurring transparent output from the DAO. If
+    /// passed, `value` is paid to `address` at the end of every `epoch_interval`-th epoch, until
+    /// `payouts` payments have been made or the disbursement is cancelled.
+    DaoDisbursement {
+        /// The value to pay out at each disbursement.
+        value: Value,
+        /// The address to send each disbursement to.
+        address: Address,
+        /// The number of epochs between disbursements.
+        epoch_interval: u64,
+        /// The total number of disbursements to make.
+        payouts: u64,
+    },
+    /// A proposal to cancel the remaining payouts of a disbursement scheduled by a previously
+    /// passed DAO disbursement proposal.
+    CancelDaoDisbursement {
+        /// The ID of the proposal which scheduled the disbursement.
+        proposal: u64,
+    },
+    /// An IBC client recovery proposal revives a frozen or expired IBC client by replacing its
+    /// state with that of an active client of the same counterparty chain. This is the
+    /// equivalent of a client substitution proposal in the Cosmos SDK.
+    RecoverIbcClient {
+        /// The frozen or expired client to recover.
+        subject_client_id: ClientId,
+        /// The active client whose state replaces the subject's.
+        substitute_client_id: ClientId,
+    },
+    /// An IBC client freeze proposal freezes an IBC client, so that no further updates or packets
+    /// are accepted through it until it is recovered.
+    FreezeIbcClient {
+        /// The client to freeze.
+        client_id: ClientId,
+    },
+    /// An IBC channel closure proposal closes this chain's end of an IBC channel.
+    CloseIbcChannel {
+        /// The port the channel is bound to.
+        port_id: PortId,
+        /// The channel to close.
+        channel_id: ChannelId,
+    },
+    /// An ICS-20 rate limit proposal sets or removes the limit on the value of an asset which can
+    /// be transferred over an ICS-20 channel in each window of blocks.
+    SetIcs20RateLimit {
+        /// The channel to limit, on the transfer port.
+        channel_id: ChannelId,
+        /// The denomination to limit, as it is known on this chain.
+        denom: asset::DenomMetadata,
+        /// The new rate limit, or `None` to remove the existing one.
+        rate_limit: Option<Ics20RateLimit>,
+    },
+    /// A proposal to register an ICS-27 interchain account for the DAO on the chain at the other
+    /// end of an IBC connection. If passed, the handshake for the account's channel is started,
+    /// and the account can be used once a relayer completes it.
+    RegisterDaoInterchainAccount {
+        /// The connection to the host chain.
+        connection_id: ConnectionId,
+    },
+    /// A proposal to send a transaction to the DAO's interchain account on another chain. If
+    /// passed, the transaction is sent to the host chain, to be executed by the account.
+    DaoInterchainAccountTx {
+        /// The connection to the host chain.
+        connection_id: ConnectionId,
+        /// The messages of the transaction, as the host chain encodes them.
+        messages: Vec<pbjson_types::Any>,
+        /// The memo of the transaction.
+        memo: String,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -278,6 +589,9 @@ pub enum ProposalPayloadToml {
         halt_chain: bool,
     },
     ParameterChange {
+        changes: BTreeMap<String, String>,
+    },
+    LegacyParameterChange {
         old: Box<ChainParameters>,
         new: Box<ChainParameters>,
     },
@@ -287,6 +601,56 @@ pub enum ProposalPayloadToml {
     UpgradePlan {
         height: u64,
     },
+    DaoDisbursement {
+        value: String,
+        address: String,
+        epoch_interval: u64,
+        payouts: u64,
+    },
+    CancelDaoDisbursement {
+        proposal: u64,
+    },
+    RecoverIbcClient {
+        subject_client_id: String,
+        substitute_client_id: String,
+    },
+    FreezeIbcClient {
+        client_id: String,
+    },
+    CloseIbcChannel {
+        port_id: String,
+        channel_id: String,
+    },
+    SetIcs20RateLimit {
+        channel_id: String,
+        denom: String,
+        rate_limit: Option<Ics20RateLimitToml>,
+    },
+    RegisterDaoInterchainAccount {
+        connection_id: String,
+    },
+    DaoInterchainAccountTx {
+        connection_id: String,
+        messages: Vec<InterchainMessageToml>,
+        #[serde(default)]
+        memo: String,
+    },
+}
+
+/// A TOML-serializable version of `Ics20RateLimit`, with amounts in base units of the denom.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ics20RateLimitToml {
+    pub window_blocks: u64,
+    pub max_inflow: String,
+    pub max_outflow: String,
+}
+
+/// A TOML-serializable version of a message for an interchain account, with its encoded value in
+/// base64.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterchainMessageToml {
+    pub type_url: String,
+    pub value: String,
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -298,8 +662,14 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::Emergency { halt_chain } => {
                 ProposalPayload::Emergency { halt_chain }
             }
-            ProposalPayloadToml::ParameterChange { old, new } => {
-                ProposalPayload::ParameterChange { old, new }
+            ProposalPayloadToml::ParameterChange { changes } => ProposalPayload::ParameterChange {
+                changes: changes
+                    .into_iter()
+                    .map(|(parameter, value)| ChainParameterChange { parameter, value })
+                    .collect(),
+            },
+            ProposalPayloadToml::LegacyParameterChange { old, new } => {
+                ProposalPayload::LegacyParameterChange { old, new }
             }
             ProposalPayloadToml::DaoSpend { transaction } => ProposalPayload::DaoSpend {
                 transaction_plan: Bytes::from(
@@ -309,6 +679,101 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
                 .to_vec(),
             },
             ProposalPayloadToml::UpgradePlan { height } => ProposalPayload::UpgradePlan { height },
+            ProposalPayloadToml::DaoDisbursement {
+                value,
+                address,
+                epoch_interval,
+                payouts,
+            } => ProposalPayload::DaoDisbursement {
+                value: value.parse().context("couldn't parse disbursement value")?,
+                address: address
+                    .parse()
+                    .context("couldn't parse disbursement address")?,
+                epoch_interval,
+                payouts,
+            },
+            ProposalPayloadToml::CancelDaoDisbursement { proposal } => {
+                ProposalPayload::CancelDaoDisbursement { proposal }
+            }
+            ProposalPayloadToml::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::RecoverIbcClient {
+                subject_client_id: subject_client_id
+                    .parse()
+                    .context("couldn't parse subject client ID")?,
+                substitute_client_id: substitute_client_id
+                    .parse()
+                    .context("couldn't parse substitute client ID")?,
+            },
+            ProposalPayloadToml::FreezeIbcClient { client_id } => {
+                ProposalPayload::FreezeIbcClient {
+                    client_id: client_id.parse().context("couldn't parse client ID")?,
+                }
+            }
+            ProposalPayloadToml::CloseIbcChannel {
+                port_id,
+                channel_id,
+            } => ProposalPayload::CloseIbcChannel {
+                port_id: port_id.parse().context("couldn't parse port ID")?,
+                channel_id: channel_id.parse().context("couldn't parse channel ID")?,
+            },
+            ProposalPayloadToml::SetIcs20RateLimit {
+                channel_id,
+                denom,
+                rate_limit,
+            } => ProposalPayload::SetIcs20RateLimit {
+                channel_id: channel_id.parse().context("couldn't parse channel ID")?,
+                denom: denom.as_str().try_into().context("couldn't parse denom")?,
+                rate_limit: rate_limit
+                    .map(|rate_limit| -> anyhow::Result<Ics20RateLimit> {
+                        Ok(Ics20RateLimit {
+                            window_blocks: rate_limit.window_blocks,
+                            max_inflow: rate_limit
+                                .max_inflow
+                                .parse::<u128>()
+                                .context("couldn't parse max inflow")?
+                                .into(),
+                            max_outflow: rate_limit
+                                .max_outflow
+                                .parse::<u128>()
+                                .context("couldn't parse max outflow")?
+                                .into(),
+                        })
+                    })
+                    .transpose()?,
+            },
+            ProposalPayloadToml::RegisterDaoInterchainAccount { connection_id } => {
+                ProposalPayload::RegisterDaoInterchainAccount {
+                    connection_id: connection_id
+                        .parse()
+                        .context("couldn't parse connection ID")?,
+                }
+            }
+            ProposalPayloadToml::DaoInterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+            } => ProposalPayload::DaoInterchainAccountTx {
+                connection_id: connection_id
+                    .parse()
+                    .context("couldn't parse connection ID")?,
+                messages: messages
+                    .into_iter()
+                    .map(|message| -> anyhow::Result<pbjson_types::Any> {
+                        Ok(pbjson_types::Any {
+                            type_url: message.type_url,
+                            value: base64::Engine::decode(
+                                &base64::engine::general_purpose::STANDARD,
+                                message.value,
+                            )
+                            .context("couldn't decode message from base64")?
+                            .into(),
+                        })
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+                memo,
+            },
         })
     }
 }
@@ -320,8 +785,14 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             ProposalPayload::Emergency { halt_chain } => {
                 ProposalPayloadToml::Emergency { halt_chain }
             }
-            ProposalPayload::ParameterChange { old, new } => {
-                ProposalPayloadToml::ParameterChange { old, new }
+            ProposalPayload::ParameterChange { changes } => ProposalPayloadToml::ParameterChange {
+                changes: changes
+                    .into_iter()
+                    .map(|ChainParameterChange { parameter, value }| (parameter, value))
+                    .collect(),
+            },
+            ProposalPayload::LegacyParameterChange { old, new } => {
+                ProposalPayloadToml::LegacyParameterChange { old, new }
             }
             ProposalPayload::DaoSpend { transaction_plan } => ProposalPayloadToml::DaoSpend {
                 transaction: base64::Engine::encode(
@@ -330,6 +801,75 @@ impl From<ProposalPayload> for ProposalPayloadToml {
                 ),
             },
             ProposalPayload::UpgradePlan { height } => ProposalPayloadToml::UpgradePlan { height },
+            ProposalPayload::DaoDisbursement {
+                value,
+                address,
+                epoch_interval,
+                payouts,
+            } => ProposalPayloadToml::DaoDisbursement {
+                value: value.format(&asset::Cache::with_known_assets()),
+                address: address.to_string(),
+                epoch_interval,
+                payouts,
+            },
+            ProposalPayload::CancelDaoDisbursement { proposal } => {
+                ProposalPayloadToml::CancelDaoDisbursement { proposal }
+            }
+            ProposalPayload::RecoverIbcClient {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayloadToml::RecoverIbcClient {
+                subject_client_id: subject_client_id.to_string(),
+                substitute_client_id: substitute_client_id.to_string(),
+            },
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                ProposalPayloadToml::FreezeIbcClient {
+                    client_id: client_id.to_string(),
+                }
+            }
+            ProposalPayload::CloseIbcChannel {
+                port_id,
+                channel_id,
+            } => ProposalPayloadToml::CloseIbcChannel {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            },
+            ProposalPayload::SetIcs20RateLimit {
+                channel_id,
+                denom,
+                rate_limit,
+            } => ProposalPayloadToml::SetIcs20RateLimit {
+                channel_id: channel_id.to_string(),
+                denom: denom.to_string(),
+                rate_limit: rate_limit.map(|rate_limit| Ics20RateLimitToml {
+                    window_blocks: rate_limit.window_blocks,
+                    max_inflow: rate_limit.max_inflow.to_string(),
+                    max_outflow: rate_limit.max_outflow.to_string(),
+                }),
+            },
+            ProposalPayload::RegisterDaoInterchainAccount { connection_id } => {
+                ProposalPayloadToml::RegisterDaoInterchainAccount {
+                    connection_id: connection_id.to_string(),
+                }
+            }
+            ProposalPayload::DaoInterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+            } => ProposalPayloadToml::DaoInterchainAccountTx {
+                connection_id: connection_id.to_string(),
+                messages: messages
+                    .into_iter()
+                    .map(|message| InterchainMessageToml {
+                        type_url: message.type_url,
+                        value: base64::Engine::encode(
+                            &base64::engine::general_purpose::STANDARD,
+                            message.value,
+                        ),
+                    })
+                    .collect(),
+                memo,
+            },
         }
     }
 }
@@ -344,10 +884,77 @@ impl ProposalPayload {
     }
 
     pub fn is_parameter_change(&self) -> bool {
-        matches!(self, ProposalPayload::ParameterChange { .. })
+        matches!(
+            self,
+            ProposalPayload::ParameterChange { .. } | ProposalPayload::LegacyParameterChange { .. }
+        )
     }
 
     pub fn is_dao_spend(&self) -> bool {
         matches!(self, ProposalPayload::DaoSpend { .. })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use penumbra_proto::Message;
+
+    use super::*;
+
+    #[test]
+    fn legacy_parameter_change_keeps_its_encoding() {
+        let old = ChainParameters::default();
+        let new = ChainParameters {
+            epoch_duration: old.epoch_duration + 1,
+            ..old.clone()
+        };
+        let legacy = pb::Proposal {
+            id: 1,
+            title: "legacy".to_string(),
+            parameter_change: Some(pb::proposal::ParameterChange {
+                old_parameters: Some(old.clone().into()),
+                new_parameters: Some(new.clone().into()),
+                changes: Vec::new(),
+            }),
+            ..Default::default()
+        };
+
+        let proposal = Proposal::try_from(legacy.clone()).unwrap();
+        let ProposalPayload::LegacyParameterChange {
+            old: decoded_old,
+            new: decoded_new,
+        } = &proposal.payload
+        else {
+            panic!("legacy proposal is read as a legacy parameter change");
+        };
+        assert_eq!(**decoded_old, old);
+        assert_eq!(**decoded_new, new);
+        assert!(proposal.payload.is_parameter_change());
+
+        // Re-encoding the proposal gives back exactly the original bytes, so that the transactions
+        // which submitted it keep their IDs and effect hashes.
+        assert_eq!(
+            pb::Proposal::from(proposal).encode_to_vec(),
+            legacy.encode_to_vec()
+        );
+    }
+
+    #[test]
+    fn legacy_parameter_change_rejects_individual_changes() {
+        let old = ChainParameters::default();
+        let mixed = pb::Proposal {
+            id: 1,
+            title: "mixed".to_string(),
+            parameter_change: Some(pb::proposal::ParameterChange {
+                old_parameters: Some(old.clone().into()),
+                new_parameters: Some(old.into()),
+                changes: vec![pb::proposal::parameter_change::Change {
+                    parameter: "epoch_duration".to_string(),
+                    value: "1".to_string(),
+                }],
+            }),
+            ..Default::default()
+        };
+        assert!(Proposal::try_from(mixed).is_err());
+    }
+}
@@ -9,19 +9,31 @@ mod connection_counter;
 mod grpc_query;
 
 mod ibc_component;
+mod ica;
+mod ics20_swap;
 mod metrics;
 mod msg_handler;
 mod packet;
+mod packet_forward;
 mod proof_verification;
+mod rate_limit;
 mod state_key;
 mod transfer;
 
 use msg_handler::MsgHandler;
 
 pub use self::metrics::register_metrics;
+pub use app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute};
 pub use channel::StateReadExt as ChannelStateReadExt;
+pub use channel::StateWriteExt as ChannelStateWriteExt;
 pub use client::StateReadExt as ClientStateReadExt;
+pub use client::StateWriteExt as ClientStateWriteExt;
 pub use connection::StateReadExt as ConnectionStateReadExt;
+pub use ica::StateReadExt as IcaStateReadExt;
+pub use ica::StateWriteExt as IcaStateWriteExt;
+pub use rate_limit::StateReadExt as RateLimitStateReadExt;
+pub use rate_limit::StateWriteExt as RateLimitStateWriteExt;
+pub use transfer::Ics20Transfer;
 
 #[cfg(feature = "rpc")]
 pub use grpc_query::IbcQuery;
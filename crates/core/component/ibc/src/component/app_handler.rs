@@ -8,9 +8,12 @@
 /// token transfers.
 use anyhow::Result;
 use async_trait::async_trait;
-use ibc_types::core::channel::msgs::{
-    MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+use ibc_types::core::channel::{
+    msgs::{
+        MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+        MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    },
+    Version,
 };
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -21,6 +24,11 @@ use penumbra_storage::{StateRead, StateWrite};
 pub trait AppHandlerCheck: Send + Sync {
     async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()>;
     async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()>;
+    /// The version of a channel opened by `ChanOpenTry`, once it has been checked. By default,
+    /// this is the version proposed by the counterparty.
+    fn chan_open_try_version(msg: &MsgChannelOpenTry) -> Version {
+        msg.version_supported_on_a.clone()
+    }
     async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()>;
     async fn chan_open_confirm_check<S: StateRead>(
         state: S,
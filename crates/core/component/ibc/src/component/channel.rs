@@ -1,4 +1,9 @@
-use crate::component::proof_verification::{commit_acknowledgement, commit_packet};
+use crate::component::{
+    app_handler::AppHandlerExecute,
+    ica::{self, IcaController, IcaHost},
+    proof_verification::{commit_acknowledgement, commit_packet},
+    transfer::Ics20TransferApp,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -7,7 +12,12 @@ use ibc_types::path::{
     AckPath, ChannelEndPath, CommitmentPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 
-use ibc_types::core::channel::{ChannelEnd, ChannelId, Packet, PortId};
+use ibc_types::core::channel::{
+    channel::{Order as ChannelOrder, State as ChannelState},
+    events,
+    msgs::MsgChannelCloseInit,
+    ChannelEnd, ChannelId, Packet, PortId,
+};
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -93,10 +103,81 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
             commit_acknowledgement(acknowledgement),
         );
     }
+
+    /// Closes this chain's end of a channel, without asking the application bound to its port
+    /// whether it may be closed, and signals the closure to the counterparty as though
+    /// `ChanCloseInit` had executed. The application is still told of the closure.
+    ///
+    /// Packets already sent over an unordered channel are still settled once it is closed (see
+    /// [`settles_packets`]), so ICS-20 transfers in flight are refunded when they time out or
+    /// are acknowledged with an error, as they would be on an open channel.
+    async fn close_channel(&mut self, channel_id: &ChannelId, port_id: &PortId) -> Result<()>
+    where
+        Self: Sized,
+    {
+        if *port_id != PortId::transfer()
+            && !ica::is_host_port(port_id)
+            && !ica::is_controller_port(port_id)
+        {
+            anyhow::bail!("invalid port id {port_id}");
+        }
+        let mut channel = self
+            .get_channel(channel_id, port_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("channel {channel_id} not found on port {port_id}"))?;
+        if channel.state_matches(&ChannelState::Closed) {
+            anyhow::bail!("channel {channel_id} is already closed");
+        }
+
+        channel.set_state(ChannelState::Closed);
+        self.put_channel(channel_id, port_id, channel.clone());
+
+        self.record(
+            events::channel::CloseInit {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                counterparty_port_id: channel.counterparty().port_id.clone(),
+                counterparty_channel_id: channel
+                    .counterparty()
+                    .channel_id
+                    .clone()
+                    .unwrap_or_default(),
+                connection_id: channel.connection_hops[0].clone(),
+            }
+            .into(),
+        );
+
+        let msg = MsgChannelCloseInit {
+            port_id_on_a: port_id.clone(),
+            chan_id_on_a: channel_id.clone(),
+            signer: String::new(),
+        };
+        if *port_id == PortId::transfer() {
+            Ics20TransferApp::chan_close_init_execute(&mut *self, &msg).await;
+        } else if ica::is_host_port(port_id) {
+            IcaHost::chan_close_init_execute(&mut *self, &msg).await;
+        } else {
+            IcaController::chan_close_init_execute(&mut *self, &msg).await;
+        }
+
+        tracing::info!(%channel_id, %port_id, "closed channel");
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
 
+/// Whether packets sent over this channel can still be acknowledged or timed out.
+///
+/// They can on an open channel, and on a closed unordered one, where packets sent before it was
+/// closed are still in flight and must be settled to refund their senders. A closed ordered
+/// channel has either timed out already, which closed it, or has no more packets to settle.
+pub(super) fn settles_packets(channel: &ChannelEnd) -> bool {
+    channel.state_matches(&ChannelState::Open)
+        || (channel.state_matches(&ChannelState::Closed)
+            && channel.ordering == ChannelOrder::Unordered)
+}
+
 #[async_trait]
 pub trait StateReadExt: StateRead {
     async fn get_channel_counter(&self) -> Result<u64> {
@@ -236,3 +317,66 @@ pub trait StateReadExt: StateRead {
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use ibc_types::core::{
+        channel::{Counterparty, Version},
+        connection::ConnectionId,
+    };
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+    use super::*;
+
+    fn channel(ordering: ChannelOrder) -> ChannelEnd {
+        ChannelEnd {
+            state: ChannelState::Open,
+            ordering,
+            remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(7))),
+            connection_hops: vec![ConnectionId::new(0)],
+            version: Version::new("ics20-1".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn closed_transfer_channel_settles_packets() -> Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let channel_id = ChannelId::new(0);
+        let port_id = PortId::transfer();
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_channel(&channel_id, &port_id, channel(ChannelOrder::Unordered));
+        state_tx.close_channel(&channel_id, &port_id).await?;
+        state_tx.apply();
+
+        let closed = state
+            .get_channel(&channel_id, &port_id)
+            .await?
+            .expect("channel exists");
+        assert!(closed.state_matches(&ChannelState::Closed));
+        // Transfers which were in flight when the channel was closed can still be refunded:
+        assert!(settles_packets(&closed));
+
+        // ...but a channel can only be closed once, and only on a port we know:
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(state_tx.close_channel(&channel_id, &port_id).await.is_err());
+        let unknown_port = PortId::from_str("oracle").unwrap();
+        state_tx.put_channel(&channel_id, &unknown_port, channel(ChannelOrder::Unordered));
+        assert!(state_tx
+            .close_channel(&channel_id, &unknown_port)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn closed_ordered_channel_settles_no_packets() {
+        let mut ordered = channel(ChannelOrder::Ordered);
+        assert!(settles_packets(&ordered));
+        ordered.set_state(ChannelState::Closed);
+        assert!(!settles_packets(&ordered));
+    }
+}
@@ -189,6 +189,73 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
 
         Ok(())
     }
+
+    /// Freezes a client at its latest height, so that it accepts no further updates until it is
+    /// recovered.
+    async fn freeze_client(&mut self, client_id: &ClientId) -> Result<()> {
+        let client_state = self.get_client_state(client_id).await?;
+        if client_state.is_frozen() {
+            anyhow::bail!("client {client_id} is already frozen");
+        }
+
+        let frozen_height = client_state.latest_height();
+        self.put_client(client_id, client_state.with_frozen_height(frozen_height));
+
+        tracing::info!(%client_id, %frozen_height, "froze client");
+        Ok(())
+    }
+
+    /// Recovers a frozen or expired client (the subject) by replacing its state with that of an
+    /// active client of the same counterparty chain (the substitute).
+    ///
+    /// As in ICS-02 client substitution, the two clients must agree on every parameter except
+    /// their latest and frozen heights, trusting periods, and chain IDs. The subject is unfrozen
+    /// and takes on the substitute's latest consensus state, but keeps its identifier, so that the
+    /// connections and channels built on it can be used again.
+    async fn recover_client(
+        &mut self,
+        subject_id: &ClientId,
+        substitute_id: &ClientId,
+    ) -> Result<()> {
+        if subject_id == substitute_id {
+            anyhow::bail!("client {subject_id} can't be substituted for itself");
+        }
+
+        let subject = self.get_client_state(subject_id).await?;
+        let substitute = self.get_client_state(substitute_id).await?;
+
+        if !subject.is_frozen() && !self.client_is_expired(subject_id, &subject).await? {
+            anyhow::bail!("client {subject_id} is neither frozen nor expired");
+        }
+        if substitute.is_frozen() || self.client_is_expired(substitute_id, &substitute).await? {
+            anyhow::bail!("substitute client {substitute_id} is not active");
+        }
+
+        let recovered = TendermintClientState {
+            chain_id: substitute.chain_id.clone(),
+            trusting_period: substitute.trusting_period,
+            latest_height: substitute.latest_height,
+            frozen_height: None,
+            ..subject
+        };
+        if recovered != substitute {
+            anyhow::bail!(
+                "substitute client {substitute_id} does not match the parameters of client {subject_id}"
+            );
+        }
+
+        let height = substitute.latest_height();
+        let consensus_state = self
+            .get_verified_consensus_state(height, substitute_id.clone())
+            .await?;
+
+        self.put_client(subject_id, recovered);
+        self.put_verified_consensus_state(height, subject_id.clone(), consensus_state)
+            .await?;
+
+        tracing::info!(%subject_id, %substitute_id, %height, "recovered client");
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -216,6 +283,23 @@ pub trait StateReadExt: StateRead {
         client_state.context(format!("could not find client state for {client_id}"))
     }
 
+    /// Returns true if the client has expired, meaning that its trusting period has elapsed since
+    /// the timestamp of its latest consensus state.
+    async fn client_is_expired(
+        &self,
+        client_id: &ClientId,
+        client_state: &TendermintClientState,
+    ) -> Result<bool> {
+        let latest_consensus_state = self
+            .get_verified_consensus_state(client_state.latest_height(), client_id.clone())
+            .await?;
+
+        let now = self.get_block_timestamp().await?;
+        let time_elapsed = now.duration_since(latest_consensus_state.timestamp)?;
+
+        Ok(client_state.expired(time_elapsed))
+    }
+
     async fn get_verified_heights(&self, client_id: &ClientId) -> Result<Option<VerifiedHeights>> {
         self.get(&format!(
             // NOTE: this is an implementation detail of the Penumbra ICS2 implementation, so
@@ -471,4 +555,102 @@ mod tests {
 
         Ok(())
     }
+
+    // test that an expired light client can be revived by substituting an active client's state.
+    #[tokio::test]
+    async fn test_recover_expired_light_client() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let timestamp = Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_chain_params(Default::default());
+        state_tx.put_block_height(1);
+        state_tx.put_block_timestamp(timestamp);
+        state_tx.put_epoch_by_height(
+            1,
+            penumbra_chain::Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+        state_tx.apply();
+
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let create_client_action = IbcAction::CreateClient(
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap(),
+        );
+        create_client_action.check_stateless(()).await?;
+        create_client_action.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_client_action.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        let subject_id = ClientId::from_str("07-tendermint-0")?;
+        let subject = state.get_client_state(&subject_id).await?;
+        let subject_consensus_state = state
+            .get_verified_consensus_state(subject.latest_height(), subject_id.clone())
+            .await?;
+
+        // Long after the client's trusting period has elapsed, the client has expired:
+        let later = (timestamp + std::time::Duration::from_secs(10 * 365 * 24 * 60 * 60))?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(2);
+        state_tx.put_block_timestamp(later);
+        state_tx.apply();
+        assert!(state.client_is_expired(&subject_id, &subject).await?);
+
+        // Meanwhile, a client of the same chain has been kept up to date:
+        let substitute_id = ClientId::from_str("07-tendermint-1")?;
+        let substitute_height = Height::new(
+            subject.latest_height().revision_number(),
+            subject.latest_height().revision_height() + 100,
+        )?;
+        let substitute_consensus_state = TendermintConsensusState {
+            timestamp: later,
+            ..subject_consensus_state
+        };
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_client(
+            &substitute_id,
+            TendermintClientState {
+                latest_height: substitute_height,
+                ..subject.clone()
+            },
+        );
+        state_tx
+            .put_verified_consensus_state(
+                substitute_height,
+                substitute_id.clone(),
+                substitute_consensus_state.clone(),
+            )
+            .await?;
+
+        // An active client can't be recovered, and an expired one can't be a substitute:
+        assert!(state_tx
+            .recover_client(&substitute_id, &subject_id)
+            .await
+            .is_err());
+
+        state_tx.recover_client(&subject_id, &substitute_id).await?;
+        state_tx.apply();
+
+        let recovered = state.get_client_state(&subject_id).await?;
+        assert_eq!(recovered.latest_height(), substitute_height);
+        assert!(!recovered.is_frozen());
+        assert!(!state.client_is_expired(&subject_id, &recovered).await?);
+        assert_eq!(
+            state
+                .get_verified_consensus_state(substitute_height, subject_id.clone())
+                .await?,
+            substitute_consensus_state
+        );
+
+        // The recovered client can be frozen again by governance:
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.freeze_client(&subject_id).await?;
+        state_tx.apply();
+        assert!(state.get_client_state(&subject_id).await?.is_frozen());
+
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
+use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::query_server::Query as ConsensusQuery;
+use ibc_proto::ibc::core::channel::v1::Channel;
 use ibc_proto::ibc::core::channel::v1::{
     PacketState, QueryChannelClientStateRequest, QueryChannelClientStateResponse,
     QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
@@ -33,10 +35,15 @@ use ibc_proto::ibc::core::connection::v1::{
     QueryConnectionsResponse,
 };
 use ibc_types::core::channel::{ChannelId, IdentifiedChannelEnd, PortId};
-use ibc_types::core::client::ClientId;
+use ibc_types::core::client::{ClientId, Height as IbcHeight};
 use ibc_types::core::connection::{ConnectionId, IdentifiedConnectionEnd};
+use ibc_types::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
+    ReceiptPath, SeqRecvPath,
+};
 use ibc_types::DomainType;
 use penumbra_chain::component::AppHashRead;
+use penumbra_storage::Snapshot;
 use prost::Message;
 use std::str::FromStr;
 use tonic::{Response, Status};
@@ -54,6 +61,28 @@ impl IbcQuery {
     }
 }
 
+/// Looks up the raw value stored at `key` in `snapshot`, along with an encoded proof of its
+/// existence (or non-existence) up to the app hash, and the height of the snapshot.
+///
+/// As with the other queries, the proof height is the version of the snapshot: the proof is
+/// checked against the app hash committed in the header of the following block.
+async fn get_with_proof(
+    snapshot: &Snapshot,
+    key: String,
+) -> Result<(Option<Vec<u8>>, Vec<u8>, Height), Status> {
+    let (value, proof) = snapshot
+        .get_with_proof_to_apphash(key.as_bytes().to_vec())
+        .await
+        .map_err(|e| tonic::Status::aborted(format!("couldn't get {key}: {e}")))?;
+
+    let height = Height {
+        revision_number: 0,
+        revision_height: snapshot.version(),
+    };
+
+    Ok((value, proof.encode_to_vec(), height))
+}
+
 #[async_trait]
 impl ConnectionQuery for IbcQuery {
     /// Connection queries an IBC connection end.
@@ -190,9 +219,34 @@ impl ConsensusQuery for IbcQuery {
     /// Channel queries an IBC Channel.
     async fn channel(
         &self,
-        _request: tonic::Request<QueryChannelRequest>,
+        request: tonic::Request<QueryChannelRequest>,
     ) -> std::result::Result<tonic::Response<QueryChannelResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let request = request.get_ref();
+
+        let chan_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid channel id: {e}")))?;
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
+
+        let (channel, proof, height) = get_with_proof(
+            &snapshot,
+            ChannelEndPath::new(&port_id, &chan_id).to_string(),
+        )
+        .await?;
+
+        let channel = channel
+            .map(|channel_bytes| Channel::decode(channel_bytes.as_ref()))
+            .transpose()
+            .map_err(|e| tonic::Status::aborted(format!("couldn't decode channel: {e}")))?;
+
+        let res = QueryChannelResponse {
+            channel,
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
     /// Channels queries all the IBC channels of a chain.
     async fn channels(
@@ -313,9 +367,29 @@ impl ConsensusQuery for IbcQuery {
     /// PacketCommitment queries a stored packet commitment hash.
     async fn packet_commitment(
         &self,
-        _request: tonic::Request<QueryPacketCommitmentRequest>,
+        request: tonic::Request<QueryPacketCommitmentRequest>,
     ) -> std::result::Result<tonic::Response<QueryPacketCommitmentResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let request = request.get_ref();
+
+        let chan_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid channel id: {e}")))?;
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
+
+        let (commitment, proof, height) = get_with_proof(
+            &snapshot,
+            CommitmentPath::new(&port_id, &chan_id, request.sequence.into()).to_string(),
+        )
+        .await?;
+
+        let res = QueryPacketCommitmentResponse {
+            commitment: commitment.unwrap_or_default(),
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
     /// PacketCommitments returns all the packet commitments hashes associated
     /// with a channel.
@@ -383,17 +457,57 @@ impl ConsensusQuery for IbcQuery {
     /// queried chain
     async fn packet_receipt(
         &self,
-        _request: tonic::Request<QueryPacketReceiptRequest>,
+        request: tonic::Request<QueryPacketReceiptRequest>,
     ) -> std::result::Result<tonic::Response<QueryPacketReceiptResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let request = request.get_ref();
+
+        let chan_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid channel id: {e}")))?;
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
+
+        let (receipt, proof, height) = get_with_proof(
+            &snapshot,
+            ReceiptPath::new(&port_id, &chan_id, request.sequence.into()).to_string(),
+        )
+        .await?;
+
+        let res = QueryPacketReceiptResponse {
+            received: receipt.is_some(),
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
     /// PacketAcknowledgement queries a stored packet acknowledgement hash.
     async fn packet_acknowledgement(
         &self,
-        _request: tonic::Request<QueryPacketAcknowledgementRequest>,
+        request: tonic::Request<QueryPacketAcknowledgementRequest>,
     ) -> std::result::Result<tonic::Response<QueryPacketAcknowledgementResponse>, tonic::Status>
     {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let request = request.get_ref();
+
+        let chan_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid channel id: {e}")))?;
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
+
+        let (acknowledgement, proof, height) = get_with_proof(
+            &snapshot,
+            AckPath::new(&port_id, &chan_id, request.sequence.into()).to_string(),
+        )
+        .await?;
+
+        let res = QueryPacketAcknowledgementResponse {
+            acknowledgement: acknowledgement.unwrap_or_default(),
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
     /// PacketAcknowledgements returns all the packet acknowledgements associated
     /// with a channel.
@@ -556,9 +670,35 @@ impl ConsensusQuery for IbcQuery {
     /// NextSequenceReceive returns the next receive sequence for a given channel.
     async fn next_sequence_receive(
         &self,
-        _request: tonic::Request<QueryNextSequenceReceiveRequest>,
+        request: tonic::Request<QueryNextSequenceReceiveRequest>,
     ) -> std::result::Result<tonic::Response<QueryNextSequenceReceiveResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let request = request.get_ref();
+
+        let chan_id = ChannelId::from_str(&request.channel_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid channel id: {e}")))?;
+        let port_id = PortId::from_str(&request.port_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
+
+        let (sequence, proof, height) =
+            get_with_proof(&snapshot, SeqRecvPath::new(&port_id, &chan_id).to_string()).await?;
+
+        let next_sequence_receive = match sequence {
+            Some(sequence_bytes) => u64::from_be_bytes(
+                sequence_bytes
+                    .try_into()
+                    .map_err(|_| tonic::Status::aborted("couldn't decode receive sequence"))?,
+            ),
+            None => 0,
+        };
+
+        let res = QueryNextSequenceReceiveResponse {
+            next_sequence_receive,
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
 }
 
@@ -566,9 +706,27 @@ impl ConsensusQuery for IbcQuery {
 impl ClientQuery for IbcQuery {
     async fn client_state(
         &self,
-        _request: tonic::Request<QueryClientStateRequest>,
+        request: tonic::Request<QueryClientStateRequest>,
     ) -> std::result::Result<Response<QueryClientStateResponse>, Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let client_id = ClientId::from_str(&request.get_ref().client_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+
+        let (client_state, proof, height) =
+            get_with_proof(&snapshot, ClientStatePath::new(&client_id).to_string()).await?;
+
+        let client_state = client_state
+            .map(|client_state_bytes| Any::decode(client_state_bytes.as_ref()))
+            .transpose()
+            .map_err(|e| tonic::Status::aborted(format!("couldn't decode client state: {e}")))?;
+
+        let res = QueryClientStateResponse {
+            client_state,
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
     /// ClientStates queries all the IBC light clients of a chain.
     async fn client_states(
@@ -607,9 +765,43 @@ impl ClientQuery for IbcQuery {
     /// a given height.
     async fn consensus_state(
         &self,
-        _request: tonic::Request<QueryConsensusStateRequest>,
+        request: tonic::Request<QueryConsensusStateRequest>,
     ) -> std::result::Result<tonic::Response<QueryConsensusStateResponse>, tonic::Status> {
-        Err(tonic::Status::unimplemented("not implemented"))
+        let snapshot = self.0.latest_snapshot();
+        let request = request.get_ref();
+
+        let client_id = ClientId::from_str(&request.client_id)
+            .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+
+        let consensus_height = if request.latest_height {
+            snapshot
+                .get_client_state(&client_id)
+                .await
+                .map_err(|e| tonic::Status::aborted(format!("couldn't get client state: {e}")))?
+                .latest_height()
+        } else {
+            IbcHeight::new(request.revision_number, request.revision_height)
+                .map_err(|e| tonic::Status::aborted(format!("invalid height: {e}")))?
+        };
+
+        let (consensus_state, proof, height) = get_with_proof(
+            &snapshot,
+            ClientConsensusStatePath::new(&client_id, &consensus_height).to_string(),
+        )
+        .await?;
+
+        let consensus_state = consensus_state
+            .map(|consensus_state_bytes| Any::decode(consensus_state_bytes.as_ref()))
+            .transpose()
+            .map_err(|e| tonic::Status::aborted(format!("couldn't decode consensus state: {e}")))?;
+
+        let res = QueryConsensusStateResponse {
+            consensus_state,
+            proof,
+            proof_height: Some(height),
+        };
+
+        Ok(tonic::Response::new(res))
     }
     /// ConsensusStates queries all the consensus state associated with a given
     /// client.
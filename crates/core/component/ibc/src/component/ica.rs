@@ -0,0 +1,1099 @@
+/// ICS-27 interchain accounts.
+///
+/// An interchain account is an account on a host chain which is controlled over IBC by an owner
+/// on a controller chain, which sends it transactions to execute in packets over an ordered
+/// channel between the controller's port, `icacontroller-{owner}`, and the host's port,
+/// `icahost`.
+///
+/// Penumbra is a controller for the DAO, which registers and uses accounts on other chains
+/// through governance proposals. Penumbra is also a host, so that the handshake for accounts
+/// registered on it completes as other chains expect, but it has no transparent accounts to
+/// execute transactions with, so every transaction sent to an account on Penumbra is refused with
+/// an error acknowledgement.
+///
+/// See: https://github.com/cosmos/ibc/tree/main/spec/app/ics-027-interchain-accounts
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types::{
+    core::{
+        channel::{
+            channel::{Order as ChannelOrder, State as ChannelState},
+            events,
+            msgs::{
+                MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+                MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
+                MsgTimeout,
+            },
+            ChannelEnd, ChannelId, Counterparty, PortId, Version,
+        },
+        client::Height,
+        connection::{ConnectionId, State as ConnectionState},
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+};
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_storage::{StateRead, StateWrite};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::component::{
+    app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+    channel::{StateReadExt as _, StateWriteExt as _},
+    client::StateReadExt as _,
+    connection::StateReadExt as _,
+    packet::{IBCPacket, SendPacketRead as _, SendPacketWrite as _, WriteAcknowledgement as _},
+    packet_forward::ack_error,
+    state_key,
+};
+
+/// The port interchain accounts are hosted on.
+pub const HOST_PORT: &str = "icahost";
+
+/// The prefix of the port an owner controls its interchain accounts through.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// The version of the interchain accounts protocol.
+pub const VERSION: &str = "ics27-1";
+
+const ENCODING: &str = "proto3";
+const TX_TYPE: &str = "sdk_multi_msg";
+const EXECUTE_TX: &str = "TYPE_EXECUTE_TX";
+
+/// How long a transaction sent to an interchain account has to be received. A packet which
+/// times out closes the account's channel, so this is generous.
+const TX_TIMEOUT_NANOS: u64 = 60 * 60 * 1_000_000_000;
+
+/// How many blocks of the host chain a transaction has to be received in. Packets must have a
+/// timeout height, but this is far enough out that the timestamp should time out first.
+const TX_TIMEOUT_BLOCKS: u64 = 100_000;
+
+/// The port through which `owner` controls its interchain accounts.
+pub fn controller_port(owner: &str) -> Result<PortId> {
+    format!("{CONTROLLER_PORT_PREFIX}{owner}")
+        .parse()
+        .context("invalid interchain account owner")
+}
+
+pub fn host_port() -> PortId {
+    HOST_PORT.parse().expect("host port is valid")
+}
+
+pub fn is_controller_port(port_id: &PortId) -> bool {
+    port_id.to_string().starts_with(CONTROLLER_PORT_PREFIX)
+}
+
+pub fn is_host_port(port_id: &PortId) -> bool {
+    port_id.to_string() == HOST_PORT
+}
+
+/// The address of the account hosted on Penumbra for the controller port `port_id` on the other
+/// side of `connection_id`.
+fn host_address(connection_id: &ConnectionId, port_id: &PortId) -> String {
+    let hash = blake2b_simd::Params::default()
+        .personal(b"PenumbraIcaHost")
+        .to_state()
+        .update(connection_id.to_string().as_bytes())
+        .update(port_id.to_string().as_bytes())
+        .finalize();
+    hex::encode(&hash.as_bytes()[0..20])
+}
+
+/// The version of an interchain account channel, which is negotiated during the handshake: the
+/// controller proposes it, and the host fills in the address of the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Metadata {
+    version: String,
+    controller_connection_id: String,
+    host_connection_id: String,
+    #[serde(default)]
+    address: String,
+    encoding: String,
+    tx_type: String,
+}
+
+impl Metadata {
+    fn new(controller_connection_id: &ConnectionId, host_connection_id: &ConnectionId) -> Self {
+        Metadata {
+            version: VERSION.to_string(),
+            controller_connection_id: controller_connection_id.to_string(),
+            host_connection_id: host_connection_id.to_string(),
+            address: String::new(),
+            encoding: ENCODING.to_string(),
+            tx_type: TX_TYPE.to_string(),
+        }
+    }
+
+    fn from_version(version: &Version) -> Result<Self> {
+        serde_json::from_str(&version.to_string()).context("invalid interchain account version")
+    }
+
+    fn to_version(&self) -> Version {
+        Version::new(serde_json::to_string(self).expect("able to serialize metadata"))
+    }
+
+    /// Check that the metadata is for a channel over the given connections, in a format we
+    /// understand.
+    fn validate(
+        &self,
+        controller_connection_id: &ConnectionId,
+        host_connection_id: &ConnectionId,
+    ) -> Result<()> {
+        if self.version != VERSION {
+            anyhow::bail!("interchain account version must be {VERSION}");
+        }
+        if self.encoding != ENCODING {
+            anyhow::bail!("unsupported interchain account encoding {}", self.encoding);
+        }
+        if self.tx_type != TX_TYPE {
+            anyhow::bail!("unsupported interchain account tx type {}", self.tx_type);
+        }
+        if self.controller_connection_id != controller_connection_id.to_string() {
+            anyhow::bail!("controller connection does not match channel");
+        }
+        if self.host_connection_id != host_connection_id.to_string() {
+            anyhow::bail!("host connection does not match channel");
+        }
+        Ok(())
+    }
+}
+
+/// The messages of a transaction sent to an interchain account.
+#[derive(Clone, PartialEq, Message)]
+struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    messages: Vec<pbjson_types::Any>,
+}
+
+/// The data of an interchain account packet, encoded as JSON, as ibc-go does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PacketData {
+    #[serde(rename = "type")]
+    ty: String,
+    /// The protobuf encoding of a `CosmosTx`, in base64.
+    data: String,
+    #[serde(default)]
+    memo: String,
+}
+
+impl PacketData {
+    fn execute_tx(messages: Vec<pbjson_types::Any>, memo: String) -> Self {
+        PacketData {
+            ty: EXECUTE_TX.to_string(),
+            data: base64::encode(CosmosTx { messages }.encode_to_vec()),
+            memo,
+        }
+    }
+
+    fn messages(&self) -> Result<Vec<pbjson_types::Any>> {
+        if self.ty != EXECUTE_TX {
+            anyhow::bail!("unsupported interchain account packet type {}", self.ty);
+        }
+        let data = base64::decode(&self.data).context("invalid interchain account packet data")?;
+        Ok(CosmosTx::decode(data.as_slice())
+            .context("invalid interchain account transaction")?
+            .messages)
+    }
+}
+
+/// The counterparty connection of `connection_id`, which must be open.
+async fn counterparty_connection_id<S: StateRead + ?Sized>(
+    state: &S,
+    connection_id: &ConnectionId,
+) -> Result<ConnectionId> {
+    let connection = state
+        .get_connection(connection_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("connection {connection_id} does not exist"))?;
+    if connection.state != ConnectionState::Open {
+        anyhow::bail!("connection {connection_id} is not open");
+    }
+    connection
+        .counterparty
+        .connection_id
+        .ok_or_else(|| anyhow::anyhow!("no counterparty connection id for {connection_id}"))
+}
+
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// The channel of the interchain account controlled through `port_id` over `connection_id`,
+    /// if one has been opened.
+    async fn get_ica_controller_channel(
+        &self,
+        port_id: &PortId,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<ChannelId>> {
+        self.get_raw(&state_key::ica_controller_channel(port_id, connection_id))
+            .await?
+            .map(|bytes| {
+                String::from_utf8(bytes)?
+                    .parse()
+                    .context("invalid interchain account channel")
+            })
+            .transpose()
+    }
+
+    /// The address on the host chain of the interchain account controlled through `port_id` over
+    /// `connection_id`, once its channel has been opened.
+    async fn get_ica_controller_address(
+        &self,
+        port_id: &PortId,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<String>> {
+        self.get_raw(&state_key::ica_controller_address(port_id, connection_id))
+            .await?
+            .map(|bytes| String::from_utf8(bytes).context("invalid interchain account address"))
+            .transpose()
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub trait StateWriteExt: StateWrite + StateReadExt {
+    /// Register an interchain account for `owner` on the chain at the other end of
+    /// `connection_id`, by starting the handshake for its channel, as though `ChanOpenInit` had
+    /// executed on the owner's controller port.
+    ///
+    /// An account whose channel was closed can be registered again, which opens a new channel
+    /// to the same account.
+    async fn register_interchain_account(
+        &mut self,
+        owner: &str,
+        connection_id: &ConnectionId,
+    ) -> Result<ChannelId> {
+        let port_id = controller_port(owner)?;
+        if let Some(channel_id) = self
+            .get_ica_controller_channel(&port_id, connection_id)
+            .await?
+        {
+            let channel = self.get_channel(&channel_id, &port_id).await?;
+            if channel.map_or(false, |channel| {
+                !channel.state_matches(&ChannelState::Closed)
+            }) {
+                anyhow::bail!(
+                    "{owner} already has an interchain account over {connection_id} on {channel_id}"
+                );
+            }
+        }
+
+        let host_connection_id = counterparty_connection_id(&*self, connection_id).await?;
+        let mut metadata = Metadata::new(connection_id, &host_connection_id);
+        if let Some(address) = self
+            .get_ica_controller_address(&port_id, connection_id)
+            .await?
+        {
+            metadata.address = address;
+        }
+
+        let channel_id = self.next_channel_id().await?;
+        let channel = ChannelEnd {
+            state: ChannelState::Init,
+            ordering: ChannelOrder::Ordered,
+            remote: Counterparty::new(host_port(), None),
+            connection_hops: vec![connection_id.clone()],
+            version: metadata.to_version(),
+        };
+
+        self.put_channel(&channel_id, &port_id, channel.clone());
+        self.put_send_sequence(&channel_id, &port_id, 1);
+        self.put_recv_sequence(&channel_id, &port_id, 1);
+        self.put_ack_sequence(&channel_id, &port_id, 1);
+        self.put_raw(
+            state_key::ica_controller_channel(&port_id, connection_id),
+            channel_id.to_string().into_bytes(),
+        );
+
+        self.record(
+            events::channel::OpenInit {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                counterparty_port_id: channel.counterparty().port_id().clone(),
+                connection_id: connection_id.clone(),
+                version: channel.version.clone(),
+            }
+            .into(),
+        );
+
+        tracing::info!(%owner, %connection_id, %channel_id, "registering interchain account");
+        Ok(channel_id)
+    }
+
+    /// Send a transaction made of `messages` to the interchain account of `owner` on the chain at
+    /// the other end of `connection_id`, to be executed by the account.
+    async fn send_interchain_tx(
+        &mut self,
+        owner: &str,
+        connection_id: &ConnectionId,
+        messages: Vec<pbjson_types::Any>,
+        memo: String,
+    ) -> Result<()> {
+        if messages.is_empty() {
+            anyhow::bail!("interchain account transactions must have at least one message");
+        }
+
+        let port_id = controller_port(owner)?;
+        let channel_id = self
+            .get_ica_controller_channel(&port_id, connection_id)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("{owner} has no interchain account over {connection_id}")
+            })?;
+        let channel = self
+            .get_channel(&channel_id, &port_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("channel {channel_id} does not exist"))?;
+        if !channel.state_matches(&ChannelState::Open) {
+            anyhow::bail!("interchain account channel {channel_id} is not open");
+        }
+
+        let connection = self
+            .get_connection(connection_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("connection {connection_id} does not exist"))?;
+        let latest_height = self
+            .get_client_state(&connection.client_id)
+            .await?
+            .latest_height();
+        let timeout_height = Height::new(
+            latest_height.revision_number(),
+            latest_height.revision_height() + TX_TIMEOUT_BLOCKS,
+        )?;
+        let now: ibc_types::timestamp::Timestamp = self.get_block_timestamp().await?.into();
+        let timeout_timestamp = now.nanoseconds().saturating_add(TX_TIMEOUT_NANOS);
+
+        let packet = IBCPacket::new(
+            port_id,
+            channel_id.clone(),
+            timeout_height,
+            timeout_timestamp,
+            serde_json::to_vec(&PacketData::execute_tx(messages, memo))?,
+        );
+        let packet = self.send_packet_check(packet).await?;
+        self.send_packet_execute(packet).await;
+
+        tracing::info!(%owner, %connection_id, %channel_id, "sent interchain account transaction");
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+/// Check a controller's request to open a channel from `controller_port_id` to the host port,
+/// over `host_connection_id`.
+async fn host_open_try_check<S: StateRead>(
+    state: S,
+    ordering: ChannelOrder,
+    controller_port_id: &PortId,
+    host_connection_id: &ConnectionId,
+    version: &Version,
+) -> Result<()> {
+    if ordering != ChannelOrder::Ordered {
+        anyhow::bail!("channel order must be ordered for interchain accounts");
+    }
+    if !is_controller_port(controller_port_id) {
+        anyhow::bail!("counterparty port must be an interchain account controller port");
+    }
+
+    let controller_connection_id = counterparty_connection_id(&state, host_connection_id).await?;
+    let metadata = Metadata::from_version(version)?;
+    metadata.validate(&controller_connection_id, host_connection_id)?;
+
+    // a controller reopening a channel to an existing account names it
+    if !metadata.address.is_empty()
+        && metadata.address != host_address(host_connection_id, controller_port_id)
+    {
+        anyhow::bail!("interchain account address does not match");
+    }
+
+    Ok(())
+}
+
+/// The version the host answers a controller's proposed `version` with, which names the account.
+fn host_version(
+    host_connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+    version: &Version,
+) -> Version {
+    match Metadata::from_version(version) {
+        Ok(mut metadata) => {
+            metadata.address = host_address(host_connection_id, controller_port_id);
+            metadata.to_version()
+        }
+        Err(_) => version.clone(),
+    }
+}
+
+/// The app handler for the host port.
+pub struct IcaHost {}
+
+#[async_trait]
+impl AppHandlerCheck for IcaHost {
+    async fn chan_open_init_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        anyhow::bail!("interchain account channels can only be opened by the controller");
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        host_open_try_check(
+            state,
+            msg.ordering,
+            &msg.port_id_on_a,
+            &msg.connection_hops_on_b[0],
+            &msg.version_supported_on_a,
+        )
+        .await
+    }
+
+    fn chan_open_try_version(msg: &MsgChannelOpenTry) -> Version {
+        host_version(
+            &msg.connection_hops_on_b[0],
+            &msg.port_id_on_a,
+            &msg.version_supported_on_a,
+        )
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(_state: S, _msg: &MsgChannelOpenAck) -> Result<()> {
+        anyhow::bail!("interchain account channels can only be opened by the controller");
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        anyhow::bail!("interchain account channels cannot be closed by users");
+    }
+
+    async fn recv_packet_check<S: StateRead>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
+        // transactions which can't be executed are refused with an error acknowledgement.
+        Ok(())
+    }
+
+    async fn timeout_packet_check<S: StateRead>(_state: S, _msg: &MsgTimeout) -> Result<()> {
+        anyhow::bail!("interchain account hosts do not send packets");
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        anyhow::bail!("interchain account hosts do not send packets");
+    }
+}
+
+/// Execute a transaction sent to an account hosted on Penumbra.
+fn execute_tx(packet_data: &[u8]) -> Result<()> {
+    let packet_data: PacketData =
+        serde_json::from_slice(packet_data).context("invalid interchain account packet")?;
+    let messages = packet_data.messages()?;
+    match messages.first() {
+        None => anyhow::bail!("interchain account transactions must have at least one message"),
+        // there are no transparent accounts on Penumbra, so no messages are allowed.
+        Some(message) => anyhow::bail!(
+            "message type {} is not allowed for interchain accounts",
+            message.type_url
+        ),
+    }
+}
+
+#[async_trait]
+impl AppHandlerExecute for IcaHost {
+    async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
+    async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
+    async fn chan_open_ack_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenAck) {}
+    async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
+    async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
+    async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
+
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
+        let ack: Vec<u8> = match execute_tx(&msg.packet.data) {
+            Ok(()) => TokenTransferAcknowledgement::success().into(),
+            Err(e) => {
+                tracing::debug!("couldnt execute interchain account tx: {:#}", e);
+                TokenTransferAcknowledgement::Error(e.to_string()).into()
+            }
+        };
+
+        state
+            .write_acknowledgement(&msg.packet, &ack)
+            .await
+            .expect("able to write acknowledgement");
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(_state: S, _msg: &MsgTimeout) {}
+    async fn acknowledge_packet_execute<S: StateWrite>(_state: S, _msg: &MsgAcknowledgement) {}
+}
+
+impl AppHandler for IcaHost {}
+
+/// The app handler for controller ports.
+pub struct IcaController {}
+
+#[async_trait]
+impl AppHandlerCheck for IcaController {
+    async fn chan_open_init_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        // the chain opens controller channels itself, when an account is registered.
+        anyhow::bail!("interchain account channels can only be opened by their owner");
+    }
+
+    async fn chan_open_try_check<S: StateRead>(_state: S, _msg: &MsgChannelOpenTry) -> Result<()> {
+        anyhow::bail!("interchain account channels can only be opened by their owner");
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        let channel = state
+            .get_channel(&msg.chan_id_on_a, &msg.port_id_on_a)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
+        let controller_connection_id = &channel.connection_hops[0];
+        let host_connection_id =
+            counterparty_connection_id(&state, controller_connection_id).await?;
+
+        let metadata = Metadata::from_version(&msg.version_on_b)?;
+        metadata.validate(controller_connection_id, &host_connection_id)?;
+        if metadata.address.is_empty() {
+            anyhow::bail!("host did not provide an interchain account address");
+        }
+        if let Some(address) = state
+            .get_ica_controller_address(&msg.port_id_on_a, controller_connection_id)
+            .await?
+        {
+            if metadata.address != address {
+                anyhow::bail!("interchain account address does not match");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        anyhow::bail!("interchain account channels can only be opened by their owner");
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        anyhow::bail!("interchain account channels cannot be closed by users");
+    }
+
+    async fn recv_packet_check<S: StateRead>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
+        anyhow::bail!("interchain account controllers do not receive packets");
+    }
+
+    async fn timeout_packet_check<S: StateRead>(_state: S, _msg: &MsgTimeout) -> Result<()> {
+        Ok(())
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AppHandlerExecute for IcaController {
+    async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
+    async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
+
+    async fn chan_open_ack_execute<S: StateWrite>(mut state: S, msg: &MsgChannelOpenAck) {
+        let channel = state
+            .get_channel(&msg.chan_id_on_a, &msg.port_id_on_a)
+            .await
+            .expect("able to get channel")
+            .expect("channel exists");
+        let metadata =
+            Metadata::from_version(&msg.version_on_b).expect("version has been validated");
+
+        tracing::info!(
+            port_id = %msg.port_id_on_a,
+            connection_id = %channel.connection_hops[0],
+            address = %metadata.address,
+            "opened interchain account"
+        );
+        state.put_raw(
+            state_key::ica_controller_address(&msg.port_id_on_a, &channel.connection_hops[0]),
+            metadata.address.into_bytes(),
+        );
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
+    async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
+    async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
+    async fn recv_packet_execute<S: StateWrite>(_state: S, _msg: &MsgRecvPacket) {}
+
+    async fn timeout_packet_execute<S: StateWrite>(_state: S, msg: &MsgTimeout) {
+        // the timeout closes the channel, after which the account must be registered again.
+        tracing::info!(
+            port_id = %msg.packet.port_on_a,
+            channel_id = %msg.packet.chan_on_a,
+            "interchain account transaction timed out"
+        );
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(_state: S, msg: &MsgAcknowledgement) {
+        match ack_error(&msg.acknowledgement) {
+            None => tracing::info!(
+                port_id = %msg.packet.port_on_a,
+                channel_id = %msg.packet.chan_on_a,
+                "interchain account transaction executed"
+            ),
+            Some(error) => tracing::info!(
+                port_id = %msg.packet.port_on_a,
+                channel_id = %msg.packet.chan_on_a,
+                %error,
+                "interchain account transaction failed"
+            ),
+        }
+    }
+}
+
+impl AppHandler for IcaController {}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use ibc_types::core::{
+        channel::Packet,
+        client::msgs::MsgCreateClient,
+        commitment::{MerklePrefix, MerkleProof},
+        connection::{
+            ConnectionEnd, Counterparty as ConnectionCounterparty, Version as ConnectionVersion,
+        },
+    };
+    use ibc_types::DomainType;
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_component::ActionHandler;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+    use tendermint::Time;
+
+    use super::*;
+    use crate::{component::connection::StateWriteExt as _, IbcAction};
+
+    #[test]
+    fn metadata_is_validated() {
+        let controller: ConnectionId = "connection-0".parse().unwrap();
+        let host: ConnectionId = "connection-7".parse().unwrap();
+
+        let metadata = Metadata::new(&controller, &host);
+        let version = metadata.to_version();
+        let metadata = Metadata::from_version(&version).unwrap();
+        assert!(metadata.validate(&controller, &host).is_ok());
+        assert!(metadata.validate(&host, &controller).is_err());
+
+        let mut json: serde_json::Value = serde_json::from_str(&version.to_string()).unwrap();
+        json["encoding"] = "proto3json".into();
+        let metadata = Metadata::from_version(&Version::new(json.to_string())).unwrap();
+        assert!(metadata.validate(&controller, &host).is_err());
+
+        assert!(Metadata::from_version(&Version::new("ics20-1".to_string())).is_err());
+    }
+
+    #[test]
+    fn packets_round_trip() {
+        let message = pbjson_types::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3].into(),
+        };
+        let packet_data = PacketData::execute_tx(vec![message.clone()], "gm".to_string());
+        let json = serde_json::to_vec(&packet_data).unwrap();
+        let packet_data: PacketData = serde_json::from_slice(&json).unwrap();
+        assert_eq!(packet_data.memo, "gm");
+        assert_eq!(packet_data.messages().unwrap(), vec![message]);
+
+        // but no messages are allowed on accounts hosted on Penumbra:
+        assert!(execute_tx(&json).is_err());
+        assert!(execute_tx(b"garbage").is_err());
+    }
+
+    #[test]
+    fn ports_are_recognized() {
+        let port = controller_port("dao").unwrap();
+        assert_eq!(port.to_string(), "icacontroller-dao");
+        assert!(is_controller_port(&port));
+        assert!(!is_host_port(&port));
+        assert!(is_host_port(&host_port()));
+        assert!(!is_controller_port(&host_port()));
+        assert!(!is_controller_port(&PortId::transfer()));
+    }
+
+    /// The host's acknowledgement of the handshake for the controller channel `channel_id`,
+    /// naming the account `address`.
+    fn open_ack(
+        channel_id: &ChannelId,
+        controller_connection_id: &ConnectionId,
+        host_connection_id: &ConnectionId,
+        address: &str,
+    ) -> MsgChannelOpenAck {
+        let mut metadata = Metadata::new(controller_connection_id, host_connection_id);
+        metadata.address = address.to_string();
+        MsgChannelOpenAck {
+            port_id_on_a: controller_port("dao").unwrap(),
+            chan_id_on_a: channel_id.clone(),
+            chan_id_on_b: ChannelId::new(3),
+            version_on_b: metadata.to_version(),
+            proof_chan_end_on_b: MerkleProof { proofs: vec![] },
+            proof_height_on_b: Height::new(0, 1).unwrap(),
+            signer: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn dao_registers_and_uses_an_interchain_account() -> anyhow::Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let timestamp = Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_chain_params(Default::default());
+        state_tx.put_block_height(1);
+        state_tx.put_block_timestamp(timestamp);
+        state_tx.put_epoch_by_height(
+            1,
+            penumbra_chain::Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+        state_tx.apply();
+
+        // The host chain has a client on Penumbra, and a connection open over it.
+        let msg_create_client =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let create_client_action =
+            IbcAction::CreateClient(MsgCreateClient::decode(msg_create_client.as_slice())?);
+        create_client_action.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_client_action.execute(&mut state_tx).await?;
+
+        let controller: ConnectionId = "connection-0".parse()?;
+        let host: ConnectionId = "connection-7".parse()?;
+        state_tx
+            .put_new_connection(
+                &controller,
+                ConnectionEnd {
+                    state: ConnectionState::Open,
+                    client_id: "07-tendermint-0".parse()?,
+                    counterparty: ConnectionCounterparty {
+                        client_id: "07-tendermint-3".parse()?,
+                        connection_id: Some(host.clone()),
+                        prefix: MerklePrefix {
+                            key_prefix: b"ibc".to_vec(),
+                        },
+                    },
+                    versions: vec![ConnectionVersion::default()],
+                    delay_period: Duration::ZERO,
+                },
+            )
+            .await?;
+
+        // Registering the DAO's account starts the handshake for its channel...
+        let port_id = controller_port("dao")?;
+        let channel_id = state_tx
+            .register_interchain_account("dao", &controller)
+            .await?;
+        let channel = state_tx.get_channel(&channel_id, &port_id).await?.unwrap();
+        assert!(channel.state_matches(&ChannelState::Init));
+        assert_eq!(channel.ordering, ChannelOrder::Ordered);
+        assert_eq!(channel.counterparty().port_id, host_port());
+        assert!(state_tx
+            .register_interchain_account("dao", &controller)
+            .await
+            .is_err());
+        // ...and no transactions can be sent to the account until it completes.
+        let message = pbjson_types::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3].into(),
+        };
+        assert!(state_tx
+            .send_interchain_tx("dao", &controller, vec![message.clone()], String::new())
+            .await
+            .is_err());
+
+        // The handshake completes once the host names the account, as the relayer acknowledges
+        // it, and the channel is opened.
+        let ack = open_ack(&channel_id, &controller, &host, "");
+        assert!(IcaController::chan_open_ack_check(&mut state_tx, &ack)
+            .await
+            .is_err());
+        let ack = open_ack(&channel_id, &controller, &host, "cosmos1account");
+        IcaController::chan_open_ack_check(&mut state_tx, &ack).await?;
+        IcaController::chan_open_ack_execute(&mut state_tx, &ack).await;
+        let mut channel = channel;
+        channel.set_state(ChannelState::Open);
+        state_tx.put_channel(&channel_id, &port_id, channel.clone());
+        assert_eq!(
+            state_tx
+                .get_ica_controller_address(&port_id, &controller)
+                .await?,
+            Some("cosmos1account".to_string())
+        );
+
+        // Transactions can then be sent to the account, whose packets are acknowledged...
+        assert!(state_tx
+            .send_interchain_tx("dao", &controller, vec![], String::new())
+            .await
+            .is_err());
+        state_tx
+            .send_interchain_tx("dao", &controller, vec![message.clone()], "gm".to_string())
+            .await?;
+        assert_eq!(state_tx.get_send_sequence(&channel_id, &port_id).await?, 2);
+        assert!(state_tx
+            .get_packet_commitment_by_id(&channel_id, &port_id, 1)
+            .await?
+            .is_some());
+
+        let packet = Packet {
+            sequence: 1u64.into(),
+            port_on_a: port_id.clone(),
+            chan_on_a: channel_id.clone(),
+            port_on_b: host_port(),
+            chan_on_b: ChannelId::new(3),
+            data: serde_json::to_vec(&PacketData::execute_tx(
+                vec![message.clone()],
+                "gm".to_string(),
+            ))?,
+            timeout_height_on_b: Height::new(0, 1_000)?.into(),
+            timeout_timestamp_on_b: ibc_types::timestamp::Timestamp::from_nanoseconds(1)?,
+        };
+        for acknowledgement in [
+            br#"{"result":"AQ=="}"#.to_vec(),
+            br#"{"error":"message type not allowed"}"#.to_vec(),
+        ] {
+            let msg = MsgAcknowledgement {
+                packet: packet.clone(),
+                acknowledgement,
+                proof_acked_on_b: MerkleProof { proofs: vec![] },
+                proof_height_on_b: Height::new(0, 1)?,
+                signer: String::new(),
+            };
+            IcaController::acknowledge_packet_check(&mut state_tx, &msg).await?;
+            IcaController::acknowledge_packet_execute(&mut state_tx, &msg).await;
+        }
+
+        // ...or time out, which closes the ordered channel.
+        let msg = MsgTimeout {
+            packet,
+            next_seq_recv_on_b: 1u64.into(),
+            proof_unreceived_on_b: MerkleProof { proofs: vec![] },
+            proof_height_on_b: Height::new(0, 1)?,
+            signer: String::new(),
+        };
+        IcaController::timeout_packet_check(&mut state_tx, &msg).await?;
+        IcaController::timeout_packet_execute(&mut state_tx, &msg).await;
+        channel.set_state(ChannelState::Closed);
+        state_tx.put_channel(&channel_id, &port_id, channel);
+        assert!(state_tx
+            .send_interchain_tx("dao", &controller, vec![message], String::new())
+            .await
+            .is_err());
+
+        // Registering the account again opens a new channel to the same account, which the host
+        // can't swap for another.
+        let new_channel_id = state_tx
+            .register_interchain_account("dao", &controller)
+            .await?;
+        assert_ne!(new_channel_id, channel_id);
+        let new_channel = state_tx
+            .get_channel(&new_channel_id, &port_id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            Metadata::from_version(&new_channel.version)?.address,
+            "cosmos1account"
+        );
+        let ack = open_ack(&new_channel_id, &controller, &host, "cosmos1other");
+        assert!(IcaController::chan_open_ack_check(&mut state_tx, &ack)
+            .await
+            .is_err());
+        let ack = open_ack(&new_channel_id, &controller, &host, "cosmos1account");
+        IcaController::chan_open_ack_check(&mut state_tx, &ack).await?;
+
+        Ok(())
+    }
+
+    /// A state with a connection on Penumbra, `host`, open to `controller` on the other chain.
+    async fn hosting_state(
+        controller: &ConnectionId,
+        host: &ConnectionId,
+    ) -> anyhow::Result<StateDelta<()>> {
+        let mut state = StateDelta::new(());
+        state
+            .put_new_connection(
+                host,
+                ConnectionEnd {
+                    state: ConnectionState::Open,
+                    client_id: "07-tendermint-0".parse()?,
+                    counterparty: ConnectionCounterparty {
+                        client_id: "07-tendermint-3".parse()?,
+                        connection_id: Some(controller.clone()),
+                        prefix: MerklePrefix {
+                            key_prefix: b"ibc".to_vec(),
+                        },
+                    },
+                    versions: vec![ConnectionVersion::default()],
+                    delay_period: Duration::ZERO,
+                },
+            )
+            .await?;
+        Ok(state)
+    }
+
+    #[tokio::test]
+    async fn controllers_open_channels_to_hosted_accounts() -> anyhow::Result<()> {
+        let controller: ConnectionId = "connection-0".parse()?;
+        let host: ConnectionId = "connection-7".parse()?;
+        let state = hosting_state(&controller, &host).await?;
+        let port_id = controller_port("dao")?;
+        let proposed = Metadata::new(&controller, &host).to_version();
+
+        host_open_try_check(&state, ChannelOrder::Ordered, &port_id, &host, &proposed).await?;
+
+        // The host names the account, whose address is determined by the controller's port and
+        // connection.
+        let version = host_version(&host, &port_id, &proposed);
+        let address = Metadata::from_version(&version)?.address;
+        assert_eq!(address, host_address(&host, &port_id));
+        assert_ne!(address, host_address(&host, &controller_port("other")?));
+        assert_ne!(address, host_address(&"connection-1".parse()?, &port_id));
+
+        // A controller reopening a channel to its account names it, and can't take over another.
+        host_open_try_check(&state, ChannelOrder::Ordered, &port_id, &host, &version).await?;
+        let mut metadata = Metadata::new(&controller, &host);
+        metadata.address = host_address(&host, &controller_port("other")?);
+        assert!(host_open_try_check(
+            &state,
+            ChannelOrder::Ordered,
+            &port_id,
+            &host,
+            &metadata.to_version()
+        )
+        .await
+        .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn host_handshake_is_checked() -> anyhow::Result<()> {
+        let controller: ConnectionId = "connection-0".parse()?;
+        let host: ConnectionId = "connection-7".parse()?;
+        let state = hosting_state(&controller, &host).await?;
+        let port_id = controller_port("dao")?;
+        let proposed = Metadata::new(&controller, &host).to_version();
+
+        // Channels must be ordered...
+        assert!(
+            host_open_try_check(&state, ChannelOrder::Unordered, &port_id, &host, &proposed)
+                .await
+                .is_err()
+        );
+        // ...from a controller port...
+        assert!(host_open_try_check(
+            &state,
+            ChannelOrder::Ordered,
+            &PortId::transfer(),
+            &host,
+            &proposed
+        )
+        .await
+        .is_err());
+        // ...over an open connection...
+        assert!(host_open_try_check(
+            &state,
+            ChannelOrder::Ordered,
+            &port_id,
+            &"connection-1".parse()?,
+            &proposed
+        )
+        .await
+        .is_err());
+        // ...which the metadata names the right way round.
+        assert!(host_open_try_check(
+            &state,
+            ChannelOrder::Ordered,
+            &port_id,
+            &host,
+            &Metadata::new(&host, &controller).to_version()
+        )
+        .await
+        .is_err());
+        assert!(host_open_try_check(
+            &state,
+            ChannelOrder::Ordered,
+            &port_id,
+            &host,
+            &Version::new(VERSION.to_string())
+        )
+        .await
+        .is_err());
+
+        // Only the controller opens channels, and they can't be closed by users.
+        let close = MsgChannelCloseInit {
+            port_id_on_a: host_port(),
+            chan_id_on_a: ChannelId::new(0),
+            signer: String::new(),
+        };
+        assert!(IcaHost::chan_close_init_check(&state, &close)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hosted_account_channels_can_be_closed_by_governance() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+        state.put_channel(
+            &ChannelId::new(0),
+            &host_port(),
+            ChannelEnd {
+                state: ChannelState::Open,
+                ordering: ChannelOrder::Ordered,
+                remote: Counterparty::new(controller_port("dao")?, Some(ChannelId::new(3))),
+                connection_hops: vec!["connection-0".parse()?],
+                version: Version::new(VERSION.to_string()),
+            },
+        );
+
+        state
+            .close_channel(&ChannelId::new(0), &host_port())
+            .await?;
+        let channel = state
+            .get_channel(&ChannelId::new(0), &host_port())
+            .await?
+            .unwrap();
+        assert!(channel.state_matches(&ChannelState::Closed));
+
+        Ok(())
+    }
+}
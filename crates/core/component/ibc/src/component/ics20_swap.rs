@@ -0,0 +1,146 @@
+/// Swaps of inbound ICS-20 transfers on arrival.
+///
+/// A transfer into Penumbra can ask for the funds received to be swapped into another asset,
+/// rather than minted to the receiver as a note, with a memo like:
+///
+/// ```json
+/// {"swap": {"asset": "upenumbra", "min_output": "1000000"}}
+/// ```
+///
+/// where `min_output` is optional. The chain makes the swap itself, with the receiver as its
+/// claim address, and the receiver claims its outputs later with a `SwapClaim`.
+use anyhow::{Context, Result};
+use ibc_types::core::channel::Packet;
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{swap::SwapPlaintext, TradingPair};
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_shielded_pool::Rseed;
+use serde::Deserialize;
+
+/// The instructions for swapping a transfer on arrival, from the `swap` field of its memo.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct SwapMemo {
+    /// The denom of the asset to swap into.
+    asset: String,
+    /// The least of the asset the swap must output, or its input is returned when it's claimed.
+    #[serde(default)]
+    min_output: Option<String>,
+}
+
+/// A swap of a received transfer into another asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ics20Swap {
+    /// The asset to swap into.
+    pub asset_id: asset::Id,
+    /// The least of the asset the swap must output, or zero for no limit.
+    pub min_output: Amount,
+}
+
+impl Ics20Swap {
+    /// Parse the swap instructions from a transfer's memo, if it has any.
+    ///
+    /// Memos which aren't JSON or have no `swap` instructions are ignored, but malformed
+    /// instructions are an error.
+    pub fn from_memo(memo: &str) -> Result<Option<Self>> {
+        let memo: serde_json::Value = match serde_json::from_str(memo) {
+            Ok(memo) => memo,
+            Err(_) => return Ok(None),
+        };
+        let swap: SwapMemo = match memo.get("swap") {
+            None => return Ok(None),
+            Some(swap) => serde_json::from_value(swap.clone()).context("invalid swap memo")?,
+        };
+
+        let denom: asset::DenomMetadata = swap
+            .asset
+            .as_str()
+            .try_into()
+            .context("invalid asset in swap memo")?;
+        let min_output = match swap.min_output {
+            None => Amount::zero(),
+            Some(min_output) => min_output
+                .try_into()
+                .context("invalid minimum output in swap memo")?,
+        };
+
+        Ok(Some(Ics20Swap {
+            asset_id: denom.id(),
+            min_output,
+        }))
+    }
+
+    /// The swap of `value`, received in `packet`, claimable by `claim_address`.
+    pub fn swap_plaintext(
+        &self,
+        packet: &Packet,
+        value: Value,
+        claim_address: Address,
+    ) -> Result<SwapPlaintext> {
+        anyhow::ensure!(
+            value.asset_id != self.asset_id,
+            "cannot swap a transfer into the asset it's made in"
+        );
+
+        let trading_pair = TradingPair::new(value.asset_id, self.asset_id);
+        let (delta_1_i, delta_2_i) = if trading_pair.asset_1() == value.asset_id {
+            (value.amount, Amount::zero())
+        } else {
+            (Amount::zero(), value.amount)
+        };
+
+        // The swap is public, so we don't need a blinding factor for privacy, but the swap
+        // commitment still needs to be unique, so we derive the rseed from the packet, which can
+        // only be received once.
+        let rseed_bytes: [u8; 32] = blake2b_simd::Params::default()
+            .personal(b"PenumbraIcsSwap")
+            .to_state()
+            .update(packet.port_on_b.to_string().as_bytes())
+            .update(packet.chan_on_b.to_string().as_bytes())
+            .update(&u64::from(packet.sequence).to_le_bytes())
+            .finalize()
+            .as_bytes()[0..32]
+            .try_into()?;
+
+        Ok(SwapPlaintext {
+            trading_pair,
+            delta_1_i,
+            delta_2_i,
+            // The swap is claimed by a transaction of its own, which pays its own fee.
+            claim_fee: Default::default(),
+            claim_address,
+            rseed: Rseed(rseed_bytes),
+            min_output: self.min_output,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swaps_are_parsed_from_memo() {
+        assert_eq!(Ics20Swap::from_memo("").unwrap(), None);
+        assert_eq!(Ics20Swap::from_memo("gm").unwrap(), None);
+        assert_eq!(Ics20Swap::from_memo(r#"{"forward": {}}"#).unwrap(), None);
+
+        let swap = Ics20Swap::from_memo(r#"{"swap": {"asset": "upenumbra"}}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!(swap.asset_id, *penumbra_asset::STAKING_TOKEN_ASSET_ID);
+        assert_eq!(swap.min_output, Amount::zero());
+
+        let swap =
+            Ics20Swap::from_memo(r#"{"swap": {"asset": "upenumbra", "min_output": "1000"}}"#)
+                .unwrap()
+                .unwrap();
+        assert_eq!(swap.min_output, 1000u64.into());
+
+        assert!(Ics20Swap::from_memo(r#"{"swap": {}}"#).is_err());
+        assert!(
+            Ics20Swap::from_memo(r#"{"swap": {"asset": "upenumbra", "min_output": "lots"}}"#)
+                .is_err()
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use ibc_types::core::{
-    channel::channel::Order as ChannelOrder, channel::channel::State as ChannelState,
-    channel::events, channel::msgs::MsgAcknowledgement, channel::PortId,
-    connection::State as ConnectionState,
+    channel::channel::Order as ChannelOrder, channel::events, channel::msgs::MsgAcknowledgement,
+    channel::PortId, connection::State as ConnectionState,
 };
 use penumbra_storage::StateWrite;
 
 use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
-    channel::{StateReadExt as _, StateWriteExt as _},
+    channel::{settles_packets, StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
+    ica::{self, IcaController, IcaHost},
     proof_verification::{commit_packet, PacketProofVerifier},
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -30,8 +30,8 @@ impl MsgHandler for MsgAcknowledgement {
             .get_channel(&self.packet.chan_on_a, &self.packet.port_on_a)
             .await?
             .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
-        if !channel.state_matches(&ChannelState::Open) {
-            anyhow::bail!("channel is not open");
+        if !settles_packets(&channel) {
+            anyhow::bail!("channel can no longer settle packets");
         }
 
         // TODO: capability authentication?
@@ -72,8 +72,12 @@ impl MsgHandler for MsgAcknowledgement {
         }
 
         let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::acknowledge_packet_check(&mut state, self).await?;
+        if self.packet.port_on_a == transfer {
+            Ics20TransferApp::acknowledge_packet_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.packet.port_on_a) {
+            IcaHost::acknowledge_packet_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.packet.port_on_a) {
+            IcaController::acknowledge_packet_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -112,8 +116,12 @@ impl MsgHandler for MsgAcknowledgement {
         );
 
         let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::acknowledge_packet_execute(state, self).await;
+        if self.packet.port_on_a == transfer {
+            Ics20TransferApp::acknowledge_packet_execute(state, self).await;
+        } else if ica::is_host_port(&self.packet.port_on_a) {
+            IcaHost::acknowledge_packet_execute(state, self).await;
+        } else if ica::is_controller_port(&self.packet.port_on_a) {
+            IcaController::acknowledge_packet_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
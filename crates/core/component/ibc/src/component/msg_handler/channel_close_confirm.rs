@@ -13,8 +13,9 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
+    ica::{self, IcaController, IcaHost},
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -83,7 +84,11 @@ impl MsgHandler for MsgChannelCloseConfirm {
 
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_close_confirm_check(&mut state, self).await?;
+            Ics20TransferApp::chan_close_confirm_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.port_id_on_b) {
+            IcaHost::chan_close_confirm_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.port_id_on_b) {
+            IcaController::chan_close_confirm_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -107,7 +112,11 @@ impl MsgHandler for MsgChannelCloseConfirm {
 
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_close_confirm_execute(state, self).await;
+            Ics20TransferApp::chan_close_confirm_execute(state, self).await;
+        } else if ica::is_host_port(&self.port_id_on_b) {
+            IcaHost::chan_close_confirm_execute(state, self).await;
+        } else if ica::is_controller_port(&self.port_id_on_b) {
+            IcaController::chan_close_confirm_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -11,8 +11,9 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
+    ica::{self, IcaController, IcaHost},
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -67,7 +68,11 @@ impl MsgHandler for MsgChannelOpenAck {
 
         let transfer = PortId::transfer();
         if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_ack_check(&mut state, self).await?;
+            Ics20TransferApp::chan_open_ack_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.port_id_on_a) {
+            IcaHost::chan_open_ack_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.port_id_on_a) {
+            IcaController::chan_open_ack_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -94,7 +99,11 @@ impl MsgHandler for MsgChannelOpenAck {
 
         let transfer = PortId::transfer();
         if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_ack_execute(state, self).await;
+            Ics20TransferApp::chan_open_ack_execute(state, self).await;
+        } else if ica::is_host_port(&self.port_id_on_a) {
+            IcaHost::chan_open_ack_execute(state, self).await;
+        } else if ica::is_controller_port(&self.port_id_on_a) {
+            IcaController::chan_open_ack_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -11,8 +11,9 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
+    ica::{self, IcaController, IcaHost},
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -78,7 +79,11 @@ impl MsgHandler for MsgChannelOpenConfirm {
 
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_confirm_check(&mut state, self).await?;
+            Ics20TransferApp::chan_open_confirm_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.port_id_on_b) {
+            IcaHost::chan_open_confirm_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.port_id_on_b) {
+            IcaController::chan_open_confirm_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -103,7 +108,11 @@ impl MsgHandler for MsgChannelOpenConfirm {
 
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_confirm_execute(state, self).await;
+            Ics20TransferApp::chan_open_confirm_execute(state, self).await;
+        } else if ica::is_host_port(&self.port_id_on_b) {
+            IcaHost::chan_open_confirm_execute(state, self).await;
+        } else if ica::is_controller_port(&self.port_id_on_b) {
+            IcaController::chan_open_confirm_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -10,7 +10,8 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
-    transfer::Ics20Transfer,
+    ica::{self, IcaController, IcaHost},
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -36,7 +37,11 @@ impl MsgHandler for MsgChannelOpenInit {
 
         let transfer = PortId::transfer();
         if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_init_check(&mut state, self).await?;
+            Ics20TransferApp::chan_open_init_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.port_id_on_a) {
+            IcaHost::chan_open_init_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.port_id_on_a) {
+            IcaController::chan_open_init_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -70,7 +75,11 @@ impl MsgHandler for MsgChannelOpenInit {
 
         let transfer = PortId::transfer();
         if self.port_id_on_a == transfer {
-            Ics20Transfer::chan_open_init_execute(state, self).await;
+            Ics20TransferApp::chan_open_init_execute(state, self).await;
+        } else if ica::is_host_port(&self.port_id_on_a) {
+            IcaHost::chan_open_init_execute(state, self).await;
+        } else if ica::is_controller_port(&self.port_id_on_a) {
+            IcaController::chan_open_init_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
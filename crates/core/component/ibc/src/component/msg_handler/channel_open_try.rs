@@ -13,8 +13,9 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::StateWriteExt,
     connection::StateReadExt,
+    ica::{self, IcaController, IcaHost},
     proof_verification::ChannelProofVerifier,
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -59,11 +60,18 @@ impl MsgHandler for MsgChannelOpenTry {
             .await?;
 
         let transfer = PortId::transfer();
-        if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_try_check(&mut state, self).await?;
+        let version = if self.port_id_on_b == transfer {
+            Ics20TransferApp::chan_open_try_check(&mut state, self).await?;
+            Ics20TransferApp::chan_open_try_version(self)
+        } else if ica::is_host_port(&self.port_id_on_b) {
+            IcaHost::chan_open_try_check(&mut state, self).await?;
+            IcaHost::chan_open_try_version(self)
+        } else if ica::is_controller_port(&self.port_id_on_b) {
+            IcaController::chan_open_try_check(&mut state, self).await?;
+            IcaController::chan_open_try_version(self)
         } else {
             anyhow::bail!("invalid port id");
-        }
+        };
 
         let channel_id = state
             .next_channel_id()
@@ -74,7 +82,7 @@ impl MsgHandler for MsgChannelOpenTry {
             ordering: self.ordering,
             remote: Counterparty::new(self.port_id_on_a.clone(), Some(self.chan_id_on_a.clone())),
             connection_hops: self.connection_hops_on_b.clone(),
-            version: self.version_supported_on_a.clone(),
+            version,
         };
 
         state.put_channel(&channel_id, &self.port_id_on_b, new_channel.clone());
@@ -100,7 +108,11 @@ impl MsgHandler for MsgChannelOpenTry {
 
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
-            Ics20Transfer::chan_open_try_execute(state, self).await;
+            Ics20TransferApp::chan_open_try_execute(state, self).await;
+        } else if ica::is_host_port(&self.port_id_on_b) {
+            IcaHost::chan_open_try_execute(state, self).await;
+        } else if ica::is_controller_port(&self.port_id_on_b) {
+            IcaController::chan_open_try_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
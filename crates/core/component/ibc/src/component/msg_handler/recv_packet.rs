@@ -17,8 +17,9 @@ use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
     channel::{StateReadExt as _, StateWriteExt},
     connection::StateReadExt as _,
+    ica::{self, IcaController, IcaHost},
     proof_verification::PacketProofVerifier,
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -105,7 +106,11 @@ impl MsgHandler for MsgRecvPacket {
 
         let transfer = PortId::transfer();
         if self.packet.port_on_b == transfer {
-            Ics20Transfer::recv_packet_check(&mut state, self).await?;
+            Ics20TransferApp::recv_packet_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.packet.port_on_b) {
+            IcaHost::recv_packet_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.packet.port_on_b) {
+            IcaController::recv_packet_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -146,7 +151,11 @@ impl MsgHandler for MsgRecvPacket {
 
         let transfer = PortId::transfer();
         if self.packet.port_on_b == transfer {
-            Ics20Transfer::recv_packet_execute(state, self).await;
+            Ics20TransferApp::recv_packet_execute(state, self).await;
+        } else if ica::is_host_port(&self.packet.port_on_b) {
+            IcaHost::recv_packet_execute(state, self).await;
+        } else if ica::is_controller_port(&self.packet.port_on_b) {
+            IcaController::recv_packet_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -10,11 +10,12 @@ use penumbra_storage::StateWrite;
 
 use crate::component::{
     app_handler::{AppHandlerCheck, AppHandlerExecute},
-    channel::{StateReadExt as _, StateWriteExt},
+    channel::{settles_packets, StateReadExt as _, StateWriteExt},
     client::StateReadExt,
     connection::StateReadExt as _,
+    ica::{self, IcaController, IcaHost},
     proof_verification::{commit_packet, PacketProofVerifier},
-    transfer::Ics20Transfer,
+    transfer::Ics20TransferApp,
     MsgHandler,
 };
 
@@ -32,8 +33,8 @@ impl MsgHandler for MsgTimeout {
             .get_channel(&self.packet.chan_on_a, &self.packet.port_on_a)
             .await?
             .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
-        if !channel.state_matches(&ChannelState::Open) {
-            anyhow::bail!("channel is not open");
+        if !settles_packets(&channel) {
+            anyhow::bail!("channel can no longer settle packets");
         }
 
         // TODO: capability authentication?
@@ -90,8 +91,12 @@ impl MsgHandler for MsgTimeout {
         }
 
         let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::timeout_packet_check(&mut state, self).await?;
+        if self.packet.port_on_a == transfer {
+            Ics20TransferApp::timeout_packet_check(&mut state, self).await?;
+        } else if ica::is_host_port(&self.packet.port_on_a) {
+            IcaHost::timeout_packet_check(&mut state, self).await?;
+        } else if ica::is_controller_port(&self.packet.port_on_a) {
+            IcaController::timeout_packet_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -127,8 +132,12 @@ impl MsgHandler for MsgTimeout {
         );
 
         let transfer = PortId::transfer();
-        if self.packet.port_on_b == transfer {
-            Ics20Transfer::timeout_packet_execute(state, self).await;
+        if self.packet.port_on_a == transfer {
+            Ics20TransferApp::timeout_packet_execute(state, self).await;
+        } else if ica::is_host_port(&self.packet.port_on_a) {
+            IcaHost::timeout_packet_execute(state, self).await;
+        } else if ica::is_controller_port(&self.packet.port_on_a) {
+            IcaController::timeout_packet_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
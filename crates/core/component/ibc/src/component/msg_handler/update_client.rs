@@ -196,18 +196,7 @@ async fn client_is_not_expired<S: StateRead>(
     client_id: &ClientId,
     client_state: &TendermintClientState,
 ) -> anyhow::Result<()> {
-    let latest_consensus_state = state
-        .get_verified_consensus_state(client_state.latest_height(), client_id.clone())
-        .await?;
-
-    // TODO(erwan): for now there is no casting that needs to happen because `get_verified_consensus_state` does not return an
-    // abstracted consensus state.
-    let latest_consensus_state_tm = latest_consensus_state;
-
-    let now = state.get_block_timestamp().await?;
-    let time_elapsed = now.duration_since(latest_consensus_state_tm.timestamp)?;
-
-    if client_state.expired(time_elapsed) {
+    if state.client_is_expired(client_id, client_state).await? {
         Err(anyhow::anyhow!("client is expired"))
     } else {
         Ok(())
@@ -44,6 +44,24 @@ pub struct IBCPacket<S: CheckStatus> {
 }
 
 impl IBCPacket<Unchecked> {
+    pub fn new(
+        source_port: PortId,
+        source_channel: ChannelId,
+        timeout_height: Height,
+        timeout_timestamp: u64,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            source_port,
+            source_channel,
+            timeout_height,
+            timeout_timestamp,
+            data,
+
+            m: std::marker::PhantomData,
+        }
+    }
+
     pub fn assume_checked(self) -> IBCPacket<Checked> {
         IBCPacket {
             source_port: self.source_port,
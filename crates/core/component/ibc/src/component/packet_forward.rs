@@ -0,0 +1,967 @@
+/// Packet forwarding for ICS-20 transfers.
+///
+/// A transfer received over one channel can carry instructions in its memo to send the funds on
+/// over another channel, so that assets can be routed through Penumbra without the receiver
+/// having to make a withdrawal themselves. The memo uses the same format as the packet forward
+/// middleware deployed on Cosmos SDK chains:
+///
+/// ```json
+/// {"forward": {"receiver": "osmo1...", "port": "transfer", "channel": "channel-1"}}
+/// ```
+///
+/// with an optional `timeout` in nanoseconds and an optional `next` memo for the following hop.
+///
+/// The forwarded funds are held by the transfer module rather than any Penumbra address, and the
+/// acknowledgement of the received packet is only written once the forwarded packet is
+/// acknowledged or times out, so that if it fails, the error is passed back along the chain and
+/// each chain refunds the funds it sent.
+use std::marker::PhantomData;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types::{
+    core::{
+        channel::{
+            msgs::{
+                MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+                MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
+                MsgTimeout,
+            },
+            ChannelId, Packet, PortId, Version,
+        },
+        client::Height,
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+    DomainType as IbcTypesDomainType,
+};
+use penumbra_asset::asset;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_num::Amount;
+use penumbra_proto::{
+    penumbra::core::component::ibc::v1alpha1::FungibleTokenPacketData, StateReadProto,
+    StateWriteProto,
+};
+use penumbra_shielded_pool::component::SupplyWrite;
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
+use serde::Deserialize;
+
+use crate::component::{
+    app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+    channel::StateReadExt as _,
+    client::StateReadExt as _,
+    connection::StateReadExt as _,
+    packet::{IBCPacket, SendPacketRead as _, SendPacketWrite as _, WriteAcknowledgement as _},
+    rate_limit::StateWriteExt as _,
+    state_key,
+    transfer::is_source,
+};
+use crate::event;
+
+/// How long a forwarded packet has to be received, if the memo doesn't say.
+const DEFAULT_FORWARD_TIMEOUT_NANOS: u64 = 10 * 60 * 1_000_000_000;
+
+/// How many blocks of the receiving chain a forwarded packet has to be received in. Packets must
+/// have a timeout height, but this is far enough out that the timestamp should time out first.
+const FORWARD_TIMEOUT_BLOCKS: u64 = 100_000;
+
+/// The instructions for forwarding a transfer, from the `forward` field of its memo.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct Forward {
+    /// The receiver on the next chain.
+    receiver: String,
+    /// The port to forward the transfer over.
+    port: String,
+    /// The channel to forward the transfer over.
+    channel: String,
+    /// How long the forwarded packet has to be received, in nanoseconds.
+    #[serde(default)]
+    timeout: Option<u64>,
+    /// The memo for the forwarded packet, which may forward it again.
+    #[serde(default)]
+    next: Option<serde_json::Value>,
+}
+
+impl Forward {
+    /// The memo to send with the forwarded packet.
+    fn next_memo(&self) -> String {
+        match &self.next {
+            None => String::new(),
+            Some(serde_json::Value::String(memo)) => memo.clone(),
+            Some(next) => next.to_string(),
+        }
+    }
+}
+
+/// Parse the forwarding instructions from the data of a received packet, if it has any.
+///
+/// Packets which aren't ICS-20 transfers or have no `forward` instructions are left to the
+/// transfer application, but malformed instructions are an error.
+fn forward_instructions(packet_data: &[u8]) -> Result<Option<Forward>> {
+    let memo = match serde_json::from_slice::<FungibleTokenPacketData>(packet_data) {
+        Ok(packet_data) => packet_data.memo,
+        Err(_) => return Ok(None),
+    };
+    let memo: serde_json::Value = match serde_json::from_str(&memo) {
+        Ok(memo) => memo,
+        Err(_) => return Ok(None),
+    };
+    match memo.get("forward") {
+        None => Ok(None),
+        Some(forward) => Ok(Some(
+            serde_json::from_value(forward.clone()).context("invalid forward memo")?,
+        )),
+    }
+}
+
+/// The error in an ICS-20 acknowledgement, if it isn't a success.
+pub(super) fn ack_error(ack: &[u8]) -> Option<String> {
+    match serde_json::from_slice::<serde_json::Value>(ack) {
+        Ok(ack) if ack.get("result").is_some() => None,
+        Ok(ack) => Some(
+            ack.get("error")
+                .and_then(|error| error.as_str())
+                .unwrap_or("unknown error")
+                .to_string(),
+        ),
+        Err(_) => Some("malformed acknowledgement".to_string()),
+    }
+}
+
+fn denom_of(packet_data: &FungibleTokenPacketData) -> Result<asset::DenomMetadata> {
+    packet_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldnt decode denom in ICS20 transfer")
+}
+
+// Whether an asset received in `packet` is returning to Penumbra, rather than bridged in.
+fn is_returning(packet: &Packet, packet_data: &FungibleTokenPacketData) -> Result<bool> {
+    Ok(is_source(
+        &packet.port_on_a,
+        &packet.chan_on_a,
+        &denom_of(packet_data)?,
+    ))
+}
+
+/// The denom of an asset received in `packet` as it's known on Penumbra.
+fn received_denom(
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<asset::DenomMetadata> {
+    let denom = if is_returning(packet, packet_data)? {
+        let prefix = format!("{}/{}/", packet.port_on_a, packet.chan_on_a);
+        packet_data
+            .denom
+            .strip_prefix(&prefix)
+            .unwrap_or(&packet_data.denom)
+            .to_string()
+    } else {
+        format!(
+            "{}/{}/{}",
+            packet.port_on_b, packet.chan_on_b, packet_data.denom
+        )
+    };
+    denom
+        .as_str()
+        .try_into()
+        .context("couldnt decode denom in ICS20 transfer")
+}
+
+// Whether an asset sent over a channel is escrowed, rather than burned because it's returning
+// to the chain it came from.
+fn is_escrowed(channel_id: &ChannelId, denom: &asset::DenomMetadata) -> bool {
+    !is_source(&PortId::transfer(), channel_id, denom)
+}
+
+#[async_trait]
+trait ForwardWriteExt: StateWrite {
+    fn put_ics20_forward(&mut self, channel_id: &ChannelId, sequence: u64, packet: &Packet) {
+        self.put_raw(
+            state_key::ics20_forward(channel_id, sequence),
+            packet.clone().encode_to_vec(),
+        );
+    }
+
+    async fn take_ics20_forward(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: u64,
+    ) -> Result<Option<Packet>> {
+        let key = state_key::ics20_forward(channel_id, sequence);
+        match self.get_raw(&key).await? {
+            Some(bytes) => {
+                self.delete(key);
+                Ok(Some(Packet::decode(bytes.as_slice())?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn add_value_balance(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        amount: Amount,
+    ) -> Result<()> {
+        let value_balance: Amount = self
+            .get(&state_key::ics20_value_balance(channel_id, asset_id))
+            .await?
+            .unwrap_or_else(Amount::zero);
+        self.put(
+            state_key::ics20_value_balance(channel_id, asset_id),
+            value_balance + amount,
+        );
+        Ok(())
+    }
+
+    async fn sub_value_balance(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        amount: Amount,
+    ) -> Result<()> {
+        let value_balance: Amount = self
+            .get(&state_key::ics20_value_balance(channel_id, asset_id))
+            .await?
+            .unwrap_or_else(Amount::zero);
+        // error text here is from the ics20 spec
+        let new_value_balance = value_balance
+            .checked_sub(&amount)
+            .context("transfer coins failed")?;
+        self.put(
+            state_key::ics20_value_balance(channel_id, asset_id),
+            new_value_balance,
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> ForwardWriteExt for T {}
+
+/// Move the funds in a transfer being forwarded over `channel_id` out of the channel they were
+/// received over and into the one they're sent over, escrowing or burning them as a withdrawal
+/// would, and return the denom and amount to send.
+async fn forward_funds<S: StateWrite>(
+    mut state: S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+    channel_id: &ChannelId,
+) -> Result<(asset::DenomMetadata, Amount)> {
+    let amount: Amount = packet_data
+        .amount
+        .clone()
+        .try_into()
+        .context("couldnt decode amount in ICS20 transfer")?;
+    let denom = received_denom(packet, packet_data)?;
+
+    // Receive the funds, as the transfer application would, but without minting them to anyone.
+    state
+        .record_ics20_inflow(&packet.chan_on_b, &denom.id(), amount)
+        .await?;
+    if is_returning(packet, packet_data)? {
+        state
+            .sub_value_balance(&packet.chan_on_b, &denom.id(), amount)
+            .await?;
+    } else {
+        state.register_denom(&denom).await?;
+        state
+            .update_token_supply(&denom.id(), amount.value() as i128)
+            .await?;
+    }
+
+    // Then send them on, as for a withdrawal.
+    state
+        .record_ics20_outflow(channel_id, &denom.id(), amount)
+        .await?;
+    if is_escrowed(channel_id, &denom) {
+        state
+            .add_value_balance(channel_id, &denom.id(), amount)
+            .await?;
+    } else {
+        state
+            .update_token_supply(&denom.id(), -(amount.value() as i128))
+            .await?;
+    }
+
+    Ok((denom, amount))
+}
+
+/// Receive the funds in a transfer into the transfer module, and send them on as instructed.
+async fn forward_packet<S: StateWrite>(
+    mut state: S,
+    msg: &MsgRecvPacket,
+    forward: &Forward,
+) -> Result<()> {
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(msg.packet.data.as_slice())
+        .with_context(|| "failed to decode FTPD packet")?;
+
+    let port_id: PortId = forward.port.parse().context("invalid forward port")?;
+    anyhow::ensure!(
+        port_id == PortId::transfer(),
+        "transfers can only be forwarded over the transfer port"
+    );
+    let channel_id: ChannelId = forward.channel.parse().context("invalid forward channel")?;
+
+    let (denom, amount) = forward_funds(&mut state, &msg.packet, &packet_data, &channel_id).await?;
+
+    let channel = state
+        .get_channel(&channel_id, &port_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("channel {} does not exist", channel_id))?;
+    let connection = state
+        .get_connection(&channel.connection_hops[0])
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!("connection {} does not exist", channel.connection_hops[0])
+        })?;
+    let latest_height = state
+        .get_client_state(&connection.client_id)
+        .await?
+        .latest_height();
+    let timeout_height = Height::new(
+        latest_height.revision_number(),
+        latest_height.revision_height() + FORWARD_TIMEOUT_BLOCKS,
+    )?;
+    let now: ibc_types::timestamp::Timestamp = state.get_block_timestamp().await?.into();
+    let timeout_timestamp = now
+        .nanoseconds()
+        .saturating_add(forward.timeout.unwrap_or(DEFAULT_FORWARD_TIMEOUT_NANOS));
+
+    let forwarded_packet_data = FungibleTokenPacketData {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+        sender: packet_data.receiver,
+        receiver: forward.receiver.clone(),
+        memo: forward.next_memo(),
+    };
+    let packet = IBCPacket::new(
+        port_id.clone(),
+        channel_id.clone(),
+        timeout_height,
+        timeout_timestamp,
+        // In violation of the ICS20 spec, ibc-go encodes transfer packets as JSON.
+        serde_json::to_vec(&forwarded_packet_data)?,
+    );
+    let packet = state.send_packet_check(packet).await?;
+
+    let sequence = state.get_send_sequence(&channel_id, &port_id).await?;
+    state.send_packet_execute(packet).await;
+    state.put_ics20_forward(&channel_id, sequence, &msg.packet);
+
+    Ok(())
+}
+
+/// Return the funds in a forwarded transfer which failed to the channel they were received over,
+/// undoing both sides of `forward_packet`.
+async fn refund_forward<S: StateWrite>(
+    mut state: S,
+    original_packet: &Packet,
+    forwarded_packet: &Packet,
+) -> Result<()> {
+    let packet_data: FungibleTokenPacketData =
+        serde_json::from_slice(forwarded_packet.data.as_slice())?;
+    let amount: Amount = packet_data.amount.try_into()?;
+    let denom = denom_of(&packet_data)?;
+
+    if is_escrowed(&forwarded_packet.chan_on_a, &denom) {
+        state
+            .sub_value_balance(&forwarded_packet.chan_on_a, &denom.id(), amount)
+            .await?;
+    } else {
+        state
+            .update_token_supply(&denom.id(), amount.value() as i128)
+            .await?;
+    }
+
+    let original_packet_data: FungibleTokenPacketData =
+        serde_json::from_slice(original_packet.data.as_slice())?;
+    if is_returning(original_packet, &original_packet_data)? {
+        state
+            .add_value_balance(&original_packet.chan_on_b, &denom.id(), amount)
+            .await?;
+    } else {
+        state
+            .update_token_supply(&denom.id(), -(amount.value() as i128))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Finish a forwarded transfer once the forwarded packet has been acknowledged, or has timed out
+/// if `error` is set, by acknowledging the packet it was received in.
+async fn finish_forward<S: StateWrite>(
+    mut state: S,
+    original_packet: &Packet,
+    forwarded_packet: &Packet,
+    error: Option<String>,
+) {
+    let ack: Vec<u8> = match error {
+        None => TokenTransferAcknowledgement::success().into(),
+        Some(error) => {
+            tracing::debug!("forwarded transfer failed: {}", error);
+            refund_forward(&mut state, original_packet, forwarded_packet)
+                .await
+                .expect("able to refund forwarded transfer");
+            TokenTransferAcknowledgement::Error(format!("forwarded transfer failed: {error}"))
+                .into()
+        }
+    };
+
+    state
+        .write_acknowledgement(original_packet, &ack)
+        .await
+        .expect("able to write acknowledgement");
+}
+
+/// Middleware which forwards ICS-20 transfers with `forward` instructions in their memo, and
+/// passes everything else through to the transfer application `A`.
+pub struct PacketForward<A> {
+    _app: PhantomData<A>,
+}
+
+#[async_trait]
+impl<A: AppHandler> AppHandlerCheck for PacketForward<A> {
+    async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        A::chan_open_init_check(state, msg).await
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        A::chan_open_try_check(state, msg).await
+    }
+
+    fn chan_open_try_version(msg: &MsgChannelOpenTry) -> Version {
+        A::chan_open_try_version(msg)
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        A::chan_open_ack_check(state, msg).await
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        A::chan_open_confirm_check(state, msg).await
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        A::chan_close_confirm_check(state, msg).await
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        A::chan_close_init_check(state, msg).await
+    }
+
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        // like transfers, forwards which can't be made are refused with an error acknowledgement
+        A::recv_packet_check(state, msg).await
+    }
+
+    async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
+        let key = state_key::ics20_forward(&msg.packet.chan_on_a, msg.packet.sequence.into());
+        if state.get_raw(&key).await?.is_some() {
+            return Ok(());
+        }
+        A::timeout_packet_check(state, msg).await
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        let key = state_key::ics20_forward(&msg.packet.chan_on_a, msg.packet.sequence.into());
+        if state.get_raw(&key).await?.is_some() {
+            return Ok(());
+        }
+        A::acknowledge_packet_check(state, msg).await
+    }
+}
+
+#[async_trait]
+impl<A: AppHandler> AppHandlerExecute for PacketForward<A> {
+    async fn chan_open_init_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenInit) {
+        A::chan_open_init_execute(state, msg).await
+    }
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) {
+        A::chan_open_try_execute(state, msg).await
+    }
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) {
+        A::chan_open_ack_execute(state, msg).await
+    }
+    async fn chan_open_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenConfirm) {
+        A::chan_open_confirm_execute(state, msg).await
+    }
+    async fn chan_close_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseConfirm) {
+        A::chan_close_confirm_execute(state, msg).await
+    }
+    async fn chan_close_init_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseInit) {
+        A::chan_close_init_execute(state, msg).await
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
+        let result = match forward_instructions(&msg.packet.data) {
+            Ok(None) => return A::recv_packet_execute(state, msg).await,
+            Ok(Some(forward)) => {
+                // the forward is made in its own delta, so that a failed forward leaves no trace
+                // besides its error acknowledgement, and the event recording why, if it was
+                // refused by a rate limit.
+                let mut delta = StateDelta::new(&mut state);
+                let result = forward_packet(&mut delta, msg, &forward).await;
+                if result.is_ok() {
+                    let (state, events) = delta.apply();
+                    for event in events {
+                        state.record(event);
+                    }
+                } else {
+                    let (state, mut changes) = delta.flatten();
+                    for event in changes.take_events() {
+                        if event::is_rate_limited(&event) {
+                            state.record(event);
+                        }
+                    }
+                }
+                result
+            }
+            Err(e) => Err(e),
+        };
+
+        // the packet is acknowledged once the forwarded packet is, unless it couldn't be sent.
+        if let Err(e) = result {
+            tracing::debug!("couldnt forward transfer: {:#}", e);
+            let ack: Vec<u8> = TokenTransferAcknowledgement::Error(e.to_string()).into();
+            state
+                .write_acknowledgement(&msg.packet, &ack)
+                .await
+                .expect("able to write acknowledgement");
+        }
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) {
+        let original_packet = state
+            .take_ics20_forward(&msg.packet.chan_on_a, msg.packet.sequence.into())
+            .await
+            .expect("able to get forwarded transfer");
+        match original_packet {
+            Some(original_packet) => {
+                finish_forward(
+                    state,
+                    &original_packet,
+                    &msg.packet,
+                    Some("packet timed out".to_string()),
+                )
+                .await
+            }
+            None => A::timeout_packet_execute(state, msg).await,
+        }
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(mut state: S, msg: &MsgAcknowledgement) {
+        let original_packet = state
+            .take_ics20_forward(&msg.packet.chan_on_a, msg.packet.sequence.into())
+            .await
+            .expect("able to get forwarded transfer");
+        match original_packet {
+            Some(original_packet) => {
+                finish_forward(
+                    state,
+                    &original_packet,
+                    &msg.packet,
+                    ack_error(&msg.acknowledgement),
+                )
+                .await
+            }
+            None => A::acknowledge_packet_execute(state, msg).await,
+        }
+    }
+}
+
+impl<A: AppHandler> AppHandler for PacketForward<A> {}
+
+#[cfg(test)]
+mod tests {
+    use ibc_types::{
+        core::{
+            channel::{
+                channel::{Order as ChannelOrder, State as ChannelState},
+                ChannelEnd, Counterparty,
+            },
+            connection::ConnectionId,
+        },
+        timestamp::Timestamp,
+    };
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_shielded_pool::component::SupplyRead as _;
+
+    use super::*;
+    use crate::{
+        component::{
+            channel::{StateReadExt as _, StateWriteExt as _},
+            proof_verification::commit_acknowledgement,
+        },
+        Ics20RateLimit,
+    };
+
+    /// The counterparty's end of the channel transfers are received over.
+    const COUNTERPARTY_CHANNEL: u64 = 9;
+    /// The channel transfers are received over.
+    const RECEIVING_CHANNEL: u64 = 0;
+    /// The channel transfers are forwarded over.
+    const FORWARDING_CHANNEL: u64 = 1;
+
+    fn packet_data(memo: &str) -> Vec<u8> {
+        serde_json::to_vec(&FungibleTokenPacketData {
+            denom: "uatom".to_string(),
+            amount: "100".to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: "penumbrav2t1receiver".to_string(),
+            memo: memo.to_string(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn forward_instructions_are_parsed_from_memo() {
+        // transfers without forward instructions are left to the transfer application:
+        assert_eq!(forward_instructions(&packet_data("")).unwrap(), None);
+        assert_eq!(forward_instructions(&packet_data("hello")).unwrap(), None);
+        assert_eq!(
+            forward_instructions(&packet_data(r#"{"wasm": {}}"#)).unwrap(),
+            None
+        );
+        assert_eq!(forward_instructions(b"not a transfer").unwrap(), None);
+
+        let forward = forward_instructions(&packet_data(
+            r#"{"forward": {
+                "receiver": "osmo1receiver",
+                "port": "transfer",
+                "channel": "channel-1",
+                "next": {"forward": {"receiver": "x", "port": "transfer", "channel": "channel-2"}}
+            }}"#,
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(forward.receiver, "osmo1receiver");
+        assert_eq!(forward.channel, "channel-1");
+        assert_eq!(forward.timeout, None);
+        assert_eq!(
+            forward_instructions(&packet_data(&forward.next_memo()))
+                .unwrap()
+                .unwrap()
+                .channel,
+            "channel-2"
+        );
+
+        // but malformed forward instructions are an error:
+        assert!(
+            forward_instructions(&packet_data(r#"{"forward": {"port": "transfer"}}"#)).is_err()
+        );
+    }
+
+    /// A transfer of 100 of `denom`, as the counterparty names it, received over the receiving
+    /// channel and to be forwarded.
+    fn received_packet(denom: &str) -> (Packet, FungibleTokenPacketData) {
+        let packet_data = FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount: "100".to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: "penumbrav2t1receiver".to_string(),
+            memo: String::new(),
+        };
+        let packet = Packet {
+            sequence: 1u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(COUNTERPARTY_CHANNEL),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(RECEIVING_CHANNEL),
+            data: serde_json::to_vec(&packet_data).unwrap(),
+            timeout_height_on_b: Height::new(0, 1_000).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+        };
+        (packet, packet_data)
+    }
+
+    /// The packet a transfer is forwarded in, over `channel_id`.
+    fn forwarded_packet(channel_id: &ChannelId, denom: &asset::DenomMetadata) -> Packet {
+        let packet_data = FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount: "100".to_string(),
+            sender: "penumbrav2t1receiver".to_string(),
+            receiver: "osmo1receiver".to_string(),
+            memo: String::new(),
+        };
+        Packet {
+            sequence: 1u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: channel_id.clone(),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(5),
+            data: serde_json::to_vec(&packet_data).unwrap(),
+            timeout_height_on_b: Height::new(0, 1_000).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+        }
+    }
+
+    async fn value_balance<S: StateRead>(
+        state: &S,
+        channel_id: u64,
+        denom: &asset::DenomMetadata,
+    ) -> Amount {
+        state
+            .get(&state_key::ics20_value_balance(
+                &ChannelId::new(channel_id),
+                &denom.id(),
+            ))
+            .await
+            .unwrap()
+            .unwrap_or_else(Amount::zero)
+    }
+
+    async fn supply<S: StateRead>(state: &S, denom: &asset::DenomMetadata) -> u64 {
+        state.token_supply(&denom.id()).await.unwrap().unwrap_or(0)
+    }
+
+    #[tokio::test]
+    async fn bridged_in_transfer_is_minted_escrowed_and_refunded() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let (packet, packet_data) = received_packet("uatom");
+        let forwarding_channel = ChannelId::new(FORWARDING_CHANNEL);
+
+        let (denom, amount) =
+            forward_funds(&mut state, &packet, &packet_data, &forwarding_channel).await?;
+        assert_eq!(denom.to_string(), "transfer/channel-0/uatom");
+        assert_eq!(amount, 100u64.into());
+        // The vouchers are minted on receipt, and escrowed in the forwarding channel.
+        assert_eq!(supply(&state, &denom).await, 100);
+        assert_eq!(
+            value_balance(&state, FORWARDING_CHANNEL, &denom).await,
+            100u64.into()
+        );
+
+        // A failed forward takes them back out of escrow and burns them again.
+        refund_forward(
+            &mut state,
+            &packet,
+            &forwarded_packet(&forwarding_channel, &denom),
+        )
+        .await?;
+        assert_eq!(supply(&state, &denom).await, 0);
+        assert_eq!(
+            value_balance(&state, FORWARDING_CHANNEL, &denom).await,
+            Amount::zero()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bridged_in_transfer_forwarded_back_is_burned_and_refunded() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let (packet, packet_data) = received_packet("uatom");
+        let receiving_channel = ChannelId::new(RECEIVING_CHANNEL);
+
+        // Forwarding vouchers back over the channel they came from burns them, as they return to
+        // their origin.
+        let (denom, _) =
+            forward_funds(&mut state, &packet, &packet_data, &receiving_channel).await?;
+        assert_eq!(supply(&state, &denom).await, 0);
+        assert_eq!(
+            value_balance(&state, RECEIVING_CHANNEL, &denom).await,
+            Amount::zero()
+        );
+
+        refund_forward(
+            &mut state,
+            &packet,
+            &forwarded_packet(&receiving_channel, &denom),
+        )
+        .await?;
+        assert_eq!(supply(&state, &denom).await, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn forward_over_the_outflow_limit_is_refused() -> Result<()> {
+        let mut state = StateDelta::new(());
+        state.put_block_height(1);
+        let (packet, packet_data) = received_packet("uatom");
+        let forwarding_channel = ChannelId::new(FORWARDING_CHANNEL);
+        let denom = received_denom(&packet, &packet_data)?;
+        state.put_ics20_rate_limit(
+            &forwarding_channel,
+            &denom.id(),
+            Some(Ics20RateLimit {
+                window_blocks: 10,
+                max_inflow: 1_000u64.into(),
+                max_outflow: 99u64.into(),
+            }),
+        );
+
+        assert!(
+            forward_funds(&mut state, &packet, &packet_data, &forwarding_channel)
+                .await
+                .is_err()
+        );
+        let (_, mut changes) = state.flatten();
+        let refused: Vec<_> = changes
+            .take_events()
+            .into_iter()
+            .filter(event::is_rate_limited)
+            .collect();
+        assert_eq!(refused.len(), 1);
+        assert_eq!(refused[0].kind, "ics20_outflow_rate_limited");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returning_transfer_moves_between_escrows_and_back() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let (packet, packet_data) = received_packet(&format!(
+            "transfer/channel-{COUNTERPARTY_CHANNEL}/upenumbra"
+        ));
+        let upenumbra: asset::DenomMetadata = "upenumbra".try_into()?;
+        let forwarding_channel = ChannelId::new(FORWARDING_CHANNEL);
+
+        // Native tokens can only return over a channel they were escrowed in.
+        assert!(
+            forward_funds(&mut state, &packet, &packet_data, &forwarding_channel)
+                .await
+                .is_err()
+        );
+        state
+            .add_value_balance(
+                &ChannelId::new(RECEIVING_CHANNEL),
+                &upenumbra.id(),
+                150u64.into(),
+            )
+            .await?;
+
+        let (denom, _) =
+            forward_funds(&mut state, &packet, &packet_data, &forwarding_channel).await?;
+        assert_eq!(denom, upenumbra);
+        assert_eq!(
+            value_balance(&state, RECEIVING_CHANNEL, &upenumbra).await,
+            50u64.into()
+        );
+        assert_eq!(
+            value_balance(&state, FORWARDING_CHANNEL, &upenumbra).await,
+            100u64.into()
+        );
+        assert_eq!(supply(&state, &upenumbra).await, 0);
+
+        refund_forward(
+            &mut state,
+            &packet,
+            &forwarded_packet(&forwarding_channel, &upenumbra),
+        )
+        .await?;
+        assert_eq!(
+            value_balance(&state, RECEIVING_CHANNEL, &upenumbra).await,
+            150u64.into()
+        );
+        assert_eq!(
+            value_balance(&state, FORWARDING_CHANNEL, &upenumbra).await,
+            Amount::zero()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_forwards_are_refunded_and_acknowledged_with_an_error() -> Result<()> {
+        let (packet, packet_data) = received_packet("uatom");
+        let forwarding_channel = ChannelId::new(FORWARDING_CHANNEL);
+        let failures = [
+            // an error acknowledgement of the forwarded packet:
+            ack_error(br#"{"error":"transfer coins failed"}"#),
+            // its timeout:
+            Some("packet timed out".to_string()),
+            // and its success:
+            ack_error(br#"{"result":"AQ=="}"#),
+        ];
+
+        for error in failures {
+            let mut state = StateDelta::new(());
+            state.put_channel(
+                &ChannelId::new(RECEIVING_CHANNEL),
+                &PortId::transfer(),
+                ChannelEnd {
+                    state: ChannelState::Open,
+                    ordering: ChannelOrder::Unordered,
+                    remote: Counterparty::new(
+                        PortId::transfer(),
+                        Some(ChannelId::new(COUNTERPARTY_CHANNEL)),
+                    ),
+                    connection_hops: vec![ConnectionId::new(0)],
+                    version: Version::new("ics20-1".to_string()),
+                },
+            );
+            let (denom, _) =
+                forward_funds(&mut state, &packet, &packet_data, &forwarding_channel).await?;
+
+            let failed = error.is_some();
+            finish_forward(
+                &mut state,
+                &packet,
+                &forwarded_packet(&forwarding_channel, &denom),
+                error,
+            )
+            .await;
+
+            let ack = state
+                .get_packet_acknowledgement(
+                    &PortId::transfer(),
+                    &ChannelId::new(RECEIVING_CHANNEL),
+                    1,
+                )
+                .await?
+                .expect("received packet is acknowledged");
+            if failed {
+                assert_ne!(
+                    ack,
+                    commit_acknowledgement(&Vec::<u8>::from(
+                        TokenTransferAcknowledgement::success()
+                    ))
+                );
+                assert_eq!(supply(&state, &denom).await, 0);
+                assert_eq!(
+                    value_balance(&state, FORWARDING_CHANNEL, &denom).await,
+                    Amount::zero()
+                );
+            } else {
+                assert_eq!(
+                    ack,
+                    commit_acknowledgement(&Vec::<u8>::from(
+                        TokenTransferAcknowledgement::success()
+                    ))
+                );
+                assert_eq!(supply(&state, &denom).await, 100);
+                assert_eq!(
+                    value_balance(&state, FORWARDING_CHANNEL, &denom).await,
+                    100u64.into()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn acks_are_parsed() {
+        assert_eq!(ack_error(br#"{"result":"AQ=="}"#), None);
+        assert_eq!(
+            ack_error(br#"{"error":"transfer coins failed"}"#).as_deref(),
+            Some("transfer coins failed")
+        );
+        assert!(ack_error(b"garbage").is_some());
+    }
+}
@@ -0,0 +1,262 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::{component::state_key, event, Ics20Flow, Ics20RateLimit, Ics20Withdrawal};
+
+// Flows are only tracked for assets which are rate limited over a channel, and refunds of timed
+// out transfers don't give back the quota they used, which errs on the side of caution.
+
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// Get the rate limit on an asset over an ICS-20 channel, if it has one.
+    async fn ics20_rate_limit(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+    ) -> Result<Option<Ics20RateLimit>> {
+        self.get(&state_key::ics20_rate_limit(channel_id, asset_id))
+            .await
+    }
+
+    /// Get the value of an asset transferred over a rate limited ICS-20 channel in the current
+    /// window.
+    async fn ics20_flow(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        limit: &Ics20RateLimit,
+    ) -> Result<Ics20Flow> {
+        let height = self.get_block_height().await?;
+        Ok(self
+            .get::<Ics20Flow>(&state_key::ics20_flow(channel_id, asset_id))
+            .await?
+            .unwrap_or_default()
+            .at_height(limit, height))
+    }
+
+    /// Check that sending `amount` of an asset over an ICS-20 channel stays within its rate limit.
+    async fn ics20_outflow_check(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        amount: Amount,
+    ) -> Result<()> {
+        if let Some(limit) = self.ics20_rate_limit(channel_id, asset_id).await? {
+            let height = self.get_block_height().await?;
+            self.ics20_flow(channel_id, asset_id, &limit)
+                .await?
+                .with_outflow(&limit, height, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Check that the withdrawals in a transaction, taken together, stay within the rate limits
+    /// on what they send.
+    ///
+    /// All of a transaction's actions are checked before any of them executes, so withdrawals of
+    /// the same asset over the same channel must be checked against its rate limit as one.
+    async fn ics20_withdrawals_check(&self, withdrawals: &[&Ics20Withdrawal]) -> Result<()> {
+        let mut outflows: Vec<(&ChannelId, asset::Id, Amount)> = Vec::new();
+        for withdrawal in withdrawals {
+            let asset_id = withdrawal.denom.id();
+            match outflows.iter_mut().find(|(channel_id, id, _)| {
+                **channel_id == withdrawal.source_channel && *id == asset_id
+            }) {
+                Some((_, _, amount)) => {
+                    *amount = amount
+                        .value()
+                        .checked_add(withdrawal.amount.value())
+                        .map(Amount::from)
+                        .ok_or_else(|| anyhow!("ics20 withdrawals overflow"))?;
+                }
+                None => outflows.push((&withdrawal.source_channel, asset_id, withdrawal.amount)),
+            }
+        }
+
+        for (channel_id, asset_id, amount) in outflows {
+            self.ics20_outflow_check(channel_id, &asset_id, amount)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub trait StateWriteExt: StateWrite {
+    /// Set the rate limit on an asset over an ICS-20 channel, or remove it if `limit` is `None`.
+    fn put_ics20_rate_limit(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        limit: Option<Ics20RateLimit>,
+    ) {
+        match limit {
+            Some(limit) => self.put(state_key::ics20_rate_limit(channel_id, asset_id), limit),
+            None => {
+                self.delete(state_key::ics20_rate_limit(channel_id, asset_id));
+                self.delete(state_key::ics20_flow(channel_id, asset_id));
+            }
+        }
+    }
+
+    /// Record the receipt of `amount` of an asset over an ICS-20 channel, failing and recording
+    /// an event if that would exceed its rate limit.
+    async fn record_ics20_inflow(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        amount: Amount,
+    ) -> Result<()> {
+        if let Some(limit) = self.ics20_rate_limit(channel_id, asset_id).await? {
+            let height = self.get_block_height().await?;
+            let flow = self.ics20_flow(channel_id, asset_id, &limit).await?;
+            match flow.clone().with_inflow(&limit, height, amount) {
+                Ok(flow) => self.put(state_key::ics20_flow(channel_id, asset_id), flow),
+                Err(error) => {
+                    self.record(event::ics20_inflow_rate_limited(
+                        channel_id, asset_id, amount, &flow, &limit,
+                    ));
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the sending of `amount` of an asset over an ICS-20 channel, failing and recording an
+    /// event if that would exceed its rate limit.
+    async fn record_ics20_outflow(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        amount: Amount,
+    ) -> Result<()> {
+        if let Some(limit) = self.ics20_rate_limit(channel_id, asset_id).await? {
+            let height = self.get_block_height().await?;
+            let flow = self.ics20_flow(channel_id, asset_id, &limit).await?;
+            match flow.clone().with_outflow(&limit, height, amount) {
+                Ok(flow) => self.put(state_key::ics20_flow(channel_id, asset_id), flow),
+                Err(error) => {
+                    self.record(event::ics20_outflow_rate_limited(
+                        channel_id, asset_id, amount, &flow, &limit,
+                    ));
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_storage::StateDelta;
+
+    #[tokio::test]
+    async fn inflow_is_rate_limited() -> Result<()> {
+        let mut state = StateDelta::new(());
+        state.put_block_height(1);
+
+        let channel_id = ChannelId::new(0);
+        let asset_id = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+
+        // Without a rate limit, nothing is tracked:
+        state
+            .record_ics20_inflow(&channel_id, &asset_id, 1_000u64.into())
+            .await?;
+        assert!(state
+            .get_raw(&state_key::ics20_flow(&channel_id, &asset_id))
+            .await?
+            .is_none());
+
+        let limit = Ics20RateLimit {
+            window_blocks: 10,
+            max_inflow: 100u64.into(),
+            max_outflow: 100u64.into(),
+        };
+        state.put_ics20_rate_limit(&channel_id, &asset_id, Some(limit));
+
+        state
+            .record_ics20_inflow(&channel_id, &asset_id, 100u64.into())
+            .await?;
+        assert!(state
+            .record_ics20_inflow(&channel_id, &asset_id, 1u64.into())
+            .await
+            .is_err());
+        // Inflows don't use up the outflow quota:
+        state
+            .ics20_outflow_check(&channel_id, &asset_id, 100u64.into())
+            .await?;
+
+        // Once the block the quota was used in leaves the window, it is available again:
+        state.put_block_height(11);
+        state
+            .record_ics20_inflow(&channel_id, &asset_id, 100u64.into())
+            .await?;
+        let flow = state.ics20_flow(&channel_id, &asset_id, &limit).await?;
+        assert_eq!(flow.blocks.len(), 1);
+        assert_eq!(flow.inflow(), 100u64.into());
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn outflow_is_rate_limited_over_a_sliding_window() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let channel_id = ChannelId::new(0);
+        let asset_id = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let limit = Ics20RateLimit {
+            window_blocks: 10,
+            max_inflow: 100u64.into(),
+            max_outflow: 100u64.into(),
+        };
+        state.put_ics20_rate_limit(&channel_id, &asset_id, Some(limit));
+
+        state.put_block_height(1);
+        state
+            .record_ics20_outflow(&channel_id, &asset_id, 60u64.into())
+            .await?;
+        state.put_block_height(5);
+        state
+            .record_ics20_outflow(&channel_id, &asset_id, 40u64.into())
+            .await?;
+
+        // The quota used at height 1 is still in the window until height 11:
+        state.put_block_height(10);
+        assert!(state
+            .record_ics20_outflow(&channel_id, &asset_id, 1u64.into())
+            .await
+            .is_err());
+        state.put_block_height(11);
+        state
+            .record_ics20_outflow(&channel_id, &asset_id, 60u64.into())
+            .await?;
+        // But the quota used at height 5 isn't freed up with it:
+        assert!(state
+            .record_ics20_outflow(&channel_id, &asset_id, 1u64.into())
+            .await
+            .is_err());
+
+        let (_, mut changes) = state.flatten();
+        let refused: Vec<_> = changes
+            .take_events()
+            .into_iter()
+            .filter(|event| event.kind == "ics20_outflow_rate_limited")
+            .collect();
+        assert_eq!(refused.len(), 2);
+
+        Ok(())
+    }
+}
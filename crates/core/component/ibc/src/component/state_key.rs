@@ -1,4 +1,9 @@
-use ibc_types::{core::channel::ChannelId, core::client::ClientId, core::client::Height};
+use ibc_types::{
+    core::channel::{ChannelId, PortId},
+    core::client::ClientId,
+    core::client::Height,
+    core::connection::ConnectionId,
+};
 
 use penumbra_asset::asset;
 
@@ -45,3 +50,23 @@ pub mod connections {
 pub fn ics20_value_balance(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
     format!("ics20-value-balance/{channel_id}/{asset_id}")
 }
+
+pub fn ics20_rate_limit(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
+    format!("ics20-rate-limit/{channel_id}/{asset_id}")
+}
+
+pub fn ics20_flow(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
+    format!("ics20-flow/{channel_id}/{asset_id}")
+}
+
+pub fn ica_controller_channel(port_id: &PortId, connection_id: &ConnectionId) -> String {
+    format!("ica-controller/{port_id}/{connection_id}/channel")
+}
+
+pub fn ica_controller_address(port_id: &PortId, connection_id: &ConnectionId) -> String {
+    format!("ica-controller/{port_id}/{connection_id}/address")
+}
+
+pub fn ics20_forward(channel_id: &ChannelId, sequence: u64) -> String {
+    format!("ics20-forward/{channel_id}/{sequence}")
+}
@@ -15,6 +15,8 @@ use ibc_types::{
     transfer::acknowledgement::TokenTransferAcknowledgement,
 };
 use penumbra_asset::{asset, asset::DenomMetadata, Value};
+use penumbra_chain::NoteSource;
+use penumbra_dex::component::SwapManager as _;
 use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{
@@ -28,10 +30,13 @@ use prost::Message;
 use crate::{
     component::{
         app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+        ics20_swap::Ics20Swap,
         packet::{
             IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked,
             WriteAcknowledgement as _,
         },
+        packet_forward::PacketForward,
+        rate_limit::{StateReadExt as _, StateWriteExt as _},
         state_key,
     },
     Ics20Withdrawal,
@@ -46,7 +51,11 @@ use crate::{
 //
 // A simple way of doing this is by parsing the denom, looking for a prefix that is only
 // appended in the case of a bridged token. That is what this logic does.
-fn is_source(source_port: &PortId, source_channel: &ChannelId, denom: &DenomMetadata) -> bool {
+pub(super) fn is_source(
+    source_port: &PortId,
+    source_channel: &ChannelId,
+    denom: &DenomMetadata,
+) -> bool {
     let prefix = format!("{source_port}/{source_channel}/");
 
     denom.starts_with(&prefix)
@@ -55,9 +64,20 @@ fn is_source(source_port: &PortId, source_channel: &ChannelId, denom: &DenomMeta
 #[derive(Clone)]
 pub struct Ics20Transfer {}
 
+/// The app handler for the transfer port: ICS-20 transfers, which are forwarded on over another
+/// channel if their memo says to.
+pub type Ics20TransferApp = PacketForward<Ics20Transfer>;
+
 #[async_trait]
 pub trait Ics20TransferReadExt: StateRead {
     async fn withdrawal_check(&self, withdrawal: &Ics20Withdrawal) -> Result<()> {
+        self.ics20_outflow_check(
+            &withdrawal.source_channel,
+            &withdrawal.denom.id(),
+            withdrawal.amount,
+        )
+        .await?;
+
         // create packet
         let packet: IBCPacket<Unchecked> = withdrawal.clone().into();
 
@@ -72,10 +92,20 @@ impl<T: StateRead + ?Sized> Ics20TransferReadExt for T {}
 
 #[async_trait]
 pub trait Ics20TransferWriteExt: StateWrite {
-    async fn withdrawal_execute(&mut self, withdrawal: &Ics20Withdrawal) {
+    async fn withdrawal_execute(&mut self, withdrawal: &Ics20Withdrawal) -> Result<()> {
         // create packet, assume it's already checked since the component caller contract calls `check` before `execute`
         let checked_packet = IBCPacket::<Unchecked>::from(withdrawal.clone()).assume_checked();
 
+        // the rate limit was checked for the whole transaction, but fail it rather than the chain if
+        // the outflow exceeds it regardless.
+        self.record_ics20_outflow(
+            &withdrawal.source_channel,
+            &withdrawal.denom.id(),
+            withdrawal.amount,
+        )
+        .await
+        .context("ics20 withdrawal exceeds its rate limit")?;
+
         let prefix = format!("transfer/{}/", &withdrawal.source_channel);
         if !withdrawal.denom.starts_with(&prefix) {
             // we are the source. add the value balance to the escrow channel.
@@ -107,6 +137,8 @@ pub trait Ics20TransferWriteExt: StateWrite {
         }
 
         self.send_packet_execute(checked_packet).await;
+
+        Ok(())
     }
 }
 
@@ -176,7 +208,10 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 
     async fn recv_packet_check<S: StateRead>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
-        // all checks on recv_packet done in execute
+        // all checks on recv_packet done in execute, including rate limits: a transfer which
+        // exceeds its rate limit is refused with an error acknowledgement, which refunds the
+        // sender right away, rather than failing the transaction and leaving the packet to time
+        // out.
         Ok(())
     }
 
@@ -211,6 +246,36 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 }
 
+// credits the value received in a transfer to its receiver, either as a note, or as a swap into
+// another asset, if the transfer's memo asks for one.
+async fn credit_receiver<S: StateWrite>(
+    mut state: S,
+    msg: &MsgRecvPacket,
+    value: Value,
+    receiver_address: &Address,
+    swap: Option<&Ics20Swap>,
+) -> Result<()> {
+    match swap {
+        None => {
+            state
+                .mint_note(value, receiver_address, NoteSource::Ics20Transfer)
+                .await
+        }
+        Some(swap) => {
+            let swap = swap.swap_plaintext(&msg.packet, value, *receiver_address)?;
+
+            // the value received enters the shielded pool as the input to the swap, rather than
+            // as a note, and its outputs are minted when the swap is claimed.
+            state
+                .update_token_supply(&value.asset_id, value.amount.value() as i128)
+                .await?;
+            state.add_chain_swap(swap, NoteSource::Ics20Swap).await;
+
+            Ok(())
+        }
+    }
+}
+
 // the main entry point for ICS20 transfer packet handling
 async fn recv_transfer_packet_inner<S: StateWrite>(
     mut state: S,
@@ -235,6 +300,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         .try_into()
         .context("couldnt decode amount in ICS20 transfer")?;
     let receiver_address = Address::from_str(&packet_data.receiver)?;
+    let swap = Ics20Swap::from_memo(&packet_data.memo)?;
 
     // NOTE: here we assume we are chain A.
 
@@ -276,11 +342,10 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         }
 
         state
-            .mint_note(
-                value,
-                &receiver_address,
-                penumbra_chain::NoteSource::Ics20Transfer, // TODO
-            )
+            .record_ics20_inflow(&msg.packet.chan_on_b, &value.asset_id, receiver_amount)
+            .await?;
+
+        credit_receiver(&mut state, msg, value, &receiver_address, swap.as_ref())
             .await
             .context("unable to mint note when receiving ics20 transfer packet")?;
 
@@ -318,6 +383,11 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             .as_str()
             .try_into()
             .context("unable to parse denom in ics20 transfer as DenomMetadata")?;
+
+        state
+            .record_ics20_inflow(&msg.packet.chan_on_b, &denom.id(), receiver_amount)
+            .await?;
+
         state
             .register_denom(&denom)
             .await
@@ -328,12 +398,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             asset_id: denom.id(),
         };
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                penumbra_chain::NoteSource::Ics20Transfer,
-            )
+        credit_receiver(&mut state, msg, value, &receiver_address, swap.as_ref())
             .await
             .context("failed to mint notes in ibc transfer")?;
     }
@@ -449,3 +514,82 @@ impl AppHandlerExecute for Ics20Transfer {
 }
 
 impl AppHandler for Ics20Transfer {}
+
+#[cfg(test)]
+mod tests {
+    use ibc_types::core::{
+        channel::{channel::State as ChannelState, ChannelEnd, Counterparty},
+        client::Height,
+        connection::ConnectionId,
+    };
+    use penumbra_chain::{component::StateWriteExt as _, test_keys};
+    use penumbra_storage::StateDelta;
+
+    use super::*;
+    use crate::{
+        component::{
+            channel::StateWriteExt as _,
+            rate_limit::{StateReadExt as _, StateWriteExt as _},
+        },
+        Ics20RateLimit,
+    };
+
+    fn withdrawal(amount: u64) -> Ics20Withdrawal {
+        Ics20Withdrawal {
+            amount: amount.into(),
+            denom: "upenumbra".try_into().unwrap(),
+            destination_chain_address: "osmo1receiver".to_string(),
+            return_address: *test_keys::ADDRESS_0,
+            timeout_height: Height::new(0, 1_000).unwrap(),
+            timeout_time: 1_000_000_000,
+            source_channel: ChannelId::new(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn withdrawals_in_one_transaction_share_the_rate_limit() -> Result<()> {
+        let mut state = StateDelta::new(());
+        state.put_block_height(1);
+        state.put_channel(
+            &ChannelId::new(0),
+            &PortId::transfer(),
+            ChannelEnd {
+                state: ChannelState::Open,
+                ordering: ChannelOrder::Unordered,
+                remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(7))),
+                connection_hops: vec![ConnectionId::new(0)],
+                version: Version::new("ics20-1".to_string()),
+            },
+        );
+        let (first, second) = (withdrawal(60), withdrawal(60));
+        state.put_ics20_rate_limit(
+            &first.source_channel,
+            &first.denom.id(),
+            Some(Ics20RateLimit {
+                window_blocks: 10,
+                max_inflow: 100u64.into(),
+                max_outflow: 100u64.into(),
+            }),
+        );
+
+        // Each withdrawal is within the rate limit on its own, but not together with the other:
+        state.ics20_withdrawals_check(&[&first]).await?;
+        state.ics20_withdrawals_check(&[&second]).await?;
+        assert!(state
+            .ics20_withdrawals_check(&[&first, &second])
+            .await
+            .is_err());
+        // ...unless they're sent over different channels.
+        let elsewhere = Ics20Withdrawal {
+            source_channel: ChannelId::new(1),
+            ..withdrawal(60)
+        };
+        state.ics20_withdrawals_check(&[&first, &elsewhere]).await?;
+
+        // Executing them regardless fails the transaction, rather than the chain:
+        state.withdrawal_execute(&first).await?;
+        assert!(state.withdrawal_execute(&second).await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use tendermint::abci::{Event, EventAttributeIndexExt};
+
+use crate::{Ics20Flow, Ics20RateLimit};
+
+pub fn ics20_inflow_rate_limited(
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+    flow: &Ics20Flow,
+    limit: &Ics20RateLimit,
+) -> Event {
+    Event::new(
+        "ics20_inflow_rate_limited",
+        [
+            ("channel_id", channel_id.to_string()).index(),
+            ("asset_id", asset_id.to_string()).index(),
+            ("amount", amount.to_string()).index(),
+            ("inflow", flow.inflow().to_string()).index(),
+            ("max_inflow", limit.max_inflow.to_string()).index(),
+            ("window_blocks", limit.window_blocks.to_string()).index(),
+        ],
+    )
+}
+
+pub fn ics20_outflow_rate_limited(
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+    flow: &Ics20Flow,
+    limit: &Ics20RateLimit,
+) -> Event {
+    Event::new(
+        "ics20_outflow_rate_limited",
+        [
+            ("channel_id", channel_id.to_string()).index(),
+            ("asset_id", asset_id.to_string()).index(),
+            ("amount", amount.to_string()).index(),
+            ("outflow", flow.outflow().to_string()).index(),
+            ("max_outflow", limit.max_outflow.to_string()).index(),
+            ("window_blocks", limit.window_blocks.to_string()).index(),
+        ],
+    )
+}
+
+/// Whether an event records a transfer refused by a rate limit.
+pub fn is_rate_limited(event: &Event) -> bool {
+    event.kind == "ics20_inflow_rate_limited" || event.kind == "ics20_outflow_rate_limited"
+}
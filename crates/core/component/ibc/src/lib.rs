@@ -9,11 +9,15 @@
 #[cfg(feature = "component")]
 pub mod component;
 
+pub mod event;
+
 mod ibc_action;
 mod ibc_token;
 mod ics20_withdrawal;
+mod rate_limit;
 mod version;
 
 pub use ibc_action::IbcAction;
 pub use ibc_token::IbcToken;
 pub use ics20_withdrawal::Ics20Withdrawal;
+pub use rate_limit::{Ics20BlockFlow, Ics20Flow, Ics20RateLimit};
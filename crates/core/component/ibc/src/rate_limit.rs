@@ -0,0 +1,276 @@
+use anyhow::{anyhow, Result};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::ibc::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+
+/// A limit on the value of an asset which can be transferred over an ICS-20 channel.
+///
+/// Transfers are counted over a sliding window of the last `window_blocks` blocks, so that the
+/// limit holds across any run of consecutive blocks that long, and each direction is limited
+/// separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Ics20RateLimit", into = "pb::Ics20RateLimit")]
+pub struct Ics20RateLimit {
+    /// The length of the window, in blocks.
+    pub window_blocks: u64,
+    /// The largest amount which can be received over the channel in a window.
+    pub max_inflow: Amount,
+    /// The largest amount which can be sent over the channel in a window.
+    pub max_outflow: Amount,
+}
+
+impl Ics20RateLimit {
+    pub fn validate(&self) -> Result<()> {
+        anyhow::ensure!(
+            self.window_blocks > 0,
+            "rate limit window must be at least one block long"
+        );
+        Ok(())
+    }
+}
+
+/// The value of an asset transferred over an ICS-20 channel in the current rate limit window.
+///
+/// This is kept per block, for the blocks of the window in which anything was transferred, so
+/// that each block's transfers can be dropped as it leaves the window.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Ics20Flow", into = "pb::Ics20Flow")]
+pub struct Ics20Flow {
+    /// The value transferred in each block, oldest first.
+    pub blocks: Vec<Ics20BlockFlow>,
+}
+
+/// The value of an asset transferred over an ICS-20 channel in a single block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Ics20BlockFlow", into = "pb::Ics20BlockFlow")]
+pub struct Ics20BlockFlow {
+    /// The height of the block.
+    pub height: u64,
+    /// The amount received over the channel in the block.
+    pub inflow: Amount,
+    /// The amount sent over the channel in the block.
+    pub outflow: Amount,
+}
+
+impl Ics20Flow {
+    /// The flow in the window ending at `height`, dropping the blocks which have left it.
+    pub fn at_height(mut self, limit: &Ics20RateLimit, height: u64) -> Self {
+        self.blocks
+            .retain(|block| block.height.saturating_add(limit.window_blocks) > height);
+        self
+    }
+
+    /// The amount received over the channel in the window.
+    pub fn inflow(&self) -> Amount {
+        self.blocks.iter().fold(Amount::zero(), |total, block| {
+            total.saturating_add(&block.inflow)
+        })
+    }
+
+    /// The amount sent over the channel in the window.
+    pub fn outflow(&self) -> Amount {
+        self.blocks.iter().fold(Amount::zero(), |total, block| {
+            total.saturating_add(&block.outflow)
+        })
+    }
+
+    /// Add `amount` received at `height` to the inflow, failing if that would exceed the limit.
+    pub fn with_inflow(
+        mut self,
+        limit: &Ics20RateLimit,
+        height: u64,
+        amount: Amount,
+    ) -> Result<Self> {
+        let inflow = self.inflow();
+        anyhow::ensure!(
+            inflow.saturating_add(&amount) <= limit.max_inflow,
+            "inflow rate limit exceeded: {} of {} already received in the last {} blocks",
+            inflow,
+            limit.max_inflow,
+            limit.window_blocks
+        );
+        let block = self.block_mut(height);
+        block.inflow = block.inflow.saturating_add(&amount);
+        Ok(self)
+    }
+
+    /// Add `amount` sent at `height` to the outflow, failing if that would exceed the limit.
+    pub fn with_outflow(
+        mut self,
+        limit: &Ics20RateLimit,
+        height: u64,
+        amount: Amount,
+    ) -> Result<Self> {
+        let outflow = self.outflow();
+        anyhow::ensure!(
+            outflow.saturating_add(&amount) <= limit.max_outflow,
+            "outflow rate limit exceeded: {} of {} already sent in the last {} blocks",
+            outflow,
+            limit.max_outflow,
+            limit.window_blocks
+        );
+        let block = self.block_mut(height);
+        block.outflow = block.outflow.saturating_add(&amount);
+        Ok(self)
+    }
+
+    /// The flow in the block at `height`, which is the latest one.
+    fn block_mut(&mut self, height: u64) -> &mut Ics20BlockFlow {
+        if self.blocks.last().map(|block| block.height) != Some(height) {
+            self.blocks.push(Ics20BlockFlow {
+                height,
+                inflow: Amount::zero(),
+                outflow: Amount::zero(),
+            });
+        }
+        self.blocks.last_mut().expect("just pushed a block")
+    }
+}
+
+impl TypeUrl for Ics20RateLimit {
+    const TYPE_URL: &'static str = "/penumbra.core.ibc.v1alpha1.Ics20RateLimit";
+}
+
+impl DomainType for Ics20RateLimit {
+    type Proto = pb::Ics20RateLimit;
+}
+
+impl From<Ics20RateLimit> for pb::Ics20RateLimit {
+    fn from(limit: Ics20RateLimit) -> Self {
+        pb::Ics20RateLimit {
+            window_blocks: limit.window_blocks,
+            max_inflow: Some(limit.max_inflow.into()),
+            max_outflow: Some(limit.max_outflow.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Ics20RateLimit> for Ics20RateLimit {
+    type Error = anyhow::Error;
+
+    fn try_from(limit: pb::Ics20RateLimit) -> Result<Self, Self::Error> {
+        Ok(Ics20RateLimit {
+            window_blocks: limit.window_blocks,
+            max_inflow: limit
+                .max_inflow
+                .ok_or_else(|| anyhow!("missing max inflow"))?
+                .try_into()?,
+            max_outflow: limit
+                .max_outflow
+                .ok_or_else(|| anyhow!("missing max outflow"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl TypeUrl for Ics20Flow {
+    const TYPE_URL: &'static str = "/penumbra.core.ibc.v1alpha1.Ics20Flow";
+}
+
+impl DomainType for Ics20Flow {
+    type Proto = pb::Ics20Flow;
+}
+
+impl From<Ics20Flow> for pb::Ics20Flow {
+    fn from(flow: Ics20Flow) -> Self {
+        pb::Ics20Flow {
+            blocks: flow.blocks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::Ics20Flow> for Ics20Flow {
+    type Error = anyhow::Error;
+
+    fn try_from(flow: pb::Ics20Flow) -> Result<Self, Self::Error> {
+        Ok(Ics20Flow {
+            blocks: flow
+                .blocks
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl TypeUrl for Ics20BlockFlow {
+    const TYPE_URL: &'static str = "/penumbra.core.ibc.v1alpha1.Ics20BlockFlow";
+}
+
+impl DomainType for Ics20BlockFlow {
+    type Proto = pb::Ics20BlockFlow;
+}
+
+impl From<Ics20BlockFlow> for pb::Ics20BlockFlow {
+    fn from(block: Ics20BlockFlow) -> Self {
+        pb::Ics20BlockFlow {
+            height: block.height,
+            inflow: Some(block.inflow.into()),
+            outflow: Some(block.outflow.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Ics20BlockFlow> for Ics20BlockFlow {
+    type Error = anyhow::Error;
+
+    fn try_from(block: pb::Ics20BlockFlow) -> Result<Self, Self::Error> {
+        Ok(Ics20BlockFlow {
+            height: block.height,
+            inflow: block
+                .inflow
+                .ok_or_else(|| anyhow!("missing inflow"))?
+                .try_into()?,
+            outflow: block
+                .outflow
+                .ok_or_else(|| anyhow!("missing outflow"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flow_is_limited_over_a_sliding_window() {
+        let limit = Ics20RateLimit {
+            window_blocks: 10,
+            max_inflow: 100u64.into(),
+            max_outflow: 50u64.into(),
+        };
+
+        let flow = Ics20Flow::default()
+            .with_inflow(&limit, 5, 60u64.into())
+            .unwrap()
+            .with_outflow(&limit, 5, 50u64.into())
+            .unwrap();
+
+        // Each direction is limited separately:
+        assert!(flow.clone().with_inflow(&limit, 5, 41u64.into()).is_err());
+        assert!(flow.clone().with_outflow(&limit, 5, 1u64.into()).is_err());
+        let flow = flow
+            .at_height(&limit, 8)
+            .with_inflow(&limit, 8, 40u64.into())
+            .unwrap();
+        assert_eq!(flow.blocks.len(), 2);
+        assert_eq!(flow.inflow(), 100u64.into());
+
+        // The transfers in a block count until `window_blocks` blocks after it:
+        assert_eq!(flow.clone().at_height(&limit, 14), flow);
+        let flow = flow.at_height(&limit, 15);
+        assert_eq!(flow.inflow(), 40u64.into());
+        assert_eq!(flow.outflow(), Amount::zero());
+        assert!(flow.clone().with_outflow(&limit, 15, 50u64.into()).is_ok());
+
+        // Rather than starting afresh once a window elapses, the quota frees up block by block:
+        assert!(flow.clone().with_inflow(&limit, 15, 61u64.into()).is_err());
+        let flow = flow
+            .with_inflow(&limit, 15, 60u64.into())
+            .unwrap()
+            .at_height(&limit, 18);
+        assert_eq!(flow.inflow(), 60u64.into());
+        assert_eq!(flow.blocks.len(), 1);
+    }
+}
@@ -0,0 +1,63 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use penumbra_sct::component::TreeWriter;
+use penumbra_storage::{StateDelta, StateWrite};
+use penumbra_tct::{StateCommitment, Tree, Witness};
+use rand::{rngs::StdRng, SeedableRng};
+use tokio::runtime::Runtime;
+
+/// The number of commitments added to the tree in each block.
+const BLOCK_SIZE: usize = 100;
+
+/// Make a tree holding `blocks` blocks' worth of commitments.
+fn make_tree(rng: &mut StdRng, blocks: usize) -> Tree {
+    let mut tree = Tree::new();
+    for _ in 0..blocks {
+        add_block(rng, &mut tree);
+    }
+    tree
+}
+
+fn add_block(rng: &mut StdRng, tree: &mut Tree) {
+    for _ in 0..BLOCK_SIZE {
+        tree.insert(Witness::Forget, StateCommitment::random(&mut *rng))
+            .expect("tree is not full");
+    }
+    tree.end_block().expect("tree is not full");
+}
+
+/// Compare the cost of persisting one more block of commitments to trees of increasing size,
+/// either by serializing the whole tree, or by only writing the changes to it.
+fn benchmarks(c: &mut Criterion) {
+    let rt = Runtime::new().expect("can start runtime");
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let mut group = c.benchmark_group("write one block");
+    for blocks in [10, 100, 1000] {
+        let tree = make_tree(&mut rng, blocks);
+        let mut next = tree.clone();
+        add_block(&mut rng, &mut next);
+
+        group.bench_with_input(BenchmarkId::new("bincode", blocks), &next, |b, next| {
+            let mut state = StateDelta::new(());
+            b.iter(|| {
+                let bytes = bincode::serialize(next).expect("can serialize tree");
+                state.nonverifiable_put_raw(b"sct/state_commitment_tree".to_vec(), bytes);
+            })
+        });
+
+        let mut state = StateDelta::new(());
+        rt.block_on(tree.to_async_writer(&mut TreeWriter(&mut state)))
+            .expect("can write tree");
+        group.bench_with_input(BenchmarkId::new("incremental", blocks), &next, |b, next| {
+            b.iter(|| {
+                let mut delta = StateDelta::new(&state);
+                rt.block_on(next.to_async_writer(&mut TreeWriter(&mut delta)))
+                    .expect("can write tree");
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+mod tree_store;
 mod view;
 
+pub use tree_store::{TreeReader, TreeWriter};
 pub use view::{SctManager, StateReadExt};
@@ -0,0 +1,278 @@
+use std::ops::Range;
+
+use anyhow::{anyhow, Context as _, Result};
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use penumbra_storage::{StateRead, StateWrite};
+use penumbra_tct::{
+    storage::{AsyncRead, AsyncWrite, StoredPosition},
+    structure::Hash,
+    Forgotten, Position, StateCommitment,
+};
+
+use crate::state_key;
+
+/// Reads the state commitment tree out of the nonverifiable storage, where [`TreeWriter`] keeps
+/// its hashes and commitments keyed by position.
+pub struct TreeReader<'a, S: ?Sized>(pub &'a S);
+
+/// Incrementally writes the state commitment tree into the nonverifiable storage, so that each
+/// write only touches the parts of the tree which changed since the last one.
+pub struct TreeWriter<'a, S: ?Sized>(pub &'a mut S);
+
+#[async_trait]
+impl<S: StateRead + ?Sized> AsyncRead for TreeReader<'_, S> {
+    type Error = anyhow::Error;
+
+    type HashesStream<'a>
+        = BoxStream<'static, Result<(Position, u8, Hash)>>
+    where
+        Self: 'a;
+
+    type CommitmentsStream<'a>
+        = BoxStream<'static, Result<(Position, StateCommitment)>>
+    where
+        Self: 'a;
+
+    async fn position(&mut self) -> Result<StoredPosition> {
+        match self
+            .0
+            .nonverifiable_get_raw(state_key::tree::position())
+            .await?
+        {
+            Some(bytes) => bincode::deserialize(&bytes).context("failed to decode SCT position"),
+            None => Ok(StoredPosition::default()),
+        }
+    }
+
+    async fn forgotten(&mut self) -> Result<Forgotten> {
+        match self
+            .0
+            .nonverifiable_get_raw(state_key::tree::forgotten())
+            .await?
+        {
+            Some(bytes) => {
+                bincode::deserialize(&bytes).context("failed to decode SCT forgotten version")
+            }
+            None => Ok(Forgotten::default()),
+        }
+    }
+
+    async fn hash(&mut self, position: Position, height: u8) -> Result<Option<Hash>> {
+        self.0
+            .nonverifiable_get_raw(&state_key::tree::hash(position, height))
+            .await?
+            .map(|bytes| decode_hash(&bytes))
+            .transpose()
+    }
+
+    fn hashes(&mut self) -> Self::HashesStream<'_> {
+        let prefix_len = state_key::tree::hashes_prefix().len();
+        self.0
+            .nonverifiable_prefix_raw(state_key::tree::hashes_prefix())
+            .and_then(move |(key, bytes)| async move {
+                let (position, height) = <[u8; 9]>::try_from(&key[prefix_len..])
+                    .map(|suffix| {
+                        let position = u64::from_be_bytes(
+                            suffix[..8].try_into().expect("slice is 8 bytes long"),
+                        );
+                        (Position::from(position), suffix[8])
+                    })
+                    .map_err(|_| anyhow!("SCT hash key was of incorrect length"))?;
+                Ok((position, height, decode_hash(&bytes)?))
+            })
+            .boxed()
+    }
+
+    async fn commitment(&mut self, position: Position) -> Result<Option<StateCommitment>> {
+        self.0
+            .nonverifiable_get_raw(&state_key::tree::commitment(position))
+            .await?
+            .map(|bytes| decode_commitment(&bytes))
+            .transpose()
+    }
+
+    fn commitments(&mut self) -> Self::CommitmentsStream<'_> {
+        let prefix_len = state_key::tree::commitments_prefix().len();
+        self.0
+            .nonverifiable_prefix_raw(state_key::tree::commitments_prefix())
+            .and_then(move |(key, bytes)| async move {
+                let position = <[u8; 8]>::try_from(&key[prefix_len..])
+                    .map(u64::from_be_bytes)
+                    .map_err(|_| anyhow!("SCT commitment key was of incorrect length"))?;
+                Ok((Position::from(position), decode_commitment(&bytes)?))
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl<S: StateWrite + ?Sized> AsyncRead for TreeWriter<'_, S> {
+    type Error = anyhow::Error;
+
+    type HashesStream<'a>
+        = BoxStream<'static, Result<(Position, u8, Hash)>>
+    where
+        Self: 'a;
+
+    type CommitmentsStream<'a>
+        = BoxStream<'static, Result<(Position, StateCommitment)>>
+    where
+        Self: 'a;
+
+    async fn position(&mut self) -> Result<StoredPosition> {
+        TreeReader(&*self.0).position().await
+    }
+
+    async fn forgotten(&mut self) -> Result<Forgotten> {
+        TreeReader(&*self.0).forgotten().await
+    }
+
+    async fn hash(&mut self, position: Position, height: u8) -> Result<Option<Hash>> {
+        TreeReader(&*self.0).hash(position, height).await
+    }
+
+    fn hashes(&mut self) -> Self::HashesStream<'_> {
+        TreeReader(&*self.0).hashes()
+    }
+
+    async fn commitment(&mut self, position: Position) -> Result<Option<StateCommitment>> {
+        TreeReader(&*self.0).commitment(position).await
+    }
+
+    fn commitments(&mut self) -> Self::CommitmentsStream<'_> {
+        TreeReader(&*self.0).commitments()
+    }
+}
+
+#[async_trait]
+impl<S: StateWrite + ?Sized> AsyncWrite for TreeWriter<'_, S> {
+    async fn add_hash(
+        &mut self,
+        position: Position,
+        height: u8,
+        hash: Hash,
+        _essential: bool,
+    ) -> Result<()> {
+        self.0.nonverifiable_put_raw(
+            state_key::tree::hash(position, height),
+            hash.to_bytes().to_vec(),
+        );
+        Ok(())
+    }
+
+    async fn add_commitment(
+        &mut self,
+        position: Position,
+        commitment: StateCommitment,
+    ) -> Result<()> {
+        self.0.nonverifiable_put_raw(
+            state_key::tree::commitment(position),
+            <[u8; 32]>::from(commitment).to_vec(),
+        );
+        Ok(())
+    }
+
+    async fn delete_range(&mut self, below_height: u8, positions: Range<Position>) -> Result<()> {
+        let start = u64::from(positions.start).to_be_bytes().to_vec();
+        let end = u64::from(positions.end).to_be_bytes().to_vec();
+
+        // Hash keys are ordered by position first, so the range of positions covers every height,
+        // and we filter out the hashes which are high enough to be kept.
+        let hashes: Vec<Vec<u8>> = self
+            .0
+            .nonverifiable_range_raw(
+                Some(state_key::tree::hashes_prefix()),
+                start.clone()..end.clone(),
+            )?
+            .map_ok(|(key, _)| key)
+            .try_filter(|key| {
+                futures::future::ready(matches!(key.last(), Some(height) if *height < below_height))
+            })
+            .try_collect()
+            .await?;
+        let commitments: Vec<Vec<u8>> = self
+            .0
+            .nonverifiable_range_raw(Some(state_key::tree::commitments_prefix()), start..end)?
+            .map_ok(|(key, _)| key)
+            .try_collect()
+            .await?;
+
+        for key in hashes.into_iter().chain(commitments) {
+            self.0.nonverifiable_delete(key);
+        }
+
+        Ok(())
+    }
+
+    async fn set_position(&mut self, position: StoredPosition) -> Result<()> {
+        self.0.nonverifiable_put_raw(
+            state_key::tree::position().to_vec(),
+            bincode::serialize(&position)?,
+        );
+        Ok(())
+    }
+
+    async fn set_forgotten(&mut self, forgotten: Forgotten) -> Result<()> {
+        self.0.nonverifiable_put_raw(
+            state_key::tree::forgotten().to_vec(),
+            bincode::serialize(&forgotten)?,
+        );
+        Ok(())
+    }
+}
+
+fn decode_hash(bytes: &[u8]) -> Result<Hash> {
+    <[u8; 32]>::try_from(bytes)
+        .map_err(|_| anyhow!("SCT hash was of incorrect length"))
+        .and_then(|array| Hash::from_bytes(array).map_err(Into::into))
+}
+
+fn decode_commitment(bytes: &[u8]) -> Result<StateCommitment> {
+    <[u8; 32]>::try_from(bytes)
+        .map_err(|_| anyhow!("SCT commitment was of incorrect length"))
+        .and_then(|array| StateCommitment::try_from(array).map_err(Into::into))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use penumbra_storage::StateDelta;
+    use penumbra_tct::{Tree, Witness};
+
+    #[tokio::test]
+    async fn tree_store_roundtrip() -> Result<()> {
+        let mut state = StateDelta::new(());
+
+        // Nothing has been stored yet, so this should be the empty tree:
+        let deserialized = Tree::from_async_reader(&mut TreeReader(&state)).await?;
+        assert_eq!(deserialized, Tree::new());
+
+        let mut tree = Tree::new();
+        tree.insert(Witness::Keep, StateCommitment::try_from([0; 32])?)?;
+        tree.end_block()?;
+        tree.insert(Witness::Forget, StateCommitment::try_from([1; 32])?)?;
+        tree.end_epoch()?;
+        tree.insert(Witness::Keep, StateCommitment::try_from([2; 32])?)?;
+        tree.to_async_writer(&mut TreeWriter(&mut state)).await?;
+
+        let deserialized = Tree::from_async_reader(&mut TreeReader(&state)).await?;
+        assert_eq!(tree, deserialized);
+
+        // Forgetting a commitment and writing again should only write the difference, and
+        // delete the forgotten commitment:
+        tree.forget(StateCommitment::try_from([0; 32])?);
+        tree.insert(Witness::Keep, StateCommitment::try_from([3; 32])?)?;
+        tree.to_async_writer(&mut TreeWriter(&mut state)).await?;
+
+        let deserialized = Tree::from_async_reader(&mut TreeReader(&state)).await?;
+        assert_eq!(tree, deserialized);
+        assert_eq!(
+            TreeReader(&state).commitment(Position::from(0u64)).await?,
+            None
+        );
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use tct::builder::{block, epoch};
 
 // TODO: make epoch management the responsibility of this component
 
+use super::{TreeReader, TreeWriter};
 use crate::state_key;
 
 /// This trait provides read access to common parts of the Penumbra
@@ -28,16 +29,30 @@ pub trait StateReadExt: StateRead {
             return tree;
         }
 
-        match self
-            .nonverifiable_get_raw(state_key::state_commitment_tree().as_bytes())
+        // A node which hasn't run the `sct-storage` migration still has the whole tree serialized
+        // under the legacy key, and nothing in the incremental storage: read it from there, so
+        // that the next write moves it over, rather than starting again from the empty tree.
+        if self
+            .nonverifiable_get_raw(state_key::tree::position())
             .await
-            .expect("able to retrieve state commitment tree from nonverifiable storage")
+            .expect("able to read state commitment tree position from nonverifiable storage")
+            .is_none()
         {
-            Some(bytes) => bincode::deserialize(&bytes).expect(
-                "able to deserialize stored state commitment tree from nonverifiable storage",
-            ),
-            None => tct::Tree::new(),
+            if let Some(bytes) = self
+                .nonverifiable_get_raw(state_key::state_commitment_tree().as_bytes())
+                .await
+                .expect("able to read legacy state commitment tree from nonverifiable storage")
+            {
+                return bincode::deserialize(&bytes)
+                    .expect("able to deserialize legacy state commitment tree");
+            }
         }
+
+        // Otherwise, load it from the hashes and commitments in the nonverifiable storage; if
+        // nothing has been stored yet, this is the empty tree.
+        tct::Tree::from_async_reader(&mut TreeReader(self))
+            .await
+            .expect("able to load state commitment tree from nonverifiable storage")
     }
 
     async fn anchor_by_height(&self, height: u64) -> Result<Option<tct::Root>> {
@@ -131,18 +146,25 @@ trait StateWriteExt: StateWrite {
         self.object_put(state_key::cached_state_commitment_tree(), tree);
     }
 
-    // Serialize the current state commitment tree to storage (slightly more expensive, should only
-    // happen once a block).
+    // Write the changes to the current state commitment tree since it was last written to storage
+    // (slightly more expensive, should only happen once a block).
     async fn write_state_commitment_tree(&mut self) {
         // If the cached tree is dirty, flush it to storage
         if let Some(tree) = self.object_get::<tct::Tree>(state_key::cached_state_commitment_tree())
         {
-            let bytes = bincode::serialize(&tree)
-                .expect("able to serialize state commitment tree to bincode");
-            self.nonverifiable_put_raw(
-                state_key::state_commitment_tree().as_bytes().to_vec(),
-                bytes,
-            );
+            // The first incremental write of a tree read from the legacy key holds all of it, so
+            // the legacy copy can go.
+            if self
+                .nonverifiable_get_raw(state_key::tree::position())
+                .await
+                .expect("able to read state commitment tree position from nonverifiable storage")
+                .is_none()
+            {
+                self.nonverifiable_delete(state_key::state_commitment_tree().as_bytes().to_vec());
+            }
+            tree.to_async_writer(&mut TreeWriter(self))
+                .await
+                .expect("able to write state commitment tree to nonverifiable storage");
         }
     }
 
@@ -189,3 +211,46 @@ trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use penumbra_storage::StateDelta;
+
+    #[tokio::test]
+    async fn legacy_tree_is_read_and_moved() -> Result<()> {
+        let mut state = StateDelta::new(());
+
+        let mut tree = tct::Tree::new();
+        tree.insert(tct::Witness::Keep, tct::StateCommitment::try_from([0; 32])?)?;
+        tree.end_block()?;
+        tree.insert(
+            tct::Witness::Forget,
+            tct::StateCommitment::try_from([1; 32])?,
+        )?;
+        state.nonverifiable_put_raw(
+            state_key::state_commitment_tree().as_bytes().to_vec(),
+            bincode::serialize(&tree)?,
+        );
+
+        // Without the migration, the tree is read from the legacy key...
+        assert_eq!(state.state_commitment_tree().await, tree);
+
+        // ...and once written, it lives only in the incremental storage.
+        state.put_state_commitment_tree(tree.clone());
+        state.write_state_commitment_tree().await;
+        assert_eq!(
+            state
+                .nonverifiable_get_raw(state_key::state_commitment_tree().as_bytes())
+                .await?,
+            None
+        );
+        assert_eq!(
+            tct::Tree::from_async_reader(&mut TreeReader(&state)).await?,
+            tree
+        );
+
+        Ok(())
+    }
+}
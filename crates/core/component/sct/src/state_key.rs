@@ -2,7 +2,7 @@ use std::string::String;
 
 use penumbra_tct::{
     builder::{block, epoch},
-    Root, StateCommitment,
+    Position, Root, StateCommitment,
 };
 
 pub fn anchor_by_height(height: u64) -> String {
@@ -13,6 +13,8 @@ pub fn anchor_lookup(anchor: Root) -> String {
     format!("sct/valid_anchors/{anchor}")
 }
 
+// The whole SCT used to be stored under this key as a single bincode blob; it is only read until
+// the tree has been moved into the incremental storage below.
 pub fn state_commitment_tree() -> &'static str {
     "sct/state_commitment_tree"
 }
@@ -37,6 +39,42 @@ pub fn note_source(note_commitment: &StateCommitment) -> String {
     format!("sct/note_source/{note_commitment}")
 }
 
+// These are used in the nonverifiable store, to persist the SCT incrementally:
+pub mod tree {
+    use super::*;
+
+    pub fn position() -> &'static [u8] {
+        b"sct/tree/position"
+    }
+
+    pub fn forgotten() -> &'static [u8] {
+        b"sct/tree/forgotten"
+    }
+
+    pub fn hashes_prefix() -> &'static [u8] {
+        b"sct/tree/hashes/"
+    }
+
+    /// Hashes are keyed by big-endian position, then height, so that a range query over positions
+    /// finds every hash within it.
+    pub fn hash(position: Position, height: u8) -> Vec<u8> {
+        let mut key = hashes_prefix().to_vec();
+        key.extend(u64::from(position).to_be_bytes());
+        key.push(height);
+        key
+    }
+
+    pub fn commitments_prefix() -> &'static [u8] {
+        b"sct/tree/commitments/"
+    }
+
+    pub fn commitment(position: Position) -> Vec<u8> {
+        let mut key = commitments_prefix().to_vec();
+        key.extend(u64::from(position).to_be_bytes());
+        key
+    }
+}
+
 // In-memory state key for caching the current SCT (avoids serialization overhead)
 pub fn cached_state_commitment_tree() -> &'static str {
     "sct/cached_state_commitment_tree"
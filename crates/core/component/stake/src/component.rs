@@ -733,6 +733,12 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 );
                 gauge!(metrics::MISSED_BLOCKS, uptime.num_missed_blocks() as f64, "identity_key" => identity_key.to_string());
 
+                // If the window length has been changed by governance, resize the uptime
+                // tracker before recording this block:
+                if uptime.signed_blocks_window_len() as u64 != params.signed_blocks_window_len {
+                    uptime.resize(params.signed_blocks_window_len as usize);
+                }
+
                 uptime.mark_height_as_signed(height, voted)?;
                 if uptime.num_missed_blocks() as u64 >= params.missed_blocks_maximum {
                     self.set_validator_state(&identity_key, validator::State::Jailed)
@@ -21,9 +21,6 @@ pub struct Uptime {
     // Note: tracking this means we *could* in principle answer queries by
     // height, they just might be surprising for new validators (we just report
     // *failures* to sign, not didn't sign)
-    //
-    // can also possibly extend this impl to support resizing the window when we
-    // get to that
     as_of_block_height: u64,
     signatures: BitVec<u8, Lsb0>,
 }
@@ -77,6 +74,31 @@ impl Uptime {
     pub fn num_missed_blocks(&self) -> usize {
         self.signatures.iter_zeros().len()
     }
+
+    /// The number of blocks in the window this tracker records.
+    pub fn signed_blocks_window_len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Change the number of blocks in the window this tracker records.
+    ///
+    /// The records for the most recent blocks are kept, up to the new window length. If the window
+    /// grows, the blocks before the oldest record are treated as signed, just as for a new
+    /// validator.
+    pub fn resize(&mut self, signed_blocks_window_len: usize) {
+        let mut signatures = bitvec![u8, Lsb0; 1; signed_blocks_window_len];
+
+        let kept = signed_blocks_window_len.min(self.signatures.len()) as u64;
+        let oldest = self
+            .as_of_block_height
+            .saturating_sub(kept.saturating_sub(1));
+        for height in oldest..=self.as_of_block_height {
+            let signed = self.signatures[(height as usize) % self.signatures.len()];
+            signatures.set((height as usize) % signed_blocks_window_len, signed);
+        }
+
+        self.signatures = signatures;
+    }
 }
 
 impl TypeUrl for Uptime {
@@ -141,6 +163,35 @@ mod tests {
         assert!(uptime.mark_height_as_signed(0, true).is_err());
     }
 
+    #[test]
+    fn resizes_window() {
+        let window = 100;
+        let mut uptime = Uptime::new(0, window);
+
+        // Miss every block in the second half of the window
+        for h in 1..(window + 1) {
+            uptime
+                .mark_height_as_signed(h as u64, h <= window / 2)
+                .unwrap();
+        }
+        assert_eq!(uptime.num_missed_blocks(), window / 2);
+
+        // Shrinking the window keeps only the most recent records...
+        uptime.resize(window / 4);
+        assert_eq!(uptime.signed_blocks_window_len(), window / 4);
+        assert_eq!(uptime.num_missed_blocks(), window / 4);
+
+        // ...and growing it treats the blocks before them as signed
+        uptime.resize(2 * window);
+        assert_eq!(uptime.num_missed_blocks(), window / 4);
+
+        // The resized tracker keeps recording blocks as a ring buffer
+        for h in (window + 1)..(3 * window + 1) {
+            uptime.mark_height_as_signed(h as u64, true).unwrap();
+        }
+        assert_eq!(uptime.num_missed_blocks(), 0);
+    }
+
     #[test]
     fn proto_round_trip() {
         // make a weird size window
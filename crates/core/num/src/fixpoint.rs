@@ -414,12 +414,9 @@ impl U128x128Var {
         lo_128_bits.into_iter().chain(hi_128_bits).collect()
     }
 
-    /// This function enforces the ordering between `self` and `other`.
-    pub fn enforce_cmp(
-        &self,
-        other: &U128x128Var,
-        ordering: std::cmp::Ordering,
-    ) -> Result<(), SynthesisError> {
+    /// Compare `self` and `other` bitwise, returning `(gt, lt)` where `gt` is true
+    /// if `self > other` and `lt` is true if `self < other`.
+    fn cmp_bits(&self, other: &U128x128Var) -> Result<(Boolean<Fq>, Boolean<Fq>), SynthesisError> {
         // Collect bits from each limb to be compared.
         let self_bits: Vec<Boolean<Fq>> = self.to_bits_le().into_iter().rev().collect();
         let other_bits: Vec<Boolean<Fq>> = other.to_bits_le().into_iter().rev().collect();
@@ -440,6 +437,23 @@ impl U128x128Var {
             lt = lt.or(&gt.not().and(&q)?.and(&p.not())?)?;
         }
 
+        Ok((gt, lt))
+    }
+
+    /// Returns a `Boolean` which is true if `self > other`.
+    pub fn is_gt(&self, other: &U128x128Var) -> Result<Boolean<Fq>, SynthesisError> {
+        let (gt, _lt) = self.cmp_bits(other)?;
+        Ok(gt)
+    }
+
+    /// This function enforces the ordering between `self` and `other`.
+    pub fn enforce_cmp(
+        &self,
+        other: &U128x128Var,
+        ordering: std::cmp::Ordering,
+    ) -> Result<(), SynthesisError> {
+        let (gt, lt) = self.cmp_bits(other)?;
+
         match ordering {
             std::cmp::Ordering::Greater => {
                 gt.enforce_equal(&Boolean::constant(true))?;
@@ -16,7 +16,7 @@ use penumbra_dex::{
 };
 use penumbra_fee::Fee;
 use penumbra_governance::{DelegatorVote, ProposalSubmit, ProposalWithdraw, ValidatorVote};
-use penumbra_ibc::IbcAction;
+use penumbra_ibc::{IbcAction, Ics20Withdrawal};
 use penumbra_keys::{FullViewingKey, PayloadKey};
 use penumbra_proto::{
     core::transaction::v1alpha1::{self as pbt},
@@ -288,6 +288,16 @@ impl Transaction {
         })
     }
 
+    pub fn ics20_withdrawals(&self) -> impl Iterator<Item = &Ics20Withdrawal> {
+        self.actions().filter_map(|action| {
+            if let Action::Ics20Withdrawal(w) = action {
+                Some(w)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn validator_votes(&self) -> impl Iterator<Item = &ValidatorVote> {
         self.actions().filter_map(|action| {
             if let Action::ValidatorVote(v) = action {
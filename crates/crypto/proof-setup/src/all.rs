@@ -0,0 +1,444 @@
+//! This module bundles together the phase 2 elements of every circuit we need a setup for.
+//!
+//! The summoning ceremony runs phase 2 for all of our circuits at once, so that a single
+//! participant contributes to each of them in one go. This module provides the types
+//! for that, along with their conversions to and from the ceremony protocol messages.
+use anyhow::{anyhow, Result};
+use ark_groth16::ProvingKey;
+use ark_relations::r1cs::ConstraintMatrices;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use decaf377::Bls12_377;
+use penumbra_proto::tools::summoning::v1alpha1::{self as pb, participate_request};
+use rand_core::CryptoRngCore;
+
+use crate::{
+    combine, dlog,
+    group::{GroupHasher, F},
+    log::{ContributionHash, Hashable},
+    phase1, phase2, transition, ExtraTransitionInformation,
+};
+
+/// The number of circuits which take part in the ceremony.
+pub const NUM_CIRCUITS: usize = 7;
+
+/// The names of the circuits, in the order used throughout this module.
+///
+/// This order matches the order of the fields in the `CeremonyCrs` message.
+pub const CIRCUIT_NAMES: [&str; NUM_CIRCUITS] = [
+    "spend",
+    "output",
+    "delegator_vote",
+    "undelegate_claim",
+    "swap",
+    "swap_claim",
+    "nullifier_derivation",
+];
+
+fn to_bytes<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    value.serialize_uncompressed(&mut out)?;
+    Ok(out)
+}
+
+fn from_bytes<T: CanonicalDeserialize>(data: &[u8]) -> Result<T> {
+    Ok(T::deserialize_uncompressed(data)?)
+}
+
+/// Collect exactly one item per circuit into an array.
+fn to_array<T>(items: impl IntoIterator<Item = T>) -> Result<[T; NUM_CIRCUITS]> {
+    let items: Vec<T> = items.into_iter().collect();
+    let len = items.len();
+    items
+        .try_into()
+        .map_err(|_| anyhow!("expected {} circuits, found {}", NUM_CIRCUITS, len))
+}
+
+/// The raw phase 2 elements for every circuit, not yet validated.
+#[derive(Clone, Debug)]
+pub struct Phase2RawCeremonyCRS([phase2::RawCRSElements; NUM_CIRCUITS]);
+
+impl Phase2RawCeremonyCRS {
+    /// Validate these elements against the root elements of the ceremony.
+    #[must_use]
+    pub fn validate<R: CryptoRngCore>(
+        self,
+        rng: &mut R,
+        root: &Phase2CeremonyCRS,
+    ) -> Option<Phase2CeremonyCRS> {
+        let mut out = Vec::with_capacity(NUM_CIRCUITS);
+        for (raw, root) in self.0.into_iter().zip(root.0.iter()) {
+            out.push(raw.validate(rng, root)?);
+        }
+        to_array(out).ok().map(Phase2CeremonyCRS)
+    }
+
+    /// Skip validation, treating these elements as valid.
+    ///
+    /// This should only be used for elements coming from a trusted source,
+    /// like the coordinator's own transcript, or for the parent elements a participant
+    /// is asked to build upon, whose validity is the coordinator's responsibility.
+    pub fn assume_valid(self) -> Phase2CeremonyCRS {
+        Phase2CeremonyCRS(self.0.map(|raw| phase2::CRSElements { raw }))
+    }
+}
+
+impl TryFrom<pb::CeremonyCrs> for Phase2RawCeremonyCRS {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::CeremonyCrs) -> Result<Self> {
+        Ok(Self(to_array([
+            from_bytes(&value.spend)?,
+            from_bytes(&value.output)?,
+            from_bytes(&value.delegator_vote)?,
+            from_bytes(&value.undelegate_claim)?,
+            from_bytes(&value.swap)?,
+            from_bytes(&value.swap_claim)?,
+            from_bytes(&value.nullifer_derivation_crs)?,
+        ])?))
+    }
+}
+
+/// The phase 2 elements for every circuit, after validation.
+#[derive(Clone, Debug)]
+pub struct Phase2CeremonyCRS([phase2::CRSElements; NUM_CIRCUITS]);
+
+impl Phase2CeremonyCRS {
+    /// Create the root elements of phase 2, by transitioning from the output of phase 1.
+    ///
+    /// The circuits must be given in the order of [`CIRCUIT_NAMES`], and the phase 1 elements
+    /// must have a large enough degree for each of them.
+    pub fn root(
+        phase1: &phase1::CRSElements,
+        circuits: &[ConstraintMatrices<F>; NUM_CIRCUITS],
+    ) -> Result<(Self, [ExtraTransitionInformation; NUM_CIRCUITS])> {
+        let mut extras = Vec::with_capacity(NUM_CIRCUITS);
+        let mut elements = Vec::with_capacity(NUM_CIRCUITS);
+        for circuit in circuits {
+            let (extra, root) = transition(phase1, circuit)?;
+            extras.push(extra);
+            elements.push(root);
+        }
+        Ok((Self(to_array(elements)?), to_array(extras)?))
+    }
+
+    /// The elements of each circuit, in the order of [`CIRCUIT_NAMES`].
+    pub fn circuits(&self) -> &[phase2::CRSElements; NUM_CIRCUITS] {
+        &self.0
+    }
+}
+
+impl Hashable for Phase2CeremonyCRS {
+    fn hash(&self) -> ContributionHash {
+        let mut hasher = GroupHasher::new(b"PC$:all_crs2");
+        for elements in &self.0 {
+            hasher.eat_bytes(elements.hash().as_ref());
+        }
+        ContributionHash(hasher.finalize_bytes())
+    }
+}
+
+impl TryFrom<Phase2CeremonyCRS> for pb::CeremonyCrs {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Phase2CeremonyCRS) -> Result<Self> {
+        let [spend, output, delegator_vote, undelegate_claim, swap, swap_claim, nullifier_derivation] =
+            value.0;
+        Ok(Self {
+            spend: to_bytes(&spend.raw)?,
+            output: to_bytes(&output.raw)?,
+            delegator_vote: to_bytes(&delegator_vote.raw)?,
+            undelegate_claim: to_bytes(&undelegate_claim.raw)?,
+            swap: to_bytes(&swap.raw)?,
+            swap_claim: to_bytes(&swap_claim.raw)?,
+            nullifer_derivation_crs: to_bytes(&nullifier_derivation.raw)?,
+        })
+    }
+}
+
+/// A contribution to every circuit, before validation.
+#[derive(Clone, Debug)]
+pub struct Phase2RawCeremonyContribution([phase2::RawContribution; NUM_CIRCUITS]);
+
+impl Phase2RawCeremonyContribution {
+    /// Parse a contribution received from a participant.
+    ///
+    /// The protocol messages don't carry the parent hashes, so we need the
+    /// parent elements the participant was asked to build upon.
+    pub fn parse(
+        parent: &Phase2CeremonyCRS,
+        value: participate_request::Contribution,
+    ) -> Result<Self> {
+        let new_elements = Phase2RawCeremonyCRS::try_from(
+            value
+                .updated
+                .ok_or_else(|| anyhow!("contribution is missing updated elements"))?,
+        )?;
+        let linking_proofs: Vec<dlog::Proof> = from_bytes(&value.update_proof)?;
+        let contributions = new_elements
+            .0
+            .into_iter()
+            .zip(linking_proofs)
+            .zip(parent.0.iter())
+            .map(
+                |((new_elements, linking_proof), parent)| phase2::RawContribution {
+                    parent: parent.hash(),
+                    new_elements,
+                    linking_proof,
+                },
+            );
+        Ok(Self(to_array(contributions)?))
+    }
+
+    /// Validate this contribution against the root elements of the ceremony.
+    #[must_use]
+    pub fn validate<R: CryptoRngCore>(
+        self,
+        rng: &mut R,
+        root: &Phase2CeremonyCRS,
+    ) -> Option<Phase2CeremonyContribution> {
+        let mut out = Vec::with_capacity(NUM_CIRCUITS);
+        for (raw, root) in self.0.into_iter().zip(root.0.iter()) {
+            out.push(raw.validate(rng, root)?);
+        }
+        to_array(out).ok().map(Phase2CeremonyContribution)
+    }
+
+    /// Skip validation, treating this contribution as valid.
+    ///
+    /// This should only be used for contributions which have already been validated,
+    /// like those read back from the coordinator's own transcript.
+    pub fn assume_valid(self) -> Phase2CeremonyContribution {
+        Phase2CeremonyContribution(self.0.map(|raw| phase2::Contribution {
+            parent: raw.parent,
+            new_elements: phase2::CRSElements {
+                raw: raw.new_elements,
+            },
+            linking_proof: raw.linking_proof,
+        }))
+    }
+}
+
+/// A contribution to every circuit, after validation.
+#[derive(Clone, Debug)]
+pub struct Phase2CeremonyContribution([phase2::Contribution; NUM_CIRCUITS]);
+
+impl Phase2CeremonyContribution {
+    /// Make a new contribution, building upon some parent elements.
+    pub fn make<R: CryptoRngCore>(rng: &mut R, parent: &Phase2CeremonyCRS) -> Self {
+        let contributions = parent
+            .0
+            .iter()
+            .map(|old| phase2::Contribution::make(rng, old.hash(), old));
+        Self(to_array(contributions).expect("one contribution per circuit"))
+    }
+
+    /// Check that this contribution builds upon some parent elements.
+    #[must_use]
+    pub fn is_linked_to(&self, parent: &Phase2CeremonyCRS) -> bool {
+        self.0
+            .iter()
+            .zip(parent.0.iter())
+            .all(|(contribution, parent)| {
+                contribution.parent == parent.hash() && contribution.is_linked_to(parent)
+            })
+    }
+
+    /// The new elements produced by this contribution.
+    pub fn new_elements(&self) -> Phase2CeremonyCRS {
+        let elements = self.0.iter().map(|c| c.new_elements.clone());
+        Phase2CeremonyCRS(to_array(elements).expect("one set of elements per circuit"))
+    }
+}
+
+impl Hashable for Phase2CeremonyContribution {
+    fn hash(&self) -> ContributionHash {
+        let mut hasher = GroupHasher::new(b"PC$:all_contr2");
+        for contribution in &self.0 {
+            hasher.eat_bytes(contribution.hash().as_ref());
+        }
+        ContributionHash(hasher.finalize_bytes())
+    }
+}
+
+impl TryFrom<Phase2CeremonyContribution> for participate_request::Contribution {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Phase2CeremonyContribution) -> Result<Self> {
+        let linking_proofs: Vec<dlog::Proof> = value.0.iter().map(|c| c.linking_proof).collect();
+        Ok(Self {
+            updated: Some(value.new_elements().try_into()?),
+            update_proof: to_bytes(&linking_proofs)?,
+        })
+    }
+}
+
+/// Verify a phase 1 transcript, returning the final elements it produces.
+///
+/// The transcript starts from the root elements of the given degree, and every
+/// contribution must be valid, and build upon the elements of the one before it.
+pub fn verify_phase1_transcript<R: CryptoRngCore>(
+    rng: &mut R,
+    degree: usize,
+    contributions: impl IntoIterator<Item = phase1::RawContribution>,
+) -> Result<phase1::CRSElements> {
+    let mut current = phase1::CRSElements::root(degree);
+    for (i, contribution) in contributions.into_iter().enumerate() {
+        if contribution.parent != current.hash() {
+            return Err(anyhow!("phase 1 contribution {} has the wrong parent", i));
+        }
+        let contribution = contribution
+            .validate(rng)
+            .ok_or_else(|| anyhow!("phase 1 contribution {} is invalid", i))?;
+        if !contribution.is_linked_to(&current) {
+            return Err(anyhow!(
+                "phase 1 contribution {} is not linked to its parent",
+                i
+            ));
+        }
+        current = contribution.new_elements;
+    }
+    Ok(current)
+}
+
+/// Verify a phase 2 transcript, returning the final elements it produces.
+///
+/// Every contribution must be valid relative to the root elements, and linked
+/// to the elements of the contribution before it.
+pub fn verify_phase2_transcript<R: CryptoRngCore>(
+    rng: &mut R,
+    root: &Phase2CeremonyCRS,
+    contributions: impl IntoIterator<Item = participate_request::Contribution>,
+) -> Result<Phase2CeremonyCRS> {
+    let mut current = root.clone();
+    for (i, contribution) in contributions.into_iter().enumerate() {
+        let contribution = Phase2RawCeremonyContribution::parse(&current, contribution)?
+            .validate(rng, root)
+            .ok_or_else(|| anyhow!("phase 2 contribution {} is invalid", i))?;
+        if !contribution.is_linked_to(&current) {
+            return Err(anyhow!(
+                "phase 2 contribution {} is not linked to its parent",
+                i
+            ));
+        }
+        current = contribution.new_elements();
+    }
+    Ok(current)
+}
+
+/// Combine the outputs of both phases into a proving key for every circuit.
+///
+/// The circuits and the extra transition information must be given in the order of
+/// [`CIRCUIT_NAMES`], as produced by [`Phase2CeremonyCRS::root`].
+pub fn combine_all(
+    circuits: &[ConstraintMatrices<F>; NUM_CIRCUITS],
+    phase1: &phase1::CRSElements,
+    phase2: &Phase2CeremonyCRS,
+    extras: &[ExtraTransitionInformation; NUM_CIRCUITS],
+) -> [ProvingKey<Bls12_377>; NUM_CIRCUITS] {
+    let keys = circuits
+        .iter()
+        .zip(phase2.0.iter())
+        .zip(extras.iter())
+        .map(|((circuit, phase2), extra)| combine(circuit, phase1, phase2, extra));
+    to_array(keys).expect("one proving key per circuit")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use ark_ec::Group;
+    use rand_core::OsRng;
+
+    use crate::group::{G1, G2};
+
+    fn root() -> Phase2CeremonyCRS {
+        Phase2CeremonyCRS([(); NUM_CIRCUITS].map(|_| {
+            let x = <F as ark_ff::UniformRand>::rand(&mut OsRng);
+            phase2::CRSElements {
+                raw: phase2::RawCRSElements {
+                    delta_1: G1::generator(),
+                    delta_2: G2::generator(),
+                    inv_delta_p_1: vec![G1::generator() * x],
+                    inv_delta_t_1: vec![G1::generator() * (x * x)],
+                },
+            }
+        }))
+    }
+
+    #[test]
+    fn test_contribution_survives_protobuf_roundtrip() -> Result<()> {
+        let root = root();
+        let contribution = Phase2CeremonyContribution::make(&mut OsRng, &root);
+        let hash = contribution.hash();
+
+        let proto = participate_request::Contribution::try_from(contribution)?;
+        let parsed = Phase2RawCeremonyContribution::parse(&root, proto)?
+            .validate(&mut OsRng, &root)
+            .expect("contribution should be valid");
+
+        assert_eq!(parsed.hash(), hash);
+        assert!(parsed.is_linked_to(&root));
+        Ok(())
+    }
+
+    #[test]
+    fn test_contribution_is_not_linked_to_other_parent() {
+        let root = root();
+        let first = Phase2CeremonyContribution::make(&mut OsRng, &root);
+        let second = Phase2CeremonyContribution::make(&mut OsRng, &first.new_elements());
+        assert!(second.is_linked_to(&first.new_elements()));
+        assert!(!second.is_linked_to(&root));
+    }
+
+    #[test]
+    fn test_phase2_transcript_verifies() -> Result<()> {
+        let root = root();
+        let first = Phase2CeremonyContribution::make(&mut OsRng, &root);
+        let second = Phase2CeremonyContribution::make(&mut OsRng, &first.new_elements());
+        let expected = second.new_elements().hash();
+
+        let transcript = vec![first.try_into()?, second.try_into()?];
+        let last = verify_phase2_transcript(&mut OsRng, &root, transcript)?;
+        assert_eq!(last.hash(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_phase2_transcript_with_skipped_contribution_fails() -> Result<()> {
+        let root = root();
+        let first = Phase2CeremonyContribution::make(&mut OsRng, &root);
+        let second = Phase2CeremonyContribution::make(&mut OsRng, &first.new_elements());
+
+        let transcript = vec![second.try_into()?];
+        assert!(verify_phase2_transcript(&mut OsRng, &root, transcript).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_phase1_transcript_verifies() -> Result<()> {
+        let degree = 4;
+        let root = phase1::CRSElements::root(degree);
+        let first = phase1::Contribution::make(&mut OsRng, root.hash(), &root);
+        let second =
+            phase1::Contribution::make(&mut OsRng, first.new_elements.hash(), &first.new_elements);
+        let expected = second.new_elements.hash();
+
+        let transcript = vec![first.into(), second.into()];
+        let last = verify_phase1_transcript(&mut OsRng, degree, transcript)?;
+        assert_eq!(last.hash(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_crs_survives_protobuf_roundtrip() -> Result<()> {
+        let root = root();
+        let hash = root.hash();
+        let proto = pb::CeremonyCrs::try_from(root.clone())?;
+        let parsed = Phase2RawCeremonyCRS::try_from(proto)?
+            .validate(&mut OsRng, &root)
+            .expect("root should be valid");
+        assert_eq!(parsed.hash(), hash);
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 #![deny(clippy::unwrap_used)]
 // Todo: prune public interface once we know exactly what's needed.
+pub mod all;
 mod dlog;
 mod group;
 pub mod log;
@@ -1,10 +1,12 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
 /// The number of bytes in a contribution hash.
 pub const CONTRIBUTION_HASH_SIZE: usize = 32;
 
 /// Represents the hash of a contribution.
 ///
 /// This is also used as the output of hashing CRS elements.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct ContributionHash(pub [u8; CONTRIBUTION_HASH_SIZE]);
 
 impl AsRef<[u8]> for ContributionHash {
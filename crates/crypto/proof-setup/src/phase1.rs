@@ -1,5 +1,6 @@
 use ark_ec::Group;
 use ark_ff::{One, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use rand_core::{CryptoRngCore, OsRng};
 
 use crate::dlog;
@@ -31,7 +32,7 @@ const fn long_len(d: usize) -> usize {
 }
 
 /// Raw CRS elements, not yet validated for consistency.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct RawCRSElements {
     pub alpha_1: G1,
     pub beta_1: G1,
@@ -210,7 +211,7 @@ impl Hashable for CRSElements {
 /// This pets two cats with one hand:
 /// 1. We show that we're actually building off of the previous elements.
 /// 2. We show that we know the secret elements we're using, avoiding rogue key chicanery.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, CanonicalSerialize, CanonicalDeserialize)]
 struct LinkingProof {
     alpha_proof: dlog::Proof,
     beta_proof: dlog::Proof,
@@ -218,7 +219,7 @@ struct LinkingProof {
 }
 
 /// Represents a contribution before validation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct RawContribution {
     pub parent: ContributionHash,
     pub new_elements: RawCRSElements,
@@ -1,6 +1,7 @@
 //! This module is very similar to the one for phase1, so reading that one might be useful.
 use ark_ec::Group;
 use ark_ff::{fields::Field, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use rand_core::{CryptoRngCore, OsRng};
 
 use crate::log::{ContributionHash, Hashable, Phase};
@@ -10,7 +11,7 @@ use crate::{
 };
 
 /// Raw CRS elements, not yet validated for consistency.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct RawCRSElements {
     pub delta_1: G1,
     pub delta_2: G2,
@@ -91,11 +92,11 @@ impl Hashable for CRSElements {
 }
 
 /// Represents a raw, unvalidatedontribution.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct RawContribution {
     pub parent: ContributionHash,
     pub new_elements: RawCRSElements,
-    linking_proof: dlog::Proof,
+    pub(crate) linking_proof: dlog::Proof,
 }
 
 impl RawContribution {
@@ -147,7 +148,7 @@ impl From<Contribution> for RawContribution {
 pub struct Contribution {
     pub parent: ContributionHash,
     pub new_elements: CRSElements,
-    linking_proof: dlog::Proof,
+    pub(crate) linking_proof: dlog::Proof,
 }
 
 impl Hashable for Contribution {
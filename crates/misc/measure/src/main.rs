@@ -16,6 +16,7 @@ use penumbra_proto::{
     Message,
 };
 
+use rand_core::OsRng;
 use tonic::transport::{Channel, ClientTlsConfig};
 use url::Url;
 
@@ -68,6 +69,19 @@ pub enum Command {
         #[clap(long)]
         full_sync: bool,
     },
+    /// Measure the speedup of batched, parallel trial decryption of compact blocks over scanning
+    /// each block sequentially, using a freshly generated viewing key.
+    ScanBlocks {
+        /// The height to start downloading compact blocks from.
+        #[clap(long, default_value = "0")]
+        start_height: u64,
+        /// The number of compact blocks to download and scan.
+        #[clap(long, default_value = "10000")]
+        num_blocks: u64,
+        /// The number of blocks to trial-decrypt together in each parallel batch.
+        #[clap(long, default_value = "1000")]
+        batch_size: usize,
+    },
     /// Load-test `pd` by holding open many connections subscribing to compact block updates,
     /// processing the messages asynchronously to create
     OpenConnectionsActive {
@@ -176,6 +190,68 @@ impl Opt {
                     res?;
                 }
             }
+            Command::ScanBlocks {
+                start_height,
+                num_blocks,
+                batch_size,
+            } => {
+                use penumbra_keys::keys::{SeedPhrase, SpendKey};
+                use std::time::Instant;
+
+                let end_height =
+                    (start_height + num_blocks).min(self.latest_known_block_height().await?.0);
+
+                let mut client =
+                    ObliviousQueryServiceClient::connect(self.node.to_string()).await?;
+                let mut stream = client
+                    .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
+                        chain_id: String::new(),
+                        start_height,
+                        end_height,
+                        keep_alive: false,
+                    }))
+                    .await?
+                    .into_inner();
+
+                // Download everything up front, so that only scanning is timed.
+                let mut blocks = Vec::new();
+                while let Some(block_rsp) = stream.message().await? {
+                    blocks.push(CompactBlock::try_from(block_rsp)?);
+                }
+                let payload_count: usize = blocks.iter().map(|b| b.state_payloads.len()).sum();
+                println!(
+                    "Fetched {} compact blocks containing {payload_count} state payloads",
+                    blocks.len()
+                );
+
+                let fvk = SpendKey::from_seed_phrase_bip39(SeedPhrase::generate(OsRng), 0)
+                    .full_viewing_key()
+                    .clone();
+
+                let (sequential, parallel) = tokio::task::spawn_blocking(move || {
+                    let start = Instant::now();
+                    for block in &blocks {
+                        penumbra_view::trial_decrypt_block(&fvk, block);
+                    }
+                    let sequential = start.elapsed();
+
+                    let start = Instant::now();
+                    for batch in blocks.chunks(batch_size.max(1)) {
+                        penumbra_view::trial_decrypt_blocks(&fvk, batch);
+                    }
+                    let parallel = start.elapsed();
+
+                    (sequential, parallel)
+                })
+                .await?;
+
+                println!("Sequential scanning: {sequential:?}");
+                println!("Parallel scanning (batches of {batch_size}): {parallel:?}");
+                println!(
+                    "Speedup: {:.2}x",
+                    sequential.as_secs_f64() / parallel.as_secs_f64()
+                );
+            }
             Command::StreamBlocks { skip_genesis } => {
                 let mut client =
                     ObliviousQueryServiceClient::connect(self.node.to_string()).await?;
@@ -440,6 +440,103 @@ pub struct SpreadResponse {
     #[prost(double, tag = "4")]
     pub approx_effective_price_2_to_1: f64,
 }
+/// Requests the aggregated order book depth of a directed trading pair.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepthRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The directed trading pair to request the depth of.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::component::dex::v1alpha1::DirectedTradingPair,
+    >,
+    /// The maximum number of price levels to return on each side, or 0 for no limit.
+    #[prost(uint64, tag = "3")]
+    pub limit: u64,
+}
+/// An aggregated price level of the order book.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepthLevel {
+    /// The effective price of the level, in units of the asset traded in per unit of the asset received.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// The reserves of the asset received available at this price.
+    #[prost(message, optional, tag = "2")]
+    pub reserves: ::core::option::Option<super::super::core::num::v1alpha1::Amount>,
+    /// The reserves of the asset received available at this price or better.
+    #[prost(message, optional, tag = "3")]
+    pub cumulative_reserves: ::core::option::Option<super::super::core::num::v1alpha1::Amount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketDepthResponse {
+    /// The price levels when trading `start` for `end`, best price first.
+    #[prost(message, repeated, tag = "1")]
+    pub start_to_end: ::prost::alloc::vec::Vec<DepthLevel>,
+    /// The price levels when trading `end` for `start`, best price first.
+    #[prost(message, repeated, tag = "2")]
+    pub end_to_start: ::prost::alloc::vec::Vec<DepthLevel>,
+}
+/// Requests the candlesticks of a directed trading pair over a range of heights.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandlesticksRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The directed trading pair to request the candlesticks of.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::component::dex::v1alpha1::DirectedTradingPair,
+    >,
+    /// The first height of the range.
+    #[prost(uint64, tag = "3")]
+    pub start_height: u64,
+    /// The last height of the range, inclusive, or 0 for the latest height.
+    #[prost(uint64, tag = "4")]
+    pub end_height: u64,
+    /// The number of blocks covered by each candle, or 0 for one block per candle.
+    #[prost(uint64, tag = "5")]
+    pub interval: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandlesticksResponse {
+    /// The candles of the heights with executions in the requested range, in height order.
+    #[prost(message, repeated, tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<
+        super::super::core::component::dex::v1alpha1::CandlestickData,
+    >,
+}
+/// Requests the time-weighted average price of a trading pair over a range of heights.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePriceRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The trading pair to request the average price of.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::component::dex::v1alpha1::TradingPair,
+    >,
+    /// The first height of the window.
+    #[prost(uint64, tag = "3")]
+    pub start_height: u64,
+    /// The end height of the window, exclusive, or 0 for the latest height.
+    #[prost(uint64, tag = "4")]
+    pub end_height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePriceResponse {
+    /// The average price over the window, in units of asset 2 per unit of asset 1.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+}
 /// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -462,6 +559,35 @@ pub struct DenomMetadataByIdResponse {
         super::super::core::asset::v1alpha1::DenomMetadata,
     >,
 }
+/// Requests the rate limit on an asset over an ICS-20 channel.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The ID of the channel, on the transfer port.
+    #[prost(string, tag = "2")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The asset id to request the rate limit of.
+    #[prost(message, optional, tag = "3")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1alpha1::AssetId>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitResponse {
+    /// If present, the rate limit on the asset over the channel.
+    ///
+    /// If the asset isn't rate limited over the channel, this field will not be present.
+    #[prost(message, optional, tag = "1")]
+    pub rate_limit: ::core::option::Option<
+        super::super::core::component::ibc::v1alpha1::Ics20RateLimit,
+    >,
+    /// If the asset is rate limited, the value of it transferred over the channel in the current
+    /// window.
+    #[prost(message, optional, tag = "2")]
+    pub flow: ::core::option::Option<super::super::core::component::ibc::v1alpha1::Ics20Flow>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProposalInfoRequest {
@@ -511,6 +637,17 @@ pub struct SimulateTradeRequest {
     pub output: ::core::option::Option<super::super::core::asset::v1alpha1::AssetId>,
     #[prost(message, optional, tag = "3")]
     pub routing: ::core::option::Option<simulate_trade_request::Routing>,
+    /// If set, the minimum output the trade must produce.
+    #[prost(message, optional, tag = "4")]
+    pub min_output: ::core::option::Option<super::super::core::num::v1alpha1::Amount>,
+    /// Positions to open before simulating the trade, as if they existed on chain.
+    #[prost(message, repeated, tag = "5")]
+    pub hypothetical_positions: ::prost::alloc::vec::Vec<
+        super::super::core::component::dex::v1alpha1::Position,
+    >,
+    /// Other input amounts to simulate the trade with, to produce a price impact curve.
+    #[prost(message, repeated, tag = "6")]
+    pub input_ladder: ::prost::alloc::vec::Vec<super::super::core::num::v1alpha1::Amount>,
 }
 /// Nested message and enum types in `SimulateTradeRequest`.
 pub mod simulate_trade_request {
@@ -545,6 +682,67 @@ pub struct SimulateTradeResponse {
     pub output: ::core::option::Option<
         super::super::core::component::dex::v1alpha1::SwapExecution,
     >,
+    /// Whether the simulated output falls short of the requested `min_output`,
+    /// meaning a swap with that minimum output would be refunded.
+    #[prost(bool, tag = "2")]
+    pub below_min_output: bool,
+    /// The input and output routed along each path the trade was split across,
+    /// in the order the paths were first used.
+    #[prost(message, repeated, tag = "3")]
+    pub paths: ::prost::alloc::vec::Vec<simulate_trade_response::Path>,
+    /// The marginal price of the best route before the trade, in units of input
+    /// per unit of output.
+    #[prost(double, tag = "4")]
+    pub spot_price: f64,
+    /// The average price of the trade, in units of input per unit of output.
+    #[prost(double, tag = "5")]
+    pub effective_price: f64,
+    /// How much worse the effective price is than the spot price, as a fraction
+    /// of the spot price.
+    #[prost(double, tag = "6")]
+    pub price_impact: f64,
+    /// The part of the input that could not be filled.
+    #[prost(message, optional, tag = "7")]
+    pub unfilled: ::core::option::Option<super::super::core::num::v1alpha1::Amount>,
+    /// The positions the trade fills against, at least partially.
+    #[prost(message, repeated, tag = "8")]
+    pub positions_consumed: ::prost::alloc::vec::Vec<
+        super::super::core::component::dex::v1alpha1::PositionId,
+    >,
+    /// The outcome of simulating the trade with each input of the request's
+    /// `input_ladder` instead.
+    #[prost(message, repeated, tag = "9")]
+    pub ladder: ::prost::alloc::vec::Vec<simulate_trade_response::LadderStep>,
+}
+/// Nested message and enum types in `SimulateTradeResponse`.
+pub mod simulate_trade_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Path {
+        /// The assets along the path, from the input asset to the output asset.
+        #[prost(message, repeated, tag = "1")]
+        pub route: ::prost::alloc::vec::Vec<super::super::super::core::asset::v1alpha1::AssetId>,
+        #[prost(message, optional, tag = "2")]
+        pub input: ::core::option::Option<super::super::super::core::asset::v1alpha1::Value>,
+        #[prost(message, optional, tag = "3")]
+        pub output: ::core::option::Option<super::super::super::core::asset::v1alpha1::Value>,
+        /// The average price of each hop along the path, in units of the hop's
+        /// input per unit of its output, or NaN for a hop without output.
+        #[prost(double, repeated, tag = "4")]
+        pub hop_prices: ::prost::alloc::vec::Vec<f64>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct LadderStep {
+        #[prost(message, optional, tag = "1")]
+        pub input: ::core::option::Option<super::super::super::core::asset::v1alpha1::Value>,
+        #[prost(message, optional, tag = "2")]
+        pub output: ::core::option::Option<super::super::super::core::asset::v1alpha1::Value>,
+        #[prost(double, tag = "3")]
+        pub effective_price: f64,
+        #[prost(double, tag = "4")]
+        pub price_impact: f64,
+    }
 }
 /// Performs a key-value query, either by key or by key hash.
 ///
@@ -640,6 +838,17 @@ pub struct TxResult {
     pub gas_used: u64,
     #[prost(message, repeated, tag = "4")]
     pub tags: ::prost::alloc::vec::Vec<Tag>,
+    /// The events emitted while executing the transaction, with their attributes.
+    #[prost(message, repeated, tag = "5")]
+    pub events: ::prost::alloc::vec::Vec<Event>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Event {
+    #[prost(string, tag = "1")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub attributes: ::prost::alloc::vec::Vec<Tag>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -795,6 +1004,22 @@ pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag = "2")]
     pub block: ::core::option::Option<super::super::super::tendermint::types::Block>,
 }
+/// GetValidatorSetRequest is the request type for the Query/GetValidatorSet RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetValidatorSetRequest {
+    #[prost(int64, tag = "1")]
+    pub height: i64,
+}
+/// GetValidatorSetResponse is the response type for the Query/GetValidatorSet RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetValidatorSetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub validator_set: ::core::option::Option<
+        super::super::super::tendermint::types::ValidatorSet,
+    >,
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod oblivious_query_service_client {
@@ -870,11 +1095,27 @@ pub mod oblivious_query_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         /// Returns a stream of `CompactBlockRangeResponse`s.
         pub async fn compact_block_range(
             &mut self,
             request: impl tonic::IntoRequest<super::CompactBlockRangeRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::CompactBlockRangeResponse>>,
             tonic::Status,
         > {
@@ -891,12 +1132,20 @@ pub mod oblivious_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.ObliviousQueryService/CompactBlockRange",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.ObliviousQueryService",
+                        "CompactBlockRange",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         pub async fn chain_parameters(
             &mut self,
             request: impl tonic::IntoRequest<super::ChainParametersRequest>,
-        ) -> Result<tonic::Response<super::ChainParametersResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ChainParametersResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -910,12 +1159,20 @@ pub mod oblivious_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.ObliviousQueryService/ChainParameters",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.ObliviousQueryService",
+                        "ChainParameters",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn epoch_by_height(
             &mut self,
             request: impl tonic::IntoRequest<super::EpochByHeightRequest>,
-        ) -> Result<tonic::Response<super::EpochByHeightResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::EpochByHeightResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -929,13 +1186,21 @@ pub mod oblivious_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.ObliviousQueryService/EpochByHeight",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.ObliviousQueryService",
+                        "EpochByHeight",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Returns a stream of `ValidatorInfoResponse`s.
         pub async fn validator_info(
             &mut self,
             request: impl tonic::IntoRequest<super::ValidatorInfoRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::ValidatorInfoResponse>>,
             tonic::Status,
         > {
@@ -952,12 +1217,20 @@ pub mod oblivious_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.ObliviousQueryService/ValidatorInfo",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.ObliviousQueryService",
+                        "ValidatorInfo",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         pub async fn info(
             &mut self,
             request: impl tonic::IntoRequest<super::InfoRequest>,
-        ) -> Result<tonic::Response<super::InfoResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::InfoResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -971,7 +1244,12 @@ pub mod oblivious_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.ObliviousQueryService/Info",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.ObliviousQueryService", "Info"),
+                );
+            self.inner.unary(req, path, codec).await
         }
     }
 }
@@ -1050,10 +1328,26 @@ pub mod specific_query_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         pub async fn transaction_by_note(
             &mut self,
             request: impl tonic::IntoRequest<super::TransactionByNoteRequest>,
-        ) -> Result<tonic::Response<super::TransactionByNoteResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::TransactionByNoteResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1067,12 +1361,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/TransactionByNote",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "TransactionByNote",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn validator_status(
             &mut self,
             request: impl tonic::IntoRequest<super::ValidatorStatusRequest>,
-        ) -> Result<tonic::Response<super::ValidatorStatusResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ValidatorStatusResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1086,12 +1388,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorStatus",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "ValidatorStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn validator_penalty(
             &mut self,
             request: impl tonic::IntoRequest<super::ValidatorPenaltyRequest>,
-        ) -> Result<tonic::Response<super::ValidatorPenaltyResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ValidatorPenaltyResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1105,12 +1415,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/ValidatorPenalty",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "ValidatorPenalty",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn next_validator_rate(
             &mut self,
             request: impl tonic::IntoRequest<super::NextValidatorRateRequest>,
-        ) -> Result<tonic::Response<super::NextValidatorRateResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::NextValidatorRateResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1124,12 +1442,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/NextValidatorRate",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "NextValidatorRate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn current_validator_rate(
             &mut self,
             request: impl tonic::IntoRequest<super::CurrentValidatorRateRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::CurrentValidatorRateResponse>,
             tonic::Status,
         > {
@@ -1146,12 +1472,23 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/CurrentValidatorRate",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "CurrentValidatorRate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn batch_swap_output_data(
             &mut self,
             request: impl tonic::IntoRequest<super::BatchSwapOutputDataRequest>,
-        ) -> Result<tonic::Response<super::BatchSwapOutputDataResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::BatchSwapOutputDataResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1165,12 +1502,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/BatchSwapOutputData",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "BatchSwapOutputData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn swap_execution(
             &mut self,
             request: impl tonic::IntoRequest<super::SwapExecutionRequest>,
-        ) -> Result<tonic::Response<super::SwapExecutionResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::SwapExecutionResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1184,12 +1529,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/SwapExecution",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "SwapExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn arb_execution(
             &mut self,
             request: impl tonic::IntoRequest<super::ArbExecutionRequest>,
-        ) -> Result<tonic::Response<super::ArbExecutionResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ArbExecutionResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1203,13 +1556,21 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/ArbExecution",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "ArbExecution",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Returns a stream of `SwapExecutionsResponses`.
         pub async fn swap_executions(
             &mut self,
             request: impl tonic::IntoRequest<super::SwapExecutionsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::SwapExecutionsResponse>>,
             tonic::Status,
         > {
@@ -1226,13 +1587,21 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/SwapExecutions",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "SwapExecutions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Returns a stream of `ArbExecutionsResponses`.
         pub async fn arb_executions(
             &mut self,
             request: impl tonic::IntoRequest<super::ArbExecutionsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::ArbExecutionsResponse>>,
             tonic::Status,
         > {
@@ -1249,13 +1618,21 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/ArbExecutions",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "ArbExecutions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Returns a stream of `LiquidityPositionsResponse`s.
         pub async fn liquidity_positions(
             &mut self,
             request: impl tonic::IntoRequest<super::LiquidityPositionsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::LiquidityPositionsResponse>>,
             tonic::Status,
         > {
@@ -1272,12 +1649,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/LiquidityPositions",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "LiquidityPositions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         pub async fn liquidity_position_by_id(
             &mut self,
             request: impl tonic::IntoRequest<super::LiquidityPositionByIdRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::LiquidityPositionByIdResponse>,
             tonic::Status,
         > {
@@ -1294,13 +1679,21 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/LiquidityPositionById",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "LiquidityPositionById",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Returns a stream of `LiquidityPositionsByIdResponse`s.
         pub async fn liquidity_positions_by_id(
             &mut self,
             request: impl tonic::IntoRequest<super::LiquidityPositionsByIdRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<
                 tonic::codec::Streaming<super::LiquidityPositionsByIdResponse>,
             >,
@@ -1319,13 +1712,21 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/LiquidityPositionsById",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "LiquidityPositionsById",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Returns a stream of `LiquidityPositionsByPriceResponse`s.
         pub async fn liquidity_positions_by_price(
             &mut self,
             request: impl tonic::IntoRequest<super::LiquidityPositionsByPriceRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<
                 tonic::codec::Streaming<super::LiquidityPositionsByPriceResponse>,
             >,
@@ -1344,12 +1745,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/LiquidityPositionsByPrice",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "LiquidityPositionsByPrice",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         pub async fn spread(
             &mut self,
             request: impl tonic::IntoRequest<super::SpreadRequest>,
-        ) -> Result<tonic::Response<super::SpreadResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1363,12 +1772,101 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/Spread",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.SpecificQueryService", "Spread"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the aggregated order book depth of a directed trading pair.
+        pub async fn market_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MarketDepthRequest>,
+        ) -> std::result::Result<tonic::Response<super::MarketDepthResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/MarketDepth",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.SpecificQueryService", "MarketDepth"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the candlesticks of a directed trading pair over a range of heights.
+        pub async fn candlesticks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CandlesticksRequest>,
+        ) -> std::result::Result<tonic::Response<super::CandlesticksResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/Candlesticks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "Candlesticks",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the time-weighted average price of a trading pair over a range of heights.
+        pub async fn time_weighted_average_price(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TimeWeightedAveragePriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePriceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/TimeWeightedAveragePrice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "TimeWeightedAveragePrice",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn denom_metadata_by_id(
             &mut self,
             request: impl tonic::IntoRequest<super::DenomMetadataByIdRequest>,
-        ) -> Result<tonic::Response<super::DenomMetadataByIdResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::DenomMetadataByIdResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1382,12 +1880,48 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/DenomMetadataById",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "DenomMetadataById",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the rate limit on an asset over an ICS-20 channel, and how much of it has been used.
+        pub async fn ics20_rate_limit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Ics20RateLimitRequest>,
+        ) -> std::result::Result<tonic::Response<super::Ics20RateLimitResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/Ics20RateLimit",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "Ics20RateLimit",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn proposal_info(
             &mut self,
             request: impl tonic::IntoRequest<super::ProposalInfoRequest>,
-        ) -> Result<tonic::Response<super::ProposalInfoResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ProposalInfoResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1401,12 +1935,20 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/ProposalInfo",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "ProposalInfo",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn proposal_rate_data(
             &mut self,
             request: impl tonic::IntoRequest<super::ProposalRateDataRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::ProposalRateDataResponse>>,
             tonic::Status,
         > {
@@ -1423,13 +1965,21 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/ProposalRateData",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "ProposalRateData",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Simulate routing and trade execution.
         pub async fn simulate_trade(
             &mut self,
             request: impl tonic::IntoRequest<super::SimulateTradeRequest>,
-        ) -> Result<tonic::Response<super::SimulateTradeResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::SimulateTradeResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1443,14 +1993,22 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/SimulateTrade",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.SpecificQueryService",
+                        "SimulateTrade",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// General-purpose key-value state query API, that can be used to query
         /// arbitrary keys in the JMT storage.
         pub async fn key_value(
             &mut self,
             request: impl tonic::IntoRequest<super::KeyValueRequest>,
-        ) -> Result<tonic::Response<super::KeyValueResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::KeyValueResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1464,7 +2022,12 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/KeyValue",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.SpecificQueryService", "KeyValue"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// General-purpose prefixed key-value state query API, that can be used to query
         /// arbitrary prefixes in the JMT storage.
@@ -1472,7 +2035,7 @@ pub mod specific_query_service_client {
         pub async fn prefix_value(
             &mut self,
             request: impl tonic::IntoRequest<super::PrefixValueRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::PrefixValueResponse>>,
             tonic::Status,
         > {
@@ -1489,7 +2052,12 @@ pub mod specific_query_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.SpecificQueryService/PrefixValue",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.SpecificQueryService", "PrefixValue"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
     }
 }
@@ -1566,11 +2134,27 @@ pub mod tendermint_proxy_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         /// Status queries the current status.
         pub async fn get_status(
             &mut self,
             request: impl tonic::IntoRequest<super::GetStatusRequest>,
-        ) -> Result<tonic::Response<super::GetStatusResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::GetStatusResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1584,13 +2168,18 @@ pub mod tendermint_proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.TendermintProxyService/GetStatus",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.TendermintProxyService", "GetStatus"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Broadcast a transaction asynchronously.
         pub async fn broadcast_tx_async(
             &mut self,
             request: impl tonic::IntoRequest<super::BroadcastTxAsyncRequest>,
-        ) -> Result<tonic::Response<super::BroadcastTxAsyncResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::BroadcastTxAsyncResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1604,13 +2193,21 @@ pub mod tendermint_proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.TendermintProxyService/BroadcastTxAsync",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.TendermintProxyService",
+                        "BroadcastTxAsync",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Broadcast a transaction synchronously.
         pub async fn broadcast_tx_sync(
             &mut self,
             request: impl tonic::IntoRequest<super::BroadcastTxSyncRequest>,
-        ) -> Result<tonic::Response<super::BroadcastTxSyncResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::BroadcastTxSyncResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1624,13 +2221,21 @@ pub mod tendermint_proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.TendermintProxyService/BroadcastTxSync",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.TendermintProxyService",
+                        "BroadcastTxSync",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Fetch a transaction by hash.
         pub async fn get_tx(
             &mut self,
             request: impl tonic::IntoRequest<super::GetTxRequest>,
-        ) -> Result<tonic::Response<super::GetTxResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::GetTxResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1644,7 +2249,12 @@ pub mod tendermint_proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.TendermintProxyService/GetTx",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.TendermintProxyService", "GetTx"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// ABCIQuery defines a query handler that supports ABCI queries directly to the
         /// application, bypassing Tendermint completely. The ABCI query must contain
@@ -1652,7 +2262,7 @@ pub mod tendermint_proxy_service_client {
         pub async fn abci_query(
             &mut self,
             request: impl tonic::IntoRequest<super::AbciQueryRequest>,
-        ) -> Result<tonic::Response<super::AbciQueryResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::AbciQueryResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1666,13 +2276,18 @@ pub mod tendermint_proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.TendermintProxyService/ABCIQuery",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.client.v1alpha1.TendermintProxyService", "ABCIQuery"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// GetBlockByHeight queries block for given height.
         pub async fn get_block_by_height(
             &mut self,
             request: impl tonic::IntoRequest<super::GetBlockByHeightRequest>,
-        ) -> Result<tonic::Response<super::GetBlockByHeightResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::GetBlockByHeightResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1686,7 +2301,43 @@ pub mod tendermint_proxy_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.client.v1alpha1.TendermintProxyService/GetBlockByHeight",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.TendermintProxyService",
+                        "GetBlockByHeight",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetValidatorSet queries the validator set for given height.
+        pub async fn get_validator_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetValidatorSetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetValidatorSetResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.TendermintProxyService/GetValidatorSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.client.v1alpha1.TendermintProxyService",
+                        "GetValidatorSet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
     }
 }
@@ -1700,7 +2351,7 @@ pub mod oblivious_query_service_server {
     pub trait ObliviousQueryService: Send + Sync + 'static {
         /// Server streaming response type for the CompactBlockRange method.
         type CompactBlockRangeStream: futures_core::Stream<
-                Item = Result<super::CompactBlockRangeResponse, tonic::Status>,
+                Item = std::result::Result<super::CompactBlockRangeResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1708,18 +2359,18 @@ pub mod oblivious_query_service_server {
         async fn compact_block_range(
             &self,
             request: tonic::Request<super::CompactBlockRangeRequest>,
-        ) -> Result<tonic::Response<Self::CompactBlockRangeStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::CompactBlockRangeStream>, tonic::Status>;
         async fn chain_parameters(
             &self,
             request: tonic::Request<super::ChainParametersRequest>,
-        ) -> Result<tonic::Response<super::ChainParametersResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ChainParametersResponse>, tonic::Status>;
         async fn epoch_by_height(
             &self,
             request: tonic::Request<super::EpochByHeightRequest>,
-        ) -> Result<tonic::Response<super::EpochByHeightResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::EpochByHeightResponse>, tonic::Status>;
         /// Server streaming response type for the ValidatorInfo method.
         type ValidatorInfoStream: futures_core::Stream<
-                Item = Result<super::ValidatorInfoResponse, tonic::Status>,
+                Item = std::result::Result<super::ValidatorInfoResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1727,11 +2378,11 @@ pub mod oblivious_query_service_server {
         async fn validator_info(
             &self,
             request: tonic::Request<super::ValidatorInfoRequest>,
-        ) -> Result<tonic::Response<Self::ValidatorInfoStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::ValidatorInfoStream>, tonic::Status>;
         async fn info(
             &self,
             request: tonic::Request<super::InfoRequest>,
-        ) -> Result<tonic::Response<super::InfoResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::InfoResponse>, tonic::Status>;
     }
     /// Methods for accessing chain state that are "oblivious" in the sense that they
     /// do not request specific portions of the chain state that could reveal private
@@ -1743,6 +2394,8 @@ pub mod oblivious_query_service_server {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
     impl<T: ObliviousQueryService> ObliviousQueryServiceServer<T> {
@@ -1755,6 +2408,8 @@ pub mod oblivious_query_service_server {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
             }
         }
         pub fn with_interceptor<F>(
@@ -1778,6 +2433,22 @@ pub mod oblivious_query_service_server {
             self.send_compression_encodings.enable(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>>
     for ObliviousQueryServiceServer<T>
@@ -1792,7 +2463,7 @@ pub mod oblivious_query_service_server {
         fn poll_ready(
             &mut self,
             _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
+        ) -> Poll<std::result::Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
@@ -1825,6 +2496,8 @@ pub mod oblivious_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1834,6 +2507,10 @@ pub mod oblivious_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -1865,6 +2542,8 @@ pub mod oblivious_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1874,6 +2553,10 @@ pub mod oblivious_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -1905,6 +2588,8 @@ pub mod oblivious_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1914,6 +2599,10 @@ pub mod oblivious_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -1946,6 +2635,8 @@ pub mod oblivious_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1955,6 +2646,10 @@ pub mod oblivious_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -1983,6 +2678,8 @@ pub mod oblivious_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1992,6 +2689,10 @@ pub mod oblivious_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2020,6 +2721,8 @@ pub mod oblivious_query_service_server {
                 inner,
                 accept_compression_encodings: self.accept_compression_encodings,
                 send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
@@ -2049,38 +2752,44 @@ pub mod specific_query_service_server {
         async fn transaction_by_note(
             &self,
             request: tonic::Request<super::TransactionByNoteRequest>,
-        ) -> Result<tonic::Response<super::TransactionByNoteResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::TransactionByNoteResponse>, tonic::Status>;
         async fn validator_status(
             &self,
             request: tonic::Request<super::ValidatorStatusRequest>,
-        ) -> Result<tonic::Response<super::ValidatorStatusResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ValidatorStatusResponse>, tonic::Status>;
         async fn validator_penalty(
             &self,
             request: tonic::Request<super::ValidatorPenaltyRequest>,
-        ) -> Result<tonic::Response<super::ValidatorPenaltyResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ValidatorPenaltyResponse>, tonic::Status>;
         async fn next_validator_rate(
             &self,
             request: tonic::Request<super::NextValidatorRateRequest>,
-        ) -> Result<tonic::Response<super::NextValidatorRateResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::NextValidatorRateResponse>, tonic::Status>;
         async fn current_validator_rate(
             &self,
             request: tonic::Request<super::CurrentValidatorRateRequest>,
-        ) -> Result<tonic::Response<super::CurrentValidatorRateResponse>, tonic::Status>;
+        ) -> std::result::Result<
+            tonic::Response<super::CurrentValidatorRateResponse>,
+            tonic::Status,
+        >;
         async fn batch_swap_output_data(
             &self,
             request: tonic::Request<super::BatchSwapOutputDataRequest>,
-        ) -> Result<tonic::Response<super::BatchSwapOutputDataResponse>, tonic::Status>;
+        ) -> std::result::Result<
+            tonic::Response<super::BatchSwapOutputDataResponse>,
+            tonic::Status,
+        >;
         async fn swap_execution(
             &self,
             request: tonic::Request<super::SwapExecutionRequest>,
-        ) -> Result<tonic::Response<super::SwapExecutionResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::SwapExecutionResponse>, tonic::Status>;
         async fn arb_execution(
             &self,
             request: tonic::Request<super::ArbExecutionRequest>,
-        ) -> Result<tonic::Response<super::ArbExecutionResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ArbExecutionResponse>, tonic::Status>;
         /// Server streaming response type for the SwapExecutions method.
         type SwapExecutionsStream: futures_core::Stream<
-                Item = Result<super::SwapExecutionsResponse, tonic::Status>,
+                Item = std::result::Result<super::SwapExecutionsResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -2088,10 +2797,10 @@ pub mod specific_query_service_server {
         async fn swap_executions(
             &self,
             request: tonic::Request<super::SwapExecutionsRequest>,
-        ) -> Result<tonic::Response<Self::SwapExecutionsStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::SwapExecutionsStream>, tonic::Status>;
         /// Server streaming response type for the ArbExecutions method.
         type ArbExecutionsStream: futures_core::Stream<
-                Item = Result<super::ArbExecutionsResponse, tonic::Status>,
+                Item = std::result::Result<super::ArbExecutionsResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -2099,10 +2808,10 @@ pub mod specific_query_service_server {
         async fn arb_executions(
             &self,
             request: tonic::Request<super::ArbExecutionsRequest>,
-        ) -> Result<tonic::Response<Self::ArbExecutionsStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::ArbExecutionsStream>, tonic::Status>;
         /// Server streaming response type for the LiquidityPositions method.
         type LiquidityPositionsStream: futures_core::Stream<
-                Item = Result<super::LiquidityPositionsResponse, tonic::Status>,
+                Item = std::result::Result<super::LiquidityPositionsResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -2110,17 +2819,17 @@ pub mod specific_query_service_server {
         async fn liquidity_positions(
             &self,
             request: tonic::Request<super::LiquidityPositionsRequest>,
-        ) -> Result<tonic::Response<Self::LiquidityPositionsStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::LiquidityPositionsStream>, tonic::Status>;
         async fn liquidity_position_by_id(
             &self,
             request: tonic::Request<super::LiquidityPositionByIdRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::LiquidityPositionByIdResponse>,
             tonic::Status,
         >;
         /// Server streaming response type for the LiquidityPositionsById method.
         type LiquidityPositionsByIdStream: futures_core::Stream<
-                Item = Result<super::LiquidityPositionsByIdResponse, tonic::Status>,
+                Item = std::result::Result<super::LiquidityPositionsByIdResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -2128,10 +2837,13 @@ pub mod specific_query_service_server {
         async fn liquidity_positions_by_id(
             &self,
             request: tonic::Request<super::LiquidityPositionsByIdRequest>,
-        ) -> Result<tonic::Response<Self::LiquidityPositionsByIdStream>, tonic::Status>;
+        ) -> std::result::Result<
+            tonic::Response<Self::LiquidityPositionsByIdStream>,
+            tonic::Status,
+        >;
         /// Server streaming response type for the LiquidityPositionsByPrice method.
         type LiquidityPositionsByPriceStream: futures_core::Stream<
-                Item = Result<super::LiquidityPositionsByPriceResponse, tonic::Status>,
+                Item = std::result::Result<super::LiquidityPositionsByPriceResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -2139,46 +2851,69 @@ pub mod specific_query_service_server {
         async fn liquidity_positions_by_price(
             &self,
             request: tonic::Request<super::LiquidityPositionsByPriceRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<Self::LiquidityPositionsByPriceStream>,
             tonic::Status,
         >;
         async fn spread(
             &self,
             request: tonic::Request<super::SpreadRequest>,
-        ) -> Result<tonic::Response<super::SpreadResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status>;
+        /// Returns the aggregated order book depth of a directed trading pair.
+        async fn market_depth(
+            &self,
+            request: tonic::Request<super::MarketDepthRequest>,
+        ) -> std::result::Result<tonic::Response<super::MarketDepthResponse>, tonic::Status>;
+        /// Returns the candlesticks of a directed trading pair over a range of heights.
+        async fn candlesticks(
+            &self,
+            request: tonic::Request<super::CandlesticksRequest>,
+        ) -> std::result::Result<tonic::Response<super::CandlesticksResponse>, tonic::Status>;
+        /// Returns the time-weighted average price of a trading pair over a range of heights.
+        async fn time_weighted_average_price(
+            &self,
+            request: tonic::Request<super::TimeWeightedAveragePriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePriceResponse>,
+            tonic::Status,
+        >;
         async fn denom_metadata_by_id(
             &self,
             request: tonic::Request<super::DenomMetadataByIdRequest>,
-        ) -> Result<tonic::Response<super::DenomMetadataByIdResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::DenomMetadataByIdResponse>, tonic::Status>;
+        /// Returns the rate limit on an asset over an ICS-20 channel, and how much of it has been used.
+        async fn ics20_rate_limit(
+            &self,
+            request: tonic::Request<super::Ics20RateLimitRequest>,
+        ) -> std::result::Result<tonic::Response<super::Ics20RateLimitResponse>, tonic::Status>;
         async fn proposal_info(
             &self,
             request: tonic::Request<super::ProposalInfoRequest>,
-        ) -> Result<tonic::Response<super::ProposalInfoResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ProposalInfoResponse>, tonic::Status>;
         /// Server streaming response type for the ProposalRateData method.
         type ProposalRateDataStream: futures_core::Stream<
-                Item = Result<super::ProposalRateDataResponse, tonic::Status>,
+                Item = std::result::Result<super::ProposalRateDataResponse, tonic::Status>,
             >
             + Send
             + 'static;
         async fn proposal_rate_data(
             &self,
             request: tonic::Request<super::ProposalRateDataRequest>,
-        ) -> Result<tonic::Response<Self::ProposalRateDataStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::ProposalRateDataStream>, tonic::Status>;
         /// Simulate routing and trade execution.
         async fn simulate_trade(
             &self,
             request: tonic::Request<super::SimulateTradeRequest>,
-        ) -> Result<tonic::Response<super::SimulateTradeResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::SimulateTradeResponse>, tonic::Status>;
         /// General-purpose key-value state query API, that can be used to query
         /// arbitrary keys in the JMT storage.
         async fn key_value(
             &self,
             request: tonic::Request<super::KeyValueRequest>,
-        ) -> Result<tonic::Response<super::KeyValueResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::KeyValueResponse>, tonic::Status>;
         /// Server streaming response type for the PrefixValue method.
         type PrefixValueStream: futures_core::Stream<
-                Item = Result<super::PrefixValueResponse, tonic::Status>,
+                Item = std::result::Result<super::PrefixValueResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -2188,7 +2923,7 @@ pub mod specific_query_service_server {
         async fn prefix_value(
             &self,
             request: tonic::Request<super::PrefixValueRequest>,
-        ) -> Result<tonic::Response<Self::PrefixValueStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::PrefixValueStream>, tonic::Status>;
     }
     /// Methods for accessing chain state that are "specific" in the sense that they
     /// request specific portions of the chain state that could reveal private
@@ -2200,6 +2935,8 @@ pub mod specific_query_service_server {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
     impl<T: SpecificQueryService> SpecificQueryServiceServer<T> {
@@ -2212,6 +2949,8 @@ pub mod specific_query_service_server {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
             }
         }
         pub fn with_interceptor<F>(
@@ -2235,6 +2974,22 @@ pub mod specific_query_service_server {
             self.send_compression_encodings.enable(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>>
     for SpecificQueryServiceServer<T>
@@ -2249,7 +3004,7 @@ pub mod specific_query_service_server {
         fn poll_ready(
             &mut self,
             _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
+        ) -> Poll<std::result::Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
@@ -2280,6 +3035,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2289,6 +3046,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2320,6 +3081,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2329,6 +3092,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2360,6 +3127,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2369,6 +3138,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2400,6 +3173,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2409,6 +3184,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2440,6 +3219,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2449,6 +3230,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2480,6 +3265,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2489,6 +3276,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2520,6 +3311,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2529,6 +3322,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2560,6 +3357,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2569,6 +3368,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2601,6 +3404,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2610,6 +3415,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2642,6 +3451,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2651,6 +3462,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2684,6 +3499,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2693,6 +3510,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2724,6 +3545,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2733,6 +3556,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2768,6 +3595,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2777,6 +3606,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2814,6 +3647,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2823,6 +3658,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2852,6 +3691,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2861,6 +3702,144 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/MarketDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct MarketDepthSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::MarketDepthRequest>
+                    for MarketDepthSvc<T> {
+                        type Response = super::MarketDepthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MarketDepthRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).market_depth(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = MarketDepthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/Candlesticks" => {
+                    #[allow(non_camel_case_types)]
+                    struct CandlesticksSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::CandlesticksRequest>
+                    for CandlesticksSvc<T> {
+                        type Response = super::CandlesticksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CandlesticksRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).candlesticks(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CandlesticksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/TimeWeightedAveragePrice" => {
+                    #[allow(non_camel_case_types)]
+                    struct TimeWeightedAveragePriceSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::TimeWeightedAveragePriceRequest>
+                    for TimeWeightedAveragePriceSvc<T> {
+                        type Response = super::TimeWeightedAveragePriceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TimeWeightedAveragePriceRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).time_weighted_average_price(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TimeWeightedAveragePriceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2892,6 +3871,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2901,6 +3882,54 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/Ics20RateLimit" => {
+                    #[allow(non_camel_case_types)]
+                    struct Ics20RateLimitSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::Ics20RateLimitRequest>
+                    for Ics20RateLimitSvc<T> {
+                        type Response = super::Ics20RateLimitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Ics20RateLimitRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).ics20_rate_limit(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = Ics20RateLimitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2932,6 +3961,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2941,6 +3972,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2974,6 +4009,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2983,6 +4020,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -3014,6 +4055,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3023,6 +4066,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3052,6 +4099,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3061,6 +4110,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3093,6 +4146,8 @@ pub mod specific_query_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3102,6 +4157,10 @@ pub mod specific_query_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -3130,6 +4189,8 @@ pub mod specific_query_service_server {
                 inner,
                 accept_compression_encodings: self.accept_compression_encodings,
                 send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
@@ -3160,34 +4221,39 @@ pub mod tendermint_proxy_service_server {
         async fn get_status(
             &self,
             request: tonic::Request<super::GetStatusRequest>,
-        ) -> Result<tonic::Response<super::GetStatusResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::GetStatusResponse>, tonic::Status>;
         /// Broadcast a transaction asynchronously.
         async fn broadcast_tx_async(
             &self,
             request: tonic::Request<super::BroadcastTxAsyncRequest>,
-        ) -> Result<tonic::Response<super::BroadcastTxAsyncResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::BroadcastTxAsyncResponse>, tonic::Status>;
         /// Broadcast a transaction synchronously.
         async fn broadcast_tx_sync(
             &self,
             request: tonic::Request<super::BroadcastTxSyncRequest>,
-        ) -> Result<tonic::Response<super::BroadcastTxSyncResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::BroadcastTxSyncResponse>, tonic::Status>;
         /// Fetch a transaction by hash.
         async fn get_tx(
             &self,
             request: tonic::Request<super::GetTxRequest>,
-        ) -> Result<tonic::Response<super::GetTxResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::GetTxResponse>, tonic::Status>;
         /// ABCIQuery defines a query handler that supports ABCI queries directly to the
         /// application, bypassing Tendermint completely. The ABCI query must contain
         /// a valid and supported path, including app, custom, p2p, and store.
         async fn abci_query(
             &self,
             request: tonic::Request<super::AbciQueryRequest>,
-        ) -> Result<tonic::Response<super::AbciQueryResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::AbciQueryResponse>, tonic::Status>;
         /// GetBlockByHeight queries block for given height.
         async fn get_block_by_height(
             &self,
             request: tonic::Request<super::GetBlockByHeightRequest>,
-        ) -> Result<tonic::Response<super::GetBlockByHeightResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::GetBlockByHeightResponse>, tonic::Status>;
+        /// GetValidatorSet queries the validator set for given height.
+        async fn get_validator_set(
+            &self,
+            request: tonic::Request<super::GetValidatorSetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetValidatorSetResponse>, tonic::Status>;
     }
     /// Defines the gRPC query service for proxying requests to an upstream Tendermint RPC.
     #[derive(Debug)]
@@ -3195,6 +4261,8 @@ pub mod tendermint_proxy_service_server {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
     impl<T: TendermintProxyService> TendermintProxyServiceServer<T> {
@@ -3207,6 +4275,8 @@ pub mod tendermint_proxy_service_server {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
             }
         }
         pub fn with_interceptor<F>(
@@ -3230,6 +4300,22 @@ pub mod tendermint_proxy_service_server {
             self.send_compression_encodings.enable(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>>
     for TendermintProxyServiceServer<T>
@@ -3244,7 +4330,7 @@ pub mod tendermint_proxy_service_server {
         fn poll_ready(
             &mut self,
             _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
+        ) -> Poll<std::result::Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
@@ -3273,6 +4359,8 @@ pub mod tendermint_proxy_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3282,6 +4370,10 @@ pub mod tendermint_proxy_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3313,6 +4405,8 @@ pub mod tendermint_proxy_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3322,6 +4416,10 @@ pub mod tendermint_proxy_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3353,6 +4451,8 @@ pub mod tendermint_proxy_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3362,6 +4462,10 @@ pub mod tendermint_proxy_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3390,6 +4494,8 @@ pub mod tendermint_proxy_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3399,6 +4505,10 @@ pub mod tendermint_proxy_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3428,6 +4538,8 @@ pub mod tendermint_proxy_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3437,6 +4549,10 @@ pub mod tendermint_proxy_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3468,6 +4584,8 @@ pub mod tendermint_proxy_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -3477,6 +4595,54 @@ pub mod tendermint_proxy_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.TendermintProxyService/GetValidatorSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetValidatorSetSvc<T: TendermintProxyService>(pub Arc<T>);
+                    impl<
+                        T: TendermintProxyService,
+                    > tonic::server::UnaryService<super::GetValidatorSetRequest>
+                    for GetValidatorSetSvc<T> {
+                        type Response = super::GetValidatorSetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetValidatorSetRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_validator_set(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetValidatorSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -3505,6 +4671,8 @@ pub mod tendermint_proxy_service_server {
                 inner,
                 accept_compression_encodings: self.accept_compression_encodings,
                 send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
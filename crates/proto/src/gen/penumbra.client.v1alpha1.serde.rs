@@ -1351,6 +1351,266 @@ impl<'de> serde::Deserialize<'de> for BroadcastTxSyncResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.BroadcastTxSyncResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CandlesticksRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.interval != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.CandlesticksRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.interval != 0 {
+            struct_ser.serialize_field("interval", ToString::to_string(&self.interval).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CandlesticksRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "trading_pair",
+            "tradingPair",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "interval",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            TradingPair,
+            StartHeight,
+            EndHeight,
+            Interval,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "interval" => Ok(GeneratedField::Interval),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandlesticksRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.CandlesticksRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CandlesticksRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut trading_pair__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut interval__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CandlesticksRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    interval: interval__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.CandlesticksRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CandlesticksResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.CandlesticksResponse", len)?;
+        if !self.data.is_empty() {
+            struct_ser.serialize_field("data", &self.data)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CandlesticksResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandlesticksResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.CandlesticksResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CandlesticksResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(CandlesticksResponse {
+                    data: data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.CandlesticksResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ChainParametersRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2180,7 +2440,7 @@ impl<'de> serde::Deserialize<'de> for DenomMetadataByIdResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.DenomMetadataByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EpochByHeightRequest {
+impl serde::Serialize for DepthLevel {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2188,29 +2448,46 @@ impl serde::Serialize for EpochByHeightRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.price != 0. {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.EpochByHeightRequest", len)?;
-        if self.height != 0 {
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.cumulative_reserves.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.DepthLevel", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.cumulative_reserves.as_ref() {
+            struct_ser.serialize_field("cumulativeReserves", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EpochByHeightRequest {
+impl<'de> serde::Deserialize<'de> for DepthLevel {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
+            "price",
+            "reserves",
+            "cumulative_reserves",
+            "cumulativeReserves",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
+            Price,
+            Reserves,
+            CumulativeReserves,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2232,7 +2509,9 @@ impl<'de> serde::Deserialize<'de> for EpochByHeightRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
+                            "price" => Ok(GeneratedField::Price),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "cumulativeReserves" | "cumulative_reserves" => Ok(GeneratedField::CumulativeReserves),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2242,30 +2521,139 @@ impl<'de> serde::Deserialize<'de> for EpochByHeightRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EpochByHeightRequest;
+            type Value = DepthLevel;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.EpochByHeightRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.DepthLevel")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<EpochByHeightRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DepthLevel, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
+                let mut price__ = None;
+                let mut reserves__ = None;
+                let mut cumulative_reserves__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
                             }
-                            height__ = 
+                            price__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                    }
-                }
-                Ok(EpochByHeightRequest {
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map.next_value()?;
+                        }
+                        GeneratedField::CumulativeReserves => {
+                            if cumulative_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativeReserves"));
+                            }
+                            cumulative_reserves__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(DepthLevel {
+                    price: price__.unwrap_or_default(),
+                    reserves: reserves__,
+                    cumulative_reserves: cumulative_reserves__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.DepthLevel", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochByHeightRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.EpochByHeightRequest", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochByHeightRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochByHeightRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.EpochByHeightRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<EpochByHeightRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(EpochByHeightRequest {
                     height: height__.unwrap_or_default(),
                 })
             }
@@ -2364,6 +2752,114 @@ impl<'de> serde::Deserialize<'de> for EpochByHeightResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.EpochByHeightResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Event {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.kind.is_empty() {
+            len += 1;
+        }
+        if !self.attributes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Event", len)?;
+        if !self.kind.is_empty() {
+            struct_ser.serialize_field("kind", &self.kind)?;
+        }
+        if !self.attributes.is_empty() {
+            struct_ser.serialize_field("attributes", &self.attributes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Event {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "kind",
+            "attributes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Kind,
+            Attributes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "kind" => Ok(GeneratedField::Kind),
+                            "attributes" => Ok(GeneratedField::Attributes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Event;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.Event")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Event, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut kind__ = None;
+                let mut attributes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Attributes => {
+                            if attributes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attributes"));
+                            }
+                            attributes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(Event {
+                    kind: kind__.unwrap_or_default(),
+                    attributes: attributes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.Event", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetBlockByHeightRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2806,7 +3302,7 @@ impl<'de> serde::Deserialize<'de> for GetTxResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.GetTxResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for InfoRequest {
+impl serde::Serialize for GetValidatorSetRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2814,56 +3310,29 @@ impl serde::Serialize for InfoRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.version.is_empty() {
-            len += 1;
-        }
-        if self.block_version != 0 {
-            len += 1;
-        }
-        if self.p2p_version != 0 {
-            len += 1;
-        }
-        if !self.abci_version.is_empty() {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.InfoRequest", len)?;
-        if !self.version.is_empty() {
-            struct_ser.serialize_field("version", &self.version)?;
-        }
-        if self.block_version != 0 {
-            struct_ser.serialize_field("blockVersion", ToString::to_string(&self.block_version).as_str())?;
-        }
-        if self.p2p_version != 0 {
-            struct_ser.serialize_field("p2pVersion", ToString::to_string(&self.p2p_version).as_str())?;
-        }
-        if !self.abci_version.is_empty() {
-            struct_ser.serialize_field("abciVersion", &self.abci_version)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.GetValidatorSetRequest", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for InfoRequest {
+impl<'de> serde::Deserialize<'de> for GetValidatorSetRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "version",
-            "block_version",
-            "blockVersion",
-            "p2p_version",
-            "p2pVersion",
-            "abci_version",
-            "abciVersion",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Version,
-            BlockVersion,
-            P2pVersion,
-            AbciVersion,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2885,10 +3354,7 @@ impl<'de> serde::Deserialize<'de> for InfoRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "version" => Ok(GeneratedField::Version),
-                            "blockVersion" | "block_version" => Ok(GeneratedField::BlockVersion),
-                            "p2pVersion" | "p2p_version" => Ok(GeneratedField::P2pVersion),
-                            "abciVersion" | "abci_version" => Ok(GeneratedField::AbciVersion),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2898,54 +3364,414 @@ impl<'de> serde::Deserialize<'de> for InfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = InfoRequest;
+            type Value = GetValidatorSetRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.InfoRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.GetValidatorSetRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<InfoRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetValidatorSetRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut version__ = None;
-                let mut block_version__ = None;
-                let mut p2p_version__ = None;
-                let mut abci_version__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Version => {
-                            if version__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("version"));
-                            }
-                            version__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::BlockVersion => {
-                            if block_version__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("blockVersion"));
-                            }
-                            block_version__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::P2pVersion => {
-                            if p2p_version__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("p2pVersion"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            p2p_version__ = 
+                            height__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::AbciVersion => {
-                            if abci_version__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("abciVersion"));
-                            }
-                            abci_version__ = Some(map.next_value()?);
-                        }
                     }
                 }
-                Ok(InfoRequest {
-                    version: version__.unwrap_or_default(),
+                Ok(GetValidatorSetRequest {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.GetValidatorSetRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimitRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Ics20RateLimitRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "channel_id",
+            "channelId",
+            "asset_id",
+            "assetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ChannelId,
+            AssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.Ics20RateLimitRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20RateLimitRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut channel_id__ = None;
+                let mut asset_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.Ics20RateLimitRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimitResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_limit.is_some() {
+            len += 1;
+        }
+        if self.flow.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.Ics20RateLimitResponse", len)?;
+        if let Some(v) = self.rate_limit.as_ref() {
+            struct_ser.serialize_field("rateLimit", v)?;
+        }
+        if let Some(v) = self.flow.as_ref() {
+            struct_ser.serialize_field("flow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_limit",
+            "rateLimit",
+            "flow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateLimit,
+            Flow,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateLimit" | "rate_limit" => Ok(GeneratedField::RateLimit),
+                            "flow" => Ok(GeneratedField::Flow),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.Ics20RateLimitResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20RateLimitResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_limit__ = None;
+                let mut flow__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::RateLimit => {
+                            if rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimit"));
+                            }
+                            rate_limit__ = map.next_value()?;
+                        }
+                        GeneratedField::Flow => {
+                            if flow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flow"));
+                            }
+                            flow__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitResponse {
+                    rate_limit: rate_limit__,
+                    flow: flow__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.Ics20RateLimitResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if self.block_version != 0 {
+            len += 1;
+        }
+        if self.p2p_version != 0 {
+            len += 1;
+        }
+        if !self.abci_version.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.InfoRequest", len)?;
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if self.block_version != 0 {
+            struct_ser.serialize_field("blockVersion", ToString::to_string(&self.block_version).as_str())?;
+        }
+        if self.p2p_version != 0 {
+            struct_ser.serialize_field("p2pVersion", ToString::to_string(&self.p2p_version).as_str())?;
+        }
+        if !self.abci_version.is_empty() {
+            struct_ser.serialize_field("abciVersion", &self.abci_version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "block_version",
+            "blockVersion",
+            "p2p_version",
+            "p2pVersion",
+            "abci_version",
+            "abciVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            BlockVersion,
+            P2pVersion,
+            AbciVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "blockVersion" | "block_version" => Ok(GeneratedField::BlockVersion),
+                            "p2pVersion" | "p2p_version" => Ok(GeneratedField::P2pVersion),
+                            "abciVersion" | "abci_version" => Ok(GeneratedField::AbciVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.InfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<InfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut block_version__ = None;
+                let mut p2p_version__ = None;
+                let mut abci_version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::BlockVersion => {
+                            if block_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockVersion"));
+                            }
+                            block_version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::P2pVersion => {
+                            if p2p_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("p2pVersion"));
+                            }
+                            p2p_version__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AbciVersion => {
+                            if abci_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("abciVersion"));
+                            }
+                            abci_version__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(InfoRequest {
+                    version: version__.unwrap_or_default(),
                     block_version: block_version__.unwrap_or_default(),
                     p2p_version: p2p_version__.unwrap_or_default(),
                     abci_version: abci_version__.unwrap_or_default(),
@@ -4275,6 +5101,245 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for MarketDepthRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.MarketDepthRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketDepthRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "trading_pair",
+            "tradingPair",
+            "limit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            TradingPair,
+            Limit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "limit" => Ok(GeneratedField::Limit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketDepthRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.MarketDepthRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<MarketDepthRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut trading_pair__ = None;
+                let mut limit__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(MarketDepthRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    limit: limit__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.MarketDepthRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketDepthResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.start_to_end.is_empty() {
+            len += 1;
+        }
+        if !self.end_to_start.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.MarketDepthResponse", len)?;
+        if !self.start_to_end.is_empty() {
+            struct_ser.serialize_field("startToEnd", &self.start_to_end)?;
+        }
+        if !self.end_to_start.is_empty() {
+            struct_ser.serialize_field("endToStart", &self.end_to_start)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketDepthResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_to_end",
+            "startToEnd",
+            "end_to_start",
+            "endToStart",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartToEnd,
+            EndToStart,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startToEnd" | "start_to_end" => Ok(GeneratedField::StartToEnd),
+                            "endToStart" | "end_to_start" => Ok(GeneratedField::EndToStart),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketDepthResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.MarketDepthResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<MarketDepthResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_to_end__ = None;
+                let mut end_to_start__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StartToEnd => {
+                            if start_to_end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startToEnd"));
+                            }
+                            start_to_end__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EndToStart => {
+                            if end_to_start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endToStart"));
+                            }
+                            end_to_start__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(MarketDepthResponse {
+                    start_to_end: start_to_end__.unwrap_or_default(),
+                    end_to_start: end_to_start__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.MarketDepthResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for NextValidatorRateRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5142,6 +6207,15 @@ impl serde::Serialize for SimulateTradeRequest {
         if self.routing.is_some() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
+        if !self.hypothetical_positions.is_empty() {
+            len += 1;
+        }
+        if !self.input_ladder.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SimulateTradeRequest", len)?;
         if let Some(v) = self.input.as_ref() {
             struct_ser.serialize_field("input", v)?;
@@ -5152,6 +6226,15 @@ impl serde::Serialize for SimulateTradeRequest {
         if let Some(v) = self.routing.as_ref() {
             struct_ser.serialize_field("routing", v)?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
+        if !self.hypothetical_positions.is_empty() {
+            struct_ser.serialize_field("hypotheticalPositions", &self.hypothetical_positions)?;
+        }
+        if !self.input_ladder.is_empty() {
+            struct_ser.serialize_field("inputLadder", &self.input_ladder)?;
+        }
         struct_ser.end()
     }
 }
@@ -5165,6 +6248,12 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             "input",
             "output",
             "routing",
+            "min_output",
+            "minOutput",
+            "hypothetical_positions",
+            "hypotheticalPositions",
+            "input_ladder",
+            "inputLadder",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5172,6 +6261,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             Input,
             Output,
             Routing,
+            MinOutput,
+            HypotheticalPositions,
+            InputLadder,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5196,6 +6288,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             "input" => Ok(GeneratedField::Input),
                             "output" => Ok(GeneratedField::Output),
                             "routing" => Ok(GeneratedField::Routing),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
+                            "hypotheticalPositions" | "hypothetical_positions" => Ok(GeneratedField::HypotheticalPositions),
+                            "inputLadder" | "input_ladder" => Ok(GeneratedField::InputLadder),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5218,6 +6313,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                 let mut input__ = None;
                 let mut output__ = None;
                 let mut routing__ = None;
+                let mut min_output__ = None;
+                let mut hypothetical_positions__ = None;
+                let mut input_ladder__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Input => {
@@ -5230,13 +6328,31 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             if output__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("output"));
                             }
-                            output__ = map.next_value()?;
+                            output__ = map.next_value()?;
+                        }
+                        GeneratedField::Routing => {
+                            if routing__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routing"));
+                            }
+                            routing__ = map.next_value()?;
+                        }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map.next_value()?;
+                        }
+                        GeneratedField::HypotheticalPositions => {
+                            if hypothetical_positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hypotheticalPositions"));
+                            }
+                            hypothetical_positions__ = Some(map.next_value()?);
                         }
-                        GeneratedField::Routing => {
-                            if routing__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("routing"));
+                        GeneratedField::InputLadder => {
+                            if input_ladder__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputLadder"));
                             }
-                            routing__ = map.next_value()?;
+                            input_ladder__ = Some(map.next_value()?);
                         }
                     }
                 }
@@ -5244,6 +6360,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                     input: input__,
                     output: output__,
                     routing: routing__,
+                    min_output: min_output__,
+                    hypothetical_positions: hypothetical_positions__.unwrap_or_default(),
+                    input_ladder: input_ladder__.unwrap_or_default(),
                 })
             }
         }
@@ -5502,7 +6621,393 @@ impl<'de> serde::Deserialize<'de> for simulate_trade_request::routing::SingleHop
         deserializer.deserialize_struct("penumbra.client.v1alpha1.SimulateTradeRequest.Routing.SingleHop", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SimulateTradeResponse {
+impl serde::Serialize for SimulateTradeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.output.is_some() {
+            len += 1;
+        }
+        if self.below_min_output {
+            len += 1;
+        }
+        if !self.paths.is_empty() {
+            len += 1;
+        }
+        if self.spot_price != 0. {
+            len += 1;
+        }
+        if self.effective_price != 0. {
+            len += 1;
+        }
+        if self.price_impact != 0. {
+            len += 1;
+        }
+        if self.unfilled.is_some() {
+            len += 1;
+        }
+        if !self.positions_consumed.is_empty() {
+            len += 1;
+        }
+        if !self.ladder.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse", len)?;
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if self.below_min_output {
+            struct_ser.serialize_field("belowMinOutput", &self.below_min_output)?;
+        }
+        if !self.paths.is_empty() {
+            struct_ser.serialize_field("paths", &self.paths)?;
+        }
+        if self.spot_price != 0. {
+            struct_ser.serialize_field("spotPrice", &self.spot_price)?;
+        }
+        if self.effective_price != 0. {
+            struct_ser.serialize_field("effectivePrice", &self.effective_price)?;
+        }
+        if self.price_impact != 0. {
+            struct_ser.serialize_field("priceImpact", &self.price_impact)?;
+        }
+        if let Some(v) = self.unfilled.as_ref() {
+            struct_ser.serialize_field("unfilled", v)?;
+        }
+        if !self.positions_consumed.is_empty() {
+            struct_ser.serialize_field("positionsConsumed", &self.positions_consumed)?;
+        }
+        if !self.ladder.is_empty() {
+            struct_ser.serialize_field("ladder", &self.ladder)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "output",
+            "below_min_output",
+            "belowMinOutput",
+            "paths",
+            "spot_price",
+            "spotPrice",
+            "effective_price",
+            "effectivePrice",
+            "price_impact",
+            "priceImpact",
+            "unfilled",
+            "positions_consumed",
+            "positionsConsumed",
+            "ladder",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Output,
+            BelowMinOutput,
+            Paths,
+            SpotPrice,
+            EffectivePrice,
+            PriceImpact,
+            Unfilled,
+            PositionsConsumed,
+            Ladder,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "output" => Ok(GeneratedField::Output),
+                            "belowMinOutput" | "below_min_output" => Ok(GeneratedField::BelowMinOutput),
+                            "paths" => Ok(GeneratedField::Paths),
+                            "spotPrice" | "spot_price" => Ok(GeneratedField::SpotPrice),
+                            "effectivePrice" | "effective_price" => Ok(GeneratedField::EffectivePrice),
+                            "priceImpact" | "price_impact" => Ok(GeneratedField::PriceImpact),
+                            "unfilled" => Ok(GeneratedField::Unfilled),
+                            "positionsConsumed" | "positions_consumed" => Ok(GeneratedField::PositionsConsumed),
+                            "ladder" => Ok(GeneratedField::Ladder),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateTradeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.SimulateTradeResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SimulateTradeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut output__ = None;
+                let mut below_min_output__ = None;
+                let mut paths__ = None;
+                let mut spot_price__ = None;
+                let mut effective_price__ = None;
+                let mut price_impact__ = None;
+                let mut unfilled__ = None;
+                let mut positions_consumed__ = None;
+                let mut ladder__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map.next_value()?;
+                        }
+                        GeneratedField::BelowMinOutput => {
+                            if below_min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("belowMinOutput"));
+                            }
+                            below_min_output__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Paths => {
+                            if paths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paths"));
+                            }
+                            paths__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SpotPrice => {
+                            if spot_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spotPrice"));
+                            }
+                            spot_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EffectivePrice => {
+                            if effective_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectivePrice"));
+                            }
+                            effective_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PriceImpact => {
+                            if price_impact__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceImpact"));
+                            }
+                            price_impact__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Unfilled => {
+                            if unfilled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled"));
+                            }
+                            unfilled__ = map.next_value()?;
+                        }
+                        GeneratedField::PositionsConsumed => {
+                            if positions_consumed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionsConsumed"));
+                            }
+                            positions_consumed__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Ladder => {
+                            if ladder__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ladder"));
+                            }
+                            ladder__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(SimulateTradeResponse {
+                    output: output__,
+                    below_min_output: below_min_output__.unwrap_or_default(),
+                    paths: paths__.unwrap_or_default(),
+                    spot_price: spot_price__.unwrap_or_default(),
+                    effective_price: effective_price__.unwrap_or_default(),
+                    price_impact: price_impact__.unwrap_or_default(),
+                    unfilled: unfilled__,
+                    positions_consumed: positions_consumed__.unwrap_or_default(),
+                    ladder: ladder__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for simulate_trade_response::LadderStep {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output.is_some() {
+            len += 1;
+        }
+        if self.effective_price != 0. {
+            len += 1;
+        }
+        if self.price_impact != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse.LadderStep", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if self.effective_price != 0. {
+            struct_ser.serialize_field("effectivePrice", &self.effective_price)?;
+        }
+        if self.price_impact != 0. {
+            struct_ser.serialize_field("priceImpact", &self.price_impact)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for simulate_trade_response::LadderStep {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input",
+            "output",
+            "effective_price",
+            "effectivePrice",
+            "price_impact",
+            "priceImpact",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Input,
+            Output,
+            EffectivePrice,
+            PriceImpact,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
+                            "effectivePrice" | "effective_price" => Ok(GeneratedField::EffectivePrice),
+                            "priceImpact" | "price_impact" => Ok(GeneratedField::PriceImpact),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = simulate_trade_response::LadderStep;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.SimulateTradeResponse.LadderStep")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<simulate_trade_response::LadderStep, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut input__ = None;
+                let mut output__ = None;
+                let mut effective_price__ = None;
+                let mut price_impact__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map.next_value()?;
+                        }
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map.next_value()?;
+                        }
+                        GeneratedField::EffectivePrice => {
+                            if effective_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectivePrice"));
+                            }
+                            effective_price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PriceImpact => {
+                            if price_impact__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceImpact"));
+                            }
+                            price_impact__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(simulate_trade_response::LadderStep {
+                    input: input__,
+                    output: output__,
+                    effective_price: effective_price__.unwrap_or_default(),
+                    price_impact: price_impact__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse.LadderStep", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for simulate_trade_response::Path {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5510,29 +7015,54 @@ impl serde::Serialize for SimulateTradeResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if !self.route.is_empty() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
         if self.output.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse", len)?;
+        if !self.hop_prices.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse.Path", len)?;
+        if !self.route.is_empty() {
+            struct_ser.serialize_field("route", &self.route)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
         if let Some(v) = self.output.as_ref() {
             struct_ser.serialize_field("output", v)?;
         }
+        if !self.hop_prices.is_empty() {
+            struct_ser.serialize_field("hopPrices", &self.hop_prices)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
+impl<'de> serde::Deserialize<'de> for simulate_trade_response::Path {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "route",
+            "input",
             "output",
+            "hop_prices",
+            "hopPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Route,
+            Input,
             Output,
+            HopPrices,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5554,7 +7084,10 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         E: serde::de::Error,
                     {
                         match value {
+                            "route" => Ok(GeneratedField::Route),
+                            "input" => Ok(GeneratedField::Input),
                             "output" => Ok(GeneratedField::Output),
+                            "hopPrices" | "hop_prices" => Ok(GeneratedField::HopPrices),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5564,33 +7097,60 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SimulateTradeResponse;
+            type Value = simulate_trade_response::Path;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.SimulateTradeResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.SimulateTradeResponse.Path")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<SimulateTradeResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<simulate_trade_response::Path, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut route__ = None;
+                let mut input__ = None;
                 let mut output__ = None;
+                let mut hop_prices__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
+                        GeneratedField::Route => {
+                            if route__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("route"));
+                            }
+                            route__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map.next_value()?;
+                        }
                         GeneratedField::Output => {
                             if output__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("output"));
                             }
                             output__ = map.next_value()?;
                         }
+                        GeneratedField::HopPrices => {
+                            if hop_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hopPrices"));
+                            }
+                            hop_prices__ = 
+                                Some(map.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
                     }
                 }
-                Ok(SimulateTradeResponse {
+                Ok(simulate_trade_response::Path {
+                    route: route__.unwrap_or_default(),
+                    input: input__,
                     output: output__,
+                    hop_prices: hop_prices__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.SimulateTradeResponse.Path", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SpreadRequest {
@@ -6652,6 +8212,249 @@ impl<'de> serde::Deserialize<'de> for Tag {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.Tag", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TimeWeightedAveragePriceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TimeWeightedAveragePriceRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePriceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "trading_pair",
+            "tradingPair",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            TradingPair,
+            StartHeight,
+            EndHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePriceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.TimeWeightedAveragePriceRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TimeWeightedAveragePriceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut trading_pair__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePriceRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    trading_pair: trading_pair__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.TimeWeightedAveragePriceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedAveragePriceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.price != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TimeWeightedAveragePriceResponse", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePriceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "price",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Price,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "price" => Ok(GeneratedField::Price),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePriceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.TimeWeightedAveragePriceResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TimeWeightedAveragePriceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut price__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePriceResponse {
+                    price: price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.TimeWeightedAveragePriceResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TransactionByNoteRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -6874,6 +8677,9 @@ impl serde::Serialize for TxResult {
         if !self.tags.is_empty() {
             len += 1;
         }
+        if !self.events.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TxResult", len)?;
         if !self.log.is_empty() {
             struct_ser.serialize_field("log", &self.log)?;
@@ -6887,6 +8693,9 @@ impl serde::Serialize for TxResult {
         if !self.tags.is_empty() {
             struct_ser.serialize_field("tags", &self.tags)?;
         }
+        if !self.events.is_empty() {
+            struct_ser.serialize_field("events", &self.events)?;
+        }
         struct_ser.end()
     }
 }
@@ -6903,6 +8712,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
             "gas_used",
             "gasUsed",
             "tags",
+            "events",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -6911,6 +8721,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
             GasWanted,
             GasUsed,
             Tags,
+            Events,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6936,6 +8747,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                             "gasWanted" | "gas_wanted" => Ok(GeneratedField::GasWanted),
                             "gasUsed" | "gas_used" => Ok(GeneratedField::GasUsed),
                             "tags" => Ok(GeneratedField::Tags),
+                            "events" => Ok(GeneratedField::Events),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6959,6 +8771,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                 let mut gas_wanted__ = None;
                 let mut gas_used__ = None;
                 let mut tags__ = None;
+                let mut events__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Log => {
@@ -6989,6 +8802,12 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                             }
                             tags__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Events => {
+                            if events__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("events"));
+                            }
+                            events__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(TxResult {
@@ -6996,6 +8815,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                     gas_wanted: gas_wanted__.unwrap_or_default(),
                     gas_used: gas_used__.unwrap_or_default(),
                     tags: tags__.unwrap_or_default(),
+                    events: events__.unwrap_or_default(),
                 })
             }
         }
@@ -88,6 +88,12 @@ pub struct SwapBody {
     /// The swap commitment and encryption of the swap data.
     #[prost(message, optional, tag = "5")]
     pub payload: ::core::option::Option<SwapPayload>,
+    /// The minimum amount of the other asset the swap must output to be filled.
+    ///
+    /// If zero, the swap is filled at any price. Otherwise, a swap whose output
+    /// would fall short of this amount is refunded when it is claimed.
+    #[prost(message, optional, tag = "6")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -122,6 +128,9 @@ pub struct SwapPlaintext {
     /// Swap rseed (blinding factors are derived from this)
     #[prost(bytes = "vec", tag = "6")]
     pub rseed: ::prost::alloc::vec::Vec<u8>,
+    /// The minimum amount of the other asset the swap must output to be filled.
+    #[prost(message, optional, tag = "7")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -288,6 +297,14 @@ pub struct BatchSwapOutputData {
     /// The starting block height of the epoch for which the batch swap data is valid.
     #[prost(uint64, tag = "9")]
     pub epoch_starting_height: u64,
+    /// The limit price (asset 2 per asset 1, as a U128x128) below which 1=>2 swaps
+    /// with a minimum output were excluded from the batch and refunded.
+    #[prost(bytes = "vec", tag = "10")]
+    pub limit_price_1: ::prost::alloc::vec::Vec<u8>,
+    /// The limit price (asset 1 per asset 2, as a U128x128) below which 2=>1 swaps
+    /// with a minimum output were excluded from the batch and refunded.
+    #[prost(bytes = "vec", tag = "11")]
+    pub limit_price_2: ::prost::alloc::vec::Vec<u8>,
 }
 /// The trading function for a specific pair.
 /// For a pair (asset_1, asset_2), a trading function is defined by:
@@ -353,6 +370,24 @@ pub struct Position {
     /// / immediately after being filled.
     #[prost(bool, tag = "5")]
     pub close_on_fill: bool,
+    /// If set, the position is opened in the pending state, and is only activated
+    /// once the execution price of its trading pair meets the trigger.
+    #[prost(message, optional, tag = "6")]
+    pub trigger: ::core::option::Option<PositionTrigger>,
+}
+/// A condition on the execution price of a position's trading pair, which
+/// activates a pending position once it is met.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionTrigger {
+    /// The trigger price, in units of asset 2 per unit of asset 1 of the trading
+    /// pair, encoded as a U128x128.
+    #[prost(bytes = "vec", tag = "1")]
+    pub price: ::prost::alloc::vec::Vec<u8>,
+    /// If set, the trigger is met by an execution price at or above the trigger
+    /// price, and otherwise by an execution price at or below it.
+    #[prost(bool, tag = "2")]
+    pub above: bool,
 }
 /// A hash of a `Position`.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -406,6 +441,9 @@ pub mod position_state {
         /// Any retroactive rewards have been claimed. The position is now an inert,
         /// historical artefact.
         Claimed = 4,
+        /// The position has been opened with a trigger that has not been met yet,
+        /// so it is inactive and cannot be traded against.
+        Pending = 5,
     }
     impl PositionStateEnum {
         /// String value of the enum field names used in the ProtoBuf definition.
@@ -419,6 +457,7 @@ pub mod position_state {
                 PositionStateEnum::Closed => "POSITION_STATE_ENUM_CLOSED",
                 PositionStateEnum::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
                 PositionStateEnum::Claimed => "POSITION_STATE_ENUM_CLAIMED",
+                PositionStateEnum::Pending => "POSITION_STATE_ENUM_PENDING",
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
@@ -429,6 +468,7 @@ pub mod position_state {
                 "POSITION_STATE_ENUM_CLOSED" => Some(Self::Closed),
                 "POSITION_STATE_ENUM_WITHDRAWN" => Some(Self::Withdrawn),
                 "POSITION_STATE_ENUM_CLAIMED" => Some(Self::Claimed),
+                "POSITION_STATE_ENUM_PENDING" => Some(Self::Pending),
                 _ => None,
             }
         }
@@ -548,6 +588,52 @@ pub mod swap_execution {
         >,
     }
 }
+/// The open, high, low, close and volume of the executions of a directed trading pair
+/// over a range of block heights.
+///
+/// Prices are quoted in units of the pair's `end` asset per unit of its `start` asset,
+/// and the volume is the amount of the `start` asset traded in either direction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CandlestickData {
+    /// The first height covered by the candle.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// The last height covered by the candle.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
+    /// The price of the first execution in the candle.
+    #[prost(double, tag = "3")]
+    pub open: f64,
+    /// The highest execution price in the candle.
+    #[prost(double, tag = "4")]
+    pub high: f64,
+    /// The lowest execution price in the candle.
+    #[prost(double, tag = "5")]
+    pub low: f64,
+    /// The price of the last execution in the candle.
+    #[prost(double, tag = "6")]
+    pub close: f64,
+    /// The amount of the `start` asset traded in the candle.
+    #[prost(message, optional, tag = "7")]
+    pub volume: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
+/// A cumulative price accumulator for a trading pair, used to compute time-weighted average prices.
+///
+/// Prices are quoted in units of asset 2 per unit of asset 1.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceAccumulator {
+    /// The height of the last price observation.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The last observed price, in effect from `height` onward.
+    #[prost(bytes = "vec", tag = "2")]
+    pub price: ::prost::alloc::vec::Vec<u8>,
+    /// The sum of the prices in effect at each height before `height`.
+    #[prost(bytes = "vec", tag = "3")]
+    pub cumulative_price: ::prost::alloc::vec::Vec<u8>,
+}
 /// Contains private and public data for withdrawing funds from a closed position.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
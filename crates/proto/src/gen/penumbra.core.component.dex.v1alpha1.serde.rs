@@ -160,6 +160,12 @@ impl serde::Serialize for BatchSwapOutputData {
         if self.epoch_starting_height != 0 {
             len += 1;
         }
+        if !self.limit_price_1.is_empty() {
+            len += 1;
+        }
+        if !self.limit_price_2.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.BatchSwapOutputData", len)?;
         if let Some(v) = self.delta_1.as_ref() {
             struct_ser.serialize_field("delta1", v)?;
@@ -188,6 +194,12 @@ impl serde::Serialize for BatchSwapOutputData {
         if self.epoch_starting_height != 0 {
             struct_ser.serialize_field("epochStartingHeight", ToString::to_string(&self.epoch_starting_height).as_str())?;
         }
+        if !self.limit_price_1.is_empty() {
+            struct_ser.serialize_field("limitPrice1", pbjson::private::base64::encode(&self.limit_price_1).as_str())?;
+        }
+        if !self.limit_price_2.is_empty() {
+            struct_ser.serialize_field("limitPrice2", pbjson::private::base64::encode(&self.limit_price_2).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -215,6 +227,10 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
             "tradingPair",
             "epoch_starting_height",
             "epochStartingHeight",
+            "limit_price_1",
+            "limitPrice1",
+            "limit_price_2",
+            "limitPrice2",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -228,6 +244,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
             Height,
             TradingPair,
             EpochStartingHeight,
+            LimitPrice1,
+            LimitPrice2,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -258,6 +276,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                             "height" => Ok(GeneratedField::Height),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             "epochStartingHeight" | "epoch_starting_height" => Ok(GeneratedField::EpochStartingHeight),
+                            "limitPrice1" | "limit_price_1" => Ok(GeneratedField::LimitPrice1),
+                            "limitPrice2" | "limit_price_2" => Ok(GeneratedField::LimitPrice2),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -286,6 +306,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                 let mut height__ = None;
                 let mut trading_pair__ = None;
                 let mut epoch_starting_height__ = None;
+                let mut limit_price_1__ = None;
+                let mut limit_price_2__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Delta1 => {
@@ -346,6 +368,22 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::LimitPrice1 => {
+                            if limit_price_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limitPrice1"));
+                            }
+                            limit_price_1__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LimitPrice2 => {
+                            if limit_price_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limitPrice2"));
+                            }
+                            limit_price_2__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(BatchSwapOutputData {
@@ -358,12 +396,221 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                     height: height__.unwrap_or_default(),
                     trading_pair: trading_pair__,
                     epoch_starting_height: epoch_starting_height__.unwrap_or_default(),
+                    limit_price_1: limit_price_1__.unwrap_or_default(),
+                    limit_price_2: limit_price_2__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.BatchSwapOutputData", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CandlestickData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.open != 0. {
+            len += 1;
+        }
+        if self.high != 0. {
+            len += 1;
+        }
+        if self.low != 0. {
+            len += 1;
+        }
+        if self.close != 0. {
+            len += 1;
+        }
+        if self.volume.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.CandlestickData", len)?;
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.open != 0. {
+            struct_ser.serialize_field("open", &self.open)?;
+        }
+        if self.high != 0. {
+            struct_ser.serialize_field("high", &self.high)?;
+        }
+        if self.low != 0. {
+            struct_ser.serialize_field("low", &self.low)?;
+        }
+        if self.close != 0. {
+            struct_ser.serialize_field("close", &self.close)?;
+        }
+        if let Some(v) = self.volume.as_ref() {
+            struct_ser.serialize_field("volume", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CandlestickData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "open",
+            "high",
+            "low",
+            "close",
+            "volume",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartHeight,
+            EndHeight,
+            Open,
+            High,
+            Low,
+            Close,
+            Volume,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "open" => Ok(GeneratedField::Open),
+                            "high" => Ok(GeneratedField::High),
+                            "low" => Ok(GeneratedField::Low),
+                            "close" => Ok(GeneratedField::Close),
+                            "volume" => Ok(GeneratedField::Volume),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandlestickData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.CandlestickData")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CandlestickData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut open__ = None;
+                let mut high__ = None;
+                let mut low__ = None;
+                let mut close__ = None;
+                let mut volume__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Open => {
+                            if open__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("open"));
+                            }
+                            open__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::High => {
+                            if high__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("high"));
+                            }
+                            high__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Low => {
+                            if low__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("low"));
+                            }
+                            low__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Close => {
+                            if close__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("close"));
+                            }
+                            close__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Volume => {
+                            if volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volume"));
+                            }
+                            volume__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(CandlestickData {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    open: open__.unwrap_or_default(),
+                    high: high__.unwrap_or_default(),
+                    low: low__.unwrap_or_default(),
+                    close: close__.unwrap_or_default(),
+                    volume: volume__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.CandlestickData", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DirectedTradingPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -604,6 +851,9 @@ impl serde::Serialize for Position {
         if self.close_on_fill {
             len += 1;
         }
+        if self.trigger.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.Position", len)?;
         if let Some(v) = self.phi.as_ref() {
             struct_ser.serialize_field("phi", v)?;
@@ -620,6 +870,9 @@ impl serde::Serialize for Position {
         if self.close_on_fill {
             struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
         }
+        if let Some(v) = self.trigger.as_ref() {
+            struct_ser.serialize_field("trigger", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -636,6 +889,7 @@ impl<'de> serde::Deserialize<'de> for Position {
             "reserves",
             "close_on_fill",
             "closeOnFill",
+            "trigger",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -645,6 +899,7 @@ impl<'de> serde::Deserialize<'de> for Position {
             State,
             Reserves,
             CloseOnFill,
+            Trigger,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -671,6 +926,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                             "state" => Ok(GeneratedField::State),
                             "reserves" => Ok(GeneratedField::Reserves),
                             "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "trigger" => Ok(GeneratedField::Trigger),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -695,6 +951,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                 let mut state__ = None;
                 let mut reserves__ = None;
                 let mut close_on_fill__ = None;
+                let mut trigger__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Phi => {
@@ -729,6 +986,12 @@ impl<'de> serde::Deserialize<'de> for Position {
                             }
                             close_on_fill__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Trigger => {
+                            if trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trigger"));
+                            }
+                            trigger__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Position {
@@ -737,6 +1000,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                     state: state__,
                     reserves: reserves__,
                     close_on_fill: close_on_fill__.unwrap_or_default(),
+                    trigger: trigger__,
                 })
             }
         }
@@ -1343,6 +1607,7 @@ impl serde::Serialize for position_state::PositionStateEnum {
             Self::Closed => "POSITION_STATE_ENUM_CLOSED",
             Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
             Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
+            Self::Pending => "POSITION_STATE_ENUM_PENDING",
         };
         serializer.serialize_str(variant)
     }
@@ -1359,6 +1624,7 @@ impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
             "POSITION_STATE_ENUM_CLOSED",
             "POSITION_STATE_ENUM_WITHDRAWN",
             "POSITION_STATE_ENUM_CLAIMED",
+            "POSITION_STATE_ENUM_PENDING",
         ];
 
         struct GeneratedVisitor;
@@ -1406,6 +1672,7 @@ impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
                     "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
                     "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
                     "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
+                    "POSITION_STATE_ENUM_PENDING" => Ok(position_state::PositionStateEnum::Pending),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -1413,6 +1680,116 @@ impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for PositionTrigger {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if self.above {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.PositionTrigger", len)?;
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", pbjson::private::base64::encode(&self.price).as_str())?;
+        }
+        if self.above {
+            struct_ser.serialize_field("above", &self.above)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionTrigger {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "price",
+            "above",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Price,
+            Above,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "price" => Ok(GeneratedField::Price),
+                            "above" => Ok(GeneratedField::Above),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionTrigger;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.PositionTrigger")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PositionTrigger, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut price__ = None;
+                let mut above__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Above => {
+                            if above__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("above"));
+                            }
+                            above__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(PositionTrigger {
+                    price: price__.unwrap_or_default(),
+                    above: above__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.PositionTrigger", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for PositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1649,6 +2026,138 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PriceAccumulator {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if !self.cumulative_price.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.PriceAccumulator", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.price.is_empty() {
+            struct_ser.serialize_field("price", pbjson::private::base64::encode(&self.price).as_str())?;
+        }
+        if !self.cumulative_price.is_empty() {
+            struct_ser.serialize_field("cumulativePrice", pbjson::private::base64::encode(&self.cumulative_price).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PriceAccumulator {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "price",
+            "cumulative_price",
+            "cumulativePrice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Price,
+            CumulativePrice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "price" => Ok(GeneratedField::Price),
+                            "cumulativePrice" | "cumulative_price" => Ok(GeneratedField::CumulativePrice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PriceAccumulator;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.PriceAccumulator")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PriceAccumulator, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut price__ = None;
+                let mut cumulative_price__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CumulativePrice => {
+                            if cumulative_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativePrice"));
+                            }
+                            cumulative_price__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PriceAccumulator {
+                    height: height__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    cumulative_price: cumulative_price__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.PriceAccumulator", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Reserves {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1888,6 +2397,9 @@ impl serde::Serialize for SwapBody {
         if self.payload.is_some() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.SwapBody", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
@@ -1904,6 +2416,9 @@ impl serde::Serialize for SwapBody {
         if let Some(v) = self.payload.as_ref() {
             struct_ser.serialize_field("payload", v)?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1923,6 +2438,8 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             "fee_commitment",
             "feeCommitment",
             "payload",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1932,6 +2449,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             Delta2I,
             FeeCommitment,
             Payload,
+            MinOutput,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1958,6 +2476,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
                             "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
                             "payload" => Ok(GeneratedField::Payload),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1982,6 +2501,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                 let mut delta_2_i__ = None;
                 let mut fee_commitment__ = None;
                 let mut payload__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -2014,6 +2534,12 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             }
                             payload__ = map.next_value()?;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(SwapBody {
@@ -2022,6 +2548,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                     delta_2_i: delta_2_i__,
                     fee_commitment: fee_commitment__,
                     payload: payload__,
+                    min_output: min_output__,
                 })
             }
         }
@@ -3189,6 +3716,9 @@ impl serde::Serialize for SwapPlaintext {
         if !self.rseed.is_empty() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.SwapPlaintext", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
@@ -3208,6 +3738,9 @@ impl serde::Serialize for SwapPlaintext {
         if !self.rseed.is_empty() {
             struct_ser.serialize_field("rseed", pbjson::private::base64::encode(&self.rseed).as_str())?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -3229,6 +3762,8 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
             "claim_address",
             "claimAddress",
             "rseed",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3239,6 +3774,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
             ClaimFee,
             ClaimAddress,
             Rseed,
+            MinOutput,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3266,6 +3802,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                             "claimFee" | "claim_fee" => Ok(GeneratedField::ClaimFee),
                             "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
                             "rseed" => Ok(GeneratedField::Rseed),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3291,6 +3828,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                 let mut claim_fee__ = None;
                 let mut claim_address__ = None;
                 let mut rseed__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -3331,6 +3869,12 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(SwapPlaintext {
@@ -3340,6 +3884,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                     claim_fee: claim_fee__,
                     claim_address: claim_address__,
                     rseed: rseed__.unwrap_or_default(),
+                    min_output: min_output__,
                 })
             }
         }
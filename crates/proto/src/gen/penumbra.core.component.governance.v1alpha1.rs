@@ -206,6 +206,29 @@ pub struct DaoOutput {
     #[prost(message, optional, tag = "2")]
     pub address: ::core::option::Option<super::super::super::keys::v1alpha1::Address>,
 }
+/// The state of a recurring DAO disbursement scheduled by a passed proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DaoDisbursement {
+    /// The ID of the proposal which scheduled the disbursement.
+    #[prost(uint64, tag = "1")]
+    pub proposal: u64,
+    /// The value paid out at each disbursement.
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<super::super::super::asset::v1alpha1::Value>,
+    /// The address each disbursement is sent to.
+    #[prost(message, optional, tag = "3")]
+    pub address: ::core::option::Option<super::super::super::keys::v1alpha1::Address>,
+    /// The number of epochs between disbursements.
+    #[prost(uint64, tag = "4")]
+    pub epoch_interval: u64,
+    /// The number of disbursements still to be made.
+    #[prost(uint64, tag = "5")]
+    pub payouts_remaining: u64,
+    /// The index of the epoch at whose end the next disbursement is due.
+    #[prost(uint64, tag = "6")]
+    pub next_epoch: u64,
+}
 /// A vote on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -396,6 +419,24 @@ pub struct Proposal {
     pub dao_spend: ::core::option::Option<proposal::DaoSpend>,
     #[prost(message, optional, tag = "9")]
     pub upgrade_plan: ::core::option::Option<proposal::UpgradePlan>,
+    #[prost(message, optional, tag = "10")]
+    pub dao_disbursement: ::core::option::Option<proposal::DaoDisbursement>,
+    #[prost(message, optional, tag = "11")]
+    pub cancel_dao_disbursement: ::core::option::Option<proposal::CancelDaoDisbursement>,
+    #[prost(message, optional, tag = "12")]
+    pub recover_ibc_client: ::core::option::Option<proposal::RecoverIbcClient>,
+    #[prost(message, optional, tag = "13")]
+    pub freeze_ibc_client: ::core::option::Option<proposal::FreezeIbcClient>,
+    #[prost(message, optional, tag = "14")]
+    pub close_ibc_channel: ::core::option::Option<proposal::CloseIbcChannel>,
+    #[prost(message, optional, tag = "15")]
+    pub set_ics20_rate_limit: ::core::option::Option<proposal::SetIcs20RateLimit>,
+    #[prost(message, optional, tag = "16")]
+    pub register_dao_interchain_account: ::core::option::Option<
+        proposal::RegisterDaoInterchainAccount,
+    >,
+    #[prost(message, optional, tag = "17")]
+    pub dao_interchain_account_tx: ::core::option::Option<proposal::DaoInterchainAccountTx>,
 }
 /// Nested message and enum types in `Proposal`.
 pub mod proposal {
@@ -422,24 +463,43 @@ pub mod proposal {
         #[prost(bool, tag = "1")]
         pub halt_chain: bool,
     }
-    /// A parameter change proposal describes a replacement of the chain parameters, which should take
-    /// effect when the proposal is passed.
+    /// A parameter change proposal describes changes to individual chain parameters, which should take
+    /// effect when the proposal is passed. Parameters which are not named keep whatever value they have
+    /// at that time.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ParameterChange {
-        /// The old chain parameters to be replaced: even if the proposal passes, the update will not be
-        /// applied if the chain parameters have changed *at all* from these chain parameters. Usually,
-        /// this should be set to the current chain parameters at time of proposal.
+        /// The old chain parameters, as set by proposals made before parameter changes named individual
+        /// parameters. These proposals replace the chain parameters with `new_parameters`, but only if
+        /// they still match `old_parameters` when the proposal passes; new proposals leave both unset and
+        /// use `changes` instead.
         #[prost(message, optional, tag = "1")]
         pub old_parameters: ::core::option::Option<
             super::super::super::chain::v1alpha1::ChainParameters,
         >,
-        /// The new chain parameters to be set: the *entire* chain parameters will be replaced with these
-        /// at the time the proposal is passed.
+        /// The new chain parameters, as set by proposals made before parameter changes named individual
+        /// parameters (see `old_parameters`).
         #[prost(message, optional, tag = "2")]
         pub new_parameters: ::core::option::Option<
             super::super::super::chain::v1alpha1::ChainParameters,
         >,
+        /// The changes to make to the chain parameters, each naming a different parameter.
+        #[prost(message, repeated, tag = "3")]
+        pub changes: ::prost::alloc::vec::Vec<parameter_change::Change>,
+    }
+    /// Nested message and enum types in `ParameterChange`.
+    pub mod parameter_change {
+        /// A change to a single chain parameter.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Change {
+            /// The name of the parameter, as in `ChainParameters` (e.g. `epoch_duration`).
+            #[prost(string, tag = "1")]
+            pub parameter: ::prost::alloc::string::String,
+            /// The new value of the parameter, in text form (e.g. `1000`, `true`, or `1/2`).
+            #[prost(string, tag = "2")]
+            pub value: ::prost::alloc::string::String,
+        }
     }
     /// A DAO spend proposal describes zero or more transactions to execute on behalf of the DAO, with
     /// access to its funds, and zero or more scheduled transactions from previous passed proposals to
@@ -461,4 +521,103 @@ pub mod proposal {
         #[prost(uint64, tag = "1")]
         pub height: u64,
     }
+    /// A DAO disbursement proposal schedules a recurring transparent output from the DAO: every
+    /// `epoch_interval` epochs, `value` is paid to `address`, until `payouts` payments have been made.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DaoDisbursement {
+        /// The value to pay out at each disbursement.
+        #[prost(message, optional, tag = "1")]
+        pub value: ::core::option::Option<super::super::super::super::asset::v1alpha1::Value>,
+        /// The address to send each disbursement to.
+        #[prost(message, optional, tag = "2")]
+        pub address: ::core::option::Option<super::super::super::super::keys::v1alpha1::Address>,
+        /// The number of epochs between disbursements.
+        #[prost(uint64, tag = "3")]
+        pub epoch_interval: u64,
+        /// The total number of disbursements to make.
+        #[prost(uint64, tag = "4")]
+        pub payouts: u64,
+    }
+    /// A proposal to cancel the remaining payouts of a disbursement scheduled by a previously passed
+    /// DAO disbursement proposal.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CancelDaoDisbursement {
+        /// The ID of the proposal which scheduled the disbursement.
+        #[prost(uint64, tag = "1")]
+        pub proposal: u64,
+    }
+    /// An IBC client recovery proposal revives a frozen or expired IBC client (the subject) by
+    /// replacing its state with that of an active client of the same counterparty chain (the
+    /// substitute), so that connections and channels built on the subject can be used again.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RecoverIbcClient {
+        /// The ID of the frozen or expired client to recover.
+        #[prost(string, tag = "1")]
+        pub subject_client_id: ::prost::alloc::string::String,
+        /// The ID of the active client whose state replaces the subject's.
+        #[prost(string, tag = "2")]
+        pub substitute_client_id: ::prost::alloc::string::String,
+    }
+    /// An IBC client freeze proposal freezes an IBC client, so that no further updates or packets
+    /// are accepted through it until it is recovered.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FreezeIbcClient {
+        /// The ID of the client to freeze.
+        #[prost(string, tag = "1")]
+        pub client_id: ::prost::alloc::string::String,
+    }
+    /// An IBC channel closure proposal closes an open IBC channel on this chain's end.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CloseIbcChannel {
+        /// The port the channel is bound to.
+        #[prost(string, tag = "1")]
+        pub port_id: ::prost::alloc::string::String,
+        /// The ID of the channel to close.
+        #[prost(string, tag = "2")]
+        pub channel_id: ::prost::alloc::string::String,
+    }
+    /// An ICS-20 rate limit proposal sets or removes the limit on the value of an asset which can be
+    /// transferred over an ICS-20 channel in each window of blocks.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SetIcs20RateLimit {
+        /// The ID of the channel to limit, on the transfer port.
+        #[prost(string, tag = "1")]
+        pub channel_id: ::prost::alloc::string::String,
+        /// The denomination to limit, as it is known on this chain.
+        #[prost(string, tag = "2")]
+        pub denom: ::prost::alloc::string::String,
+        /// The new rate limit, or absent to remove the existing one.
+        #[prost(message, optional, tag = "3")]
+        pub rate_limit: ::core::option::Option<super::super::super::ibc::v1alpha1::Ics20RateLimit>,
+    }
+    /// A proposal to register an ICS-27 interchain account for the DAO on the chain at the other end
+    /// of an IBC connection, by opening an interchain account channel to it.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RegisterDaoInterchainAccount {
+        /// The ID of the connection to the host chain.
+        #[prost(string, tag = "1")]
+        pub connection_id: ::prost::alloc::string::String,
+    }
+    /// A proposal to send a transaction to the DAO's interchain account on another chain, to be
+    /// executed by that account.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DaoInterchainAccountTx {
+        /// The ID of the connection to the host chain.
+        #[prost(string, tag = "1")]
+        pub connection_id: ::prost::alloc::string::String,
+        /// The messages of the transaction, as the host chain encodes them.
+        #[prost(message, repeated, tag = "2")]
+        pub messages: ::prost::alloc::vec::Vec<::pbjson_types::Any>,
+        /// The memo of the transaction.
+        #[prost(string, tag = "3")]
+        pub memo: ::prost::alloc::string::String,
+    }
 }
@@ -89,6 +89,193 @@ impl<'de> serde::Deserialize<'de> for DaoDeposit {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.DaoDeposit", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DaoDisbursement {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.address.is_some() {
+            len += 1;
+        }
+        if self.epoch_interval != 0 {
+            len += 1;
+        }
+        if self.payouts_remaining != 0 {
+            len += 1;
+        }
+        if self.next_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.DaoDisbursement", len)?;
+        if self.proposal != 0 {
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        if self.epoch_interval != 0 {
+            struct_ser.serialize_field("epochInterval", ToString::to_string(&self.epoch_interval).as_str())?;
+        }
+        if self.payouts_remaining != 0 {
+            struct_ser.serialize_field("payoutsRemaining", ToString::to_string(&self.payouts_remaining).as_str())?;
+        }
+        if self.next_epoch != 0 {
+            struct_ser.serialize_field("nextEpoch", ToString::to_string(&self.next_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DaoDisbursement {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "value",
+            "address",
+            "epoch_interval",
+            "epochInterval",
+            "payouts_remaining",
+            "payoutsRemaining",
+            "next_epoch",
+            "nextEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            Value,
+            Address,
+            EpochInterval,
+            PayoutsRemaining,
+            NextEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
+                            "epochInterval" | "epoch_interval" => Ok(GeneratedField::EpochInterval),
+                            "payoutsRemaining" | "payouts_remaining" => Ok(GeneratedField::PayoutsRemaining),
+                            "nextEpoch" | "next_epoch" => Ok(GeneratedField::NextEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DaoDisbursement;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.DaoDisbursement")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DaoDisbursement, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut value__ = None;
+                let mut address__ = None;
+                let mut epoch_interval__ = None;
+                let mut payouts_remaining__ = None;
+                let mut next_epoch__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map.next_value()?;
+                        }
+                        GeneratedField::EpochInterval => {
+                            if epoch_interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochInterval"));
+                            }
+                            epoch_interval__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PayoutsRemaining => {
+                            if payouts_remaining__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payoutsRemaining"));
+                            }
+                            payouts_remaining__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NextEpoch => {
+                            if next_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextEpoch"));
+                            }
+                            next_epoch__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(DaoDisbursement {
+                    proposal: proposal__.unwrap_or_default(),
+                    value: value__,
+                    address: address__,
+                    epoch_interval: epoch_interval__.unwrap_or_default(),
+                    payouts_remaining: payouts_remaining__.unwrap_or_default(),
+                    next_epoch: next_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.DaoDisbursement", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DaoOutput {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1204,6 +1391,30 @@ impl serde::Serialize for Proposal {
         if self.upgrade_plan.is_some() {
             len += 1;
         }
+        if self.dao_disbursement.is_some() {
+            len += 1;
+        }
+        if self.cancel_dao_disbursement.is_some() {
+            len += 1;
+        }
+        if self.recover_ibc_client.is_some() {
+            len += 1;
+        }
+        if self.freeze_ibc_client.is_some() {
+            len += 1;
+        }
+        if self.close_ibc_channel.is_some() {
+            len += 1;
+        }
+        if self.set_ics20_rate_limit.is_some() {
+            len += 1;
+        }
+        if self.register_dao_interchain_account.is_some() {
+            len += 1;
+        }
+        if self.dao_interchain_account_tx.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal", len)?;
         if self.id != 0 {
             struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
@@ -1229,6 +1440,30 @@ impl serde::Serialize for Proposal {
         if let Some(v) = self.upgrade_plan.as_ref() {
             struct_ser.serialize_field("upgradePlan", v)?;
         }
+        if let Some(v) = self.dao_disbursement.as_ref() {
+            struct_ser.serialize_field("daoDisbursement", v)?;
+        }
+        if let Some(v) = self.cancel_dao_disbursement.as_ref() {
+            struct_ser.serialize_field("cancelDaoDisbursement", v)?;
+        }
+        if let Some(v) = self.recover_ibc_client.as_ref() {
+            struct_ser.serialize_field("recoverIbcClient", v)?;
+        }
+        if let Some(v) = self.freeze_ibc_client.as_ref() {
+            struct_ser.serialize_field("freezeIbcClient", v)?;
+        }
+        if let Some(v) = self.close_ibc_channel.as_ref() {
+            struct_ser.serialize_field("closeIbcChannel", v)?;
+        }
+        if let Some(v) = self.set_ics20_rate_limit.as_ref() {
+            struct_ser.serialize_field("setIcs20RateLimit", v)?;
+        }
+        if let Some(v) = self.register_dao_interchain_account.as_ref() {
+            struct_ser.serialize_field("registerDaoInterchainAccount", v)?;
+        }
+        if let Some(v) = self.dao_interchain_account_tx.as_ref() {
+            struct_ser.serialize_field("daoInterchainAccountTx", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1250,6 +1485,22 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "daoSpend",
             "upgrade_plan",
             "upgradePlan",
+            "dao_disbursement",
+            "daoDisbursement",
+            "cancel_dao_disbursement",
+            "cancelDaoDisbursement",
+            "recover_ibc_client",
+            "recoverIbcClient",
+            "freeze_ibc_client",
+            "freezeIbcClient",
+            "close_ibc_channel",
+            "closeIbcChannel",
+            "set_ics20_rate_limit",
+            "setIcs20RateLimit",
+            "register_dao_interchain_account",
+            "registerDaoInterchainAccount",
+            "dao_interchain_account_tx",
+            "daoInterchainAccountTx",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1262,6 +1513,14 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             ParameterChange,
             DaoSpend,
             UpgradePlan,
+            DaoDisbursement,
+            CancelDaoDisbursement,
+            RecoverIbcClient,
+            FreezeIbcClient,
+            CloseIbcChannel,
+            SetIcs20RateLimit,
+            RegisterDaoInterchainAccount,
+            DaoInterchainAccountTx,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1291,6 +1550,14 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "daoDisbursement" | "dao_disbursement" => Ok(GeneratedField::DaoDisbursement),
+                            "cancelDaoDisbursement" | "cancel_dao_disbursement" => Ok(GeneratedField::CancelDaoDisbursement),
+                            "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
+                            "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
+                            "closeIbcChannel" | "close_ibc_channel" => Ok(GeneratedField::CloseIbcChannel),
+                            "setIcs20RateLimit" | "set_ics20_rate_limit" => Ok(GeneratedField::SetIcs20RateLimit),
+                            "registerDaoInterchainAccount" | "register_dao_interchain_account" => Ok(GeneratedField::RegisterDaoInterchainAccount),
+                            "daoInterchainAccountTx" | "dao_interchain_account_tx" => Ok(GeneratedField::DaoInterchainAccountTx),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1318,6 +1585,14 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                 let mut parameter_change__ = None;
                 let mut dao_spend__ = None;
                 let mut upgrade_plan__ = None;
+                let mut dao_disbursement__ = None;
+                let mut cancel_dao_disbursement__ = None;
+                let mut recover_ibc_client__ = None;
+                let mut freeze_ibc_client__ = None;
+                let mut close_ibc_channel__ = None;
+                let mut set_ics20_rate_limit__ = None;
+                let mut register_dao_interchain_account__ = None;
+                let mut dao_interchain_account_tx__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1370,6 +1645,54 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             }
                             upgrade_plan__ = map.next_value()?;
                         }
+                        GeneratedField::DaoDisbursement => {
+                            if dao_disbursement__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoDisbursement"));
+                            }
+                            dao_disbursement__ = map.next_value()?;
+                        }
+                        GeneratedField::CancelDaoDisbursement => {
+                            if cancel_dao_disbursement__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelDaoDisbursement"));
+                            }
+                            cancel_dao_disbursement__ = map.next_value()?;
+                        }
+                        GeneratedField::RecoverIbcClient => {
+                            if recover_ibc_client__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recoverIbcClient"));
+                            }
+                            recover_ibc_client__ = map.next_value()?;
+                        }
+                        GeneratedField::FreezeIbcClient => {
+                            if freeze_ibc_client__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("freezeIbcClient"));
+                            }
+                            freeze_ibc_client__ = map.next_value()?;
+                        }
+                        GeneratedField::CloseIbcChannel => {
+                            if close_ibc_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeIbcChannel"));
+                            }
+                            close_ibc_channel__ = map.next_value()?;
+                        }
+                        GeneratedField::SetIcs20RateLimit => {
+                            if set_ics20_rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("setIcs20RateLimit"));
+                            }
+                            set_ics20_rate_limit__ = map.next_value()?;
+                        }
+                        GeneratedField::RegisterDaoInterchainAccount => {
+                            if register_dao_interchain_account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerDaoInterchainAccount"));
+                            }
+                            register_dao_interchain_account__ = map.next_value()?;
+                        }
+                        GeneratedField::DaoInterchainAccountTx => {
+                            if dao_interchain_account_tx__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoInterchainAccountTx"));
+                            }
+                            dao_interchain_account_tx__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Proposal {
@@ -1381,13 +1704,21 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                     parameter_change: parameter_change__,
                     dao_spend: dao_spend__,
                     upgrade_plan: upgrade_plan__,
+                    dao_disbursement: dao_disbursement__,
+                    cancel_dao_disbursement: cancel_dao_disbursement__,
+                    recover_ibc_client: recover_ibc_client__,
+                    freeze_ibc_client: freeze_ibc_client__,
+                    close_ibc_channel: close_ibc_channel__,
+                    set_ics20_rate_limit: set_ics20_rate_limit__,
+                    register_dao_interchain_account: register_dao_interchain_account__,
+                    dao_interchain_account_tx: dao_interchain_account_tx__,
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::DaoSpend {
+impl serde::Serialize for proposal::CancelDaoDisbursement {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1395,30 +1726,29 @@ impl serde::Serialize for proposal::DaoSpend {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.transaction_plan.is_some() {
+        if self.proposal != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoSpend", len)?;
-        if let Some(v) = self.transaction_plan.as_ref() {
-            struct_ser.serialize_field("transactionPlan", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CancelDaoDisbursement", len)?;
+        if self.proposal != 0 {
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::DaoSpend {
+impl<'de> serde::Deserialize<'de> for proposal::CancelDaoDisbursement {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "transaction_plan",
-            "transactionPlan",
+            "proposal",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TransactionPlan,
+            Proposal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1440,7 +1770,7 @@ impl<'de> serde::Deserialize<'de> for proposal::DaoSpend {
                         E: serde::de::Error,
                     {
                         match value {
-                            "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
+                            "proposal" => Ok(GeneratedField::Proposal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1450,36 +1780,38 @@ impl<'de> serde::Deserialize<'de> for proposal::DaoSpend {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::DaoSpend;
+            type Value = proposal::CancelDaoDisbursement;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.DaoSpend")
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.CancelDaoDisbursement")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::DaoSpend, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::CancelDaoDisbursement, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut transaction_plan__ = None;
+                let mut proposal__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::TransactionPlan => {
-                            if transaction_plan__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transactionPlan"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            transaction_plan__ = map.next_value()?;
+                            proposal__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(proposal::DaoSpend {
-                    transaction_plan: transaction_plan__,
+                Ok(proposal::CancelDaoDisbursement {
+                    proposal: proposal__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoSpend", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CancelDaoDisbursement", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::Emergency {
+impl serde::Serialize for proposal::CloseIbcChannel {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1487,30 +1819,39 @@ impl serde::Serialize for proposal::Emergency {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.halt_chain {
+        if !self.port_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.Emergency", len)?;
-        if self.halt_chain {
-            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CloseIbcChannel", len)?;
+        if !self.port_id.is_empty() {
+            struct_ser.serialize_field("portId", &self.port_id)?;
+        }
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+impl<'de> serde::Deserialize<'de> for proposal::CloseIbcChannel {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "halt_chain",
-            "haltChain",
+            "port_id",
+            "portId",
+            "channel_id",
+            "channelId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            HaltChain,
+            PortId,
+            ChannelId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1532,7 +1873,8 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                         E: serde::de::Error,
                     {
                         match value {
-                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            "portId" | "port_id" => Ok(GeneratedField::PortId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1542,36 +1884,44 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::Emergency;
+            type Value = proposal::CloseIbcChannel;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.Emergency")
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.CloseIbcChannel")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::Emergency, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::CloseIbcChannel, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut halt_chain__ = None;
+                let mut port_id__ = None;
+                let mut channel_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::HaltChain => {
-                            if halt_chain__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("haltChain"));
+                        GeneratedField::PortId => {
+                            if port_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("portId"));
                             }
-                            halt_chain__ = Some(map.next_value()?);
+                            port_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(proposal::Emergency {
-                    halt_chain: halt_chain__.unwrap_or_default(),
+                Ok(proposal::CloseIbcChannel {
+                    port_id: port_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.Emergency", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CloseIbcChannel", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::ParameterChange {
+impl serde::Serialize for proposal::DaoDisbursement {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1579,22 +1929,577 @@ impl serde::Serialize for proposal::ParameterChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.old_parameters.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
-        if self.new_parameters.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange", len)?;
-        if let Some(v) = self.old_parameters.as_ref() {
-            struct_ser.serialize_field("oldParameters", v)?;
+        if self.epoch_interval != 0 {
+            len += 1;
         }
-        if let Some(v) = self.new_parameters.as_ref() {
-            struct_ser.serialize_field("newParameters", v)?;
+        if self.payouts != 0 {
+            len += 1;
         }
-        struct_ser.end()
-    }
-}
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoDisbursement", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        if self.epoch_interval != 0 {
+            struct_ser.serialize_field("epochInterval", ToString::to_string(&self.epoch_interval).as_str())?;
+        }
+        if self.payouts != 0 {
+            struct_ser.serialize_field("payouts", ToString::to_string(&self.payouts).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::DaoDisbursement {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "address",
+            "epoch_interval",
+            "epochInterval",
+            "payouts",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Address,
+            EpochInterval,
+            Payouts,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
+                            "epochInterval" | "epoch_interval" => Ok(GeneratedField::EpochInterval),
+                            "payouts" => Ok(GeneratedField::Payouts),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::DaoDisbursement;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.DaoDisbursement")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::DaoDisbursement, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut address__ = None;
+                let mut epoch_interval__ = None;
+                let mut payouts__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map.next_value()?;
+                        }
+                        GeneratedField::EpochInterval => {
+                            if epoch_interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochInterval"));
+                            }
+                            epoch_interval__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Payouts => {
+                            if payouts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payouts"));
+                            }
+                            payouts__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(proposal::DaoDisbursement {
+                    value: value__,
+                    address: address__,
+                    epoch_interval: epoch_interval__.unwrap_or_default(),
+                    payouts: payouts__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoDisbursement", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::DaoInterchainAccountTx {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        if !self.messages.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoInterchainAccountTx", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        if !self.messages.is_empty() {
+            struct_ser.serialize_field("messages", &self.messages)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::DaoInterchainAccountTx {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+            "messages",
+            "memo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
+            Messages,
+            Memo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
+                            "messages" => Ok(GeneratedField::Messages),
+                            "memo" => Ok(GeneratedField::Memo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::DaoInterchainAccountTx;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.DaoInterchainAccountTx")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::DaoInterchainAccountTx, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_id__ = None;
+                let mut messages__ = None;
+                let mut memo__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
+                            }
+                            connection_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Messages => {
+                            if messages__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("messages"));
+                            }
+                            messages__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::DaoInterchainAccountTx {
+                    connection_id: connection_id__.unwrap_or_default(),
+                    messages: messages__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoInterchainAccountTx", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::DaoSpend {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.transaction_plan.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoSpend", len)?;
+        if let Some(v) = self.transaction_plan.as_ref() {
+            struct_ser.serialize_field("transactionPlan", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::DaoSpend {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transaction_plan",
+            "transactionPlan",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransactionPlan,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::DaoSpend;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.DaoSpend")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::DaoSpend, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transaction_plan__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::TransactionPlan => {
+                            if transaction_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionPlan"));
+                            }
+                            transaction_plan__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(proposal::DaoSpend {
+                    transaction_plan: transaction_plan__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.DaoSpend", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::Emergency {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.halt_chain {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.Emergency", len)?;
+        if self.halt_chain {
+            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "halt_chain",
+            "haltChain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            HaltChain,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::Emergency;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.Emergency")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::Emergency, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut halt_chain__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::HaltChain => {
+                            if halt_chain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("haltChain"));
+                            }
+                            halt_chain__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::Emergency {
+                    halt_chain: halt_chain__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.Emergency", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::FreezeIbcClient {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.FreezeIbcClient", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::FreezeIbcClient {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "client_id",
+            "clientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClientId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::FreezeIbcClient;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.FreezeIbcClient")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::FreezeIbcClient, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut client_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
+                            }
+                            client_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::FreezeIbcClient {
+                    client_id: client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.FreezeIbcClient", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::ParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.old_parameters.is_some() {
+            len += 1;
+        }
+        if self.new_parameters.is_some() {
+            len += 1;
+        }
+        if !self.changes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange", len)?;
+        if let Some(v) = self.old_parameters.as_ref() {
+            struct_ser.serialize_field("oldParameters", v)?;
+        }
+        if let Some(v) = self.new_parameters.as_ref() {
+            struct_ser.serialize_field("newParameters", v)?;
+        }
+        if !self.changes.is_empty() {
+            struct_ser.serialize_field("changes", &self.changes)?;
+        }
+        struct_ser.end()
+    }
+}
 impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
@@ -1602,16 +2507,245 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "old_parameters",
-            "oldParameters",
-            "new_parameters",
-            "newParameters",
+            "old_parameters",
+            "oldParameters",
+            "new_parameters",
+            "newParameters",
+            "changes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OldParameters,
+            NewParameters,
+            Changes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
+                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            "changes" => Ok(GeneratedField::Changes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::ParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::ParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut old_parameters__ = None;
+                let mut new_parameters__ = None;
+                let mut changes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::OldParameters => {
+                            if old_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldParameters"));
+                            }
+                            old_parameters__ = map.next_value()?;
+                        }
+                        GeneratedField::NewParameters => {
+                            if new_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                            }
+                            new_parameters__ = map.next_value()?;
+                        }
+                        GeneratedField::Changes => {
+                            if changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changes"));
+                            }
+                            changes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::ParameterChange {
+                    old_parameters: old_parameters__,
+                    new_parameters: new_parameters__,
+                    changes: changes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::parameter_change::Change {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.parameter.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange.Change", len)?;
+        if !self.parameter.is_empty() {
+            struct_ser.serialize_field("parameter", &self.parameter)?;
+        }
+        if !self.value.is_empty() {
+            struct_ser.serialize_field("value", &self.value)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::parameter_change::Change {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parameter",
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parameter,
+            Value,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parameter" => Ok(GeneratedField::Parameter),
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::parameter_change::Change;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange.Change")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::parameter_change::Change, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parameter__ = None;
+                let mut value__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Parameter => {
+                            if parameter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameter"));
+                            }
+                            parameter__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::parameter_change::Change {
+                    parameter: parameter__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange.Change", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::RecoverIbcClient {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.subject_client_id.is_empty() {
+            len += 1;
+        }
+        if !self.substitute_client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.RecoverIbcClient", len)?;
+        if !self.subject_client_id.is_empty() {
+            struct_ser.serialize_field("subjectClientId", &self.subject_client_id)?;
+        }
+        if !self.substitute_client_id.is_empty() {
+            struct_ser.serialize_field("substituteClientId", &self.substitute_client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::RecoverIbcClient {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "subject_client_id",
+            "subjectClientId",
+            "substitute_client_id",
+            "substituteClientId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            OldParameters,
-            NewParameters,
+            SubjectClientId,
+            SubstituteClientId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1633,8 +2767,8 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
-                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            "subjectClientId" | "subject_client_id" => Ok(GeneratedField::SubjectClientId),
+                            "substituteClientId" | "substitute_client_id" => Ok(GeneratedField::SubstituteClientId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1644,41 +2778,260 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::ParameterChange;
+            type Value = proposal::RecoverIbcClient;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange")
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.RecoverIbcClient")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::ParameterChange, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::RecoverIbcClient, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut old_parameters__ = None;
-                let mut new_parameters__ = None;
+                let mut subject_client_id__ = None;
+                let mut substitute_client_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::OldParameters => {
-                            if old_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("oldParameters"));
+                        GeneratedField::SubjectClientId => {
+                            if subject_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subjectClientId"));
                             }
-                            old_parameters__ = map.next_value()?;
+                            subject_client_id__ = Some(map.next_value()?);
                         }
-                        GeneratedField::NewParameters => {
-                            if new_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                        GeneratedField::SubstituteClientId => {
+                            if substitute_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("substituteClientId"));
                             }
-                            new_parameters__ = map.next_value()?;
+                            substitute_client_id__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(proposal::ParameterChange {
-                    old_parameters: old_parameters__,
-                    new_parameters: new_parameters__,
+                Ok(proposal::RecoverIbcClient {
+                    subject_client_id: subject_client_id__.unwrap_or_default(),
+                    substitute_client_id: substitute_client_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.RecoverIbcClient", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::RegisterDaoInterchainAccount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.RegisterDaoInterchainAccount", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::RegisterDaoInterchainAccount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::RegisterDaoInterchainAccount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.RegisterDaoInterchainAccount")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::RegisterDaoInterchainAccount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
+                            }
+                            connection_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::RegisterDaoInterchainAccount {
+                    connection_id: connection_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.RegisterDaoInterchainAccount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::SetIcs20RateLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        if self.rate_limit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.SetIcs20RateLimit", len)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if let Some(v) = self.rate_limit.as_ref() {
+            struct_ser.serialize_field("rateLimit", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::SetIcs20RateLimit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel_id",
+            "channelId",
+            "denom",
+            "rate_limit",
+            "rateLimit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChannelId,
+            Denom,
+            RateLimit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "rateLimit" | "rate_limit" => Ok(GeneratedField::RateLimit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::SetIcs20RateLimit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.SetIcs20RateLimit")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::SetIcs20RateLimit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel_id__ = None;
+                let mut denom__ = None;
+                let mut rate_limit__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::RateLimit => {
+                            if rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimit"));
+                            }
+                            rate_limit__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(proposal::SetIcs20RateLimit {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    denom: denom__.unwrap_or_default(),
+                    rate_limit: rate_limit__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.SetIcs20RateLimit", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for proposal::Signaling {
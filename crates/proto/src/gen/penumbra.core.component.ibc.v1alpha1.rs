@@ -22,6 +22,9 @@ pub struct FungibleTokenPacketData {
     /// the recipient address on the destination chain
     #[prost(string, tag = "4")]
     pub receiver: ::prost::alloc::string::String,
+    /// an optional memo, which may contain instructions for the receiving chain
+    #[prost(string, tag = "5")]
+    pub memo: ::prost::alloc::string::String,
 }
 /// A Penumbra transaction action requesting an ICS20 transfer.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -96,3 +99,40 @@ pub struct ClientConnections {
     #[prost(string, repeated, tag = "1")]
     pub connections: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+/// A limit on the value of an asset which can be transferred over an ICS-20 channel in any window
+/// of consecutive blocks, in each direction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// The length of the window, in blocks.
+    #[prost(uint64, tag = "1")]
+    pub window_blocks: u64,
+    /// The largest amount which can be received over the channel in a window.
+    #[prost(message, optional, tag = "2")]
+    pub max_inflow: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+    /// The largest amount which can be sent over the channel in a window.
+    #[prost(message, optional, tag = "3")]
+    pub max_outflow: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
+/// The value of an asset transferred over an ICS-20 channel in the current rate limit window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20Flow {
+    /// The value transferred in each block of the window in which any was, oldest first.
+    #[prost(message, repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<Ics20BlockFlow>,
+}
+/// The value of an asset transferred over an ICS-20 channel in a single block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20BlockFlow {
+    /// The height of the block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The amount received over the channel in the block.
+    #[prost(message, optional, tag = "2")]
+    pub inflow: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+    /// The amount sent over the channel in the block.
+    #[prost(message, optional, tag = "3")]
+    pub outflow: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
@@ -535,6 +535,9 @@ impl serde::Serialize for FungibleTokenPacketData {
         if !self.receiver.is_empty() {
             len += 1;
         }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.FungibleTokenPacketData", len)?;
         if !self.denom.is_empty() {
             struct_ser.serialize_field("denom", &self.denom)?;
@@ -548,6 +551,9 @@ impl serde::Serialize for FungibleTokenPacketData {
         if !self.receiver.is_empty() {
             struct_ser.serialize_field("receiver", &self.receiver)?;
         }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
         struct_ser.end()
     }
 }
@@ -562,6 +568,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
             "amount",
             "sender",
             "receiver",
+            "memo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -570,6 +577,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
             Amount,
             Sender,
             Receiver,
+            Memo,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -595,6 +603,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                             "amount" => Ok(GeneratedField::Amount),
                             "sender" => Ok(GeneratedField::Sender),
                             "receiver" => Ok(GeneratedField::Receiver),
+                            "memo" => Ok(GeneratedField::Memo),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -618,6 +627,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                 let mut amount__ = None;
                 let mut sender__ = None;
                 let mut receiver__ = None;
+                let mut memo__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Denom => {
@@ -644,6 +654,12 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                             }
                             receiver__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(FungibleTokenPacketData {
@@ -651,6 +667,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                     amount: amount__.unwrap_or_default(),
                     sender: sender__.unwrap_or_default(),
                     receiver: receiver__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
                 })
             }
         }
@@ -749,6 +766,354 @@ impl<'de> serde::Deserialize<'de> for IbcAction {
         deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.IbcAction", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Ics20BlockFlow {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.inflow.is_some() {
+            len += 1;
+        }
+        if self.outflow.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20BlockFlow", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.inflow.as_ref() {
+            struct_ser.serialize_field("inflow", v)?;
+        }
+        if let Some(v) = self.outflow.as_ref() {
+            struct_ser.serialize_field("outflow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20BlockFlow {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "inflow",
+            "outflow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Inflow,
+            Outflow,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "inflow" => Ok(GeneratedField::Inflow),
+                            "outflow" => Ok(GeneratedField::Outflow),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20BlockFlow;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20BlockFlow")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20BlockFlow, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut inflow__ = None;
+                let mut outflow__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Inflow => {
+                            if inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow"));
+                            }
+                            inflow__ = map.next_value()?;
+                        }
+                        GeneratedField::Outflow => {
+                            if outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow"));
+                            }
+                            outflow__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20BlockFlow {
+                    height: height__.unwrap_or_default(),
+                    inflow: inflow__,
+                    outflow: outflow__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20BlockFlow", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20Flow {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.blocks.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20Flow", len)?;
+        if !self.blocks.is_empty() {
+            struct_ser.serialize_field("blocks", &self.blocks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20Flow {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "blocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Blocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blocks" => Ok(GeneratedField::Blocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20Flow;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20Flow")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20Flow, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut blocks__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Blocks => {
+                            if blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocks"));
+                            }
+                            blocks__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(Ics20Flow {
+                    blocks: blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20Flow", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.window_blocks != 0 {
+            len += 1;
+        }
+        if self.max_inflow.is_some() {
+            len += 1;
+        }
+        if self.max_outflow.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimit", len)?;
+        if self.window_blocks != 0 {
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
+        }
+        if let Some(v) = self.max_inflow.as_ref() {
+            struct_ser.serialize_field("maxInflow", v)?;
+        }
+        if let Some(v) = self.max_outflow.as_ref() {
+            struct_ser.serialize_field("maxOutflow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "window_blocks",
+            "windowBlocks",
+            "max_inflow",
+            "maxInflow",
+            "max_outflow",
+            "maxOutflow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WindowBlocks,
+            MaxInflow,
+            MaxOutflow,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            "maxInflow" | "max_inflow" => Ok(GeneratedField::MaxInflow),
+                            "maxOutflow" | "max_outflow" => Ok(GeneratedField::MaxOutflow),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20RateLimit")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20RateLimit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut window_blocks__ = None;
+                let mut max_inflow__ = None;
+                let mut max_outflow__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
+                            }
+                            window_blocks__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxInflow => {
+                            if max_inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxInflow"));
+                            }
+                            max_inflow__ = map.next_value()?;
+                        }
+                        GeneratedField::MaxOutflow => {
+                            if max_outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOutflow"));
+                            }
+                            max_outflow__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimit {
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                    max_inflow: max_inflow__,
+                    max_outflow: max_outflow__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimit", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Ics20Withdrawal {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -179,6 +179,22 @@ pub mod ceremony_coordinator_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         /// The protocol used to participate in the ceremony.
         ///
         /// The message flow is
@@ -195,7 +211,7 @@ pub mod ceremony_coordinator_service_client {
             request: impl tonic::IntoStreamingRequest<
                 Message = super::ParticipateRequest,
             >,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::ParticipateResponse>>,
             tonic::Status,
         > {
@@ -212,7 +228,15 @@ pub mod ceremony_coordinator_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.tools.summoning.v1alpha1.CeremonyCoordinatorService/Participate",
             );
-            self.inner.streaming(request.into_streaming_request(), path, codec).await
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.tools.summoning.v1alpha1.CeremonyCoordinatorService",
+                        "Participate",
+                    ),
+                );
+            self.inner.streaming(req, path, codec).await
         }
     }
 }
@@ -226,7 +250,7 @@ pub mod ceremony_coordinator_service_server {
     pub trait CeremonyCoordinatorService: Send + Sync + 'static {
         /// Server streaming response type for the Participate method.
         type ParticipateStream: futures_core::Stream<
-                Item = Result<super::ParticipateResponse, tonic::Status>,
+                Item = std::result::Result<super::ParticipateResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -244,7 +268,7 @@ pub mod ceremony_coordinator_service_server {
         async fn participate(
             &self,
             request: tonic::Request<tonic::Streaming<super::ParticipateRequest>>,
-        ) -> Result<tonic::Response<Self::ParticipateStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::ParticipateStream>, tonic::Status>;
     }
     /// Runs a Phase 2 MPC ceremony with dynamic slot allocation.
     #[derive(Debug)]
@@ -252,6 +276,8 @@ pub mod ceremony_coordinator_service_server {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
     impl<T: CeremonyCoordinatorService> CeremonyCoordinatorServiceServer<T> {
@@ -264,6 +290,8 @@ pub mod ceremony_coordinator_service_server {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
             }
         }
         pub fn with_interceptor<F>(
@@ -287,6 +315,22 @@ pub mod ceremony_coordinator_service_server {
             self.send_compression_encodings.enable(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>>
     for CeremonyCoordinatorServiceServer<T>
@@ -301,7 +345,7 @@ pub mod ceremony_coordinator_service_server {
         fn poll_ready(
             &mut self,
             _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
+        ) -> Poll<std::result::Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
@@ -333,6 +377,8 @@ pub mod ceremony_coordinator_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -342,6 +388,10 @@ pub mod ceremony_coordinator_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.streaming(method, req).await;
                         Ok(res)
@@ -370,6 +420,8 @@ pub mod ceremony_coordinator_service_server {
                 inner,
                 accept_compression_encodings: self.accept_compression_encodings,
                 send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
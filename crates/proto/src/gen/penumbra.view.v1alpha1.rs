@@ -135,6 +135,11 @@ pub mod transaction_planner_request {
         pub claim_address: ::core::option::Option<
             super::super::super::core::keys::v1alpha1::Address,
         >,
+        /// If set, the minimum amount of the target asset the swap must output, or else be refunded.
+        #[prost(message, optional, tag = "5")]
+        pub min_output: ::core::option::Option<
+            super::super::super::core::num::v1alpha1::Amount,
+        >,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
@@ -824,11 +829,27 @@ pub mod view_protocol_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         /// Get current status of chain sync
         pub async fn status(
             &mut self,
             request: impl tonic::IntoRequest<super::StatusRequest>,
-        ) -> Result<tonic::Response<super::StatusResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::StatusResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -842,14 +863,19 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/Status",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "Status"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Stream sync status updates until the view service has caught up with the chain.
         /// Returns a stream of `StatusStreamResponse`s.
         pub async fn status_stream(
             &mut self,
             request: impl tonic::IntoRequest<super::StatusStreamRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::StatusStreamResponse>>,
             tonic::Status,
         > {
@@ -866,14 +892,19 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/StatusStream",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "StatusStream"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Queries for notes that have been accepted by the chain.
         /// Returns a stream of `NotesResponse`s.
         pub async fn notes(
             &mut self,
             request: impl tonic::IntoRequest<super::NotesRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::NotesResponse>>,
             tonic::Status,
         > {
@@ -890,13 +921,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/Notes",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "Notes"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Returns a stream of `NotesForVotingResponse`s.
         pub async fn notes_for_voting(
             &mut self,
             request: impl tonic::IntoRequest<super::NotesForVotingRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::NotesForVotingResponse>>,
             tonic::Status,
         > {
@@ -913,7 +949,12 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/NotesForVoting",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "NotesForVoting"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Returns authentication paths for the given note commitments.
         ///
@@ -924,7 +965,7 @@ pub mod view_protocol_service_client {
         pub async fn witness(
             &mut self,
             request: impl tonic::IntoRequest<super::WitnessRequest>,
-        ) -> Result<tonic::Response<super::WitnessResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::WitnessResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -938,12 +979,17 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/Witness",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "Witness"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         pub async fn witness_and_build(
             &mut self,
             request: impl tonic::IntoRequest<super::WitnessAndBuildRequest>,
-        ) -> Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -957,14 +1003,22 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/WitnessAndBuild",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "WitnessAndBuild",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Queries for assets.
         /// Returns a stream of `AssetsResponse`s.
         pub async fn assets(
             &mut self,
             request: impl tonic::IntoRequest<super::AssetsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::AssetsResponse>>,
             tonic::Status,
         > {
@@ -981,13 +1035,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/Assets",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "Assets"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Query for the current chain parameters.
         pub async fn chain_parameters(
             &mut self,
             request: impl tonic::IntoRequest<super::ChainParametersRequest>,
-        ) -> Result<tonic::Response<super::ChainParametersResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ChainParametersResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1001,13 +1060,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/ChainParameters",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "ChainParameters",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for the current FMD parameters.
         pub async fn fmd_parameters(
             &mut self,
             request: impl tonic::IntoRequest<super::FmdParametersRequest>,
-        ) -> Result<tonic::Response<super::FmdParametersResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::FmdParametersResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1021,13 +1088,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/FMDParameters",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "FMDParameters"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for an address given an address index
         pub async fn address_by_index(
             &mut self,
             request: impl tonic::IntoRequest<super::AddressByIndexRequest>,
-        ) -> Result<tonic::Response<super::AddressByIndexResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::AddressByIndexResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1041,13 +1113,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/AddressByIndex",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "AddressByIndex"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for an address given an address index
         pub async fn index_by_address(
             &mut self,
             request: impl tonic::IntoRequest<super::IndexByAddressRequest>,
-        ) -> Result<tonic::Response<super::IndexByAddressResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::IndexByAddressResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1061,13 +1138,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/IndexByAddress",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "IndexByAddress"),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for an ephemeral address
         pub async fn ephemeral_address(
             &mut self,
             request: impl tonic::IntoRequest<super::EphemeralAddressRequest>,
-        ) -> Result<tonic::Response<super::EphemeralAddressResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::EphemeralAddressResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1081,14 +1163,22 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/EphemeralAddress",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "EphemeralAddress",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for balance of a given address.
         /// Returns a stream of `BalancesResponses`.
         pub async fn balances(
             &mut self,
             request: impl tonic::IntoRequest<super::BalancesRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::BalancesResponse>>,
             tonic::Status,
         > {
@@ -1105,13 +1195,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/Balances",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "Balances"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Query for a note by its note commitment, optionally waiting until the note is detected.
         pub async fn note_by_commitment(
             &mut self,
             request: impl tonic::IntoRequest<super::NoteByCommitmentRequest>,
-        ) -> Result<tonic::Response<super::NoteByCommitmentResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::NoteByCommitmentResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1125,13 +1220,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/NoteByCommitment",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "NoteByCommitment",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for a swap by its swap commitment, optionally waiting until the swap is detected.
         pub async fn swap_by_commitment(
             &mut self,
             request: impl tonic::IntoRequest<super::SwapByCommitmentRequest>,
-        ) -> Result<tonic::Response<super::SwapByCommitmentResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::SwapByCommitmentResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1145,13 +1248,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/SwapByCommitment",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "SwapByCommitment",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for all unclaimed swaps.
         pub async fn unclaimed_swaps(
             &mut self,
             request: impl tonic::IntoRequest<super::UnclaimedSwapsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::UnclaimedSwapsResponse>>,
             tonic::Status,
         > {
@@ -1168,13 +1279,18 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/UnclaimedSwaps",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewProtocolService", "UnclaimedSwaps"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Query for whether a nullifier has been spent, optionally waiting until it is spent.
         pub async fn nullifier_status(
             &mut self,
             request: impl tonic::IntoRequest<super::NullifierStatusRequest>,
-        ) -> Result<tonic::Response<super::NullifierStatusResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::NullifierStatusResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1188,13 +1304,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/NullifierStatus",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "NullifierStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for a given transaction by its hash.
         pub async fn transaction_info_by_hash(
             &mut self,
             request: impl tonic::IntoRequest<super::TransactionInfoByHashRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::TransactionInfoByHashResponse>,
             tonic::Status,
         > {
@@ -1211,14 +1335,22 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/TransactionInfoByHash",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "TransactionInfoByHash",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for the full transactions in the given range of blocks.
         /// Returns a stream of `TransactionInfoResponse`s.
         pub async fn transaction_info(
             &mut self,
             request: impl tonic::IntoRequest<super::TransactionInfoRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::TransactionInfoResponse>>,
             tonic::Status,
         > {
@@ -1235,13 +1367,24 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/TransactionInfo",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "TransactionInfo",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Query for a transaction plan
         pub async fn transaction_planner(
             &mut self,
             request: impl tonic::IntoRequest<super::TransactionPlannerRequest>,
-        ) -> Result<tonic::Response<super::TransactionPlannerResponse>, tonic::Status> {
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionPlannerResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1255,13 +1398,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/TransactionPlanner",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "TransactionPlanner",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Broadcast a transaction to the network, optionally waiting for full confirmation.
         pub async fn broadcast_transaction(
             &mut self,
             request: impl tonic::IntoRequest<super::BroadcastTransactionRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::BroadcastTransactionResponse>,
             tonic::Status,
         > {
@@ -1278,13 +1429,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/BroadcastTransaction",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "BroadcastTransaction",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
         /// Query for owned position IDs for the given trading pair and in the given position state.
         pub async fn owned_position_ids(
             &mut self,
             request: impl tonic::IntoRequest<super::OwnedPositionIdsRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<tonic::codec::Streaming<super::OwnedPositionIdsResponse>>,
             tonic::Status,
         > {
@@ -1301,13 +1460,21 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/OwnedPositionIds",
             );
-            self.inner.server_streaming(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "OwnedPositionIds",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
         /// Authorize a transaction plan and build the transaction.
         pub async fn authorize_and_build(
             &mut self,
             request: impl tonic::IntoRequest<super::AuthorizeAndBuildRequest>,
-        ) -> Result<tonic::Response<super::AuthorizeAndBuildResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::AuthorizeAndBuildResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1321,7 +1488,15 @@ pub mod view_protocol_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewProtocolService/AuthorizeAndBuild",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "AuthorizeAndBuild",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
     }
 }
@@ -1395,10 +1570,26 @@ pub mod view_auth_service_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
         pub async fn view_auth(
             &mut self,
             request: impl tonic::IntoRequest<super::ViewAuthRequest>,
-        ) -> Result<tonic::Response<super::ViewAuthResponse>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::ViewAuthResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1412,7 +1603,12 @@ pub mod view_auth_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/penumbra.view.v1alpha1.ViewAuthService/ViewAuth",
             );
-            self.inner.unary(request.into_request(), path, codec).await
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1alpha1.ViewAuthService", "ViewAuth"),
+                );
+            self.inner.unary(req, path, codec).await
         }
     }
 }
@@ -1428,10 +1624,10 @@ pub mod view_protocol_service_server {
         async fn status(
             &self,
             request: tonic::Request<super::StatusRequest>,
-        ) -> Result<tonic::Response<super::StatusResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::StatusResponse>, tonic::Status>;
         /// Server streaming response type for the StatusStream method.
         type StatusStreamStream: futures_core::Stream<
-                Item = Result<super::StatusStreamResponse, tonic::Status>,
+                Item = std::result::Result<super::StatusStreamResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1440,10 +1636,10 @@ pub mod view_protocol_service_server {
         async fn status_stream(
             &self,
             request: tonic::Request<super::StatusStreamRequest>,
-        ) -> Result<tonic::Response<Self::StatusStreamStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::StatusStreamStream>, tonic::Status>;
         /// Server streaming response type for the Notes method.
         type NotesStream: futures_core::Stream<
-                Item = Result<super::NotesResponse, tonic::Status>,
+                Item = std::result::Result<super::NotesResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1452,10 +1648,10 @@ pub mod view_protocol_service_server {
         async fn notes(
             &self,
             request: tonic::Request<super::NotesRequest>,
-        ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::NotesStream>, tonic::Status>;
         /// Server streaming response type for the NotesForVoting method.
         type NotesForVotingStream: futures_core::Stream<
-                Item = Result<super::NotesForVotingResponse, tonic::Status>,
+                Item = std::result::Result<super::NotesForVotingResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1463,7 +1659,7 @@ pub mod view_protocol_service_server {
         async fn notes_for_voting(
             &self,
             request: tonic::Request<super::NotesForVotingRequest>,
-        ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status>;
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -1473,14 +1669,14 @@ pub mod view_protocol_service_server {
         async fn witness(
             &self,
             request: tonic::Request<super::WitnessRequest>,
-        ) -> Result<tonic::Response<super::WitnessResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::WitnessResponse>, tonic::Status>;
         async fn witness_and_build(
             &self,
             request: tonic::Request<super::WitnessAndBuildRequest>,
-        ) -> Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::WitnessAndBuildResponse>, tonic::Status>;
         /// Server streaming response type for the Assets method.
         type AssetsStream: futures_core::Stream<
-                Item = Result<super::AssetsResponse, tonic::Status>,
+                Item = std::result::Result<super::AssetsResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1489,35 +1685,35 @@ pub mod view_protocol_service_server {
         async fn assets(
             &self,
             request: tonic::Request<super::AssetsRequest>,
-        ) -> Result<tonic::Response<Self::AssetsStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::AssetsStream>, tonic::Status>;
         /// Query for the current chain parameters.
         async fn chain_parameters(
             &self,
             request: tonic::Request<super::ChainParametersRequest>,
-        ) -> Result<tonic::Response<super::ChainParametersResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ChainParametersResponse>, tonic::Status>;
         /// Query for the current FMD parameters.
         async fn fmd_parameters(
             &self,
             request: tonic::Request<super::FmdParametersRequest>,
-        ) -> Result<tonic::Response<super::FmdParametersResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::FmdParametersResponse>, tonic::Status>;
         /// Query for an address given an address index
         async fn address_by_index(
             &self,
             request: tonic::Request<super::AddressByIndexRequest>,
-        ) -> Result<tonic::Response<super::AddressByIndexResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::AddressByIndexResponse>, tonic::Status>;
         /// Query for an address given an address index
         async fn index_by_address(
             &self,
             request: tonic::Request<super::IndexByAddressRequest>,
-        ) -> Result<tonic::Response<super::IndexByAddressResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::IndexByAddressResponse>, tonic::Status>;
         /// Query for an ephemeral address
         async fn ephemeral_address(
             &self,
             request: tonic::Request<super::EphemeralAddressRequest>,
-        ) -> Result<tonic::Response<super::EphemeralAddressResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::EphemeralAddressResponse>, tonic::Status>;
         /// Server streaming response type for the Balances method.
         type BalancesStream: futures_core::Stream<
-                Item = Result<super::BalancesResponse, tonic::Status>,
+                Item = std::result::Result<super::BalancesResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1526,20 +1722,20 @@ pub mod view_protocol_service_server {
         async fn balances(
             &self,
             request: tonic::Request<super::BalancesRequest>,
-        ) -> Result<tonic::Response<Self::BalancesStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::BalancesStream>, tonic::Status>;
         /// Query for a note by its note commitment, optionally waiting until the note is detected.
         async fn note_by_commitment(
             &self,
             request: tonic::Request<super::NoteByCommitmentRequest>,
-        ) -> Result<tonic::Response<super::NoteByCommitmentResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::NoteByCommitmentResponse>, tonic::Status>;
         /// Query for a swap by its swap commitment, optionally waiting until the swap is detected.
         async fn swap_by_commitment(
             &self,
             request: tonic::Request<super::SwapByCommitmentRequest>,
-        ) -> Result<tonic::Response<super::SwapByCommitmentResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::SwapByCommitmentResponse>, tonic::Status>;
         /// Server streaming response type for the UnclaimedSwaps method.
         type UnclaimedSwapsStream: futures_core::Stream<
-                Item = Result<super::UnclaimedSwapsResponse, tonic::Status>,
+                Item = std::result::Result<super::UnclaimedSwapsResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1547,23 +1743,23 @@ pub mod view_protocol_service_server {
         async fn unclaimed_swaps(
             &self,
             request: tonic::Request<super::UnclaimedSwapsRequest>,
-        ) -> Result<tonic::Response<Self::UnclaimedSwapsStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::UnclaimedSwapsStream>, tonic::Status>;
         /// Query for whether a nullifier has been spent, optionally waiting until it is spent.
         async fn nullifier_status(
             &self,
             request: tonic::Request<super::NullifierStatusRequest>,
-        ) -> Result<tonic::Response<super::NullifierStatusResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::NullifierStatusResponse>, tonic::Status>;
         /// Query for a given transaction by its hash.
         async fn transaction_info_by_hash(
             &self,
             request: tonic::Request<super::TransactionInfoByHashRequest>,
-        ) -> Result<
+        ) -> std::result::Result<
             tonic::Response<super::TransactionInfoByHashResponse>,
             tonic::Status,
         >;
         /// Server streaming response type for the TransactionInfo method.
         type TransactionInfoStream: futures_core::Stream<
-                Item = Result<super::TransactionInfoResponse, tonic::Status>,
+                Item = std::result::Result<super::TransactionInfoResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1572,20 +1768,23 @@ pub mod view_protocol_service_server {
         async fn transaction_info(
             &self,
             request: tonic::Request<super::TransactionInfoRequest>,
-        ) -> Result<tonic::Response<Self::TransactionInfoStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::TransactionInfoStream>, tonic::Status>;
         /// Query for a transaction plan
         async fn transaction_planner(
             &self,
             request: tonic::Request<super::TransactionPlannerRequest>,
-        ) -> Result<tonic::Response<super::TransactionPlannerResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::TransactionPlannerResponse>, tonic::Status>;
         /// Broadcast a transaction to the network, optionally waiting for full confirmation.
         async fn broadcast_transaction(
             &self,
             request: tonic::Request<super::BroadcastTransactionRequest>,
-        ) -> Result<tonic::Response<super::BroadcastTransactionResponse>, tonic::Status>;
+        ) -> std::result::Result<
+            tonic::Response<super::BroadcastTransactionResponse>,
+            tonic::Status,
+        >;
         /// Server streaming response type for the OwnedPositionIds method.
         type OwnedPositionIdsStream: futures_core::Stream<
-                Item = Result<super::OwnedPositionIdsResponse, tonic::Status>,
+                Item = std::result::Result<super::OwnedPositionIdsResponse, tonic::Status>,
             >
             + Send
             + 'static;
@@ -1593,12 +1792,12 @@ pub mod view_protocol_service_server {
         async fn owned_position_ids(
             &self,
             request: tonic::Request<super::OwnedPositionIdsRequest>,
-        ) -> Result<tonic::Response<Self::OwnedPositionIdsStream>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<Self::OwnedPositionIdsStream>, tonic::Status>;
         /// Authorize a transaction plan and build the transaction.
         async fn authorize_and_build(
             &self,
             request: tonic::Request<super::AuthorizeAndBuildRequest>,
-        ) -> Result<tonic::Response<super::AuthorizeAndBuildResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::AuthorizeAndBuildResponse>, tonic::Status>;
     }
     /// The view protocol is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -1612,6 +1811,8 @@ pub mod view_protocol_service_server {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
     impl<T: ViewProtocolService> ViewProtocolServiceServer<T> {
@@ -1624,6 +1825,8 @@ pub mod view_protocol_service_server {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
             }
         }
         pub fn with_interceptor<F>(
@@ -1647,6 +1850,22 @@ pub mod view_protocol_service_server {
             self.send_compression_encodings.enable(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>> for ViewProtocolServiceServer<T>
     where
@@ -1660,7 +1879,7 @@ pub mod view_protocol_service_server {
         fn poll_ready(
             &mut self,
             _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
+        ) -> Poll<std::result::Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
@@ -1689,6 +1908,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1698,6 +1919,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -1730,6 +1955,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1739,6 +1966,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -1769,6 +2000,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1778,6 +2011,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -1810,6 +2047,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1819,6 +2058,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -1848,6 +2091,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1857,6 +2102,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -1888,6 +2137,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1897,6 +2148,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -1927,6 +2182,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1936,6 +2193,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -1967,6 +2228,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -1976,6 +2239,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2007,6 +2274,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2016,6 +2285,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2047,6 +2320,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2056,6 +2331,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2087,6 +2366,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2096,6 +2377,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2127,6 +2412,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2136,6 +2423,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2166,6 +2457,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2175,6 +2468,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2206,6 +2503,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2215,6 +2514,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2246,6 +2549,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2255,6 +2560,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2287,6 +2596,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2296,6 +2607,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2327,6 +2642,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2336,6 +2653,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2367,6 +2688,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2376,6 +2699,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2409,6 +2736,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2418,6 +2747,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2449,6 +2782,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2458,6 +2793,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2489,6 +2828,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2498,6 +2839,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2531,6 +2876,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2540,6 +2887,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.server_streaming(method, req).await;
                         Ok(res)
@@ -2571,6 +2922,8 @@ pub mod view_protocol_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2580,6 +2933,10 @@ pub mod view_protocol_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2608,6 +2965,8 @@ pub mod view_protocol_service_server {
                 inner,
                 accept_compression_encodings: self.accept_compression_encodings,
                 send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
@@ -2637,13 +2996,15 @@ pub mod view_auth_service_server {
         async fn view_auth(
             &self,
             request: tonic::Request<super::ViewAuthRequest>,
-        ) -> Result<tonic::Response<super::ViewAuthResponse>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::ViewAuthResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct ViewAuthServiceServer<T: ViewAuthService> {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
     impl<T: ViewAuthService> ViewAuthServiceServer<T> {
@@ -2656,6 +3017,8 @@ pub mod view_auth_service_server {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
             }
         }
         pub fn with_interceptor<F>(
@@ -2679,6 +3042,22 @@ pub mod view_auth_service_server {
             self.send_compression_encodings.enable(encoding);
             self
         }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
     }
     impl<T, B> tonic::codegen::Service<http::Request<B>> for ViewAuthServiceServer<T>
     where
@@ -2692,7 +3071,7 @@ pub mod view_auth_service_server {
         fn poll_ready(
             &mut self,
             _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
+        ) -> Poll<std::result::Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
@@ -2721,6 +3100,8 @@ pub mod view_auth_service_server {
                     }
                     let accept_compression_encodings = self.accept_compression_encodings;
                     let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
@@ -2730,6 +3111,10 @@ pub mod view_auth_service_server {
                             .apply_compression_config(
                                 accept_compression_encodings,
                                 send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
                             );
                         let res = grpc.unary(method, req).await;
                         Ok(res)
@@ -2758,6 +3143,8 @@ pub mod view_auth_service_server {
                 inner,
                 accept_compression_encodings: self.accept_compression_encodings,
                 send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
             }
         }
     }
@@ -5440,6 +5440,9 @@ impl serde::Serialize for transaction_planner_request::Swap {
         if self.claim_address.is_some() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Swap", len)?;
         if let Some(v) = self.value.as_ref() {
             struct_ser.serialize_field("value", v)?;
@@ -5453,6 +5456,9 @@ impl serde::Serialize for transaction_planner_request::Swap {
         if let Some(v) = self.claim_address.as_ref() {
             struct_ser.serialize_field("claimAddress", v)?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5469,6 +5475,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
             "fee",
             "claim_address",
             "claimAddress",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5477,6 +5485,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
             TargetAsset,
             Fee,
             ClaimAddress,
+            MinOutput,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5502,6 +5511,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                             "targetAsset" | "target_asset" => Ok(GeneratedField::TargetAsset),
                             "fee" => Ok(GeneratedField::Fee),
                             "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5525,6 +5535,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                 let mut target_asset__ = None;
                 let mut fee__ = None;
                 let mut claim_address__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Value => {
@@ -5551,6 +5562,12 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                             }
                             claim_address__ = map.next_value()?;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(transaction_planner_request::Swap {
@@ -5558,6 +5575,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                     target_asset: target_asset__,
                     fee: fee__,
                     claim_address: claim_address__,
+                    min_output: min_output__,
                 })
             }
         }
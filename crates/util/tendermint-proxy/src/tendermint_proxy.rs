@@ -17,6 +17,8 @@ use proto::client::v1alpha1::GetStatusRequest;
 use proto::client::v1alpha1::GetStatusResponse;
 use proto::client::v1alpha1::GetTxRequest;
 use proto::client::v1alpha1::GetTxResponse;
+use proto::client::v1alpha1::GetValidatorSetRequest;
+use proto::client::v1alpha1::GetValidatorSetResponse;
 use proto::DomainType;
 use proto::Message;
 use tendermint::abci::Code;
@@ -85,6 +87,23 @@ impl TendermintProxyService for TendermintProxy {
                         })
                     })
                     .collect(),
+                events: rsp
+                    .tx_result
+                    .events
+                    .iter()
+                    .map(|e| proto::client::v1alpha1::Event {
+                        kind: e.kind.clone(),
+                        attributes: e
+                            .attributes
+                            .iter()
+                            .map(|a| proto::client::v1alpha1::Tag {
+                                key: a.key.to_string().as_bytes().to_vec(),
+                                value: a.value.to_string().as_bytes().to_vec(),
+                                index: a.index,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
             }),
             height: rsp.height.value(),
             index: rsp.index as u64,
@@ -323,7 +342,7 @@ impl TendermintProxyService for TendermintProxy {
                     height: res.block.header.height.into(),
                     time: Some(pbjson_types::Timestamp {
                         seconds: header_time.timestamp(),
-                        nanos: header_time.timestamp_nanos_opt().unwrap() as i32,
+                        nanos: header_time.timestamp_subsec_nanos() as i32,
                     }),
                     last_block_id: res.block.header.last_block_id.map(|id| {
                         penumbra_proto::tendermint::types::BlockId {
@@ -396,7 +415,7 @@ impl TendermintProxyService for TendermintProxy {
                                         }),
                                         timestamp: Some(pbjson_types::Timestamp{
                                             seconds: DateTime::parse_from_rfc3339(&e.votes().0.timestamp.expect("timestamp").to_rfc3339()).expect("timestamp should roundtrip to string").timestamp(),
-                                            nanos: DateTime::parse_from_rfc3339(&e.votes().0.timestamp.expect("timestamp").to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_nanos_opt().unwrap() as i32,
+                                            nanos: DateTime::parse_from_rfc3339(&e.votes().0.timestamp.expect("timestamp").to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_subsec_nanos() as i32,
                                         }),
                                         validator_address: e.votes().0.validator_address.into(),
                                         validator_index: e.votes().0.validator_index.into(),
@@ -418,7 +437,7 @@ impl TendermintProxyService for TendermintProxy {
                                         }),
                                         timestamp: Some(pbjson_types::Timestamp{
                                             seconds: DateTime::parse_from_rfc3339(&e.votes().1.timestamp.expect("timestamp").to_rfc3339()).expect("timestamp should roundtrip to string").timestamp(),
-                                            nanos: DateTime::parse_from_rfc3339(&e.votes().1.timestamp.expect("timestamp").to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_nanos_opt().unwrap() as i32,
+                                            nanos: DateTime::parse_from_rfc3339(&e.votes().1.timestamp.expect("timestamp").to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_subsec_nanos() as i32,
                                         }),
                                         validator_address: e.votes().1.validator_address.into(),
                                         validator_index: e.votes().1.validator_index.into(),
@@ -470,7 +489,7 @@ impl TendermintProxyService for TendermintProxy {
                                         validator_address: validator_address.into(),
                                         timestamp: Some(pbjson_types::Timestamp{
                                             seconds: DateTime::parse_from_rfc3339(&timestamp.to_rfc3339()).expect("timestamp should roundtrip to string").timestamp(),
-                                            nanos: DateTime::parse_from_rfc3339(&timestamp.to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_nanos_opt().unwrap() as i32,
+                                            nanos: DateTime::parse_from_rfc3339(&timestamp.to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_subsec_nanos() as i32,
                                         }),
                                         signature: signature.expect("signature").into(),
                                     },
@@ -479,7 +498,7 @@ impl TendermintProxyService for TendermintProxy {
                                         validator_address: validator_address.into(),
                                         timestamp: Some(pbjson_types::Timestamp{
                                             seconds: DateTime::parse_from_rfc3339(&timestamp.to_rfc3339()).expect("timestamp should roundtrip to string").timestamp(),
-                                            nanos: DateTime::parse_from_rfc3339(&timestamp.to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_nanos_opt().unwrap() as i32,
+                                            nanos: DateTime::parse_from_rfc3339(&timestamp.to_rfc3339()).expect("timestamp should roundtrip to string").timestamp_subsec_nanos() as i32,
                                         }),
                                         signature: signature.expect("signature").into(),
                                     },
@@ -492,6 +511,38 @@ impl TendermintProxyService for TendermintProxy {
             }),
         }))
     }
+
+    async fn get_validator_set(
+        &self,
+        req: tonic::Request<GetValidatorSetRequest>,
+    ) -> Result<tonic::Response<GetValidatorSetResponse>, Status> {
+        // generic bounds on HttpClient::new are not well-constructed, so we have to
+        // render the URL as a String, then borrow it, then re-parse the borrowed &str
+        let client = HttpClient::new(self.tendermint_url.to_string().as_ref()).map_err(|e| {
+            tonic::Status::unavailable(format!("error creating tendermint http client: {e:#?}"))
+        })?;
+
+        let height = tendermint::block::Height::try_from(req.get_ref().height)
+            .map_err(|_| tonic::Status::invalid_argument("height should be less than 2^63"))?;
+        let res = client
+            .validators(height, tendermint_rpc::Paging::All)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error querying validators: {e}")))?;
+
+        // As with the evidence above, round-trip through the tendermint-proto encoding
+        // to get to our copy of the same proto type.
+        let validator_set = tendermint_proto::types::ValidatorSet::from(
+            tendermint::validator::Set::without_proposer(res.validators),
+        );
+        let validator_set = proto::tendermint::types::ValidatorSet::decode(
+            validator_set.encode_to_vec().as_slice(),
+        )
+        .map_err(|e| tonic::Status::internal(format!("error encoding validator set: {e}")))?;
+
+        Ok(tonic::Response::new(GetValidatorSetResponse {
+            validator_set: Some(validator_set),
+        }))
+    }
 }
 
 /// Implements service traits for Tonic gRPC services.
@@ -0,0 +1,338 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use anyhow::Context;
+use ark_std::UniformRand;
+use decaf377::Fq;
+use penumbra_chain::params::ChainParameters;
+use penumbra_dex::swap_claim::SwapClaimPlan;
+use penumbra_proto::view::v1alpha1::{
+    self as pb, view_protocol_service_server::ViewProtocolService,
+};
+use penumbra_tct::StateCommitment;
+use penumbra_transaction::{plan::TransactionPlan, AuthorizationData, Transaction};
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+use crate::{metrics, Storage, SwapRecord, ViewService};
+
+/// Configuration for automatically claiming the outputs of swaps.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoClaimConfig {
+    /// How often to check for unclaimed swaps, in seconds.
+    pub poll_interval_secs: u64,
+    /// How long to wait before retrying a failed claim, in seconds.
+    ///
+    /// The wait doubles after each consecutive failure of the same claim.
+    pub initial_backoff_secs: u64,
+    /// The longest time to wait before retrying a failed claim, in seconds.
+    pub max_backoff_secs: u64,
+    /// How long to wait for a submitted claim to be detected before submitting it again,
+    /// in seconds.
+    pub confirmation_timeout_secs: u64,
+}
+
+impl Default for AutoClaimConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: 10,
+            initial_backoff_secs: 5,
+            max_backoff_secs: 300,
+            confirmation_timeout_secs: 60,
+        }
+    }
+}
+
+impl AutoClaimConfig {
+    /// The time to wait before retrying a claim that failed after `attempts` earlier attempts.
+    fn backoff(&self, attempts: u32) -> Duration {
+        let backoff_secs = self
+            .initial_backoff_secs
+            .saturating_mul(2u64.saturating_pow(attempts));
+        Duration::from_secs(backoff_secs.min(self.max_backoff_secs))
+    }
+}
+
+/// A claim that was attempted, but whose swap is not yet known to be claimed.
+struct PendingClaim {
+    attempts: u32,
+    retry_at: Instant,
+}
+
+/// The claims that were attempted, and when each of them may be attempted again.
+#[derive(Default)]
+struct PendingClaims(BTreeMap<StateCommitment, PendingClaim>);
+
+impl PendingClaims {
+    /// Forgets the claims whose swaps are no longer unclaimed.
+    fn retain_unclaimed(&mut self, unclaimed: &[StateCommitment]) {
+        self.0
+            .retain(|swap_commitment, _| unclaimed.contains(swap_commitment));
+    }
+
+    /// Returns the number of earlier attempts to claim the swap, if it may be claimed at `now`.
+    fn due(&self, swap_commitment: &StateCommitment, now: Instant) -> Option<u32> {
+        match self.0.get(swap_commitment) {
+            Some(pending) if pending.retry_at > now => None,
+            Some(pending) => Some(pending.attempts),
+            None => Some(0),
+        }
+    }
+
+    /// Records a claim submitted at `now`, giving it time to be detected before it is submitted
+    /// again.
+    fn submitted(
+        &mut self,
+        swap_commitment: StateCommitment,
+        attempts: u32,
+        now: Instant,
+        config: &AutoClaimConfig,
+    ) {
+        self.record(
+            swap_commitment,
+            attempts,
+            now + Duration::from_secs(config.confirmation_timeout_secs),
+        );
+    }
+
+    /// Records a claim that failed at `now`, returning how long to back off before retrying it.
+    fn failed(
+        &mut self,
+        swap_commitment: StateCommitment,
+        attempts: u32,
+        now: Instant,
+        config: &AutoClaimConfig,
+    ) -> Duration {
+        let backoff = config.backoff(attempts);
+        self.record(swap_commitment, attempts, now + backoff);
+        backoff
+    }
+
+    fn record(&mut self, swap_commitment: StateCommitment, attempts: u32, retry_at: Instant) {
+        self.0.insert(
+            swap_commitment,
+            PendingClaim {
+                attempts: attempts.saturating_add(1),
+                retry_at,
+            },
+        );
+    }
+}
+
+/// Builds and broadcasts a `SwapClaim` for each unclaimed swap whose output is known.
+///
+/// Claims are paid for by the fee prepaid in the swap, so they spend no notes and need no
+/// authorization from custody.
+pub(crate) struct AutoClaimer {
+    service: ViewService,
+    storage: Storage,
+    config: AutoClaimConfig,
+    pending: PendingClaims,
+}
+
+impl AutoClaimer {
+    pub(crate) fn new(service: ViewService, storage: Storage, config: AutoClaimConfig) -> Self {
+        Self {
+            service,
+            storage,
+            config,
+            pending: PendingClaims::default(),
+        }
+    }
+
+    pub(crate) async fn run(mut self) {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs.max(1)));
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.claim_swaps().await {
+                tracing::warn!(?e, "error claiming swaps");
+            }
+        }
+    }
+
+    async fn claim_swaps(&mut self) -> anyhow::Result<()> {
+        let unclaimed_swaps = self.storage.unclaimed_swaps().await?;
+        metrics::gauge!(metrics::UNCLAIMED_SWAPS, unclaimed_swaps.len() as f64);
+
+        // Forget the claims that have since been detected.
+        self.pending.retain_unclaimed(
+            &unclaimed_swaps
+                .iter()
+                .map(|swap| swap.swap_commitment)
+                .collect::<Vec<_>>(),
+        );
+        if unclaimed_swaps.is_empty() {
+            return Ok(());
+        }
+
+        let chain_params = self.storage.chain_params().await?;
+        for swap in unclaimed_swaps {
+            let now = Instant::now();
+            let Some(attempts) = self.pending.due(&swap.swap_commitment, now) else {
+                continue;
+            };
+
+            let swap_commitment = swap.swap_commitment;
+            match self.claim(swap, &chain_params).await {
+                Ok(id) => {
+                    tracing::info!(%id, ?swap_commitment, "submitted swap claim");
+                    metrics::increment_counter!(metrics::SWAP_CLAIMS_SUBMITTED_TOTAL);
+                    self.pending
+                        .submitted(swap_commitment, attempts, now, &self.config);
+                }
+                Err(e) => {
+                    let backoff = self
+                        .pending
+                        .failed(swap_commitment, attempts, now, &self.config);
+                    tracing::warn!(?e, ?swap_commitment, ?backoff, "failed to claim swap");
+                    metrics::increment_counter!(metrics::SWAP_CLAIMS_FAILED_TOTAL);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn claim(
+        &self,
+        swap: SwapRecord,
+        chain_params: &ChainParameters,
+    ) -> anyhow::Result<penumbra_transaction::Id> {
+        let mut plan = TransactionPlan {
+            chain_id: chain_params.chain_id.clone(),
+            fee: swap.swap.claim_fee.clone(),
+            // The transaction doesn't need a memo, because it's to ourselves.
+            memo_plan: None,
+            ..Default::default()
+        };
+        plan.actions.push(
+            SwapClaimPlan {
+                swap_plaintext: swap.swap,
+                position: swap.position,
+                output_data: swap.output_data,
+                epoch_duration: chain_params.epoch_duration,
+                proof_blinding_r: Fq::rand(&mut OsRng),
+                proof_blinding_s: Fq::rand(&mut OsRng),
+            }
+            .into(),
+        );
+
+        // The claim spends no notes, so its only authorization is the binding signature.
+        let fvk = self.storage.full_viewing_key().await?;
+        let authorization_data = AuthorizationData {
+            effect_hash: plan.effect_hash(&fvk),
+            spend_auths: Vec::new(),
+            delegator_vote_auths: Vec::new(),
+        };
+
+        let transaction: Transaction = self
+            .service
+            .witness_and_build(tonic::Request::new(pb::WitnessAndBuildRequest {
+                transaction_plan: Some(plan.into()),
+                authorization_data: Some(authorization_data.into()),
+            }))
+            .await?
+            .into_inner()
+            .transaction
+            .context("missing transaction in witness and build response")?
+            .try_into()?;
+
+        self.service.broadcast_transaction(transaction, false).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AutoClaimConfig {
+        AutoClaimConfig {
+            poll_interval_secs: 10,
+            initial_backoff_secs: 5,
+            max_backoff_secs: 60,
+            confirmation_timeout_secs: 30,
+        }
+    }
+
+    fn swap_commitment(n: u64) -> StateCommitment {
+        StateCommitment(Fq::from(n))
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let config = config();
+        let backoffs = (0..6)
+            .map(|attempts| config.backoff(attempts).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(backoffs, vec![5, 10, 20, 40, 60, 60]);
+        // The schedule saturates rather than overflowing.
+        assert_eq!(config.backoff(u32::MAX), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn new_claims_are_due_immediately() {
+        let pending = PendingClaims::default();
+        assert_eq!(pending.due(&swap_commitment(1), Instant::now()), Some(0));
+    }
+
+    #[test]
+    fn submitted_claims_wait_for_confirmation() {
+        let config = config();
+        let mut pending = PendingClaims::default();
+        let now = Instant::now();
+        pending.submitted(swap_commitment(1), 0, now, &config);
+
+        assert_eq!(pending.due(&swap_commitment(1), now), None);
+        assert_eq!(
+            pending.due(&swap_commitment(1), now + Duration::from_secs(29)),
+            None
+        );
+        // A claim not detected in time is submitted again, as a second attempt.
+        assert_eq!(
+            pending.due(&swap_commitment(1), now + Duration::from_secs(30)),
+            Some(1)
+        );
+        // Other claims are unaffected.
+        assert_eq!(pending.due(&swap_commitment(2), now), Some(0));
+    }
+
+    #[test]
+    fn failed_claims_are_retried_with_backoff() {
+        let config = config();
+        let mut pending = PendingClaims::default();
+        let mut now = Instant::now();
+
+        let mut attempts = 0;
+        for expected_backoff in [5, 10, 20, 40, 60, 60] {
+            let backoff = pending.failed(swap_commitment(1), attempts, now, &config);
+            assert_eq!(backoff, Duration::from_secs(expected_backoff));
+            assert_eq!(
+                pending.due(&swap_commitment(1), now + backoff - Duration::from_secs(1)),
+                None
+            );
+            now += backoff;
+            attempts = pending.due(&swap_commitment(1), now).unwrap();
+        }
+        assert_eq!(attempts, 6);
+    }
+
+    #[test]
+    fn detected_claims_are_forgotten() {
+        let config = config();
+        let mut pending = PendingClaims::default();
+        let now = Instant::now();
+        pending.failed(swap_commitment(1), 0, now, &config);
+        pending.submitted(swap_commitment(2), 0, now, &config);
+
+        // The second swap's claim was detected, so it is no longer unclaimed.
+        pending.retain_unclaimed(&[swap_commitment(1)]);
+        assert_eq!(pending.due(&swap_commitment(1), now), None);
+        assert_eq!(pending.due(&swap_commitment(2), now), Some(0));
+
+        // A failed claim whose swap is claimed by other means is forgotten too.
+        pending.retain_unclaimed(&[]);
+        assert_eq!(pending.due(&swap_commitment(1), now), Some(0));
+    }
+}
@@ -1,5 +1,6 @@
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "256"]
+mod auto_claim;
 mod client;
 mod metrics;
 mod note_record;
@@ -15,6 +16,7 @@ mod worker;
 use worker::Worker;
 
 pub use crate::metrics::register_metrics;
+pub use auto_claim::AutoClaimConfig;
 pub use client::ViewClient;
 pub use note_record::SpendableNoteRecord;
 pub use planner::Planner;
@@ -22,4 +24,5 @@ pub use service::ViewService;
 pub use status::StatusStreamResponse;
 pub use storage::Storage;
 pub use swap_record::SwapRecord;
+pub use sync::{trial_decrypt_block, trial_decrypt_blocks, TrialDecryptions};
 pub use transaction_info::TransactionInfo;
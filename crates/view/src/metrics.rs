@@ -15,15 +15,23 @@ pub use metrics::*;
 
 /// Registers all metrics used by this crate.
 pub fn register_metrics() {
-    /*
-    // Sample code for reference -- delete when adding the first metric
-    register_counter!(MEMPOOL_CHECKTX_TOTAL);
+    register_gauge!(UNCLAIMED_SWAPS);
+    describe_gauge!(
+        UNCLAIMED_SWAPS,
+        "The number of swaps whose outputs have not been claimed"
+    );
+    register_counter!(SWAP_CLAIMS_SUBMITTED_TOTAL);
+    describe_counter!(
+        SWAP_CLAIMS_SUBMITTED_TOTAL,
+        "The total number of swap claims automatically submitted"
+    );
+    register_counter!(SWAP_CLAIMS_FAILED_TOTAL);
     describe_counter!(
-        MEMPOOL_CHECKTX_TOTAL,
-        "The total number of checktx requests made to the mempool"
+        SWAP_CLAIMS_FAILED_TOTAL,
+        "The total number of automatic swap claims that failed to be built or broadcast"
     );
-     */
 }
 
-// Sample code for reference -- delete when adding the first metric
-// pub const MEMPOOL_CHECKTX_TOTAL: &str = "penumbra_pd_mempool_checktx_total";
+pub const UNCLAIMED_SWAPS: &str = "penumbra_view_unclaimed_swaps";
+pub const SWAP_CLAIMS_SUBMITTED_TOTAL: &str = "penumbra_view_swap_claims_submitted_total";
+pub const SWAP_CLAIMS_FAILED_TOTAL: &str = "penumbra_view_swap_claims_failed_total";
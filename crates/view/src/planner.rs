@@ -206,11 +206,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     }
 
     /// Perform a swap based on input notes in the transaction.
+    ///
+    /// If `min_output` is nonzero, the swap is refunded instead of filled unless it outputs at
+    /// least that much of `into_asset`.
     #[instrument(skip(self))]
     pub fn swap(
         &mut self,
         input_value: Value,
         into_asset: asset::Id,
+        min_output: Amount,
         swap_claim_fee: Fee,
         claim_address: Address,
     ) -> Result<&mut Self> {
@@ -241,7 +245,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             delta_2,
             swap_claim_fee,
             claim_address,
-        );
+        )
+        .with_min_output(min_output);
 
         let swap = SwapPlan::new(&mut self.rng, swap_plaintext).into();
         self.action(swap);
@@ -53,7 +53,10 @@ use tonic::{async_trait, transport::Channel};
 use tracing::instrument;
 use url::Url;
 
-use crate::{Planner, Storage, Worker};
+use crate::{
+    auto_claim::{AutoClaimConfig, AutoClaimer},
+    Planner, Storage, Worker,
+};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -116,6 +119,13 @@ impl ViewService {
         })
     }
 
+    /// Spawns a task that builds and broadcasts a `SwapClaim` for each unclaimed swap once
+    /// its batch swap output data is known.
+    pub fn spawn_auto_claim(&self, config: AutoClaimConfig) {
+        let auto_claimer = AutoClaimer::new(self.clone(), self.storage.clone(), config);
+        tokio::spawn(auto_claimer.run());
+    }
+
     /// Checks that the account group ID, if present, matches the one for this service.
     async fn check_account_group_id(
         &self,
@@ -172,7 +182,7 @@ impl ViewService {
     }
 
     #[instrument(skip(self, transaction), fields(id = %transaction.id()))]
-    async fn broadcast_transaction(
+    pub(crate) async fn broadcast_transaction(
         &self,
         transaction: Transaction,
         await_detection: bool,
@@ -453,8 +463,17 @@ impl ViewProtocolService for ViewService {
                     tonic::Status::invalid_argument(format!("Could not parse claim address: {e:#}"))
                 })?;
 
+            let min_output: Amount = swap
+                .min_output
+                .map(TryInto::try_into)
+                .transpose()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse min output: {e:#}"))
+                })?
+                .unwrap_or_default();
+
             planner
-                .swap(value, target_asset, fee, claim_address)
+                .swap(value, target_asset, min_output, fee, claim_address)
                 .map_err(|e| {
                     tonic::Status::invalid_argument(format!("Could not plan swap: {e:#}"))
                 })?;
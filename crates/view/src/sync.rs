@@ -5,12 +5,11 @@ use penumbra_chain::{
     NoteSource,
 };
 use penumbra_compact_block::{CompactBlock, StatePayload};
-use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
+use penumbra_dex::swap::SwapPlaintext;
 use penumbra_keys::FullViewingKey;
 use penumbra_sct::Nullifier;
-use penumbra_shielded_pool::{Note, NotePayload};
+use penumbra_shielded_pool::Note;
 use penumbra_tct as tct;
-use tracing::Instrument;
 
 use crate::{SpendableNoteRecord, Storage, SwapRecord};
 
@@ -38,6 +37,109 @@ impl FilteredBlock {
     }
 }
 
+/// The notes and swaps in a single block which trial-decrypted successfully
+/// under a particular [`FullViewingKey`].
+#[derive(Debug, Clone, Default)]
+pub struct TrialDecryptions {
+    pub notes: BTreeMap<tct::StateCommitment, Note>,
+    pub swaps: BTreeMap<tct::StateCommitment, SwapPlaintext>,
+}
+
+impl TrialDecryptions {
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty() && self.swaps.is_empty()
+    }
+}
+
+/// Trial-decrypt every note and swap payload in a single block.
+///
+/// This is CPU-bound and should not be called directly from an async context;
+/// see [`trial_decrypt_blocks`] for the batched, parallel version.
+pub fn trial_decrypt_block(fvk: &FullViewingKey, block: &CompactBlock) -> TrialDecryptions {
+    let mut decryptions = TrialDecryptions::default();
+    for payload in block.state_payloads.iter() {
+        match payload {
+            StatePayload::Note { note, .. } => {
+                if let Some(note) = note.trial_decrypt(fvk) {
+                    decryptions.notes.insert(note.commit(), note);
+                }
+            }
+            StatePayload::Swap { swap, .. } => {
+                if let Some(swap) = swap.trial_decrypt(fvk) {
+                    decryptions.swaps.insert(swap.swap_commitment(), swap);
+                }
+            }
+            StatePayload::RolledUp(_) => {}
+        }
+    }
+    decryptions
+}
+
+/// Trial-decrypt the payloads of many blocks at once, in parallel.
+///
+/// The payloads of all the blocks are flattened into a single work list, so
+/// that a few busy blocks among many empty ones still spread evenly across the
+/// worker pool. The returned decryptions are in the same order as `blocks`.
+///
+/// This blocks the calling thread until the whole batch is done, so it should
+/// be run inside [`tokio::task::spawn_blocking`].
+pub fn trial_decrypt_blocks(
+    fvk: &FullViewingKey,
+    blocks: &[CompactBlock],
+) -> Vec<TrialDecryptions> {
+    use rayon::prelude::*;
+
+    enum Decrypted {
+        Note(Note),
+        Swap(SwapPlaintext),
+    }
+
+    let payloads = blocks
+        .iter()
+        .enumerate()
+        .flat_map(|(index, block)| {
+            block
+                .state_payloads
+                .iter()
+                .map(move |payload| (index, payload))
+        })
+        .collect::<Vec<_>>();
+
+    let found = payloads
+        .into_par_iter()
+        .filter_map(|(index, payload)| match payload {
+            StatePayload::Note { note, .. } => note
+                .trial_decrypt(fvk)
+                .map(|note| (index, Decrypted::Note(note))),
+            StatePayload::Swap { swap, .. } => swap
+                .trial_decrypt(fvk)
+                .map(|swap| (index, Decrypted::Swap(swap))),
+            StatePayload::RolledUp(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut decryptions = vec![TrialDecryptions::default(); blocks.len()];
+    for (index, decrypted) in found {
+        match decrypted {
+            Decrypted::Note(note) => {
+                decryptions[index].notes.insert(note.commit(), note);
+            }
+            Decrypted::Swap(swap) => {
+                decryptions[index]
+                    .swaps
+                    .insert(swap.swap_commitment(), swap);
+            }
+        }
+    }
+    decryptions
+}
+
+/// Apply the results of trial-decrypting a block to the state commitment tree
+/// and collect the new records to be committed to storage.
+///
+/// The trial decryption itself is expected to have already happened, via
+/// [`trial_decrypt_block`] or [`trial_decrypt_blocks`]; this function must be
+/// called on blocks strictly in order.
 #[tracing::instrument(skip_all, fields(height = %height))]
 pub async fn scan_block(
     fvk: &FullViewingKey,
@@ -55,67 +157,26 @@ pub async fn scan_block(
         // proposal_started,
         ..
     }: CompactBlock,
+    TrialDecryptions {
+        notes: decrypted_notes,
+        swaps: swap_advice,
+    }: TrialDecryptions,
     storage: &Storage,
 ) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
-    let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-        // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(
-            async move { note_payload.trial_decrypt(&fvk2) }.instrument(tracing::Span::current()),
-        )
-    };
-    // Trial-decrypt a swap with our own specific viewing key
-    let trial_decrypt_swap =
-        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-            // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
-            tokio::spawn(
-                async move { swap_payload.trial_decrypt(&fvk2) }
-                    .instrument(tracing::Span::current()),
-            )
-        };
-
     // Nullifiers we've found in this block
     let spent_nullifiers: Vec<Nullifier> = nullifiers;
 
-    // Trial-decrypt the notes in this block, keeping track of the ones that were meant for us
-    let mut note_decryptions = Vec::new();
-    let mut swap_decryptions = Vec::new();
-    let mut unknown_commitments = Vec::new();
-
-    for payload in state_payloads.iter() {
-        match payload {
-            StatePayload::Note { note, .. } => {
-                note_decryptions.push(trial_decrypt_note((**note).clone()));
-            }
-            StatePayload::Swap { swap, .. } => {
-                swap_decryptions.push(trial_decrypt_swap((**swap).clone()));
-            }
-            StatePayload::RolledUp(commitment) => unknown_commitments.push(*commitment),
-        }
-    }
-    // Having started trial decryption in the background, ask the Storage for scanning advice:
+    // Ask the Storage for scanning advice about the commitments we can't see
+    // into, and merge in the notes we trial-decrypted ourselves:
+    let unknown_commitments = state_payloads
+        .iter()
+        .filter_map(|payload| match payload {
+            StatePayload::RolledUp(commitment) => Some(*commitment),
+            _ => None,
+        })
+        .collect();
     let mut note_advice = storage.scan_advice(unknown_commitments).await?;
-    for decryption in note_decryptions {
-        if let Some(note) = decryption
-            .await
-            .expect("able to join tokio note decryption handle")
-        {
-            note_advice.insert(note.commit(), note);
-        }
-    }
-    let mut swap_advice = BTreeMap::new();
-    for decryption in swap_decryptions {
-        if let Some(swap) = decryption
-            .await
-            .expect("able to join tokio swap decryption handle")
-        {
-            swap_advice.insert(swap.swap_commitment(), swap);
-        }
-    }
+    note_advice.extend(decrypted_notes);
 
     // Newly detected spendable notes.
     let mut new_notes = Vec::new();
@@ -25,15 +25,22 @@ use tonic::transport::Channel;
 use url::Url;
 
 use crate::{
-    sync::{scan_block, FilteredBlock},
+    sync::{scan_block, trial_decrypt_blocks, FilteredBlock, TrialDecryptions},
     Storage,
 };
 
+/// The maximum number of blocks to trial-decrypt together in one parallel batch.
+const SCAN_BATCH_SIZE: usize = 1000;
+
+/// The number of trial-decrypted blocks that may be queued up waiting to be
+/// committed to storage before trial decryption pauses.
+const DECRYPTED_BLOCK_BUFFER: usize = 2 * SCAN_BATCH_SIZE;
+
 pub struct Worker {
     storage: Storage,
     client: ObliviousQueryServiceClient<Channel>,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
-    fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    fvk: Arc<FullViewingKey>, // TODO: notifications (see TODOs on ViewService)
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
     tm_client: TendermintProxyServiceClient<Channel>,
@@ -59,7 +66,7 @@ impl Worker {
         ),
         anyhow::Error,
     > {
-        let fvk = storage.full_viewing_key().await?;
+        let fvk = Arc::new(storage.full_viewing_key().await?);
 
         // Create a shared, in-memory SCT.
         let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
@@ -184,8 +191,62 @@ impl Worker {
             }
         });
 
-        while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
+        // Spawn a second task to trial-decrypt batches of buffered blocks in
+        // parallel on the blocking thread pool. Decryption of later blocks
+        // proceeds while earlier blocks are committed to storage below, which
+        // must happen strictly in order.
+        let (decrypted_tx, mut decrypted_blocks) =
+            tokio::sync::mpsc::channel(DECRYPTED_BLOCK_BUFFER);
+        let fvk = self.fvk.clone();
+        tokio::spawn(async move {
+            while let Some(first) = buffered_stream.recv().await {
+                let mut batch = vec![first];
+                while batch.len() < SCAN_BATCH_SIZE {
+                    match buffered_stream.try_recv() {
+                        Ok(block) => batch.push(block),
+                        Err(_) => break,
+                    }
+                }
+
+                // Parse the blocks up to the first error, which is forwarded
+                // after the blocks that preceded it.
+                let mut blocks = Vec::with_capacity(batch.len());
+                let mut error = None;
+                for block in batch {
+                    match block
+                        .map_err(anyhow::Error::from)
+                        .and_then(CompactBlock::try_from)
+                    {
+                        Ok(block) => blocks.push(block),
+                        Err(e) => {
+                            error = Some(e);
+                            break;
+                        }
+                    }
+                }
+
+                let fvk = fvk.clone();
+                let (blocks, decryptions) = tokio::task::spawn_blocking(move || {
+                    let decryptions = trial_decrypt_blocks(&fvk, &blocks);
+                    (blocks, decryptions)
+                })
+                .await
+                .expect("able to join trial decryption task");
+
+                for decrypted in blocks.into_iter().zip(decryptions) {
+                    if decrypted_tx.send(Ok(decrypted)).await.is_err() {
+                        return;
+                    }
+                }
+                if let Some(e) = error {
+                    let _ = decrypted_tx.send(Err(e)).await;
+                    return;
+                }
+            }
+        });
+
+        while let Some(decrypted) = decrypted_blocks.recv().await {
+            let (block, decryptions): (CompactBlock, TrialDecryptions) = decrypted?;
 
             let height = block.height;
 
@@ -209,7 +270,8 @@ impl Worker {
             } else {
                 // Otherwise, scan the block and commit its changes:
                 let filtered_block =
-                    scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
+                    scan_block(&self.fvk, &mut sct_guard, block, decryptions, &self.storage)
+                        .await?;
 
                 // Download any transactions we detected.
                 let transactions = self.fetch_transactions(&filtered_block).await?;
@@ -159,6 +159,7 @@ fn main() -> anyhow::Result<()> {
             ".penumbra.client.v1alpha1.ABCIQueryResponse".to_owned(),
             ".penumbra.client.v1alpha1.GetBlockByHeightResponse".to_owned(),
             ".penumbra.client.v1alpha1.GetStatusResponse".to_owned(),
+            ".penumbra.client.v1alpha1.GetValidatorSetResponse".to_owned(),
         ])
         .build(&[".penumbra"])?;
 
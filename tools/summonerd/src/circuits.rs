@@ -0,0 +1,74 @@
+use anyhow::{anyhow, Result};
+use ark_ec::pairing::Pairing;
+use ark_relations::r1cs::ConstraintMatrices;
+use decaf377::Bls12_377;
+use penumbra_dex::{swap::proof::SwapCircuit, swap_claim::proof::SwapClaimCircuit};
+use penumbra_governance::DelegatorVoteCircuit;
+use penumbra_proof_params::{
+    delegator_vote, generate_constraint_matrices, nullifier_derivation, output, spend, swap,
+    swapclaim, undelegateclaim,
+};
+use penumbra_proof_setup::{all::NUM_CIRCUITS, circuit_degree};
+use penumbra_shielded_pool::{NullifierDerivationCircuit, OutputCircuit, SpendCircuit};
+use penumbra_stake::UndelegateClaimCircuit;
+
+pub type Matrices = ConstraintMatrices<<Bls12_377 as Pairing>::ScalarField>;
+
+/// The constraint matrices of every circuit, in the order of `all::CIRCUIT_NAMES`.
+pub fn circuits() -> [Matrices; NUM_CIRCUITS] {
+    [
+        generate_constraint_matrices::<SpendCircuit>(),
+        generate_constraint_matrices::<OutputCircuit>(),
+        generate_constraint_matrices::<DelegatorVoteCircuit>(),
+        generate_constraint_matrices::<UndelegateClaimCircuit>(),
+        generate_constraint_matrices::<SwapCircuit>(),
+        generate_constraint_matrices::<SwapClaimCircuit>(),
+        generate_constraint_matrices::<NullifierDerivationCircuit>(),
+    ]
+}
+
+/// The phase 1 degree needed to run phase 2 for every circuit.
+pub fn degree(circuits: &[Matrices; NUM_CIRCUITS]) -> Result<usize> {
+    circuits
+        .iter()
+        .map(circuit_degree)
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .max()
+        .ok_or_else(|| anyhow!("no circuits to run a ceremony for"))
+}
+
+/// The keys shipped in `penumbra-proof-params` for each circuit, in the order of
+/// `all::CIRCUIT_NAMES`.
+///
+/// Each entry holds the name the keys are stored under in `proof-params/src/gen`,
+/// along with the identifiers of the proving and verifying keys.
+pub const SHIPPED_KEYS: [(&str, &str, &str); NUM_CIRCUITS] = [
+    ("spend", spend::PROVING_KEY_ID, spend::VERIFICATION_KEY_ID),
+    (
+        "output",
+        output::PROVING_KEY_ID,
+        output::VERIFICATION_KEY_ID,
+    ),
+    (
+        "delegator_vote",
+        delegator_vote::PROVING_KEY_ID,
+        delegator_vote::VERIFICATION_KEY_ID,
+    ),
+    (
+        "undelegateclaim",
+        undelegateclaim::PROVING_KEY_ID,
+        undelegateclaim::VERIFICATION_KEY_ID,
+    ),
+    ("swap", swap::PROVING_KEY_ID, swap::VERIFICATION_KEY_ID),
+    (
+        "swapclaim",
+        swapclaim::PROVING_KEY_ID,
+        swapclaim::VERIFICATION_KEY_ID,
+    ),
+    (
+        "nullifier_derivation",
+        nullifier_derivation::PROVING_KEY_ID,
+        nullifier_derivation::VERIFICATION_KEY_ID,
+    ),
+];
@@ -0,0 +1,411 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use penumbra_proof_setup::all::{Phase2CeremonyContribution, Phase2RawCeremonyContribution};
+use penumbra_proto::tools::summoning::v1alpha1::CeremonyCrs;
+use rand_core::OsRng;
+use tokio::sync::mpsc;
+use tonic::Status;
+
+use crate::{participant::Participant, queue::Queue, storage::Transcript};
+
+/// Runs the ceremony, handing out contribution slots to participants one at a time.
+pub struct Coordinator {
+    transcript: Transcript,
+    contribution_timeout: Duration,
+    new_participants: mpsc::Receiver<Participant>,
+    queue: Queue,
+}
+
+impl Coordinator {
+    pub fn new(
+        transcript: Transcript,
+        contribution_timeout: Duration,
+        new_participants: mpsc::Receiver<Participant>,
+        max_queue_length: usize,
+    ) -> Self {
+        Self {
+            transcript,
+            contribution_timeout,
+            new_participants,
+            queue: Queue::new(max_queue_length),
+        }
+    }
+
+    /// Run the coordinator until the server shuts down.
+    pub async fn run(mut self) -> Result<()> {
+        loop {
+            // Wait for someone to show up if there's nobody left in the queue...
+            if self.queue.is_empty() {
+                match self.new_participants.recv().await {
+                    Some(participant) => self.queue.push(participant).await,
+                    None => return Ok(()),
+                }
+            }
+            // ...and take in everybody else who's joined in the meantime, while there's room.
+            // Those left waiting on the channel fill it up, so that the server refuses more.
+            while !self.queue.is_full() {
+                match self.new_participants.try_recv() {
+                    Ok(participant) => self.queue.push(participant).await,
+                    Err(_) => break,
+                }
+            }
+
+            self.queue.inform().await;
+
+            if let Some(participant) = self.queue.pop() {
+                self.run_slot(participant).await?;
+            }
+        }
+    }
+
+    /// Give a participant the current slot.
+    ///
+    /// Failures on the participant's side only end their session; only failing to
+    /// record an accepted contribution is an error for the coordinator itself.
+    async fn run_slot(&mut self, mut participant: Participant) -> Result<()> {
+        let address = participant.address().to_string();
+        tracing::info!(%address, "asking participant to contribute");
+
+        let parent = self.transcript.current().clone();
+        if participant
+            .send_contribute_now(CeremonyCrs::try_from(parent.clone())?)
+            .await
+            .is_err()
+        {
+            tracing::info!(%address, "participant left before contributing");
+            return Ok(());
+        }
+
+        let contribution =
+            match tokio::time::timeout(self.contribution_timeout, participant.recv_contribution())
+                .await
+            {
+                Err(_) => {
+                    tracing::info!(%address, "participant timed out");
+                    participant
+                        .fail(Status::deadline_exceeded("contribution took too long"))
+                        .await;
+                    return Ok(());
+                }
+                Ok(Err(e)) => {
+                    tracing::info!(%address, ?e, "failed to receive contribution");
+                    participant
+                        .fail(Status::invalid_argument(e.to_string()))
+                        .await;
+                    return Ok(());
+                }
+                Ok(Ok(contribution)) => contribution,
+            };
+
+        // Validation is expensive, so keep it off of the async runtime.
+        let root = self.transcript.root().clone();
+        let validated =
+            tokio::task::spawn_blocking(move || -> Result<Phase2CeremonyContribution> {
+                let contribution = Phase2RawCeremonyContribution::parse(&parent, contribution)?
+                    .validate(&mut OsRng, &root)
+                    .ok_or_else(|| anyhow!("contribution is invalid"))?;
+                if !contribution.is_linked_to(&parent) {
+                    return Err(anyhow!(
+                        "contribution is not linked to the current elements"
+                    ));
+                }
+                Ok(contribution)
+            })
+            .await?;
+
+        match validated {
+            Err(e) => {
+                tracing::info!(%address, ?e, "rejected contribution");
+                participant
+                    .fail(Status::invalid_argument(e.to_string()))
+                    .await;
+            }
+            Ok(contribution) => {
+                let slot = self
+                    .transcript
+                    .append(participant.address(), contribution)?;
+                tracing::info!(%address, slot, "accepted contribution");
+                // The contribution is recorded either way, so it doesn't matter if they've left.
+                let _ = participant.send_confirm(slot).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSystem, ConstraintSystemRef},
+    };
+    use decaf377::Fq;
+    use penumbra_keys::Address;
+    use penumbra_proof_setup::{
+        all::{verify_phase2_transcript, Phase2CeremonyCRS, Phase2RawCeremonyCRS, NUM_CIRCUITS},
+        log::Hashable,
+        Phase1CRSElements, Phase1Contribution, Phase1RawContribution,
+    };
+    use penumbra_proto::tools::summoning::v1alpha1::{participate_request, participate_response};
+    use tonic::Code;
+
+    use super::*;
+    use crate::{
+        circuits::{self, Matrices},
+        testing::{address, join, serve, serve_to, Client},
+    };
+
+    /// A circuit with a single constraint, so that the ceremony is quick to run.
+    fn tiny_circuit() -> Matrices {
+        let cs: ConstraintSystemRef<Fq> = ConstraintSystem::new_ref();
+        let x = cs
+            .new_witness_variable(|| Ok(Fq::from(3u64)))
+            .expect("can allocate witness");
+        let y = cs
+            .new_input_variable(|| Ok(Fq::from(9u64)))
+            .expect("can allocate input");
+        cs.enforce_constraint(lc!() + x, lc!() + x, lc!() + y)
+            .expect("can enforce constraint");
+        cs.finalize();
+        cs.to_matrices().expect("can build matrices")
+    }
+
+    /// Initialize a ceremony over tiny circuits in a temporary directory.
+    fn init() -> anyhow::Result<(tempfile::TempDir, Transcript)> {
+        let dir = tempfile::tempdir()?;
+        let circuits = [(); NUM_CIRCUITS].map(|_| tiny_circuit());
+        let phase1root = Phase1CRSElements::root(circuits::degree(&circuits)?);
+        let phase1 = Phase1Contribution::make(&mut OsRng, phase1root.hash(), &phase1root);
+        let (_, root) = Phase2CeremonyCRS::root(&phase1.new_elements, &circuits)?;
+        let transcript = Transcript::init(dir.path(), &Phase1RawContribution::from(phase1), root)?;
+        Ok((dir, transcript))
+    }
+
+    /// Join the ceremony, handing the participant straight to the test.
+    async fn connect(
+        url: &str,
+        rx: &mut mpsc::Receiver<Participant>,
+        address: Address,
+    ) -> anyhow::Result<(Client, Participant)> {
+        let client = join(url, address).await?;
+        let participant = rx.recv().await.expect("participant joined");
+        Ok((client, participant))
+    }
+
+    /// Wait to be asked to contribute, returning the elements to build upon.
+    async fn contribute_now(client: &mut Client) -> Phase2CeremonyCRS {
+        loop {
+            match client.next_msg().await {
+                participate_response::Msg::Position(_) => continue,
+                participate_response::Msg::ContributeNow(contribute_now) => {
+                    return Phase2RawCeremonyCRS::try_from(
+                        contribute_now.parent.expect("parent is present"),
+                    )
+                    .expect("parent is well-formed")
+                    .assume_valid()
+                }
+                other => panic!("expected to be asked to contribute, got {other:?}"),
+            }
+        }
+    }
+
+    /// Contribute on top of `parent`, returning the new elements.
+    async fn contribute(client: &Client, parent: &Phase2CeremonyCRS) -> Phase2CeremonyCRS {
+        let contribution = Phase2CeremonyContribution::make(&mut OsRng, parent);
+        let new_elements = contribution.new_elements();
+        client
+            .send(participate_request::Msg::Contribution(
+                contribution
+                    .try_into()
+                    .expect("contribution can be encoded"),
+            ))
+            .await;
+        new_elements
+    }
+
+    async fn confirmed_slot(client: &mut Client) -> u64 {
+        match client.next_msg().await {
+            participate_response::Msg::Confirm(confirm) => confirm.slot,
+            other => panic!("expected a confirmation, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn valid_contributions_are_appended() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(8).await?;
+        let (_dir, transcript) = init()?;
+        let (_tx, new_participants) = mpsc::channel(1);
+        let mut coordinator =
+            Coordinator::new(transcript, Duration::from_secs(60), new_participants, 8);
+
+        for expected_slot in 1..=2 {
+            let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+            let (result, (slot, new_elements)) =
+                tokio::join!(coordinator.run_slot(participant), async {
+                    let parent = contribute_now(&mut client).await;
+                    let new_elements = contribute(&client, &parent).await;
+                    (confirmed_slot(&mut client).await, new_elements)
+                });
+            result?;
+            assert_eq!(slot, expected_slot);
+            assert_eq!(coordinator.transcript.current().hash(), new_elements.hash());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_contributions_are_rejected() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(8).await?;
+        let (_dir, transcript) = init()?;
+        let root = transcript.root().clone();
+        let (_tx, new_participants) = mpsc::channel(1);
+        let mut coordinator =
+            Coordinator::new(transcript, Duration::from_secs(60), new_participants, 8);
+
+        // Move the ceremony past its root, so that building on the root is stale.
+        let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+        let (result, _) = tokio::join!(coordinator.run_slot(participant), async {
+            let parent = contribute_now(&mut client).await;
+            contribute(&client, &parent).await;
+            confirmed_slot(&mut client).await
+        });
+        result?;
+        let current = coordinator.transcript.current().hash();
+
+        // A contribution that doesn't build on the current elements...
+        let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+        let (result, status) = tokio::join!(coordinator.run_slot(participant), async {
+            contribute_now(&mut client).await;
+            contribute(&client, &root).await;
+            client
+                .responses()
+                .message()
+                .await
+                .expect_err("contribution is rejected")
+        });
+        result?;
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        // ...or isn't a contribution at all, is turned away without touching the transcript.
+        let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+        let (result, status) = tokio::join!(coordinator.run_slot(participant), async {
+            contribute_now(&mut client).await;
+            client
+                .send(participate_request::Msg::Identify(
+                    participate_request::Identify {
+                        address: Some(address().into()),
+                    },
+                ))
+                .await;
+            client
+                .responses()
+                .message()
+                .await
+                .expect_err("message is rejected")
+        });
+        result?;
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        assert_eq!(coordinator.transcript.current().hash(), current);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn slow_participants_time_out() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(8).await?;
+        let (_dir, transcript) = init()?;
+        let (_tx, new_participants) = mpsc::channel(1);
+        let mut coordinator =
+            Coordinator::new(transcript, Duration::from_millis(100), new_participants, 8);
+
+        let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+        coordinator.run_slot(participant).await?;
+        contribute_now(&mut client).await;
+        let status = client
+            .responses()
+            .message()
+            .await
+            .expect_err("participant timed out");
+        assert_eq!(status.code(), Code::DeadlineExceeded);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn transcript_resumes_from_the_last_contribution() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(8).await?;
+        let (dir, transcript) = init()?;
+        let (_tx, new_participants) = mpsc::channel(1);
+        let mut coordinator =
+            Coordinator::new(transcript, Duration::from_secs(60), new_participants, 8);
+
+        let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+        let (result, _) = tokio::join!(coordinator.run_slot(participant), async {
+            let parent = contribute_now(&mut client).await;
+            contribute(&client, &parent).await;
+            confirmed_slot(&mut client).await
+        });
+        result?;
+        let current = coordinator.transcript.current().hash();
+        drop(coordinator);
+
+        // A restarted coordinator picks up where the last one left off.
+        let transcript = Transcript::load(dir.path())?;
+        assert_eq!(transcript.current().hash(), current);
+        let (_tx, new_participants) = mpsc::channel(1);
+        let mut coordinator =
+            Coordinator::new(transcript, Duration::from_secs(60), new_participants, 8);
+
+        let (mut client, participant) = connect(&url, &mut rx, address()).await?;
+        let (result, (parent, slot)) = tokio::join!(coordinator.run_slot(participant), async {
+            let parent = contribute_now(&mut client).await;
+            contribute(&client, &parent).await;
+            (parent, confirmed_slot(&mut client).await)
+        });
+        result?;
+        assert_eq!(parent.hash(), current);
+        assert_eq!(slot, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn participants_contribute_in_turn() -> anyhow::Result<()> {
+        let (dir, transcript) = init()?;
+        let root = transcript.root().clone();
+
+        // Run the real coordinator behind the gRPC frontend.
+        let (tx, new_participants) = mpsc::channel(8);
+        let coordinator =
+            Coordinator::new(transcript, Duration::from_secs(60), new_participants, 8);
+        let url = serve_to(tx).await?;
+        let coordinator = tokio::spawn(coordinator.run());
+
+        let mut clients = Vec::new();
+        for _ in 0..3 {
+            clients.push(join(&url, address()).await?);
+        }
+        let mut slots = Vec::new();
+        let mut last = root.hash();
+        for mut client in clients {
+            let parent = contribute_now(&mut client).await;
+            // Each participant builds upon the contribution before theirs.
+            assert_eq!(parent.hash(), last);
+            last = contribute(&client, &parent).await.hash();
+            slots.push(confirmed_slot(&mut client).await);
+        }
+        assert_eq!(slots, [1, 2, 3]);
+        coordinator.abort();
+
+        // The transcript verifies from the root to the last contribution.
+        let (_, contributions) = Transcript::read_phase2(dir.path())?;
+        assert_eq!(contributions.len(), 3);
+        let verified = verify_phase2_transcript(&mut OsRng, &root, contributions)?;
+        assert_eq!(verified.hash(), last);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{fs, io::BufWriter, path::Path};
+
+use anyhow::{anyhow, Result};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::CanonicalSerialize;
+use decaf377::Bls12_377;
+use penumbra_proof_params::{ProvingKeyExt, VerifyingKeyExt};
+use penumbra_proof_setup::{
+    all::{
+        combine_all, verify_phase1_transcript, verify_phase2_transcript, Phase2CeremonyCRS,
+        Phase2RawCeremonyCRS, CIRCUIT_NAMES,
+    },
+    log::Hashable,
+};
+use rand_core::OsRng;
+
+use crate::{circuits, storage::Transcript};
+
+/// Verify every link in a ceremony transcript, and write out the keys it produces.
+///
+/// The keys are written in the same layout as `penumbra-proof-params` uses in `src/gen`.
+/// Unless `check_ids` is false, this fails if the keys don't match the ones shipped there.
+pub fn export(storage_dir: &Path, target_dir: &Path, check_ids: bool) -> Result<()> {
+    let circuits = circuits::circuits();
+    let degree = circuits::degree(&circuits)?;
+
+    tracing::info!(degree, "verifying phase 1");
+    let phase1_contributions = Transcript::read_phase1(storage_dir)?;
+    if phase1_contributions.is_empty() {
+        return Err(anyhow!("the transcript has no phase 1 contributions"));
+    }
+    let phase1 = verify_phase1_transcript(&mut OsRng, degree, phase1_contributions)?;
+
+    // We derive the phase 2 root ourselves, rather than trusting the one in the transcript.
+    tracing::info!("transitioning to phase 2");
+    let (root, extras) = Phase2CeremonyCRS::root(&phase1, &circuits)?;
+    let (stored_root, phase2_contributions) = Transcript::read_phase2(storage_dir)?;
+    if Phase2RawCeremonyCRS::try_from(stored_root)?
+        .assume_valid()
+        .hash()
+        != root.hash()
+    {
+        return Err(anyhow!(
+            "the phase 2 root in the transcript doesn't match the output of phase 1"
+        ));
+    }
+
+    tracing::info!(
+        contributions = phase2_contributions.len(),
+        "verifying phase 2"
+    );
+    if phase2_contributions.is_empty() {
+        return Err(anyhow!("the transcript has no phase 2 contributions"));
+    }
+    let phase2 = verify_phase2_transcript(&mut OsRng, &root, phase2_contributions)?;
+
+    let proving_keys = combine_all(&circuits, &phase1, &phase2, &extras);
+
+    fs::create_dir_all(target_dir)?;
+    let mut mismatches = Vec::new();
+    for ((circuit, (name, shipped_pk_id, shipped_vk_id)), pk) in CIRCUIT_NAMES
+        .iter()
+        .zip(circuits::SHIPPED_KEYS)
+        .zip(proving_keys)
+    {
+        let (pk_id, vk_id) = write_params(target_dir, name, &pk, &pk.vk)?;
+        tracing::info!(circuit, %pk_id, %vk_id, "wrote keys");
+        if pk_id != shipped_pk_id || vk_id != shipped_vk_id {
+            mismatches.push(*circuit);
+        }
+    }
+
+    if check_ids && !mismatches.is_empty() {
+        return Err(anyhow!(
+            "the keys for {} don't match the ones shipped in penumbra-proof-params",
+            mismatches.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Write the keys for a circuit, returning their identifiers.
+fn write_params(
+    target_dir: &Path,
+    name: &str,
+    pk: &ProvingKey<Bls12_377>,
+    vk: &VerifyingKey<Bls12_377>,
+) -> Result<(String, String)> {
+    let pk_location = target_dir.join(format!("{}_pk.bin", name));
+    let vk_location = target_dir.join(format!("{}_vk.param", name));
+    let id_location = target_dir.join(format!("{}_id.rs", name));
+
+    pk.serialize_uncompressed(BufWriter::new(fs::File::create(&pk_location)?))?;
+    vk.serialize_uncompressed(BufWriter::new(fs::File::create(&vk_location)?))?;
+
+    let pk_id = pk.debug_id();
+    let vk_id = vk.debug_id();
+    fs::write(
+        id_location,
+        format!(
+            r#"
+pub const PROVING_KEY_ID: &'static str = "{pk_id}";
+pub const VERIFICATION_KEY_ID: &'static str = "{vk_id}";
+"#,
+        ),
+    )?;
+
+    Ok((pk_id, vk_id))
+}
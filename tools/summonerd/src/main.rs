@@ -0,0 +1,147 @@
+#![deny(clippy::unwrap_used)]
+mod circuits;
+mod coordinator;
+mod export;
+mod participant;
+mod queue;
+mod server;
+mod storage;
+#[cfg(test)]
+mod testing;
+
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use penumbra_proof_setup::{
+    all::Phase2CeremonyCRS, log::Hashable, Phase1CRSElements, Phase1Contribution,
+    Phase1RawContribution,
+};
+use penumbra_proto::tools::summoning::v1alpha1::ceremony_coordinator_service_server::CeremonyCoordinatorServiceServer;
+use rand_core::OsRng;
+use tokio::sync::mpsc;
+use tonic::transport::Server;
+use tracing_subscriber::EnvFilter;
+
+use coordinator::Coordinator;
+use server::CoordinatorService;
+use storage::Transcript;
+
+/// The largest message we accept, which needs to fit the CRS elements of every circuit.
+const MAX_MESSAGE_SIZE: usize = 1 << 30;
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "summonerd",
+    about = "Coordinates the summoning ceremony, Penumbra's trusted setup.",
+    version
+)]
+struct Opt {
+    #[clap(subcommand)]
+    cmd: Command,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+    /// Initialize a new ceremony, computing the root elements for every circuit.
+    Init {
+        /// The directory holding the ceremony transcript.
+        #[clap(long)]
+        storage_dir: PathBuf,
+    },
+    /// Start coordinating contributions to an initialized ceremony.
+    Start {
+        /// The directory holding the ceremony transcript.
+        #[clap(long)]
+        storage_dir: PathBuf,
+        /// The address to listen for participants on.
+        #[clap(long, default_value = "127.0.0.1:8081")]
+        listen: SocketAddr,
+        /// How long, in seconds, a participant has to send their contribution.
+        #[clap(long, default_value = "600")]
+        contribution_timeout: u64,
+        /// How many participants may wait for a slot before new ones are turned away.
+        #[clap(long, default_value = "100")]
+        max_queue_length: usize,
+    },
+    /// Verify every contribution in a ceremony transcript, and write out the resulting keys.
+    ///
+    /// By default, this also checks that the keys match those shipped in `penumbra-proof-params`.
+    Export {
+        /// The directory holding the ceremony transcript.
+        #[clap(long)]
+        storage_dir: PathBuf,
+        /// The directory to write the proving and verifying keys to.
+        #[clap(long)]
+        target_dir: PathBuf,
+        /// Don't fail if the keys differ from those shipped in `penumbra-proof-params`.
+        #[clap(long)]
+        skip_id_check: bool,
+    },
+}
+
+impl Opt {
+    async fn exec(self) -> Result<()> {
+        match self.cmd {
+            Command::Init { storage_dir } => {
+                let circuits = circuits::circuits();
+                let degree = circuits::degree(&circuits)?;
+
+                // Phase 1 isn't coordinated by this server, so we stand in for its
+                // ceremony with a single local contribution.
+                tracing::info!(degree, "running phase 1");
+                let phase1root = Phase1CRSElements::root(degree);
+                let phase1 = Phase1Contribution::make(&mut OsRng, phase1root.hash(), &phase1root);
+
+                tracing::info!("transitioning to phase 2");
+                let (_, root) = Phase2CeremonyCRS::root(&phase1.new_elements, &circuits)?;
+
+                Transcript::init(&storage_dir, &Phase1RawContribution::from(phase1), root)?;
+                tracing::info!(storage_dir = %storage_dir.display(), "initialized ceremony");
+                Ok(())
+            }
+            Command::Start {
+                storage_dir,
+                listen,
+                contribution_timeout,
+                max_queue_length,
+            } => {
+                let transcript = Transcript::load(&storage_dir)?;
+                let (tx, rx) = mpsc::channel(max_queue_length.max(1));
+                let coordinator = Coordinator::new(
+                    transcript,
+                    Duration::from_secs(contribution_timeout),
+                    rx,
+                    max_queue_length,
+                );
+                let service = CeremonyCoordinatorServiceServer::new(CoordinatorService::new(tx))
+                    .max_decoding_message_size(MAX_MESSAGE_SIZE)
+                    .max_encoding_message_size(MAX_MESSAGE_SIZE);
+
+                tracing::info!(%listen, "starting coordinator");
+                tokio::select! {
+                    res = coordinator.run() => res,
+                    res = Server::builder().add_service(service).serve(listen) => {
+                        res.map_err(|e| anyhow!(e))
+                    }
+                }
+            }
+            Command::Export {
+                storage_dir,
+                target_dir,
+                skip_id_check,
+            } => export::export(&storage_dir, &target_dir, !skip_id_check),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    Opt::parse().exec().await
+}
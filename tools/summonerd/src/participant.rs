@@ -0,0 +1,95 @@
+use anyhow::{anyhow, Result};
+use penumbra_keys::Address;
+use penumbra_proto::tools::summoning::v1alpha1::{
+    participate_request, participate_response, CeremonyCrs, ParticipateRequest, ParticipateResponse,
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Status, Streaming};
+
+/// A participant connected to the coordinator.
+///
+/// This wraps both halves of the participant's `Participate` stream.
+pub struct Participant {
+    address: Address,
+    rx: Streaming<ParticipateRequest>,
+    tx: mpsc::Sender<Result<ParticipateResponse, Status>>,
+}
+
+impl Participant {
+    /// Create a new participant, returning the stream of responses to send back to them.
+    pub fn new(
+        address: Address,
+        rx: Streaming<ParticipateRequest>,
+    ) -> (Self, ReceiverStream<Result<ParticipateResponse, Status>>) {
+        let (tx, responses) = mpsc::channel(8);
+        (Self { address, rx, tx }, ReceiverStream::new(responses))
+    }
+
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Check whether the participant has hung up.
+    pub fn is_disconnected(&self) -> bool {
+        self.tx.is_closed()
+    }
+
+    async fn send(&self, msg: participate_response::Msg) -> Result<()> {
+        self.tx
+            .send(Ok(ParticipateResponse { msg: Some(msg) }))
+            .await
+            .map_err(|_| anyhow!("participant disconnected"))
+    }
+
+    /// Inform the participant of their position in the queue.
+    pub async fn send_position(&self, position: u32, connected_participants: u32) -> Result<()> {
+        self.send(participate_response::Msg::Position(
+            participate_response::Position {
+                position,
+                connected_participants,
+                // We don't run an auction for slots, so there are no bids to report.
+                last_slot_bid: None,
+                your_bid: None,
+            },
+        ))
+        .await
+    }
+
+    /// Ask the participant to contribute, building upon the given elements.
+    pub async fn send_contribute_now(&self, parent: CeremonyCrs) -> Result<()> {
+        self.send(participate_response::Msg::ContributeNow(
+            participate_response::ContributeNow {
+                parent: Some(parent),
+            },
+        ))
+        .await
+    }
+
+    /// Confirm to the participant that their contribution was accepted.
+    pub async fn send_confirm(&self, slot: u64) -> Result<()> {
+        self.send(participate_response::Msg::Confirm(
+            participate_response::Confirm { slot },
+        ))
+        .await
+    }
+
+    /// End the participant's session with an error.
+    pub async fn fail(self, status: Status) {
+        // If they've already hung up, there's nobody left to tell.
+        let _ = self.tx.send(Err(status)).await;
+    }
+
+    /// Wait for the participant to send their contribution.
+    pub async fn recv_contribution(&mut self) -> Result<participate_request::Contribution> {
+        let msg = self
+            .rx
+            .message()
+            .await?
+            .ok_or_else(|| anyhow!("participant disconnected"))?;
+        match msg.msg {
+            Some(participate_request::Msg::Contribution(contribution)) => Ok(contribution),
+            _ => Err(anyhow!("expected a contribution message")),
+        }
+    }
+}
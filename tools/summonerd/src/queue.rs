@@ -0,0 +1,185 @@
+use std::collections::VecDeque;
+
+use tonic::Status;
+
+use crate::participant::Participant;
+
+/// The participants waiting for a contribution slot, in the order they joined.
+///
+/// Each address holds at most one place in the queue, and the coordinator stops taking in
+/// participants once it holds `max_len` of them.
+pub struct Queue {
+    participants: VecDeque<Participant>,
+    max_len: usize,
+}
+
+impl Queue {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            participants: VecDeque::new(),
+            max_len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.participants.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.participants.len() >= self.max_len
+    }
+
+    /// Add a participant to the back of the queue.
+    ///
+    /// Each address holds at most one place. Anybody can claim to be any address, so a new
+    /// connection with the address of a participant who's still connected is refused, rather
+    /// than letting it take their place. If they've hung up, the new connection is them
+    /// reconnecting, and takes over their place.
+    pub async fn push(&mut self, participant: Participant) {
+        match self
+            .participants
+            .iter_mut()
+            .find(|queued| queued.address() == participant.address())
+        {
+            Some(queued) if queued.is_disconnected() => {
+                tracing::info!(address = %participant.address(), "participant reconnected");
+                *queued = participant;
+            }
+            Some(_) => {
+                tracing::info!(
+                    address = %participant.address(),
+                    "refusing participant whose address is already queued"
+                );
+                participant
+                    .fail(Status::already_exists(
+                        "a participant with this address is already connected",
+                    ))
+                    .await;
+            }
+            None => self.participants.push_back(participant),
+        }
+    }
+
+    /// Take the participant at the front of the queue.
+    pub fn pop(&mut self) -> Option<Participant> {
+        self.participants.pop_front()
+    }
+
+    /// Tell every participant in the queue where they stand, dropping those who've left.
+    pub async fn inform(&mut self) {
+        self.participants.retain(|p| !p.is_disconnected());
+        let connected_participants = self.participants.len() as u32;
+        let mut remaining = VecDeque::with_capacity(self.participants.len());
+        for participant in self.participants.drain(..) {
+            let position = remaining.len() as u32 + 1;
+            match participant
+                .send_position(position, connected_participants)
+                .await
+            {
+                Ok(()) => remaining.push_back(participant),
+                Err(_) => tracing::info!(address = %participant.address(), "participant left"),
+            }
+        }
+        self.participants = remaining;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tonic::Code;
+
+    use super::*;
+    use crate::testing::{address, join, serve};
+
+    #[tokio::test]
+    async fn connected_participant_keeps_their_place() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(8).await?;
+        let mut queue = Queue::new(8);
+        let (alice, bob) = (address(), address());
+
+        let mut alice_client = join(&url, alice).await?;
+        let mut bob_client = join(&url, bob).await?;
+        let mut impostor = join(&url, alice).await?;
+        for _ in 0..3 {
+            queue
+                .push(rx.recv().await.expect("participant joined"))
+                .await;
+        }
+        // The second connection claiming Alice's address is refused, and can't take her place.
+        let status = impostor
+            .responses()
+            .message()
+            .await
+            .expect_err("duplicate connection is refused");
+        assert_eq!(status.code(), Code::AlreadyExists);
+
+        queue.inform().await;
+        assert_eq!(alice_client.next_position().await, (1, 2));
+        assert_eq!(bob_client.next_position().await, (2, 2));
+        assert_eq!(queue.pop().expect("queue is not empty").address(), &alice);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reconnecting_participant_keeps_their_place() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(8).await?;
+        let mut queue = Queue::new(8);
+        let (alice, bob) = (address(), address());
+
+        let alice_client = join(&url, alice).await?;
+        queue
+            .push(rx.recv().await.expect("participant joined"))
+            .await;
+        let mut bob_client = join(&url, bob).await?;
+        queue
+            .push(rx.recv().await.expect("participant joined"))
+            .await;
+
+        // Alice hangs up, and the coordinator notices once her response stream is dropped.
+        drop(alice_client);
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !queue.participants[0].is_disconnected() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+
+        // Her new connection takes over her place ahead of Bob.
+        let mut alice_client = join(&url, alice).await?;
+        queue
+            .push(rx.recv().await.expect("participant joined"))
+            .await;
+        queue.inform().await;
+        assert_eq!(alice_client.next_position().await, (1, 2));
+        assert_eq!(bob_client.next_position().await, (2, 2));
+        assert_eq!(queue.pop().expect("queue is not empty").address(), &alice);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn full_queue_refuses_participants() -> anyhow::Result<()> {
+        let (url, mut rx) = serve(1).await?;
+        let mut queue = Queue::new(1);
+
+        let first = join(&url, address()).await?;
+        first.responses?;
+        queue
+            .push(rx.recv().await.expect("participant joined"))
+            .await;
+        assert!(queue.is_full());
+
+        // The next participant waits to be taken into the queue, and there's no room for
+        // the one after that.
+        let second = join(&url, address()).await?;
+        second.responses?;
+        let third = join(&url, address()).await?;
+        let status = third.responses.expect_err("participant is refused");
+        assert_eq!(status.code(), Code::ResourceExhausted);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use penumbra_keys::Address;
+use penumbra_proto::tools::summoning::v1alpha1::{
+    ceremony_coordinator_service_server::CeremonyCoordinatorService, participate_request,
+    ParticipateRequest, ParticipateResponse,
+};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::participant::Participant;
+
+/// The gRPC frontend of the coordinator.
+///
+/// This only identifies participants, before handing them off to the coordinator's queue.
+pub struct CoordinatorService {
+    participants: mpsc::Sender<Participant>,
+}
+
+impl CoordinatorService {
+    pub fn new(participants: mpsc::Sender<Participant>) -> Self {
+        Self { participants }
+    }
+}
+
+#[tonic::async_trait]
+impl CeremonyCoordinatorService for CoordinatorService {
+    type ParticipateStream = ReceiverStream<Result<ParticipateResponse, Status>>;
+
+    async fn participate(
+        &self,
+        request: Request<Streaming<ParticipateRequest>>,
+    ) -> Result<Response<Self::ParticipateStream>, Status> {
+        let mut requests = request.into_inner();
+        let identify = match requests.message().await? {
+            Some(ParticipateRequest {
+                msg: Some(participate_request::Msg::Identify(identify)),
+            }) => identify,
+            _ => {
+                return Err(Status::invalid_argument(
+                    "first message must identify the participant",
+                ))
+            }
+        };
+        let address: Address = identify
+            .address
+            .ok_or_else(|| Status::invalid_argument("missing address"))?
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("invalid address: {e}")))?;
+
+        tracing::info!(%address, "participant connected");
+        let (participant, responses) = Participant::new(address, requests);
+        self.participants
+            .try_send(participant)
+            .map_err(|e| match e {
+                TrySendError::Full(_) => {
+                    Status::resource_exhausted("the ceremony queue is full, try again later")
+                }
+                TrySendError::Closed(_) => Status::unavailable("the coordinator has shut down"),
+            })?;
+
+        Ok(Response::new(responses))
+    }
+}
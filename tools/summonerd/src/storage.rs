@@ -0,0 +1,189 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use penumbra_keys::Address;
+use penumbra_proof_setup::{
+    all::{
+        Phase2CeremonyCRS, Phase2CeremonyContribution, Phase2RawCeremonyCRS,
+        Phase2RawCeremonyContribution,
+    },
+    Phase1RawContribution,
+};
+use penumbra_proto::tools::summoning::v1alpha1::{self as pb, participate_request};
+use prost::Message;
+
+/// The on-disk transcript of the ceremony.
+///
+/// Every accepted contribution is appended to the transcript before it is confirmed
+/// to its participant, so the transcript always reflects the current state of the ceremony.
+pub struct Transcript {
+    dir: PathBuf,
+    root: Phase2CeremonyCRS,
+    current: Phase2CeremonyCRS,
+    slot: u64,
+}
+
+fn phase1_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join("phase1").join(format!("{index:06}.bin"))
+}
+
+fn root_path(dir: &Path) -> PathBuf {
+    dir.join("phase2").join("root.bin")
+}
+
+fn contribution_path(dir: &Path, slot: u64) -> PathBuf {
+    dir.join("phase2").join(format!("{slot:06}.bin"))
+}
+
+fn address_path(dir: &Path, slot: u64) -> PathBuf {
+    dir.join("phase2").join(format!("{slot:06}.address"))
+}
+
+/// Write a file atomically, so that a crash never leaves a partial entry in the transcript.
+fn write_atomic(path: &Path, data: &[u8]) -> Result<()> {
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, data).with_context(|| format!("failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, path).with_context(|| format!("failed to rename {}", tmp.display()))?;
+    Ok(())
+}
+
+impl Transcript {
+    /// Create a new transcript, starting from the given root elements.
+    pub fn init(
+        dir: &Path,
+        phase1: &Phase1RawContribution,
+        root: Phase2CeremonyCRS,
+    ) -> Result<Self> {
+        if root_path(dir).exists() {
+            return Err(anyhow!(
+                "a ceremony already exists in {}, refusing to overwrite it",
+                dir.display()
+            ));
+        }
+        fs::create_dir_all(dir.join("phase1"))?;
+        fs::create_dir_all(dir.join("phase2"))?;
+
+        let mut phase1_bytes = Vec::new();
+        phase1.serialize_uncompressed(&mut phase1_bytes)?;
+        write_atomic(&phase1_path(dir, 0), &phase1_bytes)?;
+
+        let root_proto = pb::CeremonyCrs::try_from(root.clone())?;
+        write_atomic(&root_path(dir), &root_proto.encode_to_vec())?;
+
+        Ok(Self {
+            dir: dir.to_owned(),
+            current: root.clone(),
+            root,
+            slot: 0,
+        })
+    }
+
+    /// Load an existing transcript, resuming from its last contribution.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let root_bytes = fs::read(root_path(dir))
+            .with_context(|| format!("no ceremony found in {}", dir.display()))?;
+        // The transcript only ever contains elements we've already validated,
+        // so we don't need to pay for validating them again here.
+        let root = Phase2RawCeremonyCRS::try_from(pb::CeremonyCrs::decode(root_bytes.as_slice())?)?
+            .assume_valid();
+
+        let mut current = root.clone();
+        let mut slot = 0;
+        loop {
+            let path = contribution_path(dir, slot + 1);
+            if !path.exists() {
+                break;
+            }
+            let proto = participate_request::Contribution::decode(fs::read(&path)?.as_slice())?;
+            let contribution = Phase2RawCeremonyContribution::parse(&current, proto)
+                .with_context(|| format!("failed to parse {}", path.display()))?
+                .assume_valid();
+            current = contribution.new_elements();
+            slot += 1;
+        }
+        tracing::info!(slot, "loaded ceremony transcript");
+
+        Ok(Self {
+            dir: dir.to_owned(),
+            root,
+            current,
+            slot,
+        })
+    }
+
+    /// The root elements of the ceremony, which every contribution is validated against.
+    pub fn root(&self) -> &Phase2CeremonyCRS {
+        &self.root
+    }
+
+    /// The most recent elements, which the next contribution should build upon.
+    pub fn current(&self) -> &Phase2CeremonyCRS {
+        &self.current
+    }
+
+    /// Append a contribution to the transcript, returning the slot it was recorded in.
+    ///
+    /// The contribution must have already been validated, and linked to the current elements.
+    pub fn append(
+        &mut self,
+        address: &Address,
+        contribution: Phase2CeremonyContribution,
+    ) -> Result<u64> {
+        let slot = self.slot + 1;
+        let new_elements = contribution.new_elements();
+        let proto = participate_request::Contribution::try_from(contribution)?;
+        write_atomic(
+            &address_path(&self.dir, slot),
+            address.to_string().as_bytes(),
+        )?;
+        write_atomic(&contribution_path(&self.dir, slot), &proto.encode_to_vec())?;
+
+        self.current = new_elements;
+        self.slot = slot;
+        Ok(slot)
+    }
+
+    /// Read the raw phase 1 contributions in a transcript, in order.
+    pub fn read_phase1(dir: &Path) -> Result<Vec<Phase1RawContribution>> {
+        let mut out = Vec::new();
+        loop {
+            let path = phase1_path(dir, out.len() as u64);
+            if !path.exists() {
+                break;
+            }
+            let contribution =
+                Phase1RawContribution::deserialize_uncompressed(fs::read(&path)?.as_slice())
+                    .with_context(|| format!("failed to parse {}", path.display()))?;
+            out.push(contribution);
+        }
+        Ok(out)
+    }
+
+    /// Read the root elements and raw phase 2 contributions in a transcript, in order.
+    ///
+    /// Unlike [`Transcript::load`], nothing read here is assumed to be valid.
+    pub fn read_phase2(
+        dir: &Path,
+    ) -> Result<(pb::CeremonyCrs, Vec<participate_request::Contribution>)> {
+        let root = pb::CeremonyCrs::decode(
+            fs::read(root_path(dir))
+                .with_context(|| format!("no ceremony found in {}", dir.display()))?
+                .as_slice(),
+        )?;
+        let mut contributions = Vec::new();
+        loop {
+            let path = contribution_path(dir, contributions.len() as u64 + 1);
+            if !path.exists() {
+                break;
+            }
+            contributions.push(participate_request::Contribution::decode(
+                fs::read(&path)?.as_slice(),
+            )?);
+        }
+        Ok((root, contributions))
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers for testing the coordinator against participants connected over gRPC.
+
+use penumbra_keys::{
+    keys::{SeedPhrase, SpendKey},
+    Address,
+};
+use penumbra_proto::tools::summoning::v1alpha1::{
+    ceremony_coordinator_service_client::CeremonyCoordinatorServiceClient,
+    ceremony_coordinator_service_server::CeremonyCoordinatorServiceServer, participate_request,
+    participate_response, ParticipateRequest, ParticipateResponse,
+};
+use rand_core::OsRng;
+use tokio::{net::TcpListener, sync::mpsc};
+use tokio_stream::{
+    wrappers::{ReceiverStream, TcpListenerStream},
+    StreamExt,
+};
+use tonic::{
+    transport::{Channel, Server},
+    Status, Streaming,
+};
+
+use crate::{participant::Participant, server::CoordinatorService};
+
+pub fn address() -> Address {
+    let spend_key = SpendKey::from_seed_phrase_bip39(SeedPhrase::generate(OsRng), 0);
+    spend_key.full_viewing_key().payment_address(0u32.into()).0
+}
+
+/// Serve the coordinator's gRPC frontend, handing participants off to a channel of
+/// `capacity` that the test takes them from in place of the coordinator.
+pub async fn serve(capacity: usize) -> anyhow::Result<(String, mpsc::Receiver<Participant>)> {
+    let (tx, rx) = mpsc::channel(capacity);
+    Ok((serve_to(tx).await?, rx))
+}
+
+/// Serve the coordinator's gRPC frontend, handing participants off to `participants`, and
+/// returning the URL to join at.
+pub async fn serve_to(participants: mpsc::Sender<Participant>) -> anyhow::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(
+        Server::builder()
+            .add_service(CeremonyCoordinatorServiceServer::new(
+                CoordinatorService::new(participants),
+            ))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    Ok(url)
+}
+
+/// A participant's end of a connection to the coordinator.
+pub struct Client {
+    /// Keeps the connection open.
+    pub _client: CeremonyCoordinatorServiceClient<Channel>,
+    /// Sends the participant's messages after they've identified themselves.
+    pub requests: mpsc::Sender<ParticipateRequest>,
+    pub responses: Result<Streaming<ParticipateResponse>, Status>,
+}
+
+impl Client {
+    /// The stream of responses, if the coordinator accepted the connection.
+    pub fn responses(&mut self) -> &mut Streaming<ParticipateResponse> {
+        self.responses
+            .as_mut()
+            .expect("coordinator accepted the connection")
+    }
+
+    pub async fn next_msg(&mut self) -> participate_response::Msg {
+        match self.responses().message().await {
+            Ok(Some(ParticipateResponse { msg: Some(msg) })) => msg,
+            other => panic!("expected a message, got {other:?}"),
+        }
+    }
+
+    pub async fn next_position(&mut self) -> (u32, u32) {
+        match self.next_msg().await {
+            participate_response::Msg::Position(position) => {
+                (position.position, position.connected_participants)
+            }
+            other => panic!("expected a position, got {other:?}"),
+        }
+    }
+
+    pub async fn send(&self, msg: participate_request::Msg) {
+        self.requests
+            .send(ParticipateRequest { msg: Some(msg) })
+            .await
+            .expect("connection is open");
+    }
+}
+
+/// Join the ceremony as `address`.
+pub async fn join(url: &str, address: Address) -> anyhow::Result<Client> {
+    let mut client = CeremonyCoordinatorServiceClient::connect(url.to_owned()).await?;
+    let identify = ParticipateRequest {
+        msg: Some(participate_request::Msg::Identify(
+            participate_request::Identify {
+                address: Some(address.into()),
+            },
+        )),
+    };
+    // Keep the request stream open, as a participant waiting for their slot would.
+    let (requests, rx) = mpsc::channel(8);
+    let responses = client
+        .participate(tokio_stream::iter(vec![identify]).chain(ReceiverStream::new(rx)))
+        .await
+        .map(|response| response.into_inner());
+    Ok(Client {
+        _client: client,
+        requests,
+        responses,
+    })
+}